@@ -0,0 +1,69 @@
+//! Generates the built-in extrusion profiles from NopSCADLib's `extrusions.scad`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/nopscadlib/scad.rs"]
+mod scad;
+
+#[allow(dead_code)]
+#[path = "src/nopscadlib/layout.rs"]
+mod layout;
+
+const EXTRUSIONS: &str = "data/nopscadlib/extrusions.scad";
+
+fn main() {
+    for path in [EXTRUSIONS, "src/nopscadlib/scad.rs", "src/nopscadlib/layout.rs"] {
+        println!("cargo:rerun-if-changed={}", path);
+    }
+
+    let text = fs::read_to_string(EXTRUSIONS).unwrap_or_else(|e| panic!("{}: {}", EXTRUSIONS, e));
+    let assignments = scad::parse(&text).unwrap_or_else(|e| panic!("{}: {}", EXTRUSIONS, e));
+    let mut code = String::new();
+    let mut names = Vec::new();
+    for (variable, value) in &assignments {
+        let Some(record) = layout::EXTRUSIONS.record(variable, value) else { continue };
+        let get = |parameter: &str| {
+            record
+                .parameters
+                .iter()
+                .rev()
+                .find(|(key, _)| *key == parameter)
+                .map(|&(_, value)| value)
+                .unwrap_or_else(|| panic!("{}: `{}` has no `{}`", EXTRUSIONS, variable, parameter))
+        };
+        let recess = match (get("recess_width"), get("recess_depth")) {
+            (width, depth) if width > 0.0 && depth > 0.0 => format!("ChannelRecess::Some({:?}, {:?})", width, depth),
+            _ => "ChannelRecess::None".to_string(),
+        };
+        let constant = variable.to_uppercase();
+        writeln!(code, "/// `{}` from NopSCADLib's `extrusions.scad`.", variable).unwrap();
+        writeln!(code, "pub const {}: ExtrusionProfile = ExtrusionProfile {{", constant).unwrap();
+        writeln!(code, "    name: Cow::Borrowed({:?}),", record.name).unwrap();
+        for field in [
+            "width",
+            "height",
+            "center_hole_wd",
+            "corner_hole_wd",
+            "center_square_wd",
+            "channel_width",
+            "channel_width_internal",
+            "tab_thickness",
+            "spar_thickness",
+            "fillet_radius",
+        ] {
+            writeln!(code, "    {}: {:?},", field, get(field)).unwrap();
+        }
+        writeln!(code, "    recess: {},", recess).unwrap();
+        writeln!(code, "}};\n").unwrap();
+        names.push(constant);
+    }
+    writeln!(code, "/// Every profile in NopSCADLib's `extrusions.scad`, in file order.").unwrap();
+    writeln!(code, "pub const ALL_EXTRUSIONS: &[ExtrusionProfile] = &[{}];", names.join(", ")).unwrap();
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("extrusions.rs");
+    fs::write(&out, code).unwrap_or_else(|e| panic!("{}: {}", out.display(), e));
+}
@@ -0,0 +1,108 @@
+//! Named frames on parts and placing one part against another by them, like the `*_hole_positions()`
+//! and `*_screw_pos()` conventions of NopSCADLib made into data.
+//!
+//! An anchor's Z axis points out of the part, the way a mating part arrives, and its X axis
+//! fixes the spin about it. Two anchors mate face to face: their origins meet, their Z axes
+//! oppose and their X axes agree.
+
+use crate::assembly::Transform;
+use crate::error::PartError;
+use crate::mounting::Mountable;
+use crate::part::Part;
+use nalgebra::{Isometry3, Matrix3, Rotation3, Translation3, UnitQuaternion, Vector3};
+use std::f64::consts::PI;
+
+/// A named coordinate frame on a part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Anchor {
+    pub name: String,
+    /// The frame in the part's own coordinates.
+    pub frame: Isometry3<f64>,
+}
+
+impl Anchor {
+    pub fn new(name: impl Into<String>, frame: Isometry3<f64>) -> Self {
+        Anchor { name: name.into(), frame }
+    }
+
+    /// An anchor at `origin` with Z along `normal`.
+    ///
+    /// Facing up leaves the part's axes as they are and facing down turns them over about X,
+    /// as [`mounting::on_bottom`](crate::mounting::on_bottom) does. Other directions take the
+    /// shortest rotation from +Z.
+    pub fn facing(name: impl Into<String>, origin: Vector3<f64>, normal: Vector3<f64>) -> Self {
+        let rotation = UnitQuaternion::rotation_between(&Vector3::z(), &normal)
+            .unwrap_or_else(|| UnitQuaternion::from_axis_angle(&Vector3::x_axis(), PI));
+        Anchor::new(name, Isometry3::from_parts(Translation3::from(origin), rotation))
+    }
+
+    /// An anchor at `origin` with Z along a horizontal `normal` and Y up, so text on a side
+    /// face reads from outside the way it would on the top
+    pub fn upright(name: impl Into<String>, origin: Vector3<f64>, normal: Vector3<f64>) -> Self {
+        let rotation = UnitQuaternion::face_towards(&normal, &Vector3::z());
+        Anchor::new(name, Isometry3::from_parts(Translation3::from(origin), rotation))
+    }
+}
+
+/// Transform that places `b` so its anchor `anchor_b` mates with `a`'s anchor `anchor_a`.
+///
+/// The transform is in `a`'s coordinates, so for an `a` placed at `t` in an assembly, place
+/// `b` at `attach(..)?.then(&t)`.
+pub fn attach<A, B>(a: &A, anchor_a: &str, b: &B, anchor_b: &str) -> Result<Transform, PartError>
+where
+    A: Part + ?Sized,
+    B: Part + ?Sized,
+{
+    let frame_a = a.anchor(anchor_a).ok_or_else(|| unknown(a.name(), anchor_a))?;
+    let frame_b = b.anchor(anchor_b).ok_or_else(|| unknown(b.name(), anchor_b))?;
+    Ok(mate(&frame_a, &frame_b))
+}
+
+fn unknown(part: &str, anchor: &str) -> PartError {
+    PartError::UnknownAnchor { part: part.to_string(), anchor: anchor.to_string() }
+}
+
+/// Transform that brings frame `b` face to face with frame `a`
+pub fn mate(a: &Isometry3<f64>, b: &Isometry3<f64>) -> Transform {
+    let turn_over = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), PI);
+    Transform::from_matrix((a * turn_over * b.inverse()).to_homogeneous())
+}
+
+/// Anchors `hole_0`, `hole_1`, ... at each of a mountable part's hole positions
+pub fn holes<M: Mountable + ?Sized>(part: &M) -> Vec<Anchor> {
+    part.hole_positions()
+        .into_iter()
+        .enumerate()
+        .map(|(i, frame)| Anchor::new(format!("hole_{}", i), frame))
+        .collect()
+}
+
+/// Anchors at the middle of each face of a box from `mins` to `maxs`: `top` and `bottom`,
+/// then `front` (-Y), `back`, `left` (-X) and `right` upright
+pub fn box_faces(mins: Vector3<f64>, maxs: Vector3<f64>) -> Vec<Anchor> {
+    let centre = (mins + maxs) / 2.0;
+    let on = |axis: usize, value: f64| {
+        let mut point = centre;
+        point[axis] = value;
+        point
+    };
+    vec![
+        Anchor::facing("top", on(2, maxs.z), Vector3::z()),
+        Anchor::facing("bottom", on(2, mins.z), -Vector3::z()),
+        Anchor::upright("front", on(1, mins.y), -Vector3::y()),
+        Anchor::upright("back", on(1, maxs.y), Vector3::y()),
+        Anchor::upright("left", on(0, mins.x), -Vector3::x()),
+        Anchor::upright("right", on(0, maxs.x), Vector3::x()),
+    ]
+}
+
+/// The rigid motion of a transform, or `None` if it mirrors or scales
+pub(crate) fn isometry(transform: &Transform) -> Option<Isometry3<f64>> {
+    let m = &transform.matrix;
+    let linear: Matrix3<f64> = m.fixed_view::<3, 3>(0, 0).into_owned();
+    if (linear.transpose() * linear - Matrix3::identity()).norm() > 1e-9 || linear.determinant() < 0.0 {
+        return None;
+    }
+    let rotation = UnitQuaternion::from_rotation_matrix(&Rotation3::from_matrix_unchecked(linear));
+    Some(Isometry3::from_parts(Translation3::new(m[(0, 3)], m[(1, 3)], m[(2, 3)]), rotation))
+}
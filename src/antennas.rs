@@ -1,5 +1,8 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of an antenna module
 #[derive(Debug, Clone)]
@@ -15,37 +18,23 @@ pub struct Antenna {
 
 impl Antenna {
     /// Generate the base of the antenna
-    pub fn base(&self) -> CSG<()> {
-        CSG::cylinder_z(self.base_diameter / 2.0, self.base_height)
+    pub fn base(&self) -> CSG {
+        CSG::z_cylinder(self.base_diameter / 2.0, self.base_height)
     }
     
     /// Generate the rod of the antenna
-    pub fn rod(&self) -> CSG<()> {
-        CSG::cylinder_z(self.rod_diameter / 2.0, self.rod_length)
-            .translate(Vector3::new(0.0, 0.0, self.base_height))
+    pub fn rod(&self) -> CSG {
+        CSG::z_cylinder(self.rod_diameter / 2.0, self.rod_length)
+            .translated(Vector3::new(0.0, 0.0, self.base_height))
     }
     
     /// Generate the mounting holes
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-            (-self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.base_height)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.base_height)
     }
     
     /// Generate the full antenna model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut antenna = self.base();
         let rod = self.rod();
         let holes = self.mounting_holes();
@@ -57,3 +46,26 @@ impl Antenna {
     }
 }
 
+impl Part for Antenna {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Antenna::assemble(self)
+    }
+}
+
+impl Mountable for Antenna {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::square(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
@@ -0,0 +1,411 @@
+use crate::anchor::{self, Anchor};
+use crate::bom::Bom;
+use crate::colour::Colour;
+use crate::mass::MassProperties;
+use crate::material::{Finish, Finishing};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use crate::CSG;
+use csgrs::enums::Axis;
+use nalgebra::{Matrix4, Point3, Rotation3, Translation3, Vector3};
+use std::fmt;
+use std::sync::Arc;
+
+/// Affine placement of a child within its parent, built up the same way as nested
+/// OpenSCAD `translate()` / `rotate()` / `mirror()` calls.
+///
+/// Builder methods apply *after* the existing transform, so
+/// `Transform::identity().rotate(90.0, 0.0, 0.0).translate(v)` rotates first and then
+/// translates, i.e. `translate(v) rotate([90, 0, 0])` in SCAD.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    pub matrix: Matrix4<f64>,
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Transform {
+    pub fn identity() -> Self {
+        Transform { matrix: Matrix4::identity() }
+    }
+
+    /// Any affine matrix. A singular one flattens what it places, so [`Transform::apply`]
+    /// gives an empty solid.
+    pub fn from_matrix(matrix: Matrix4<f64>) -> Self {
+        Transform { matrix }
+    }
+
+    /// Pure translation
+    pub fn translation(v: Vector3<f64>) -> Self {
+        Transform { matrix: Translation3::from(v).to_homogeneous() }
+    }
+
+    /// Rotation in degrees about X, then Y, then Z, matching `CSG::rotate`
+    pub fn rotation(x_deg: f64, y_deg: f64, z_deg: f64) -> Self {
+        let rx = Rotation3::from_axis_angle(&Vector3::x_axis(), x_deg.to_radians());
+        let ry = Rotation3::from_axis_angle(&Vector3::y_axis(), y_deg.to_radians());
+        let rz = Rotation3::from_axis_angle(&Vector3::z_axis(), z_deg.to_radians());
+        Transform { matrix: (rz * ry * rx).to_homogeneous() }
+    }
+
+    /// Reflection in the plane through the origin normal to `axis`, matching `CSG::mirror`
+    pub fn mirroring(axis: Axis) -> Self {
+        let scale = match axis {
+            Axis::X => Vector3::new(-1.0, 1.0, 1.0),
+            Axis::Y => Vector3::new(1.0, -1.0, 1.0),
+            Axis::Z => Vector3::new(1.0, 1.0, -1.0),
+        };
+        Transform { matrix: Matrix4::new_nonuniform_scaling(&scale) }
+    }
+
+    /// Apply `next` after this transform
+    pub fn then(&self, next: &Transform) -> Transform {
+        Transform { matrix: next.matrix * self.matrix }
+    }
+
+    pub fn translate(self, v: Vector3<f64>) -> Self {
+        self.then(&Transform::translation(v))
+    }
+
+    pub fn rotate(self, x_deg: f64, y_deg: f64, z_deg: f64) -> Self {
+        self.then(&Transform::rotation(x_deg, y_deg, z_deg))
+    }
+
+    pub fn mirror(self, axis: Axis) -> Self {
+        self.then(&Transform::mirroring(axis))
+    }
+
+    /// True if the transform turns solids inside out, i.e. contains an odd number of mirrors.
+    pub fn is_mirrored(&self) -> bool {
+        self.matrix.fixed_view::<3, 3>(0, 0).determinant() < 0.0
+    }
+
+    pub fn transform_point(&self, p: &Point3<f64>) -> Point3<f64> {
+        self.matrix.transform_point(p)
+    }
+
+    /// Place a solid.
+    ///
+    /// Mirroring transforms have their polygon winding restored so the result stays outward facing,
+    /// and singular ones give an empty solid.
+    pub fn apply(&self, csg: &CSG) -> CSG {
+        if self.matrix == Matrix4::identity() {
+            return csg.clone();
+        }
+        csg.transformed(&self.matrix)
+    }
+}
+
+/// What a child of an assembly holds.
+#[derive(Clone)]
+pub enum Node {
+    /// A single part, shared so the same part can be placed many times without copying it.
+    Part(Arc<dyn Part + Send + Sync>),
+    /// A nested sub-assembly.
+    Assembly(Assembly),
+}
+
+impl fmt::Debug for Node {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Node::Part(part) => f.debug_tuple("Part").field(&part.name()).finish(),
+            Node::Assembly(assembly) => f.debug_tuple("Assembly").field(assembly).finish(),
+        }
+    }
+}
+
+/// A named, placed and optionally coloured entry in an [`Assembly`].
+#[derive(Debug, Clone)]
+pub struct Child {
+    pub name: String,
+    pub transform: Transform,
+    /// Overrides the colour of everything below it that has no colour of its own.
+    pub colour: Option<Colour>,
+    /// Offset in the parent's coordinates when shown exploded, like NopSCADLib's `explode()`.
+    pub explode: Vector3<f64>,
+    /// Assembly step at which the child is fitted. Step 0 is there from the start.
+    pub step: u32,
+    pub node: Node,
+}
+
+impl Child {
+    pub fn set_colour(&mut self, colour: Colour) -> &mut Self {
+        self.colour = Some(colour);
+        self
+    }
+
+    pub fn set_transform(&mut self, transform: Transform) -> &mut Self {
+        self.transform = transform;
+        self
+    }
+
+    pub fn set_explode(&mut self, explode: Vector3<f64>) -> &mut Self {
+        self.explode = explode;
+        self
+    }
+
+    pub fn set_step(&mut self, step: u32) -> &mut Self {
+        self.step = step;
+        self
+    }
+
+    /// The child moved `factor` of the way along its explode vector
+    fn exploded_by(&self, factor: f64) -> Child {
+        Child { transform: self.transform.translate(self.explode * factor), ..self.clone() }
+    }
+}
+
+/// One frame of an assembly animation, see [`Assembly::frames`].
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub step: u32,
+    /// How far the parts fitted at this step are from home, 1 being fully exploded.
+    pub factor: f64,
+    pub assembly: Assembly,
+}
+
+/// A leaf part of an assembly with its accumulated placement.
+#[derive(Clone)]
+pub struct PlacedPart {
+    /// Child names from the root down, joined with `/`.
+    pub path: String,
+    pub transform: Transform,
+    pub colour: Option<Colour>,
+    pub part: Arc<dyn Part + Send + Sync>,
+}
+
+impl PlacedPart {
+    /// The part's solid in assembly coordinates, with the child colour applied to any
+    /// polygons the part did not colour itself.
+    ///
+    /// Parts that give a [`Part::cache_key`] are built once through the shared geometry cache,
+    /// so repeated leaves such as the screws of a fixing set share one solid.
+    pub fn solid(&self) -> CSG {
+        let solid = self.transform.apply(&crate::cache::shared().assemble(&*self.part));
+        match self.colour {
+            Some(colour) => solid.finished_default(Finish::colour(colour)),
+            None => solid,
+        }
+    }
+}
+
+/// A tree of placed parts and sub-assemblies.
+///
+/// Unlike the `assemble()` functions of individual parts, which union everything into one
+/// solid, an assembly keeps the identity and placement of each child and only merges them
+/// when [`Assembly::flatten`] is called.
+#[derive(Debug, Clone, Default)]
+pub struct Assembly {
+    pub name: String,
+    pub children: Vec<Child>,
+}
+
+impl Assembly {
+    pub fn new(name: impl Into<String>) -> Self {
+        Assembly { name: name.into(), children: Vec::new() }
+    }
+
+    /// Place a part, returning the new child so its colour, step and explode vector can be set
+    pub fn add_part<P>(&mut self, name: impl Into<String>, part: P, transform: Transform) -> &mut Child
+    where
+        P: Part + Send + Sync + 'static,
+    {
+        self.add_shared(name, Arc::new(part), transform)
+    }
+
+    /// Place a part that is shared with other children or assemblies
+    pub fn add_shared(
+        &mut self,
+        name: impl Into<String>,
+        part: Arc<dyn Part + Send + Sync>,
+        transform: Transform,
+    ) -> &mut Child {
+        self.push(name.into(), Node::Part(part), transform)
+    }
+
+    /// Place a sub-assembly
+    pub fn add_assembly(&mut self, name: impl Into<String>, assembly: Assembly, transform: Transform) -> &mut Child {
+        self.push(name.into(), Node::Assembly(assembly), transform)
+    }
+
+    fn push(&mut self, name: String, node: Node, transform: Transform) -> &mut Child {
+        self.children.push(Child { name, transform, colour: None, explode: Vector3::zeros(), step: 0, node });
+        self.children.last_mut().unwrap()
+    }
+
+    /// Look up a child by its `/` separated path, e.g. `"motor/screw_1"`
+    pub fn find(&self, path: &str) -> Option<&Child> {
+        let (head, rest) = match path.split_once('/') {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (path, None),
+        };
+        let child = self.children.iter().find(|c| c.name == head)?;
+        match (rest, &child.node) {
+            (None, _) => Some(child),
+            (Some(rest), Node::Assembly(sub)) => sub.find(rest),
+            (Some(_), Node::Part(_)) => None,
+        }
+    }
+
+    /// Every leaf part with its path, placement and colour, depth first in insertion order
+    pub fn leaves(&self) -> Vec<PlacedPart> {
+        let mut out = Vec::new();
+        self.collect_leaves("", &Transform::identity(), None, &mut out);
+        out
+    }
+
+    fn collect_leaves(
+        &self,
+        prefix: &str,
+        parent: &Transform,
+        colour: Option<Colour>,
+        out: &mut Vec<PlacedPart>,
+    ) {
+        for child in &self.children {
+            let path = if prefix.is_empty() {
+                child.name.clone()
+            } else {
+                format!("{}/{}", prefix, child.name)
+            };
+            let transform = child.transform.then(parent);
+            // The innermost colour wins, as in OpenSCAD.
+            let colour = child.colour.or(colour);
+            match &child.node {
+                Node::Part(part) => out.push(PlacedPart { path, transform, colour, part: Arc::clone(part) }),
+                Node::Assembly(sub) => sub.collect_leaves(&path, &transform, colour, out),
+            }
+        }
+    }
+
+    /// Every assembly step used in the tree, in order
+    pub fn steps(&self) -> Vec<u32> {
+        let mut steps = Vec::new();
+        self.collect_steps(&mut steps);
+        steps.sort_unstable();
+        steps.dedup();
+        steps
+    }
+
+    fn collect_steps(&self, steps: &mut Vec<u32>) {
+        for child in &self.children {
+            steps.push(child.step);
+            if let Node::Assembly(sub) = &child.node {
+                sub.collect_steps(steps);
+            }
+        }
+    }
+
+    /// The assembly as it stands at `step`, with the children fitted at that step moved
+    /// `factor` of the way along their explode vectors.
+    ///
+    /// Children fitted at later steps are left out, along with everything below them. Steps
+    /// are numbered across the whole tree, so a sub-assembly fitted at step 2 can have parts of
+    /// its own fitted at step 3. `exploded(step, 0.0)` is the assembly with that step complete.
+    pub fn exploded(&self, step: u32, factor: f64) -> Assembly {
+        self.map_children(&|child| {
+            (child.step <= step).then(|| if child.step == step { child.exploded_by(factor) } else { child.clone() })
+        })
+    }
+
+    /// Every child, at every level, moved `factor` of the way along its explode vector
+    pub fn explode_all(&self, factor: f64) -> Assembly {
+        self.map_children(&|child| Some(child.exploded_by(factor)))
+    }
+
+    /// Copy of the tree with `f` applied to every child, dropping those it returns `None` for
+    fn map_children(&self, f: &dyn Fn(&Child) -> Option<Child>) -> Assembly {
+        let children = self
+            .children
+            .iter()
+            .filter_map(|child| {
+                let mut mapped = f(child)?;
+                if let Node::Assembly(sub) = &child.node {
+                    mapped.node = Node::Assembly(sub.map_children(f));
+                }
+                Some(mapped)
+            })
+            .collect();
+        Assembly { name: self.name.clone(), children }
+    }
+
+    /// Animation of the build, `frames_per_step` frames for each step in which the parts
+    /// fitted at that step move from exploded to home.
+    ///
+    /// With a single frame per step, each step is shown fully exploded, as in NopSCADLib's
+    /// assembly instructions.
+    pub fn frames(&self, frames_per_step: usize) -> Vec<Frame> {
+        let factors: Vec<f64> = match frames_per_step {
+            0 => Vec::new(),
+            1 => vec![1.0],
+            n => (0..n).map(|i| 1.0 - i as f64 / (n - 1) as f64).collect(),
+        };
+        self.steps()
+            .into_iter()
+            .flat_map(|step| {
+                factors
+                    .iter()
+                    .map(move |&factor| Frame { step, factor, assembly: self.exploded(step, factor) })
+            })
+            .collect()
+    }
+
+    /// Union every placed part into a single solid
+    pub fn flatten(&self) -> CSG {
+        self.leaves()
+            .iter()
+            .fold(CSG::new(), |acc, leaf| acc.union(&leaf.solid()))
+    }
+}
+
+impl Part for Assembly {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Misc
+    }
+
+    fn assemble(&self) -> CSG {
+        self.flatten()
+    }
+
+    /// Total price of every part in the tree, or `None` if any of them has no price.
+    fn unit_cost(&self) -> Option<f64> {
+        self.leaves().iter().map(|leaf| leaf.part.unit_cost()).sum()
+    }
+
+    /// Every part's properties moved into assembly coordinates and combined.
+    fn mass_properties(&self) -> MassProperties {
+        self.leaves()
+            .iter()
+            .map(|leaf| leaf.part.mass_properties().transformed(&leaf.transform))
+            .fold(MassProperties::default(), |total, part| total.combine(&part))
+    }
+
+    /// Every part in the tree, rather than a line for the assembly itself.
+    fn bom(&self, bom: &mut Bom) {
+        for leaf in self.leaves() {
+            leaf.part.bom(bom);
+        }
+    }
+
+    /// The anchors of every part in the tree, named by path, e.g. `"motor/shaft"`, and moved
+    /// into assembly coordinates. Parts placed with a mirror have none.
+    fn anchors(&self) -> Vec<Anchor> {
+        self.leaves()
+            .iter()
+            .filter_map(|leaf| Some((leaf, anchor::isometry(&leaf.transform)?)))
+            .flat_map(|(leaf, placement)| {
+                leaf.part
+                    .anchors()
+                    .into_iter()
+                    .map(move |a| Anchor::new(format!("{}/{}", leaf.path, a.name), placement * a.frame))
+            })
+            .collect()
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an Audio Jack
 #[derive(Debug, Clone)]
@@ -11,19 +14,32 @@ pub struct AudioJack {
 
 impl AudioJack {
     /// Generate the main body of the audio jack
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_length)
     }
 
     /// Generate the pin
-    pub fn pin(&self) -> CSG<()> {
-        CSG::cylinder_z(self.pin_diameter / 2.0, self.pin_length)
-            .translate(Vector3::new(0.0, 0.0, -self.pin_length))
+    pub fn pin(&self) -> CSG {
+        CSG::z_cylinder(self.pin_diameter / 2.0, self.pin_length)
+            .translated(Vector3::new(0.0, 0.0, -self.pin_length))
     }
 
     /// Assemble the complete audio jack
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.body().union(&self.pin())
     }
 }
 
+impl Part for AudioJack {
+    fn name(&self) -> &str {
+        "AudioJack"
+    }
+
+    fn category(&self) -> Category {
+        Category::Connector
+    }
+
+    fn assemble(&self) -> CSG {
+        AudioJack::assemble(self)
+    }
+}
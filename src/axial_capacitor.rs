@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an Axial Capacitor
 #[derive(Debug, Clone)]
@@ -11,20 +14,33 @@ pub struct AxialCapacitor {
 
 impl AxialCapacitor {
     /// Generate the capacitor body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_length)
     }
 
     /// Generate the leads
-    pub fn leads(&self) -> CSG<()> {
-        let lead = CSG::cylinder_z(self.lead_diameter / 2.0, self.lead_length);
-        lead.translate(Vector3::new(0.0, 0.0, -self.lead_length))
-            .union(&lead.translate(Vector3::new(0.0, 0.0, self.body_length)))
+    pub fn leads(&self) -> CSG {
+        let lead = CSG::z_cylinder(self.lead_diameter / 2.0, self.lead_length);
+        lead.translated(Vector3::new(0.0, 0.0, -self.lead_length))
+            .union(&lead.translated(Vector3::new(0.0, 0.0, self.body_length)))
     }
 
     /// Assemble the complete axial capacitor
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.body().union(&self.leads())
     }
 }
 
+impl Part for AxialCapacitor {
+    fn name(&self) -> &str {
+        "AxialCapacitor"
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        AxialCapacitor::assemble(self)
+    }
+}
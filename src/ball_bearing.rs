@@ -1,27 +1,67 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::error::{inconsistent, largest_bore, positive, PartError};
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use crate::tolerance::Fit;
 
 /// Representation of a Ball Bearing
 #[derive(Debug, Clone)]
 pub struct BallBearing {
     pub outer_diameter: f64,
     pub inner_diameter: f64,
+    /// Fit of the shaft in the bore.
+    pub bore_fit: Fit,
     pub width: f64,
 }
 
 impl BallBearing {
+    /// A bearing checked by [`validate`](BallBearing::validate)
+    pub fn new(outer_diameter: f64, inner_diameter: f64, width: f64, bore_fit: Fit) -> Result<BallBearing, PartError> {
+        let bearing = BallBearing { outer_diameter, inner_diameter, bore_fit, width };
+        bearing.validate()?;
+        Ok(bearing)
+    }
+
+    /// Check the dimensions are positive and the bore, opened up by its fit, is inside the bearing
+    pub fn validate(&self) -> Result<(), PartError> {
+        positive("outer_diameter", self.outer_diameter)?;
+        positive("inner_diameter", self.inner_diameter)?;
+        positive("width", self.width)?;
+        if largest_bore(self.name(), &self.bore_fit, self.inner_diameter)? >= self.outer_diameter {
+            return Err(inconsistent(self.name(), "the bore is wider than the bearing"));
+        }
+        Ok(())
+    }
+
     /// Generate the outer race
-    pub fn outer_race(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.width)
+    pub fn outer_race(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.width)
+            .finished(Material::Steel)
     }
 
     /// Generate the inner race
-    pub fn inner_race(&self) -> CSG<()> {
-        CSG::cylinder_z(self.inner_diameter / 2.0, self.width + 1.0)
+    pub fn inner_race(&self) -> CSG {
+        CSG::z_cylinder(self.bore_fit.hole_diameter(self.inner_diameter) / 2.0, self.width + 1.0)
     }
 
     /// Assemble the complete ball bearing
-    pub fn assemble(&self) -> CSG<()> {
-        self.outer_race().difference(&self.inner_race())
+    pub fn assemble(&self) -> CSG {
+        self.outer_race().subtract(&self.inner_race())
+            .finished_default(Material::Steel)
     }
 }
 
+impl Part for BallBearing {
+    fn name(&self) -> &str {
+        "BallBearing"
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        BallBearing::assemble(self)
+    }
+}
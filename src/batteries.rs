@@ -1,5 +1,8 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a battery module
 #[derive(Debug, Clone)]
@@ -15,12 +18,13 @@ pub struct Battery {
 
 impl Battery {
     /// Generate the rectangular body of the battery
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
+            .finished(Material::Plastic)
     }
     
     /// Generate the terminals of the battery
-    pub fn terminals(&self) -> CSG<()> {
+    pub fn terminals(&self) -> CSG {
         let mut terminals = CSG::new();
         let offsets = [
             (-self.terminal_spacing / 2.0, 0.0),
@@ -28,16 +32,16 @@ impl Battery {
         ];
         
         for &(x, y) in &offsets {
-            let terminal = CSG::cylinder_z(self.terminal_diameter / 2.0, self.terminal_height)
-                .translate(Vector3::new(x, y, self.body_depth));
+            let terminal = CSG::z_cylinder(self.terminal_diameter / 2.0, self.terminal_height)
+                .translated(Vector3::new(x, y, self.body_depth));
             terminals = terminals.union(&terminal);
         }
         
-        terminals
+        terminals.finished(Material::StainlessSteel)
     }
     
     /// Generate the full battery model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut battery = self.body();
         let terminals = self.terminals();
         
@@ -47,3 +51,16 @@ impl Battery {
     }
 }
 
+impl Part for Battery {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Power
+    }
+
+    fn assemble(&self) -> CSG {
+        Battery::assemble(self)
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Bearing Block
 #[derive(Debug, Clone)]
@@ -14,26 +17,39 @@ pub struct BearingBlock {
 
 impl BearingBlock {
     /// Generate the block body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the bearing hole
-    pub fn bearing_hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.bearing_diameter / 2.0, self.bearing_depth)
-            .translate(Vector3::new(0.0, 0.0, self.depth / 2.0))
+    pub fn bearing_hole(&self) -> CSG {
+        CSG::z_cylinder(self.bearing_diameter / 2.0, self.bearing_depth)
+            .translated(Vector3::new(0.0, 0.0, self.depth / 2.0))
     }
 
     /// Generate the mounting holes
-    pub fn mount_holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.mount_hole_diameter / 2.0, self.depth);
-        hole.translate(Vector3::new(self.mount_hole_spacing / 2.0, 0.0, 0.0))
-            .union(&hole.translate(Vector3::new(-self.mount_hole_spacing / 2.0, 0.0, 0.0)))
+    pub fn mount_holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.mount_hole_diameter / 2.0, self.depth);
+        hole.translated(Vector3::new(self.mount_hole_spacing / 2.0, 0.0, 0.0))
+            .union(&hole.translated(Vector3::new(-self.mount_hole_spacing / 2.0, 0.0, 0.0)))
     }
 
     /// Assemble the complete bearing block
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.bearing_hole()).difference(&self.mount_holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.bearing_hole()).subtract(&self.mount_holes())
     }
 }
 
+impl Part for BearingBlock {
+    fn name(&self) -> &str {
+        "BearingBlock"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        BearingBlock::assemble(self)
+    }
+}
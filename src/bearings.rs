@@ -1,5 +1,10 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::error::{inconsistent, largest_bore, positive, PartError};
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use crate::tolerance::Fit;
+use nalgebra::Vector3;
 
 /// Representation of a linear bearing
 #[derive(Debug, Clone)]
@@ -7,30 +12,51 @@ pub struct Bearing {
     pub name: String,
     pub outer_diameter: f64,
     pub inner_diameter: f64,
+    /// Fit of the rod in the bore.
+    pub bore_fit: Fit,
     pub length: f64,
     pub groove_diameter: Option<f64>,
     pub groove_spacing: Option<f64>,
 }
 
 impl Bearing {
+    /// Check the dimensions are positive, the bore is inside the bearing and any grooves are
+    /// cut into its outside
+    pub fn validate(&self) -> Result<(), PartError> {
+        positive("outer_diameter", self.outer_diameter)?;
+        positive("inner_diameter", self.inner_diameter)?;
+        positive("length", self.length)?;
+        if largest_bore(&self.name, &self.bore_fit, self.inner_diameter)? >= self.outer_diameter {
+            return Err(inconsistent(&self.name, "the bore is wider than the bearing"));
+        }
+        if let (Some(diameter), Some(spacing)) = (self.groove_diameter, self.groove_spacing) {
+            positive("groove_spacing", spacing)?;
+            if diameter >= self.outer_diameter {
+                return Err(inconsistent(&self.name, "the grooves are wider than the bearing"));
+            }
+        }
+        Ok(())
+    }
+
     /// Generate the outer cylindrical body of the bearing
-    pub fn outer_body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.length)
+    pub fn outer_body(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.length)
+            .finished(Material::Steel)
     }
     
     /// Generate the inner hole of the bearing
-    pub fn hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.inner_diameter / 2.0, self.length)
+    pub fn hole(&self) -> CSG {
+        CSG::z_cylinder(self.bore_fit.hole_diameter(self.inner_diameter) / 2.0, self.length)
     }
     
     /// Generate the grooves if specified
-    pub fn grooves(&self) -> Option<CSG<()>> {
+    pub fn grooves(&self) -> Option<CSG> {
         if let (Some(diameter), Some(spacing)) = (self.groove_diameter, self.groove_spacing) {
             let mut grooves = CSG::new();
             let num_grooves = (self.length / spacing).floor() as i32;
             for i in 0..num_grooves {
-                let groove = CSG::cylinder_z(diameter / 2.0, spacing / 4.0)
-                    .translate(Vector3::new(0.0, 0.0, i as f64 * spacing));
+                let groove = CSG::z_cylinder(diameter / 2.0, spacing / 4.0)
+                    .translated(Vector3::new(0.0, 0.0, i as f64 * spacing));
                 grooves = grooves.union(&groove);
             }
             Some(grooves)
@@ -40,7 +66,7 @@ impl Bearing {
     }
     
     /// Generate the full bearing model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut bearing = self.outer_body();
         let hole = self.hole();
         bearing = bearing.subtract(&hole);
@@ -49,7 +75,20 @@ impl Bearing {
             bearing = bearing.subtract(&grooves);
         }
         
-        bearing
+        bearing.finished_default(Material::Steel)
     }
 }
 
+impl Part for Bearing {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        Bearing::assemble(self)
+    }
+}
@@ -1,5 +1,10 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::cache;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::pattern;
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a timing belt
 #[derive(Debug, Clone)]
@@ -14,26 +19,26 @@ pub struct Belt {
 
 impl Belt {
     /// Generate the base rectangular body of the belt
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.length, self.width, self.thickness)
+            .finished(Material::Rubber)
     }
     
     /// Generate the teeth along the belt
-    pub fn teeth(&self) -> CSG<()> {
-        let mut teeth = CSG::new();
-        let num_teeth = (self.length / self.tooth_pitch).floor() as i32;
-        
-        for i in 0..num_teeth {
-            let tooth = CSG::prism(self.tooth_pitch, self.width, self.tooth_height)
-                .translate(Vector3::new(i as f64 * self.tooth_pitch - (self.length / 2.0), 0.0, self.thickness));
-            teeth = teeth.union(&tooth);
-        }
-        
-        teeth
+    pub fn teeth(&self) -> CSG {
+        let num_teeth = (self.length / self.tooth_pitch).floor() as usize;
+        // The first tooth starts at the end of the belt.
+        let first = (num_teeth as f64 - 1.0) / 2.0 * self.tooth_pitch - self.length / 2.0;
+        let key = format!("belt tooth {:?} {:?} {:?}", self.tooth_pitch, self.width, self.tooth_height);
+        let tooth = cache::shared()
+            .get_or_build(&key, || CSG::prism(self.tooth_pitch, self.width, self.tooth_height))
+            .translated(Vector3::new(first, 0.0, self.thickness));
+        let placements = pattern::linear(num_teeth, Vector3::new(self.tooth_pitch, 0.0, 0.0));
+        pattern::place(&tooth, &placements).finished(Material::Rubber)
     }
     
     /// Generate the full belt model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut belt = self.body();
         let teeth = self.teeth();
         
@@ -43,3 +48,24 @@ impl Belt {
     }
 }
 
+impl Part for Belt {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        Belt::assemble(self)
+    }
+
+    fn cache_key(&self) -> Option<String> {
+        Some(cache::debug_key(self))
+    }
+
+    fn description(&self) -> String {
+        format!("Belt {} x {}mm", self.name, self.length)
+    }
+}
@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a BLDC (Brushless DC) Motor module
 #[derive(Debug, Clone)]
@@ -15,42 +19,28 @@ pub struct BLDCMotor {
 
 impl BLDCMotor {
     /// Generate the stator of the BLDC motor
-    pub fn stator(&self) -> CSG<()> {
-        CSG::cylinder_z(self.stator_diameter / 2.0, self.height)
+    pub fn stator(&self) -> CSG {
+        CSG::z_cylinder(self.stator_diameter / 2.0, self.height)
     }
     
     /// Generate the rotor of the BLDC motor
-    pub fn rotor(&self) -> CSG<()> {
-        CSG::cylinder_z(self.rotor_diameter / 2.0, self.height)
+    pub fn rotor(&self) -> CSG {
+        CSG::z_cylinder(self.rotor_diameter / 2.0, self.height)
     }
     
     /// Generate the shaft of the BLDC motor
-    pub fn shaft(&self) -> CSG<()> {
-        CSG::cylinder_z(self.shaft_diameter / 2.0, self.shaft_length)
-            .translate(Vector3::new(0.0, 0.0, self.height))
+    pub fn shaft(&self) -> CSG {
+        CSG::z_cylinder(self.shaft_diameter / 2.0, self.shaft_length)
+            .translated(Vector3::new(0.0, 0.0, self.height))
     }
     
     /// Generate the mounting holes of the BLDC motor
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-            (-self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.height)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.height)
     }
     
     /// Generate the full BLDC motor model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut motor = self.stator();
         let rotor = self.rotor();
         let shaft = self.shaft();
@@ -64,3 +54,26 @@ impl BLDCMotor {
     }
 }
 
+impl Part for BLDCMotor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motor
+    }
+
+    fn assemble(&self) -> CSG {
+        BLDCMotor::assemble(self)
+    }
+}
+
+impl Mountable for BLDCMotor {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::square(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
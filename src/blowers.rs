@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
 
 /// Representation of a Blower module
 #[derive(Debug, Clone)]
@@ -14,30 +17,32 @@ pub struct Blower {
 
 impl Blower {
     /// Generate the cylindrical body of the blower
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_height)
+            .finished(Material::Plastic)
     }
     
-    /// Generate the outlet of the blower
-    pub fn outlet(&self) -> CSG<()> {
-        CSG::prism(self.outlet_width, self.outlet_height, self.body_height)
-            .translate(Vector3::new(self.body_diameter / 2.0, 0.0, 0.0))
+    /// Generate the outlet of the blower, sticking `outlet_width` out of the side of the body
+    pub fn outlet(&self) -> CSG {
+        // Start from the axis so the outlet merges with the body rather than touching it.
+        CSG::prism(self.body_diameter / 2.0 + self.outlet_width, self.outlet_height, self.body_height)
+            .finished(Material::Plastic)
     }
     
     /// Generate the fan inside the blower
-    pub fn fan(&self) -> CSG<()> {
+    pub fn fan(&self) -> CSG {
         let mut fan = CSG::new();
         for i in 0..self.fan_blades {
             let angle = (i as f64) * (360.0 / self.fan_blades as f64);
             let blade = CSG::prism(self.fan_diameter / 2.0, self.body_height / 10.0, self.body_height)
-                .rotate_z(angle);
+                .rotate(0.0, 0.0, angle);
             fan = fan.union(&blade);
         }
-        fan
+        fan.finished(Material::Plastic)
     }
     
     /// Generate the full blower model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut blower = self.body();
         let outlet = self.outlet();
         let fan = self.fan();
@@ -49,3 +54,16 @@ impl Blower {
     }
 }
 
+impl Part for Blower {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Cooling
+    }
+
+    fn assemble(&self) -> CSG {
+        Blower::assemble(self)
+    }
+}
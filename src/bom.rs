@@ -0,0 +1,204 @@
+use crate::part::{Category, Part};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// A single rolled-up line of a bill of materials.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BomItem {
+    pub description: String,
+    pub quantity: u32,
+    pub category: Category,
+    /// Price of one item, if known.
+    pub unit_cost: Option<f64>,
+}
+
+impl BomItem {
+    /// Printed parts are made rather than bought; everything else is a vitamin.
+    pub fn is_printed(&self) -> bool {
+        self.category == Category::Printed
+    }
+
+    /// Cost of the whole line, if the unit cost is known
+    pub fn total_cost(&self) -> Option<f64> {
+        self.unit_cost.map(|cost| cost * self.quantity as f64)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Line {
+    quantity: u32,
+    unit_cost: Option<f64>,
+}
+
+/// Bill of materials collector, the equivalent of NopSCADLib's `vitamin()` / `stl()` logging.
+///
+/// Parts record themselves with [`Part::bom`] each time they are placed, and identical
+/// descriptions within a category are rolled up into a single line with a quantity.
+#[derive(Debug, Clone, Default)]
+pub struct Bom {
+    lines: BTreeMap<(Category, String), Line>,
+}
+
+impl Bom {
+    /// Create an empty bill of materials
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `quantity` of an item
+    pub fn add(&mut self, description: impl Into<String>, category: Category, quantity: u32) {
+        if quantity == 0 {
+            return;
+        }
+        self.lines.entry((category, description.into())).or_default().quantity += quantity;
+    }
+
+    /// Record `quantity` of an item that costs `unit_cost` each.
+    ///
+    /// The last price given for a description wins.
+    pub fn add_costed(&mut self, description: impl Into<String>, category: Category, quantity: u32, unit_cost: f64) {
+        if quantity == 0 {
+            return;
+        }
+        let line = self.lines.entry((category, description.into())).or_default();
+        line.quantity += quantity;
+        line.unit_cost = Some(unit_cost);
+    }
+
+    /// Record one placement of `part`
+    pub fn add_part<P: Part + ?Sized>(&mut self, part: &P) {
+        part.bom(self);
+    }
+
+    /// Roll up every line of `other` into this BOM
+    pub fn merge(&mut self, other: &Bom) {
+        for ((category, description), line) in &other.lines {
+            match line.unit_cost {
+                Some(cost) => self.add_costed(description.clone(), *category, line.quantity, cost),
+                None => self.add(description.clone(), *category, line.quantity),
+            }
+        }
+    }
+
+    /// All lines, sorted by category then description
+    pub fn items(&self) -> Vec<BomItem> {
+        self.lines
+            .iter()
+            .map(|((category, description), line)| BomItem {
+                description: description.clone(),
+                quantity: line.quantity,
+                category: *category,
+                unit_cost: line.unit_cost,
+            })
+            .collect()
+    }
+
+    /// Lines for bought-in parts
+    pub fn vitamins(&self) -> Vec<BomItem> {
+        self.items().into_iter().filter(|item| !item.is_printed()).collect()
+    }
+
+    /// Lines for 3D printed parts
+    pub fn printed(&self) -> Vec<BomItem> {
+        self.items().into_iter().filter(BomItem::is_printed).collect()
+    }
+
+    /// Total number of individual items
+    pub fn total_quantity(&self) -> u32 {
+        self.lines.values().map(|line| line.quantity).sum()
+    }
+
+    /// Sum of every line with a known price
+    pub fn total_cost(&self) -> f64 {
+        self.items().iter().filter_map(BomItem::total_cost).sum()
+    }
+
+    /// Lines with no price, so a total cost can be flagged as incomplete
+    pub fn uncosted(&self) -> Vec<BomItem> {
+        self.items().into_iter().filter(|item| item.unit_cost.is_none()).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Comma separated values with a header row, leaving the unit cost blank where unknown
+    pub fn to_csv(&self) -> String {
+        let mut out = String::from("quantity,description,category,unit_cost\n");
+        for item in self.items() {
+            let _ = writeln!(
+                out,
+                "{},{},{},{}",
+                item.quantity,
+                csv_field(&item.description),
+                item.category,
+                item.unit_cost.map(|cost| cost.to_string()).unwrap_or_default()
+            );
+        }
+        out
+    }
+
+    /// JSON array of `{ "description", "quantity", "category", "unit_cost" }` objects.
+    ///
+    /// An unknown or non-finite unit cost is written as `null`.
+    pub fn to_json(&self) -> String {
+        let json = serde_json::to_string_pretty(&self.items()).expect("BOM lines always serialize");
+        json + "\n"
+    }
+
+    /// Markdown tables, vitamins first then printed parts, in the style of NopSCADLib's `bom.md`.
+    ///
+    /// Cost columns are only added when at least one line has a price.
+    pub fn to_markdown(&self) -> String {
+        let costed = self.lines.values().any(|line| line.unit_cost.is_some());
+        let mut out = String::new();
+        for (title, items) in [("Vitamins", self.vitamins()), ("Printed parts", self.printed())] {
+            if items.is_empty() {
+                continue;
+            }
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            let _ = writeln!(out, "### {}\n", title);
+            if costed {
+                out.push_str("| Qty | Description | Category | Unit cost | Cost |\n");
+                out.push_str("|----:|:------------|:---------|----------:|-----:|\n");
+            } else {
+                out.push_str("| Qty | Description | Category |\n");
+                out.push_str("|----:|:------------|:---------|\n");
+            }
+            for item in &items {
+                let _ = write!(
+                    out,
+                    "| {} | {} | {} |",
+                    item.quantity,
+                    item.description.replace('|', "\\|"),
+                    item.category
+                );
+                if costed {
+                    let money = |cost: Option<f64>| cost.map(|c| format!("{:.2}", c)).unwrap_or_default();
+                    let _ = write!(out, " {} | {} |", money(item.unit_cost), money(item.total_cost()));
+                }
+                out.push('\n');
+            }
+            let total: u32 = items.iter().map(|item| item.quantity).sum();
+            let _ = write!(out, "| {} | Total {} | |", total, title.to_lowercase());
+            if costed {
+                let cost: f64 = items.iter().filter_map(BomItem::total_cost).sum();
+                let _ = write!(out, " | {:.2} |", cost);
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote or newline.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::bom::Bom;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Box Assembly
 #[derive(Debug, Clone)]
@@ -11,26 +15,47 @@ pub struct BoxAssembly {
 
 impl BoxAssembly {
     /// Generate the box panels
-    pub fn panels(&self) -> CSG<()> {
+    pub fn panels(&self) -> CSG {
         let base = CSG::prism(self.width, self.depth, self.sheet_thickness);
-        let top = base.translate(Vector3::new(0.0, 0.0, self.height));
+        let top = base.translated(Vector3::new(0.0, 0.0, self.height));
 
         let side = CSG::prism(self.sheet_thickness, self.depth, self.height)
-            .translate(Vector3::new(self.width / 2.0, 0.0, self.height / 2.0))
+            .translated(Vector3::new(self.width / 2.0, 0.0, self.height / 2.0))
             .union(&CSG::prism(self.sheet_thickness, self.depth, self.height)
-                .translate(Vector3::new(-self.width / 2.0, 0.0, self.height / 2.0)));
+                .translated(Vector3::new(-self.width / 2.0, 0.0, self.height / 2.0)));
 
         let front_back = CSG::prism(self.width, self.sheet_thickness, self.height)
-            .translate(Vector3::new(0.0, self.depth / 2.0, self.height / 2.0))
+            .translated(Vector3::new(0.0, self.depth / 2.0, self.height / 2.0))
             .union(&CSG::prism(self.width, self.sheet_thickness, self.height)
-                .translate(Vector3::new(0.0, -self.depth / 2.0, self.height / 2.0)));
+                .translated(Vector3::new(0.0, -self.depth / 2.0, self.height / 2.0)));
 
         base.union(&top).union(&side).union(&front_back)
     }
 
     /// Assemble the complete box
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.panels()
     }
 }
 
+impl Part for BoxAssembly {
+    fn name(&self) -> &str {
+        "BoxAssembly"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structural
+    }
+
+    fn assemble(&self) -> CSG {
+        BoxAssembly::assemble(self)
+    }
+
+    /// The box is bought as its six sheet panels.
+    fn bom(&self, bom: &mut Bom) {
+        let t = self.sheet_thickness;
+        bom.add(format!("Sheet {} x {} x {}mm", self.width, self.depth, t), Category::Structural, 2);
+        bom.add(format!("Sheet {} x {} x {}mm", t, self.depth, self.height), Category::Structural, 2);
+        bom.add(format!("Sheet {} x {} x {}mm", self.width, t, self.height), Category::Structural, 2);
+    }
+}
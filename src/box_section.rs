@@ -1,4 +1,5 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
 
 /// Representation of a Box Section module
 #[derive(Debug, Clone)]
@@ -12,12 +13,12 @@ pub struct BoxSection {
 
 impl BoxSection {
     /// Generate the outer shell of the box section
-    pub fn outer_shell(&self) -> CSG<()> {
+    pub fn outer_shell(&self) -> CSG {
         CSG::prism(self.outer_width, self.outer_height, self.outer_length)
     }
     
     /// Generate the inner hollow section
-    pub fn inner_hollow(&self) -> CSG<()> {
+    pub fn inner_hollow(&self) -> CSG {
         CSG::prism(
             self.outer_width - 2.0 * self.wall_thickness,
             self.outer_height - 2.0 * self.wall_thickness,
@@ -26,7 +27,7 @@ impl BoxSection {
     }
     
     /// Generate the full box section model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut box_section = self.outer_shell();
         let hollow = self.inner_hollow();
         
@@ -36,3 +37,16 @@ impl BoxSection {
     }
 }
 
+impl Part for BoxSection {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Structural
+    }
+
+    fn assemble(&self) -> CSG {
+        BoxSection::assemble(self)
+    }
+}
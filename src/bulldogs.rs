@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Bulldog Clamp module
 #[derive(Debug, Clone)]
@@ -15,24 +18,24 @@ pub struct BulldogClamp {
 
 impl BulldogClamp {
     /// Generate the rectangular body of the bulldog clamp
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
     }
     
     /// Generate the jaws of the bulldog clamp
-    pub fn jaws(&self) -> CSG<()> {
+    pub fn jaws(&self) -> CSG {
         CSG::prism(self.jaw_width, self.body_height, self.jaw_depth)
-            .translate(Vector3::new(0.0, 0.0, self.body_depth / 2.0))
+            .translated(Vector3::new(0.0, 0.0, self.body_depth / 2.0))
     }
     
     /// Generate the spring mechanism
-    pub fn spring(&self) -> CSG<()> {
-        CSG::cylinder_z(self.spring_diameter / 2.0, self.spring_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_depth / 2.0))
+    pub fn spring(&self) -> CSG {
+        CSG::z_cylinder(self.spring_diameter / 2.0, self.spring_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_depth / 2.0))
     }
     
     /// Generate the full bulldog clamp model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut clamp = self.body();
         let jaws = self.jaws();
         let spring = self.spring();
@@ -44,3 +47,16 @@ impl BulldogClamp {
     }
 }
 
+impl Part for BulldogClamp {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Fastener
+    }
+
+    fn assemble(&self) -> CSG {
+        BulldogClamp::assemble(self)
+    }
+}
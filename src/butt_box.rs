@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::anchor::{self, Anchor};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Butt Box
 #[derive(Debug, Clone)]
@@ -11,22 +15,40 @@ pub struct ButtBox {
 
 impl ButtBox {
     /// Generate the box body
-    pub fn outer_body(&self) -> CSG<()> {
+    pub fn outer_body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the hollow inside
-    pub fn inner_body(&self) -> CSG<()> {
+    pub fn inner_body(&self) -> CSG {
         CSG::prism(
             self.width - 2.0 * self.wall_thickness,
             self.height - 2.0 * self.wall_thickness,
             self.depth - 2.0 * self.wall_thickness,
         )
+        .translated(Vector3::repeat(self.wall_thickness))
     }
 
     /// Assemble the complete butt box
-    pub fn assemble(&self) -> CSG<()> {
-        self.outer_body().difference(&self.inner_body())
+    pub fn assemble(&self) -> CSG {
+        self.outer_body().subtract(&self.inner_body())
     }
 }
 
+impl Part for ButtBox {
+    fn name(&self) -> &str {
+        "ButtBox"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        ButtBox::assemble(self)
+    }
+
+    fn anchors(&self) -> Vec<Anchor> {
+        anchor::box_faces(Vector3::zeros(), Vector3::new(self.width, self.height, self.depth))
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Push Button
 #[derive(Debug, Clone)]
@@ -13,27 +16,40 @@ pub struct Button {
 
 impl Button {
     /// Generate the button body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_height)
     }
 
     /// Generate the button cap
-    pub fn cap(&self) -> CSG<()> {
-        CSG::cylinder_z(self.cap_diameter / 2.0, self.cap_height)
-            .translate(Vector3::new(0.0, 0.0, self.body_height))
+    pub fn cap(&self) -> CSG {
+        CSG::z_cylinder(self.cap_diameter / 2.0, self.cap_height)
+            .translated(Vector3::new(0.0, 0.0, self.body_height))
     }
 
     /// Generate the button pins
-    pub fn pins(&self) -> CSG<()> {
-        let pin = CSG::cylinder_z(self.pin_diameter / 2.0, self.pin_length);
+    pub fn pins(&self) -> CSG {
+        let pin = CSG::z_cylinder(self.pin_diameter / 2.0, self.pin_length);
         let spacing = self.body_diameter / 3.0;
-        pin.translate(Vector3::new(-spacing, 0.0, -self.pin_length))
-            .union(&pin.translate(Vector3::new(spacing, 0.0, -self.pin_length)))
+        pin.translated(Vector3::new(-spacing, 0.0, -self.pin_length))
+            .union(&pin.translated(Vector3::new(spacing, 0.0, -self.pin_length)))
     }
 
     /// Assemble the complete button
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.body().union(&self.cap()).union(&self.pins())
     }
 }
 
+impl Part for Button {
+    fn name(&self) -> &str {
+        "Button"
+    }
+
+    fn category(&self) -> Category {
+        Category::Switch
+    }
+
+    fn assemble(&self) -> CSG {
+        Button::assemble(self)
+    }
+}
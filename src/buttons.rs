@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a push button
 #[derive(Debug, Clone)]
@@ -16,18 +18,18 @@ pub struct Button {
 
 impl Button {
     /// Generate the cylindrical body of the button
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_height)
     }
     
     /// Generate the button cap
-    pub fn cap(&self) -> CSG<()> {
-        CSG::cylinder_z(self.button_diameter / 2.0, self.button_height)
-            .translate(Vector3::new(0.0, 0.0, self.body_height))
+    pub fn cap(&self) -> CSG {
+        CSG::z_cylinder(self.button_diameter / 2.0, self.button_height)
+            .translated(Vector3::new(0.0, 0.0, self.body_height))
     }
     
     /// Generate the pins of the button
-    pub fn pins(&self) -> CSG<()> {
+    pub fn pins(&self) -> CSG {
         let mut pins = CSG::new();
         let offsets = [
             (-self.pin_spacing / 2.0, 0.0),
@@ -35,8 +37,8 @@ impl Button {
         ];
         
         for &(x, y) in &offsets {
-            let pin = CSG::cylinder_z(self.pin_diameter / 2.0, self.pin_length)
-                .translate(Vector3::new(x, y, -self.pin_length));
+            let pin = CSG::z_cylinder(self.pin_diameter / 2.0, self.pin_length)
+                .translated(Vector3::new(x, y, -self.pin_length));
             pins = pins.union(&pin);
         }
         
@@ -44,7 +46,7 @@ impl Button {
     }
     
     /// Generate the full button model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut button = self.body();
         let cap = self.cap();
         let pins = self.pins();
@@ -55,3 +57,17 @@ impl Button {
         button
     }
 }
+
+impl Part for Button {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Switch
+    }
+
+    fn assemble(&self) -> CSG {
+        Button::assemble(self)
+    }
+}
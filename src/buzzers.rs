@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a buzzer module
 #[derive(Debug, Clone)]
@@ -15,17 +17,17 @@ pub struct Buzzer {
 
 impl Buzzer {
     /// Generate the cylindrical body of the buzzer
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_height)
     }
     
     /// Generate the sound hole of the buzzer
-    pub fn sound_hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.sound_hole_diameter / 2.0, self.body_height)
+    pub fn sound_hole(&self) -> CSG {
+        CSG::z_cylinder(self.sound_hole_diameter / 2.0, self.body_height)
     }
     
     /// Generate the pins of the buzzer
-    pub fn pins(&self) -> CSG<()> {
+    pub fn pins(&self) -> CSG {
         let mut pins = CSG::new();
         let offsets = [
             (-self.pin_spacing / 2.0, 0.0),
@@ -33,8 +35,8 @@ impl Buzzer {
         ];
         
         for &(x, y) in &offsets {
-            let pin = CSG::cylinder_z(self.pin_diameter / 2.0, self.pin_length)
-                .translate(Vector3::new(x, y, -self.pin_length));
+            let pin = CSG::z_cylinder(self.pin_diameter / 2.0, self.pin_length)
+                .translated(Vector3::new(x, y, -self.pin_length));
             pins = pins.union(&pin);
         }
         
@@ -42,7 +44,7 @@ impl Buzzer {
     }
     
     /// Generate the full buzzer model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut buzzer = self.body();
         let sound_hole = self.sound_hole();
         let pins = self.pins();
@@ -54,3 +56,16 @@ impl Buzzer {
     }
 }
 
+impl Part for Buzzer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Buzzer::assemble(self)
+    }
+}
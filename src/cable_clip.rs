@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Cable Clip
 #[derive(Debug, Clone)]
@@ -12,25 +15,38 @@ pub struct CableClip {
 
 impl CableClip {
     /// Generate the clip body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the cable slot
-    pub fn cable_slot(&self) -> CSG<()> {
-        CSG::cylinder_z(self.cable_diameter / 2.0, self.depth)
-            .translate(Vector3::new(0.0, self.height / 4.0, 0.0))
+    pub fn cable_slot(&self) -> CSG {
+        CSG::z_cylinder(self.cable_diameter / 2.0, self.depth)
+            .translated(Vector3::new(0.0, self.height / 4.0, 0.0))
     }
 
     /// Generate the mounting hole
-    pub fn hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.hole_diameter / 2.0, self.depth + 1.0)
-            .translate(Vector3::new(0.0, -self.height / 4.0, 0.0))
+    pub fn hole(&self) -> CSG {
+        CSG::z_cylinder(self.hole_diameter / 2.0, self.depth + 1.0)
+            .translated(Vector3::new(0.0, -self.height / 4.0, 0.0))
     }
 
     /// Assemble the complete cable clip
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.cable_slot()).difference(&self.hole())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.cable_slot()).subtract(&self.hole())
     }
 }
 
+impl Part for CableClip {
+    fn name(&self) -> &str {
+        "CableClip"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        CableClip::assemble(self)
+    }
+}
@@ -1,4 +1,6 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
 
 /// Representation of a Cable Grommet
 #[derive(Debug, Clone)]
@@ -10,18 +12,31 @@ pub struct CableGrommet {
 
 impl CableGrommet {
     /// Generate the outer body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.height)
     }
 
     /// Generate the inner cable hole
-    pub fn hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.inner_diameter / 2.0, self.height + 1.0)
+    pub fn hole(&self) -> CSG {
+        CSG::z_cylinder(self.inner_diameter / 2.0, self.height + 1.0)
     }
 
     /// Assemble the complete cable grommet
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.hole())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.hole())
     }
 }
 
+impl Part for CableGrommet {
+    fn name(&self) -> &str {
+        "CableGrommet"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structural
+    }
+
+    fn assemble(&self) -> CSG {
+        CableGrommet::assemble(self)
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Cable Strip
 #[derive(Debug, Clone)]
@@ -14,27 +17,40 @@ pub struct CableStrip {
 
 impl CableStrip {
     /// Generate the main strip body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the slots for the cables
-    pub fn slots(&self) -> CSG<()> {
+    pub fn slots(&self) -> CSG {
         let slot = CSG::prism(self.slot_width, self.slot_depth, self.height);
         let mut slots = CSG::new();
         let start_x = -((self.slot_count as f64 - 1.0) / 2.0) * self.slot_spacing;
 
         for i in 0..self.slot_count {
             let x_offset = start_x + (i as f64) * self.slot_spacing;
-            slots = slots.union(&slot.translate(Vector3::new(x_offset, 0.0, self.height / 2.0)));
+            slots = slots.union(&slot.translated(Vector3::new(x_offset, 0.0, self.height / 2.0)));
         }
 
         slots
     }
 
     /// Assemble the complete cable strip
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.slots())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.slots())
     }
 }
 
+impl Part for CableStrip {
+    fn name(&self) -> &str {
+        "CableStrip"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        CableStrip::assemble(self)
+    }
+}
@@ -0,0 +1,368 @@
+//! Memoised part geometry, so parts placed many times are only built once.
+//!
+//! Only parts that give a [`cache_key`](Part::cache_key) are cached, keyed by it together with
+//! the [current](crate::resolution::current) resolution. The parts of an
+//! [`Assembly`](crate::assembly::Assembly) are built through the [shared] cache, so a screw
+//! placed a hundred times is built once.
+
+use crate::anchor::Anchor;
+use crate::bom::Bom;
+use crate::colour::Colour;
+use crate::mass::MassProperties;
+use crate::material::{Finish, Material};
+use crate::part::{Category, Part};
+use crate::resolution;
+use crate::CSG;
+use csgrs::float_types::parry3d::bounding_volume::Aabb;
+use csgrs::plane::Plane;
+use csgrs::polygon::Polygon;
+use csgrs::vertex::Vertex;
+use nalgebra::{Point3, Vector3};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Environment variable naming a directory for the [shared] cache to keep meshes in.
+pub const DIRECTORY_VARIABLE: &str = "PARTSRS_CACHE_DIR";
+
+/// First line of every cached mesh file, bumped whenever the layout changes.
+const MAGIC: &[u8] = b"partsrs mesh 1\n";
+
+/// How often a cache has been asked for a solid and where the answer came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheStats {
+    /// Solids already in memory.
+    pub hits: usize,
+    /// Solids read back from the directory.
+    pub disk_hits: usize,
+    /// Solids that had to be built.
+    pub misses: usize,
+}
+
+/// A thread-safe store of built solids, optionally backed by a directory of mesh files.
+///
+/// Threads asking for the same solid at the same time wait for the first to build it;
+/// different solids are built in parallel. Nothing is ever evicted, call
+/// [`clear`](GeometryCache::clear) between unrelated models.
+#[derive(Debug, Default)]
+pub struct GeometryCache {
+    entries: Mutex<HashMap<String, Arc<OnceLock<CSG>>>>,
+    /// What parts work out from their solids, which they may do without it, so kept apart.
+    bounds: Mutex<HashMap<String, Aabb>>,
+    mass: Mutex<HashMap<String, MassProperties>>,
+    directory: Option<PathBuf>,
+    hits: AtomicUsize,
+    disk_hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl GeometryCache {
+    /// An empty cache held in memory only
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also keep every solid built in `directory`, so later runs can read it back.
+    ///
+    /// The directory is created when the first solid is written. Files written by a
+    /// different version of the crate are never read, but a changed part module within a
+    /// version is not noticed, so clear the directory after editing one.
+    pub fn with_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    pub fn directory(&self) -> Option<&Path> {
+        self.directory.as_deref()
+    }
+
+    /// The part's solid at the current resolution, built only if it hasn't been already, or
+    /// every time for a part without a [`cache_key`](Part::cache_key)
+    pub fn assemble<P: Part + ?Sized>(&self, part: &P) -> CSG {
+        match part.cache_key() {
+            Some(key) => self.get_or_build(&key, || part.assemble()),
+            None => part.assemble(),
+        }
+    }
+
+    /// The part's bounding box at the current resolution, worked out by the part only once.
+    ///
+    /// Unlike solids, two threads asking at the same time may both work it out.
+    pub fn bounding_box<P: Part + ?Sized>(&self, part: &P) -> Aabb {
+        match part.cache_key() {
+            Some(key) => memo(&self.bounds, full_key(&key), || part.bounding_box()),
+            None => part.bounding_box(),
+        }
+    }
+
+    /// The part's mass properties at the current resolution, worked out by the part only once
+    pub fn mass_properties<P: Part + ?Sized>(&self, part: &P) -> MassProperties {
+        match part.cache_key() {
+            Some(key) => memo(&self.mass, full_key(&key), || part.mass_properties()),
+            None => part.mass_properties(),
+        }
+    }
+
+    /// The solid stored under `key` at the current resolution, or the one `build` makes.
+    ///
+    /// `key` must identify everything `build` depends on apart from the resolution, e.g.
+    /// `format!("belt tooth {} {}", pitch, height)`.
+    pub fn get_or_build(&self, key: &str, build: impl FnOnce() -> CSG) -> CSG {
+        let key = full_key(key);
+        let cell = {
+            let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            Arc::clone(entries.entry(key.clone()).or_default())
+        };
+        if let Some(solid) = cell.get() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return solid.clone();
+        }
+        cell.get_or_init(|| {
+            if let Some(solid) = self.read(&key) {
+                self.disk_hits.fetch_add(1, Ordering::Relaxed);
+                return solid;
+            }
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            let solid = build();
+            // The cache is only an optimisation, so a directory that can't be written to is
+            // no reason to fail.
+            let _ = self.write(&key, &solid);
+            solid
+        })
+        .clone()
+    }
+
+    /// Number of solids held in memory
+    pub fn len(&self) -> usize {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.values().filter(|cell| cell.get().is_some()).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forget everything held in memory and reset the statistics, leaving any files alone
+    pub fn clear(&self) {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        self.bounds.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        self.mass.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        self.hits.store(0, Ordering::Relaxed);
+        self.disk_hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            disk_hits: self.disk_hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// File a key is stored in, named by a hash that stays the same from run to run
+    fn path(&self, key: &str) -> Option<PathBuf> {
+        let hash = key
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3));
+        Some(self.directory.as_ref()?.join(format!("{:016x}.mesh", hash)))
+    }
+
+    fn read(&self, key: &str) -> Option<CSG> {
+        let bytes = fs::read(self.path(key)?).ok()?;
+        decode(&bytes, key)
+    }
+
+    fn write(&self, key: &str, solid: &CSG) -> io::Result<()> {
+        let Some(path) = self.path(key) else {
+            return Ok(());
+        };
+        fs::create_dir_all(path.parent().unwrap())?;
+        // Write then rename, so another process never reads half a file.
+        let partial = path.with_extension(format!("{}.part", std::process::id()));
+        fs::write(&partial, encode(key, solid))?;
+        fs::rename(&partial, &path)
+    }
+}
+
+/// The part's type and `Debug` output, a [`cache_key`](Part::cache_key) for parts whose `Debug`
+/// output lists every dimension
+pub fn debug_key<P: fmt::Debug + ?Sized>(part: &P) -> String {
+    format!("{}{:?}", std::any::type_name::<P>(), part)
+}
+
+/// `key` with the crate version and current resolution, which everything built depends on
+fn full_key(key: &str) -> String {
+    format!("{} {} {:?}", env!("CARGO_PKG_VERSION"), key, resolution::current())
+}
+
+/// The value stored under `key`, or the one `work_out` gives, stored for next time
+fn memo<T: Copy>(values: &Mutex<HashMap<String, T>>, key: String, work_out: impl FnOnce() -> T) -> T {
+    if let Some(value) = values.lock().unwrap_or_else(|e| e.into_inner()).get(&key) {
+        return *value;
+    }
+    // Not held locked while working out, as that may use the cache again.
+    let value = work_out();
+    values.lock().unwrap_or_else(|e| e.into_inner()).insert(key, value);
+    value
+}
+
+/// The cache used by [`Cached`] parts and the leaves of assemblies, shared by every thread.
+///
+/// It keeps mesh files in the directory named by [`DIRECTORY_VARIABLE`], if that is set when
+/// the cache is first used.
+pub fn shared() -> &'static GeometryCache {
+    static SHARED: OnceLock<GeometryCache> = OnceLock::new();
+    SHARED.get_or_init(|| match std::env::var_os(DIRECTORY_VARIABLE) {
+        Some(directory) if !directory.is_empty() => GeometryCache::new().with_directory(directory),
+        _ => GeometryCache::new(),
+    })
+}
+
+/// A part whose solid, bounding box and mass properties come from the [shared] cache wherever it
+/// is used, not only when placed in an assembly, e.g. `Cached(screw).assemble()`.
+///
+/// Only parts with a [`cache_key`](Part::cache_key) are cached; others are built every time.
+#[derive(Debug, Clone)]
+pub struct Cached<P>(pub P);
+
+// No `cache_key` of its own: the solid already comes from the cache, and building it under the
+// same key again would wait on itself.
+impl<P: Part> Part for Cached<P> {
+    fn name(&self) -> &str {
+        self.0.name()
+    }
+
+    fn category(&self) -> Category {
+        self.0.category()
+    }
+
+    fn assemble(&self) -> CSG {
+        shared().assemble(&self.0)
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        shared().bounding_box(&self.0)
+    }
+
+    fn description(&self) -> String {
+        self.0.description()
+    }
+
+    fn density(&self) -> Option<f64> {
+        self.0.density()
+    }
+
+    fn catalogue_mass(&self) -> Option<f64> {
+        self.0.catalogue_mass()
+    }
+
+    fn mass_properties(&self) -> MassProperties {
+        shared().mass_properties(&self.0)
+    }
+
+    fn unit_cost(&self) -> Option<f64> {
+        self.0.unit_cost()
+    }
+
+    fn bom(&self, bom: &mut Bom) {
+        self.0.bom(bom)
+    }
+
+    fn anchors(&self) -> Vec<Anchor> {
+        self.0.anchors()
+    }
+}
+
+/// Mesh file layout: the magic line, the key, then each polygon's finish, plane and vertices,
+/// with lengths as little-endian `u64`.
+fn encode(key: &str, solid: &CSG) -> Vec<u8> {
+    let mut out = MAGIC.to_vec();
+    out.extend((key.len() as u64).to_le_bytes());
+    out.extend(key.as_bytes());
+    out.extend((solid.polygons.len() as u64).to_le_bytes());
+    for polygon in &solid.polygons {
+        match polygon.metadata {
+            None => out.push(0),
+            Some(Finish { colour, material }) => {
+                out.push(1);
+                for channel in [colour.r, colour.g, colour.b, colour.a] {
+                    out.extend(channel.to_le_bytes());
+                }
+                out.push(material.map_or(0, |m| Material::ALL.iter().position(|&n| n == m).unwrap() as u8 + 1));
+            }
+        }
+        out.push(polygon.open as u8);
+        for value in polygon.plane.normal.iter().chain([&polygon.plane.w]) {
+            out.extend(value.to_le_bytes());
+        }
+        out.extend((polygon.vertices.len() as u64).to_le_bytes());
+        for vertex in &polygon.vertices {
+            for value in vertex.pos.iter().chain(vertex.normal.iter()) {
+                out.extend(value.to_le_bytes());
+            }
+        }
+    }
+    out
+}
+
+/// The solid in a mesh file, if the file is complete and was written for `key`
+fn decode(bytes: &[u8], key: &str) -> Option<CSG> {
+    let mut input = bytes.strip_prefix(MAGIC)?;
+    let len = read_len(&mut input)?;
+    if read_bytes(&mut input, len)? != key.as_bytes() {
+        return None;
+    }
+    let mut polygons = Vec::new();
+    for _ in 0..read_len(&mut input)? {
+        let metadata = match read_bytes(&mut input, 1)?[0] {
+            0 => None,
+            _ => {
+                let mut channel = || Some(f32::from_le_bytes(read_bytes(&mut input, 4)?.try_into().ok()?));
+                let colour = Colour { r: channel()?, g: channel()?, b: channel()?, a: channel()? };
+                let material = match read_bytes(&mut input, 1)?[0] {
+                    0 => None,
+                    index => Some(*Material::ALL.get(index as usize - 1)?),
+                };
+                Some(Finish { colour, material })
+            }
+        };
+        let open = read_bytes(&mut input, 1)?[0] != 0;
+        let [x, y, z, w] = read_floats(&mut input)?;
+        let plane = Plane { normal: Vector3::new(x, y, z), w };
+        let mut vertices = Vec::new();
+        for _ in 0..read_len(&mut input)? {
+            let [x, y, z, nx, ny, nz] = read_floats(&mut input)?;
+            vertices.push(Vertex::new(Point3::new(x, y, z), Vector3::new(nx, ny, nz)));
+        }
+        polygons.push(Polygon { vertices, open, metadata, plane });
+    }
+    input.is_empty().then(|| CSG::from_polygons(polygons))
+}
+
+fn read_len(input: &mut &[u8]) -> Option<usize> {
+    let mut bytes = [0; 8];
+    input.read_exact(&mut bytes).ok()?;
+    usize::try_from(u64::from_le_bytes(bytes)).ok()
+}
+
+fn read_floats<const N: usize>(input: &mut &[u8]) -> Option<[f64; N]> {
+    let mut values = [0.0; N];
+    for value in &mut values {
+        *value = f64::from_le_bytes(read_bytes(input, 8)?.try_into().ok()?);
+    }
+    Some(values)
+}
+
+fn read_bytes<'a>(input: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if input.len() < len {
+        return None;
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Some(head)
+}
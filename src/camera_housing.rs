@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Camera Housing
 #[derive(Debug, Clone)]
@@ -12,19 +15,32 @@ pub struct CameraHousing {
 
 impl CameraHousing {
     /// Generate the housing body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the lens hole
-    pub fn lens_hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.lens_diameter / 2.0, self.lens_depth + 1.0)
-            .translate(Vector3::new(0.0, 0.0, self.depth / 2.0))
+    pub fn lens_hole(&self) -> CSG {
+        CSG::z_cylinder(self.lens_diameter / 2.0, self.lens_depth + 1.0)
+            .translated(Vector3::new(0.0, 0.0, self.depth / 2.0))
     }
 
     /// Assemble the complete camera housing
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.lens_hole())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.lens_hole())
     }
 }
 
+impl Part for CameraHousing {
+    fn name(&self) -> &str {
+        "CameraHousing"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        CameraHousing::assemble(self)
+    }
+}
@@ -1,5 +1,8 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::material::{Finishing, Material, TINNED_COPPER};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a capacitor module
 #[derive(Debug, Clone)]
@@ -14,12 +17,13 @@ pub struct Capacitor {
 
 impl Capacitor {
     /// Generate the cylindrical body of the capacitor
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_height)
+            .finished(Material::Plastic)
     }
     
     /// Generate the leads of the capacitor
-    pub fn leads(&self) -> CSG<()> {
+    pub fn leads(&self) -> CSG {
         let mut leads = CSG::new();
         let offsets = [
             (-self.lead_spacing / 2.0, 0.0),
@@ -27,16 +31,16 @@ impl Capacitor {
         ];
         
         for &(x, y) in &offsets {
-            let lead = CSG::cylinder_z(self.lead_diameter / 2.0, self.lead_length)
-                .translate(Vector3::new(x, y, -self.lead_length));
+            let lead = CSG::z_cylinder(self.lead_diameter / 2.0, self.lead_length)
+                .translated(Vector3::new(x, y, -self.lead_length));
             leads = leads.union(&lead);
         }
         
-        leads
+        leads.finished(TINNED_COPPER)
     }
     
     /// Generate the full capacitor model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut capacitor = self.body();
         let leads = self.leads();
         
@@ -46,3 +50,16 @@ impl Capacitor {
     }
 }
 
+impl Part for Capacitor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Capacitor::assemble(self)
+    }
+}
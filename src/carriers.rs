@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Carrier Bracket
 #[derive(Debug, Clone)]
@@ -13,27 +16,40 @@ pub struct CarrierBracket {
 
 impl CarrierBracket {
     /// Generate the main bracket body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.thickness)
     }
 
     /// Generate the mounting holes
-    pub fn holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.thickness + 1.0);
+    pub fn holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.thickness + 1.0);
         let mut hole_array = CSG::new();
         let start_x = -((self.hole_count as f64 - 1.0) / 2.0) * self.hole_spacing;
 
         for i in 0..self.hole_count {
             let x_offset = start_x + (i as f64) * self.hole_spacing;
-            hole_array = hole_array.union(&hole.translate(Vector3::new(x_offset, 0.0, 0.0)));
+            hole_array = hole_array.union(&hole.translated(Vector3::new(x_offset, 0.0, 0.0)));
         }
 
         hole_array
     }
 
     /// Assemble the complete carrier bracket
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.holes())
     }
 }
 
+impl Part for CarrierBracket {
+    fn name(&self) -> &str {
+        "CarrierBracket"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        CarrierBracket::assemble(self)
+    }
+}
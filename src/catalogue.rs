@@ -0,0 +1,598 @@
+use crate::extrusion::{ChannelRecess, Extrusion, ALL_EXTRUSIONS, E2020};
+use crate::fans::{all_fans, fan80x25, Fan};
+use crate::nopscadlib::{self, Layout, Scope};
+use crate::part::Part;
+use crate::stepper_motors::{all_stepper_motors, nema17_40, StepperMotor};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+
+/// Errors from looking up, configuring or loading catalogue parts.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CatalogueError {
+    UnknownPart(String),
+    UnknownParameter { part: String, parameter: String },
+    /// An anchor name the part doesn't have.
+    UnknownAnchor { part: String, anchor: String },
+    InvalidValue { parameter: String, value: f64 },
+    /// Parameters that make sense alone but not together, e.g. holes outside the frame.
+    Inconsistent { part: String, reason: String },
+    /// A data file section that names no known family of parts.
+    UnknownFamily(String),
+    MissingName,
+    MissingParameters { part: String, parameters: Vec<String> },
+    /// A parameter given as text rather than a number or flag.
+    NotANumber { parameter: String },
+    /// A `name` or `base` given as something other than text.
+    NotText { parameter: String },
+    /// A `base` entry from a different family.
+    WrongFamily { base: String, family: String },
+    /// A data file that could not be read or parsed.
+    File { path: String, message: String },
+    /// An error in one entry of a data file, e.g. `fans[2]` or `fans[2] "fan92x25"`.
+    Entry { entry: String, source: Box<CatalogueError> },
+}
+
+impl fmt::Display for CatalogueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CatalogueError::UnknownPart(name) => write!(f, "no catalogue part named `{}`", name),
+            CatalogueError::UnknownParameter { part, parameter } => {
+                write!(f, "`{}` has no parameter `{}`", part, parameter)
+            }
+            CatalogueError::UnknownAnchor { part, anchor } => write!(f, "`{}` has no anchor `{}`", part, anchor),
+            CatalogueError::InvalidValue { parameter, value } => {
+                write!(f, "invalid value {} for `{}`", value, parameter)
+            }
+            CatalogueError::Inconsistent { part, reason } => write!(f, "`{}`: {}", part, reason),
+            CatalogueError::UnknownFamily(family) => write!(f, "no family of parts called `{}`", family),
+            CatalogueError::MissingName => write!(f, "missing `name`"),
+            CatalogueError::MissingParameters { part, parameters } => {
+                write!(f, "`{}` is missing `{}`", part, parameters.join("`, `"))
+            }
+            CatalogueError::NotANumber { parameter } => write!(f, "`{}` must be a number", parameter),
+            CatalogueError::NotText { parameter } => write!(f, "`{}` must be text", parameter),
+            CatalogueError::WrongFamily { base, family } => {
+                write!(f, "base part `{}` is not in family `{}`", base, family)
+            }
+            CatalogueError::File { path, message } => write!(f, "{}: {}", path, message),
+            CatalogueError::Entry { entry, source } => write!(f, "{}: {}", entry, source),
+        }
+    }
+}
+
+impl Error for CatalogueError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CatalogueError::Entry { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// A part whose dimensions can be listed and overridden by name, e.g. from the command line
+/// or a catalogue data file.
+///
+/// Flags are exposed as `0.0` / `1.0`.
+pub trait Configurable: Part + Send + Sync {
+    /// Every parameter with its current value
+    fn parameters(&self) -> Vec<(&'static str, f64)>;
+
+    /// Override a single parameter
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), CatalogueError>;
+
+    fn set_name(&mut self, name: &str);
+
+    /// Check the parameters make sense together, once they have all been set
+    fn validate(&self) -> Result<(), CatalogueError> {
+        Ok(())
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Configurable>;
+}
+
+/// `value` if it is finite and greater than zero.
+fn positive(name: &str, value: f64) -> Result<f64, CatalogueError> {
+    if value > 0.0 && value.is_finite() {
+        Ok(value)
+    } else {
+        Err(CatalogueError::InvalidValue { parameter: name.to_string(), value })
+    }
+}
+
+/// `value` if it is a whole number of at least one.
+fn count(name: &str, value: f64) -> Result<u32, CatalogueError> {
+    if value >= 1.0 && value.fract() == 0.0 && value <= u32::MAX as f64 {
+        Ok(value as u32)
+    } else {
+        Err(CatalogueError::InvalidValue { parameter: name.to_string(), value })
+    }
+}
+
+fn unknown_parameter(part: &str, name: &str) -> CatalogueError {
+    CatalogueError::UnknownParameter { part: part.to_string(), parameter: name.to_string() }
+}
+
+fn inconsistent(part: &str, reason: &str) -> CatalogueError {
+    CatalogueError::Inconsistent { part: part.to_string(), reason: reason.to_string() }
+}
+
+impl Configurable for Extrusion {
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        let p = &self.profile;
+        let (recess_width, recess_depth) = match p.recess {
+            ChannelRecess::None => (0.0, 0.0),
+            ChannelRecess::Some(width, depth) => (width, depth),
+        };
+        vec![
+            ("length", self.length),
+            ("center", self.center as u8 as f64),
+            ("corner_holes", self.corner_holes as u8 as f64),
+            ("width", p.width),
+            ("height", p.height),
+            ("center_hole_wd", p.center_hole_wd),
+            ("corner_hole_wd", p.corner_hole_wd),
+            ("center_square_wd", p.center_square_wd),
+            ("channel_width", p.channel_width),
+            ("channel_width_internal", p.channel_width_internal),
+            ("tab_thickness", p.tab_thickness),
+            ("spar_thickness", p.spar_thickness),
+            ("fillet_radius", p.fillet_radius),
+            ("recess_width", recess_width),
+            ("recess_depth", recess_depth),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), CatalogueError> {
+        if !value.is_finite() {
+            return Err(CatalogueError::InvalidValue { parameter: name.to_string(), value });
+        }
+        let p = &mut self.profile;
+        let (recess_width, recess_depth) = match p.recess {
+            ChannelRecess::None => (0.0, 0.0),
+            ChannelRecess::Some(width, depth) => (width, depth),
+        };
+        match name {
+            "length" => self.length = positive(name, value)?,
+            "center" => self.center = value != 0.0,
+            "corner_holes" => self.corner_holes = value != 0.0,
+            "width" => p.width = positive(name, value)?,
+            "height" => p.height = positive(name, value)?,
+            "center_hole_wd" => p.center_hole_wd = value,
+            "corner_hole_wd" => p.corner_hole_wd = value,
+            "center_square_wd" => p.center_square_wd = value,
+            "channel_width" => p.channel_width = positive(name, value)?,
+            "channel_width_internal" => p.channel_width_internal = positive(name, value)?,
+            "tab_thickness" => p.tab_thickness = positive(name, value)?,
+            "spar_thickness" => p.spar_thickness = positive(name, value)?,
+            "fillet_radius" => p.fillet_radius = value.max(0.0),
+            "recess_width" | "recess_depth" => {
+                if value < 0.0 {
+                    return Err(CatalogueError::InvalidValue { parameter: name.to_string(), value });
+                }
+                let (width, depth) = if name == "recess_width" {
+                    (value, recess_depth)
+                } else {
+                    (recess_width, value)
+                };
+                // Kept while only one size is set, as they are set one at a time.
+                p.recess = if width > 0.0 || depth > 0.0 {
+                    ChannelRecess::Some(width, depth)
+                } else {
+                    ChannelRecess::None
+                };
+            }
+            _ => return Err(unknown_parameter(&p.name, name)),
+        }
+        Ok(())
+    }
+
+    fn set_name(&mut self, name: &str) {
+        self.profile.name = name.to_string().into();
+    }
+
+    fn validate(&self) -> Result<(), CatalogueError> {
+        let p = &self.profile;
+        if p.channel_width > p.channel_width_internal {
+            return Err(inconsistent(&p.name, "the channel opening is wider than the channel"));
+        }
+        if p.channel_width_internal >= p.width.min(p.height) {
+            return Err(inconsistent(&p.name, "the channel is wider than the extrusion"));
+        }
+        Ok(())
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Configurable> {
+        Box::new(self.clone())
+    }
+}
+
+impl Configurable for Fan {
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("frame_width", self.frame_width),
+            ("frame_height", self.frame_height),
+            ("frame_depth", self.frame_depth),
+            ("blade_diameter", self.blade_diameter),
+            ("hub_diameter", self.hub_diameter),
+            ("blade_count", self.blade_count as f64),
+            ("mounting_hole_diameter", self.mounting_hole_diameter),
+            ("mounting_hole_spacing", self.mounting_hole_spacing),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), CatalogueError> {
+        match name {
+            "frame_width" => self.frame_width = positive(name, value)?,
+            "frame_height" => self.frame_height = positive(name, value)?,
+            "frame_depth" => self.frame_depth = positive(name, value)?,
+            "blade_diameter" => self.blade_diameter = positive(name, value)?,
+            "hub_diameter" => self.hub_diameter = positive(name, value)?,
+            "blade_count" => self.blade_count = count(name, value)?,
+            "mounting_hole_diameter" => self.mounting_hole_diameter = positive(name, value)?,
+            "mounting_hole_spacing" => self.mounting_hole_spacing = positive(name, value)?,
+            _ => return Err(unknown_parameter(&self.name, name)),
+        }
+        Ok(())
+    }
+
+    fn set_name(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    fn validate(&self) -> Result<(), CatalogueError> {
+        Ok(Fan::validate(self)?)
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Configurable> {
+        Box::new(self.clone())
+    }
+}
+
+impl Configurable for StepperMotor {
+    fn parameters(&self) -> Vec<(&'static str, f64)> {
+        vec![
+            ("side_length", self.side_length),
+            ("body_length", self.body_length),
+            ("shaft_diameter", self.shaft_diameter),
+            ("shaft_length", self.shaft_length),
+            ("mount_hole_spacing", self.mount_hole_spacing),
+            ("mount_hole_diameter", self.mount_hole_diameter),
+            ("boss_diameter", self.boss_diameter),
+            ("boss_height", self.boss_height),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: f64) -> Result<(), CatalogueError> {
+        match name {
+            "side_length" => self.side_length = positive(name, value)?,
+            "body_length" => self.body_length = positive(name, value)?,
+            "shaft_diameter" => self.shaft_diameter = positive(name, value)?,
+            "shaft_length" => self.shaft_length = positive(name, value)?,
+            "mount_hole_spacing" => self.mount_hole_spacing = positive(name, value)?,
+            "mount_hole_diameter" => self.mount_hole_diameter = positive(name, value)?,
+            "boss_diameter" => self.boss_diameter = positive(name, value)?,
+            "boss_height" => self.boss_height = positive(name, value)?,
+            _ => return Err(unknown_parameter(&self.name, name)),
+        }
+        Ok(())
+    }
+
+    fn set_name(&mut self, name: &str) {
+        self.name = name.to_string();
+    }
+
+    fn validate(&self) -> Result<(), CatalogueError> {
+        Ok(StepperMotor::validate(self)?)
+    }
+
+    fn boxed_clone(&self) -> Box<dyn Configurable> {
+        Box::new(self.clone())
+    }
+}
+
+/// Length of extrusion used when a catalogue profile is built without one.
+pub const DEFAULT_EXTRUSION_LENGTH: f64 = 100.0;
+
+/// A named entry in a catalogue.
+pub struct Entry {
+    pub name: String,
+    /// Module the part comes from, e.g. `"extrusion"`.
+    pub family: &'static str,
+    pub part: Box<dyn Configurable>,
+}
+
+impl Clone for Entry {
+    fn clone(&self) -> Self {
+        Entry { name: self.name.clone(), family: self.family, part: self.part.boxed_clone() }
+    }
+}
+
+/// Every built-in catalogue part, with default parameters
+pub fn entries() -> Vec<Entry> {
+    let extrusions = ALL_EXTRUSIONS.iter().map(|profile| Entry {
+        name: profile.name.to_string(),
+        family: "extrusion",
+        part: Box::new(Extrusion {
+            profile: profile.clone(),
+            length: DEFAULT_EXTRUSION_LENGTH,
+            center: false,
+            corner_holes: false,
+        }),
+    });
+    let fans = all_fans().into_iter().map(|fan| Entry {
+        name: fan.name.clone(),
+        family: "fans",
+        part: Box::new(fan),
+    });
+    let steppers = all_stepper_motors().into_iter().map(|motor| Entry {
+        name: motor.name.clone(),
+        family: "stepper_motors",
+        part: Box::new(motor),
+    });
+    extrusions.chain(fans).chain(steppers).collect()
+}
+
+/// A part of `family` for data file entries to configure, so any catalogue can load them, even
+/// an empty one. Entries without a `base` set every parameter, so its values don't matter.
+fn template(family: &str) -> Option<Entry> {
+    let (family, part): (&'static str, Box<dyn Configurable>) = match family {
+        "extrusion" => (
+            "extrusion",
+            Box::new(Extrusion {
+                profile: E2020,
+                length: DEFAULT_EXTRUSION_LENGTH,
+                center: false,
+                corner_holes: false,
+            }),
+        ),
+        "fans" => ("fans", Box::new(fan80x25())),
+        "stepper_motors" => ("stepper_motors", Box::new(nema17_40())),
+        _ => return None,
+    };
+    Some(Entry { name: part.name().to_string(), family, part })
+}
+
+/// Look up a built-in catalogue part by name, ignoring case
+pub fn find(name: &str) -> Result<Entry, CatalogueError> {
+    Catalogue::builtin().find(name)
+}
+
+/// Syntax of a catalogue data file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFormat {
+    Toml,
+    Json,
+}
+
+impl DataFormat {
+    /// Guess the format from a file extension, e.g. `"toml"` or `"json"`
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "toml" => Some(DataFormat::Toml),
+            "json" => Some(DataFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// A value in a data file entry.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Value {
+    Number(f64),
+    Flag(bool),
+    Text(String),
+}
+
+/// A data file: for each family, a list of entries mapping parameter names to values.
+type DataFile = BTreeMap<String, Vec<BTreeMap<String, Value>>>;
+
+/// A set of parts that can be looked up by name: the built-in dataset plus any loaded from files.
+///
+/// Data files have a section per family, named as in [`Entry::family`], holding a list of
+/// entries. Each entry has a `name` and either every parameter of that family, or a `base` part
+/// to copy and the parameters that differ from it:
+///
+/// ```toml
+/// [[fans]]
+/// name = "fan92x25"
+/// base = "fan80x25"
+/// frame_width = 92
+/// frame_height = 92
+/// blade_diameter = 88
+/// mounting_hole_spacing = 82.5
+/// ```
+///
+/// JSON files use the same layout, e.g. `{ "fans": [{ "name": "fan92x25", ... }] }`.
+/// NopSCADLib's own `extrusions.scad`, `fans.scad` and `stepper_motors.scad` can also be
+/// loaded, see [`nopscadlib`]. Entries with the name of an existing part replace it.
+#[derive(Clone)]
+pub struct Catalogue {
+    entries: Vec<Entry>,
+    /// Variables of the `.scad` files loaded so far, for later files to refer to.
+    scad: Scope,
+}
+
+impl Catalogue {
+    /// A catalogue with no parts, not even the built-in ones
+    pub fn empty() -> Self {
+        Catalogue { entries: Vec::new(), scad: Scope::new() }
+    }
+
+    /// The built-in parts
+    pub fn builtin() -> Self {
+        Catalogue { entries: entries(), scad: Scope::new() }
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Look up a part by name, ignoring case
+    pub fn find(&self, name: &str) -> Result<Entry, CatalogueError> {
+        self.entries
+            .iter()
+            .find(|e| e.name.eq_ignore_ascii_case(name))
+            .cloned()
+            .ok_or_else(|| CatalogueError::UnknownPart(name.to_string()))
+    }
+
+    /// Add a part, replacing any with the same name
+    pub fn insert(&mut self, entry: Entry) {
+        match self.entries.iter_mut().find(|e| e.name.eq_ignore_ascii_case(&entry.name)) {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    /// Load a TOML, JSON or NopSCADLib `.scad` data file, chosen by its extension, returning the
+    /// number of parts added.
+    ///
+    /// A `.scad` file populates the family its name is for, e.g. `fans.scad`. Others, like
+    /// `screws.scad`, only define variables for the files loaded after them.
+    pub fn load_file(&mut self, path: &Path) -> Result<usize, CatalogueError> {
+        let file_error = |message: String| CatalogueError::File { path: path.display().to_string(), message };
+        let is_scad = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("scad"));
+        let format = DataFormat::from_path(path);
+        if format.is_none() && !is_scad {
+            return Err(file_error("expected a .toml, .json or .scad file".to_string()));
+        }
+        let text = std::fs::read_to_string(path).map_err(|e| file_error(e.to_string()))?;
+        let loaded = match format {
+            Some(format) => self.load_str(&text, format),
+            None => {
+                let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+                self.load_scad_layout(&text, nopscadlib::layout_for_file(stem))
+            }
+        };
+        loaded.map_err(|e| match e {
+            CatalogueError::File { message, .. } => file_error(message),
+            e => CatalogueError::File { path: path.display().to_string(), message: e.to_string() },
+        })
+    }
+
+    /// Load parts from the text of a data file, returning the number added.
+    ///
+    /// Nothing is added unless every entry is valid.
+    pub fn load_str(&mut self, text: &str, format: DataFormat) -> Result<usize, CatalogueError> {
+        let data: DataFile = match format {
+            DataFormat::Toml => toml::from_str(text).map_err(|e| e.to_string()),
+            DataFormat::Json => serde_json::from_str(text).map_err(|e| e.to_string()),
+        }
+        .map_err(|message| CatalogueError::File { path: String::new(), message })?;
+
+        // Build into a copy so later entries can use earlier ones as a base, and a bad
+        // entry leaves this catalogue untouched.
+        let mut loaded = self.clone();
+        let mut added = 0;
+        for (family, records) in &data {
+            for (index, record) in records.iter().enumerate() {
+                let entry = loaded.entry_from(family, record).map_err(|e| {
+                    let name = match record.get("name") {
+                        Some(Value::Text(name)) => format!(" `{}`", name),
+                        _ => String::new(),
+                    };
+                    CatalogueError::Entry { entry: format!("{}[{}]{}", family, index, name), source: Box::new(e) }
+                })?;
+                loaded.insert(entry);
+                added += 1;
+            }
+        }
+        *self = loaded;
+        Ok(added)
+    }
+
+    /// Load the type vectors of catalogue `family` from the text of a NopSCADLib `.scad` file,
+    /// returning the number of parts added.
+    ///
+    /// The file can refer to variables of `.scad` files loaded before it. Assignments that
+    /// aren't types of the family, such as lists of them, are ignored, and nothing is added
+    /// unless every type is valid.
+    pub fn load_scad(&mut self, text: &str, family: &str) -> Result<usize, CatalogueError> {
+        let layout = nopscadlib::layout(family).ok_or_else(|| CatalogueError::UnknownFamily(family.to_string()))?;
+        self.load_scad_layout(text, Some(layout))
+    }
+
+    /// Run a `.scad` file, adding the types that match `layout` if there is one
+    fn load_scad_layout(&mut self, text: &str, layout: Option<&Layout>) -> Result<usize, CatalogueError> {
+        let mut loaded = self.clone();
+        let assignments = loaded
+            .scad
+            .run(text)
+            .map_err(|e| CatalogueError::File { path: String::new(), message: e.to_string() })?;
+        let mut added = 0;
+        if let Some(layout) = layout {
+            let records = assignments.iter().filter_map(|(variable, value)| layout.record(variable, value));
+            for (index, record) in records.enumerate() {
+                let mut fields: BTreeMap<String, Value> =
+                    record.parameters.iter().map(|&(key, value)| (key.to_string(), Value::Number(value))).collect();
+                fields.insert("name".to_string(), Value::Text(record.name.clone()));
+                let entry = loaded.entry_from(layout.family, &fields).map_err(|e| CatalogueError::Entry {
+                    entry: format!("{}[{}] `{}`", layout.family, index, record.name),
+                    source: Box::new(e),
+                })?;
+                loaded.insert(entry);
+                added += 1;
+            }
+        }
+        *self = loaded;
+        Ok(added)
+    }
+
+    /// Build and validate one data file entry
+    fn entry_from(&self, family: &str, record: &BTreeMap<String, Value>) -> Result<Entry, CatalogueError> {
+        let template = template(family).ok_or_else(|| CatalogueError::UnknownFamily(family.to_string()))?;
+        let name = match record.get("name") {
+            Some(Value::Text(name)) if !name.is_empty() => name.clone(),
+            Some(Value::Text(_)) | None => return Err(CatalogueError::MissingName),
+            Some(_) => return Err(CatalogueError::NotText { parameter: "name".to_string() }),
+        };
+        let base = match record.get("base") {
+            Some(Value::Text(base)) => {
+                let base = self.find(base)?;
+                if base.family != family {
+                    return Err(CatalogueError::WrongFamily { base: base.name, family: family.to_string() });
+                }
+                Some(base)
+            }
+            Some(_) => return Err(CatalogueError::NotText { parameter: "base".to_string() }),
+            None => None,
+        };
+
+        let mut part = base.as_ref().unwrap_or(&template).part.boxed_clone();
+        part.set_name(&name);
+        for (key, value) in record {
+            let value = match (key.as_str(), value) {
+                ("name" | "base", _) => continue,
+                (_, Value::Number(v)) => *v,
+                (_, Value::Flag(flag)) => *flag as u8 as f64,
+                (_, Value::Text(_)) => return Err(CatalogueError::NotANumber { parameter: key.clone() }),
+            };
+            part.set_parameter(key, value)?;
+        }
+        if base.is_none() {
+            let missing: Vec<String> = part
+                .parameters()
+                .iter()
+                .map(|(key, _)| key.to_string())
+                .filter(|key| !record.contains_key(key))
+                .collect();
+            if !missing.is_empty() {
+                return Err(CatalogueError::MissingParameters { part: name, parameters: missing });
+            }
+        }
+        part.validate()?;
+
+        Ok(Entry { name, family: template.family, part })
+    }
+}
+
+impl Default for Catalogue {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
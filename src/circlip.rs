@@ -1,4 +1,19 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::error::{inconsistent, positive, PartError};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+
+/// How far a circlip is sprung open, which sets the diameter of its ring.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CirclipState {
+    /// At the nominal size of the tube or shaft it is named for.
+    Nominal,
+    /// Sprung into its groove.
+    #[default]
+    Installed,
+    /// Free, before it is fitted.
+    Relaxed,
+}
 
 /// Representation of a Circlip (Retaining Ring) model
 #[derive(Debug, Clone)]
@@ -8,13 +23,15 @@ pub struct Circlip {
     pub relaxed_od: f64,   // Relaxed OD when not installed
     pub thickness: f64,    // Thickness
     pub lug_size: f64,     // Size of the lugs
-    pub taper_width: f64,  // Widest part of the taper
+    pub taper_width: f64,  // Widest part of the taper, and the width of the ring
     pub plier_hole_diameter: f64, // Plier hole diameter
     pub closed_angle: f64, // Closed angle (default to 25 degrees)
 }
 
 impl Circlip {
-    /// Create a new circlip with default parameters
+    /// Create a new circlip checked by [`validate`](Circlip::validate), the closed angle
+    /// defaulting to 25 degrees
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         nominal_od: f64,
         groove_diameter: f64,
@@ -24,8 +41,8 @@ impl Circlip {
         taper_width: f64,
         plier_hole_diameter: f64,
         closed_angle: Option<f64>,
-    ) -> Self {
-        Circlip {
+    ) -> Result<Self, PartError> {
+        let circlip = Circlip {
             nominal_od,
             groove_diameter,
             relaxed_od,
@@ -34,37 +51,84 @@ impl Circlip {
             taper_width,
             plier_hole_diameter,
             closed_angle: closed_angle.unwrap_or(25.0),
+        };
+        circlip.validate()?;
+        Ok(circlip)
+    }
+
+    /// Check the dimensions are positive, the ring springs out into a groove wider than the tube,
+    /// leaves a hole through the middle and the plier holes fit in the lugs
+    pub fn validate(&self) -> Result<(), PartError> {
+        positive("nominal_od", self.nominal_od)?;
+        positive("groove_diameter", self.groove_diameter)?;
+        positive("relaxed_od", self.relaxed_od)?;
+        positive("thickness", self.thickness)?;
+        positive("lug_size", self.lug_size)?;
+        positive("taper_width", self.taper_width)?;
+        positive("plier_hole_diameter", self.plier_hole_diameter)?;
+        if !(self.closed_angle > 0.0 && self.closed_angle < 360.0) {
+            return Err(PartError::InvalidValue { parameter: "closed_angle".to_string(), value: self.closed_angle });
         }
+        if self.groove_diameter <= self.nominal_od {
+            return Err(inconsistent(self.name(), "the groove is no wider than the tube"));
+        }
+        if self.relaxed_od < self.groove_diameter {
+            return Err(inconsistent(self.name(), "the relaxed ring is smaller than its groove"));
+        }
+        if self.taper_width >= self.nominal_od / 2.0 {
+            return Err(inconsistent(self.name(), "the ring is wider than its radius"));
+        }
+        if self.plier_hole_diameter >= self.lug_size.min(self.taper_width) {
+            return Err(inconsistent(self.name(), "the plier holes are bigger than the lugs"));
+        }
+        Ok(())
     }
 
-    /// Generate the circlip ring shape
-    pub fn ring(&self, open: i32) -> CSG<()> {
-        let od = match open {
-            -1 => self.nominal_od,
-            0 => self.groove_diameter,
-            1 => self.relaxed_od,
-            _ => self.groove_diameter,
-        };
-        let inner_radius = self.nominal_od / 2.0;
-        let outer_radius = od / 2.0;
-        let csg_outer = CSG::cylinder_z(outer_radius, self.thickness);
-        let csg_inner = CSG::cylinder_z(inner_radius, self.thickness);
+    /// Outside diameter of the ring in `state`
+    pub fn diameter(&self, state: CirclipState) -> f64 {
+        match state {
+            CirclipState::Nominal => self.nominal_od,
+            CirclipState::Installed => self.groove_diameter,
+            CirclipState::Relaxed => self.relaxed_od,
+        }
+    }
+
+    /// Generate the circlip ring shape, `taper_width` wide inside its diameter in `state`
+    pub fn ring(&self, state: CirclipState) -> CSG {
+        let outer_radius = self.diameter(state) / 2.0;
+        let inner_radius = outer_radius - self.taper_width;
+        let csg_outer = CSG::z_cylinder(outer_radius, self.thickness);
+        let csg_inner = CSG::z_cylinder(inner_radius, self.thickness);
         csg_outer.subtract(&csg_inner)
     }
 
     /// Generate the circlip lugs
-    pub fn lugs(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.plier_hole_diameter / 2.0, self.thickness);
+    pub fn lugs(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.plier_hole_diameter / 2.0, self.thickness);
         let lug = CSG::prism(self.lug_size, self.taper_width, self.thickness);
         lug.subtract(&hole)
     }
 
     /// Assemble the full circlip model
-    pub fn assemble(&self, open: i32) -> CSG<()> {
-        let mut circlip = self.ring(open);
+    pub fn assemble(&self, state: CirclipState) -> CSG {
+        let mut circlip = self.ring(state);
         let lugs = self.lugs();
         circlip = circlip.union(&lugs);
         circlip
     }
 }
 
+impl Part for Circlip {
+    fn name(&self) -> &str {
+        "Circlip"
+    }
+
+    fn category(&self) -> Category {
+        Category::Fastener
+    }
+
+    /// The circlip as installed in its groove.
+    fn assemble(&self) -> CSG {
+        Circlip::assemble(self, CirclipState::Installed)
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+
+/// An RGBA colour with components in `0.0..=1.0`, the equivalent of OpenSCAD's `color([r, g, b, a])`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Colour {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Colour {
+    /// Opaque colour from components in `0.0..=1.0`
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Colour { r, g, b, a: 1.0 }
+    }
+
+    /// Colour with alpha from components in `0.0..=1.0`
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Colour { r, g, b, a }
+    }
+
+    /// Neutral grey, `percent` from 0 (black) to 100 (white), like NopSCADLib's `grey(n)`.
+    pub fn grey(percent: f32) -> Self {
+        let v = (percent / 100.0).clamp(0.0, 1.0);
+        Colour::rgb(v, v, v)
+    }
+
+    /// Components as bytes, `[r, g, b, a]`
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        let byte = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [byte(self.r), byte(self.g), byte(self.b), byte(self.a)]
+    }
+}
+
+/// Formats as `#RRGGBB`, or `#RRGGBBAA` when not fully opaque.
+impl fmt::Display for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [r, g, b, a] = self.to_rgba8();
+        if a == 255 {
+            write!(f, "#{:02X}{:02X}{:02X}", r, g, b)
+        } else {
+            write!(f, "#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+        }
+    }
+}
+
+pub const BLACK: Colour = Colour::rgb(0.0, 0.0, 0.0);
+pub const WHITE: Colour = Colour::rgb(1.0, 1.0, 1.0);
+pub const RED: Colour = Colour::rgb(1.0, 0.0, 0.0);
+pub const GREEN: Colour = Colour::rgb(0.0, 0.5, 0.0);
+pub const BLUE: Colour = Colour::rgb(0.0, 0.0, 1.0);
+pub const SILVER: Colour = Colour::rgb(0.75, 0.75, 0.75);
+pub const GOLD: Colour = Colour::rgb(1.0, 0.84, 0.0);
+pub const BRASS: Colour = Colour::rgb(0.71, 0.65, 0.26);
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Corner Block
 #[derive(Debug, Clone)]
@@ -9,21 +12,34 @@ pub struct CornerBlock {
 
 impl CornerBlock {
     /// Generate the main block
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.size, self.size, self.size)
     }
 
     /// Generate the screw holes
-    pub fn holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.size + 1.0);
-        hole.translate(Vector3::new(0.0, 0.0, self.size / 2.0))
+    pub fn holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.size + 1.0);
+        hole.translated(Vector3::new(0.0, 0.0, self.size / 2.0))
             .union(&hole.rotate(90.0, 0.0, 0.0))
             .union(&hole.rotate(0.0, 90.0, 0.0))
     }
 
     /// Assemble the complete corner block
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.holes())
     }
 }
 
+impl Part for CornerBlock {
+    fn name(&self) -> &str {
+        "CornerBlock"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        CornerBlock::assemble(self)
+    }
+}
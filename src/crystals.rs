@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a crystal oscillator module
 #[derive(Debug, Clone)]
@@ -15,12 +17,12 @@ pub struct Crystal {
 
 impl Crystal {
     /// Generate the rectangular body of the crystal
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
     }
     
     /// Generate the leads of the crystal
-    pub fn leads(&self) -> CSG<()> {
+    pub fn leads(&self) -> CSG {
         let mut leads = CSG::new();
         let offsets = [
             (-self.lead_spacing / 2.0, 0.0),
@@ -28,8 +30,8 @@ impl Crystal {
         ];
         
         for &(x, y) in &offsets {
-            let lead = CSG::cylinder_z(self.lead_diameter / 2.0, self.lead_length)
-                .translate(Vector3::new(x, y, -self.lead_length));
+            let lead = CSG::z_cylinder(self.lead_diameter / 2.0, self.lead_length)
+                .translated(Vector3::new(x, y, -self.lead_length));
             leads = leads.union(&lead);
         }
         
@@ -37,7 +39,7 @@ impl Crystal {
     }
     
     /// Generate the full crystal model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut crystal = self.body();
         let leads = self.leads();
         
@@ -47,3 +49,16 @@ impl Crystal {
     }
 }
 
+impl Part for Crystal {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Crystal::assemble(self)
+    }
+}
@@ -1,40 +1,141 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
-/// Representation of a Cable Strip
+/// Representation of a D-subminiature connector, panel mounted from behind with the shell
+/// poking through.
+///
+/// The back of the flange is on Z = 0 with the shell pointing up +Z and the body below.
 #[derive(Debug, Clone)]
-pub struct CableStrip {
-    pub width: f64,
-    pub height: f64,
-    pub depth: f64,
-    pub slot_width: f64,
-    pub slot_depth: f64,
-    pub slot_spacing: f64,
-    pub slot_count: usize,
+pub struct DConnector {
+    pub name: String,
+    pub pins: u32,
+    pub flange_width: f64,
+    pub flange_height: f64,
+    pub flange_thickness: f64,
+    /// Width across the wide side of the D.
+    pub shell_width: f64,
+    pub shell_height: f64,
+    pub shell_depth: f64,
+    pub body_width: f64,
+    pub body_height: f64,
+    pub body_depth: f64,
+    pub hole_pitch: f64,
+    pub hole_diameter: f64,
 }
 
-impl CableStrip {
-    /// Generate the main strip body
-    pub fn body(&self) -> CSG<()> {
-        CSG::prism(self.width, self.height, self.depth)
+/// Slope of the sides of the D.
+const SHELL_ANGLE: f64 = 10.0;
+
+impl DConnector {
+    /// The D shaped outline grown by `clearance`, extruded `length` up from Z = 0
+    fn d_shape(&self, clearance: f64, length: f64) -> CSG {
+        let h = self.shell_height / 2.0 + clearance;
+        let top = self.shell_width / 2.0 + clearance;
+        let bottom = top - self.shell_height * SHELL_ANGLE.to_radians().tan();
+        CSG::polygon_2d(&[[-bottom, -h], [bottom, -h], [top, h], [-top, h]]).extrude(length)
+    }
+
+    /// Generate the flange
+    pub fn flange(&self) -> CSG {
+        CSG::prism(self.flange_width, self.flange_height, self.flange_thickness)
+            .translated(Vector3::new(-self.flange_width / 2.0, -self.flange_height / 2.0, 0.0))
+            .subtract(&mounting::holes(&self.hole_positions(), self.hole_diameter, self.flange_thickness))
+            .finished(Material::Steel)
     }
 
-    /// Generate the slots for the cables
-    pub fn slots(&self) -> CSG<()> {
-        let slot = CSG::prism(self.slot_width, self.slot_depth, self.height);
-        let mut slots = CSG::new();
-        let start_x = -((self.slot_count as f64 - 1.0) / 2.0) * self.slot_spacing;
+    /// Generate the D shaped shell
+    pub fn shell(&self) -> CSG {
+        self.d_shape(0.0, self.shell_depth)
+            .translated(Vector3::new(0.0, 0.0, self.flange_thickness))
+            .finished(Material::Steel)
+    }
 
-        for i in 0..self.slot_count {
-            let x_offset = start_x + (i as f64) * self.slot_spacing;
-            slots = slots.union(&slot.translate(Vector3::new(x_offset, 0.0, self.height / 2.0)));
-        }
+    /// Generate the insulating body behind the flange
+    pub fn body(&self) -> CSG {
+        CSG::prism(self.body_width, self.body_height, self.body_depth)
+            .translated(Vector3::new(-self.body_width / 2.0, -self.body_height / 2.0, -self.body_depth))
+            .finished(Material::Plastic)
+    }
 
-        slots
+    /// Assemble the complete connector
+    pub fn assemble(&self) -> CSG {
+        self.flange().union(&self.shell()).union(&self.body())
     }
+}
 
-    /// Assemble the complete cable strip
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.slots())
+/// Connector in NopSCADLib's `d_connectors.scad` sizes, which share a flange height and shell profile
+fn dconn(name: &str, pins: u32, flange_width: f64, shell_width: f64, hole_pitch: f64) -> DConnector {
+    DConnector {
+        name: name.to_string(),
+        pins,
+        flange_width,
+        flange_height: 12.55,
+        flange_thickness: 1.0,
+        shell_width,
+        shell_height: 6.0,
+        shell_depth: 6.0,
+        body_width: shell_width + 2.0,
+        body_height: 10.0,
+        body_depth: 8.0,
+        hole_pitch,
+        hole_diameter: 3.05,
     }
 }
 
+pub fn dconn9() -> DConnector {
+    dconn("DCONN9", 9, 30.81, 16.33, 24.99)
+}
+
+pub fn dconn15() -> DConnector {
+    dconn("DCONN15", 15, 39.14, 24.66, 33.32)
+}
+
+pub fn dconn25() -> DConnector {
+    dconn("DCONN25", 25, 53.04, 38.38, 47.04)
+}
+
+pub fn dconn37() -> DConnector {
+    dconn("DCONN37", 37, 69.32, 54.84, 63.5)
+}
+
+impl Part for DConnector {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Connector
+    }
+
+    fn assemble(&self) -> CSG {
+        DConnector::assemble(self)
+    }
+
+    fn description(&self) -> String {
+        format!("D-type {} way connector", self.pins)
+    }
+}
+
+impl Mountable for DConnector {
+    /// Screws through the panel and flange either side of the shell.
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_top(&mounting::pair(self.hole_pitch), self.flange_thickness)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.hole_diameter
+    }
+
+    /// The screw holes and a D shaped opening for the shell.
+    fn panel_cutout(&self, clearance: f64) -> CSG {
+        let opening = self
+            .d_shape(clearance, mounting::CUTOUT_DEPTH)
+            .translated(Vector3::new(0.0, 0.0, self.flange_thickness - mounting::CUTOUT_DEPTH / 2.0));
+        mounting::through_holes(&self.hole_positions(), self.hole_diameter + 2.0 * clearance)
+            .union(&opening)
+    }
+}
@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a diode module
 #[derive(Debug, Clone)]
@@ -14,28 +16,28 @@ pub struct Diode {
 
 impl Diode {
     /// Generate the cylindrical body of the diode
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_length)
     }
     
     /// Generate the leads of the diode
-    pub fn leads(&self) -> CSG<()> {
-        let lead1 = CSG::cylinder_z(self.lead_diameter / 2.0, self.lead_length)
-            .translate(Vector3::new(0.0, 0.0, -self.lead_length));
-        let lead2 = CSG::cylinder_z(self.lead_diameter / 2.0, self.lead_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_length));
+    pub fn leads(&self) -> CSG {
+        let lead1 = CSG::z_cylinder(self.lead_diameter / 2.0, self.lead_length)
+            .translated(Vector3::new(0.0, 0.0, -self.lead_length));
+        let lead2 = CSG::z_cylinder(self.lead_diameter / 2.0, self.lead_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_length));
         
         lead1.union(&lead2)
     }
     
     /// Generate the cathode band marking
-    pub fn cathode_band(&self) -> CSG<()> {
+    pub fn cathode_band(&self) -> CSG {
         CSG::prism(self.body_diameter, self.cathode_band_width, self.body_diameter)
-            .translate(Vector3::new(0.0, self.body_length - self.cathode_band_width / 2.0, 0.0))
+            .translated(Vector3::new(0.0, self.body_length - self.cathode_band_width / 2.0, 0.0))
     }
     
     /// Generate the full diode model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut diode = self.body();
         let leads = self.leads();
         let band = self.cathode_band();
@@ -47,3 +49,16 @@ impl Diode {
     }
 }
 
+impl Part for Diode {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Diode::assemble(self)
+    }
+}
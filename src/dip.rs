@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Dual In-line Package (DIP) module
 #[derive(Debug, Clone)]
@@ -14,19 +17,19 @@ pub struct DIP {
 
 impl DIP {
     /// Generate the body of the DIP package
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_length, self.body_width, self.body_height)
     }
     
     /// Generate the pins of the DIP package
-    pub fn pins(&self) -> CSG<()> {
+    pub fn pins(&self) -> CSG {
         let mut pins = CSG::new();
         let start_x = -((self.pin_count as f64 / 2.0 - 0.5) * self.pin_spacing);
         
         for i in 0..self.pin_count {
             let x_offset = start_x + i as f64 * self.pin_spacing;
-            let pin = CSG::cylinder_z(self.pin_diameter / 2.0, self.body_height / 2.0)
-                .translate(Vector3::new(x_offset, 0.0, -self.body_height / 2.0));
+            let pin = CSG::z_cylinder(self.pin_diameter / 2.0, self.body_height / 2.0)
+                .translated(Vector3::new(x_offset, 0.0, -self.body_height / 2.0));
             pins = pins.union(&pin);
         }
         
@@ -34,7 +37,7 @@ impl DIP {
     }
     
     /// Generate the full DIP model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut dip = self.body();
         let pins = self.pins();
         
@@ -44,3 +47,16 @@ impl DIP {
     }
 }
 
+impl Part for DIP {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        DIP::assemble(self)
+    }
+}
@@ -1,5 +1,8 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of an electronic display
 #[derive(Debug, Clone)]
@@ -17,38 +20,24 @@ pub struct Display {
 
 impl Display {
     /// Generate the screen of the display
-    pub fn screen(&self) -> CSG<()> {
+    pub fn screen(&self) -> CSG {
         CSG::prism(self.screen_width, self.screen_height, self.screen_depth)
     }
     
     /// Generate the bezel of the display
-    pub fn bezel(&self) -> CSG<()> {
+    pub fn bezel(&self) -> CSG {
         CSG::prism(self.bezel_width, self.bezel_height, self.bezel_depth)
     }
     
     /// Generate the mounting holes
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-            (-self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.bezel_depth)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.bezel_depth)
     }
     
     /// Generate the full display model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut display = self.bezel();
-        let screen = self.screen().translate(Vector3::new(0.0, 0.0, self.bezel_depth - self.screen_depth));
+        let screen = self.screen().translated(Vector3::new(0.0, 0.0, self.bezel_depth - self.screen_depth));
         let holes = self.mounting_holes();
         
         display = display.union(&screen);
@@ -58,3 +47,26 @@ impl Display {
     }
 }
 
+impl Part for Display {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Display
+    }
+
+    fn assemble(&self) -> CSG {
+        Display::assemble(self)
+    }
+}
+
+impl Mountable for Display {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::square(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Door Hinge
 #[derive(Debug, Clone)]
@@ -14,33 +17,46 @@ pub struct DoorHinge {
 
 impl DoorHinge {
     /// Generate the hinge leaves
-    pub fn leaves(&self) -> CSG<()> {
+    pub fn leaves(&self) -> CSG {
         let leaf = CSG::prism(self.leaf_width, self.leaf_height, self.thickness);
-        leaf.union(&leaf.translate(Vector3::new(0.0, 0.0, self.thickness * 2.0)))
+        leaf.union(&leaf.translated(Vector3::new(0.0, 0.0, self.thickness * 2.0)))
     }
 
     /// Generate the pin
-    pub fn pin(&self) -> CSG<()> {
-        CSG::cylinder_z(self.pin_diameter / 2.0, self.thickness * 3.0)
+    pub fn pin(&self) -> CSG {
+        CSG::z_cylinder(self.pin_diameter / 2.0, self.thickness * 3.0)
     }
 
     /// Generate the mounting holes
-    pub fn holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.thickness + 1.0);
+    pub fn holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.thickness + 1.0);
         let mut hole_array = CSG::new();
         let start_x = -((self.hole_count as f64 - 1.0) / 2.0) * self.hole_spacing;
 
         for i in 0..self.hole_count {
             let x_offset = start_x + (i as f64) * self.hole_spacing;
-            hole_array = hole_array.union(&hole.translate(Vector3::new(x_offset, 0.0, 0.0)));
+            hole_array = hole_array.union(&hole.translated(Vector3::new(x_offset, 0.0, 0.0)));
         }
 
         hole_array
     }
 
     /// Assemble the complete door hinge
-    pub fn assemble(&self) -> CSG<()> {
-        self.leaves().union(&self.pin()).difference(&self.holes())
+    pub fn assemble(&self) -> CSG {
+        self.leaves().union(&self.pin()).subtract(&self.holes())
     }
 }
 
+impl Part for DoorHinge {
+    fn name(&self) -> &str {
+        "DoorHinge"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        DoorHinge::assemble(self)
+    }
+}
@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a dot matrix display module
 #[derive(Debug, Clone)]
@@ -16,17 +18,17 @@ pub struct DotMatrixDisplay {
 
 impl DotMatrixDisplay {
     /// Generate a single pixel of the display
-    pub fn pixel(&self) -> CSG<()> {
-        CSG::cylinder_z(self.pixel_diameter / 2.0, self.panel_depth)
+    pub fn pixel(&self) -> CSG {
+        CSG::z_cylinder(self.pixel_diameter / 2.0, self.panel_depth)
     }
     
     /// Generate the panel of the display
-    pub fn panel(&self) -> CSG<()> {
+    pub fn panel(&self) -> CSG {
         CSG::prism(self.panel_width, self.panel_height, self.panel_depth)
     }
     
     /// Generate the full dot matrix display model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut display = self.panel();
         let start_x = -((self.columns as f64 - 1.0) * self.pixel_spacing) / 2.0;
         let start_y = -((self.rows as f64 - 1.0) * self.pixel_spacing) / 2.0;
@@ -35,7 +37,7 @@ impl DotMatrixDisplay {
             for col in 0..self.columns {
                 let x_offset = start_x + col as f64 * self.pixel_spacing;
                 let y_offset = start_y + row as f64 * self.pixel_spacing;
-                let pixel = self.pixel().translate(Vector3::new(x_offset, y_offset, self.panel_depth / 2.0));
+                let pixel = self.pixel().translated(Vector3::new(x_offset, y_offset, self.panel_depth / 2.0));
                 display = display.union(&pixel);
             }
         }
@@ -44,3 +46,16 @@ impl DotMatrixDisplay {
     }
 }
 
+impl Part for DotMatrixDisplay {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Display
+    }
+
+    fn assemble(&self) -> CSG {
+        DotMatrixDisplay::assemble(self)
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Drag Chain Link
 #[derive(Debug, Clone)]
@@ -11,20 +14,33 @@ pub struct DragChainLink {
 
 impl DragChainLink {
     /// Generate the link body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.length, self.height)
     }
 
     /// Generate the pivot holes
-    pub fn pivot_holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.width + 1.0);
-        hole.translate(Vector3::new(0.0, -self.length / 2.0, 0.0))
-            .union(&hole.translate(Vector3::new(0.0, self.length / 2.0, 0.0)))
+    pub fn pivot_holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.width + 1.0);
+        hole.translated(Vector3::new(0.0, -self.length / 2.0, 0.0))
+            .union(&hole.translated(Vector3::new(0.0, self.length / 2.0, 0.0)))
     }
 
     /// Assemble the complete drag chain link
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.pivot_holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.pivot_holes())
     }
 }
 
+impl Part for DragChainLink {
+    fn name(&self) -> &str {
+        "DragChainLink"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        DragChainLink::assemble(self)
+    }
+}
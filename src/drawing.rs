@@ -0,0 +1,400 @@
+use crate::export::{weld_key, xml_escape, VertexGrid};
+use crate::CSG;
+use nalgebra::{Point2, Vector2};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// 2D file formats that flat outlines can be written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawingFormat {
+    Svg,
+    Dxf,
+}
+
+impl DrawingFormat {
+    /// Pick a format from a file extension
+    pub fn from_extension(ext: &str) -> Option<DrawingFormat> {
+        match ext.to_ascii_lowercase().as_str() {
+            "svg" => Some(DrawingFormat::Svg),
+            "dxf" => Some(DrawingFormat::Dxf),
+            _ => None,
+        }
+    }
+
+    /// File extension for the format, without the dot
+    pub fn extension(&self) -> &'static str {
+        match self {
+            DrawingFormat::Svg => "svg",
+            DrawingFormat::Dxf => "dxf",
+        }
+    }
+
+    /// Pick a format from the extension of `path`
+    pub fn from_path(path: &Path) -> Option<DrawingFormat> {
+        path.extension().and_then(|e| e.to_str()).and_then(DrawingFormat::from_extension)
+    }
+}
+
+/// One connected piece of a flat shape: an anticlockwise outer loop and the clockwise loops of
+/// any holes in it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Region {
+    pub outer: Vec<Point2<f64>>,
+    pub holes: Vec<Vec<Point2<f64>>>,
+}
+
+impl Region {
+    /// Area enclosed by the outer loop less that of the holes
+    pub fn area(&self) -> f64 {
+        signed_area(&self.outer) + self.holes.iter().map(|h| signed_area(h)).sum::<f64>()
+    }
+}
+
+/// The regions of a flat shape, drawn together on an optionally named layer.
+#[derive(Debug, Clone, Default)]
+pub struct Layer {
+    pub name: Option<String>,
+    pub regions: Vec<Region>,
+}
+
+impl Layer {
+    /// The outline of a 2D shape such as [`ExtrusionProfile::cross_section_2d`], projected onto XY.
+    ///
+    /// [`ExtrusionProfile::cross_section_2d`]: crate::extrusion::ExtrusionProfile::cross_section_2d
+    pub fn from_csg(shape: &CSG) -> Self {
+        let polygons = shape
+            .polygons
+            .iter()
+            .filter(|p| p.plane.normal.z.abs() > 1e-9)
+            .map(|p| {
+                let mut loop_: Vec<Point2<f64>> = p.vertices.iter().map(|v| v.pos.xy()).collect();
+                if p.plane.normal.z < 0.0 {
+                    loop_.reverse();
+                }
+                loop_
+            })
+            .collect::<Vec<_>>();
+        Layer { name: None, regions: regions(&polygons) }
+    }
+
+    /// The outline of a flat solid such as a sheet, PCB or fan guard, taken from the faces it
+    /// would lie on if laid flat on its underside.
+    pub fn footprint(solid: &CSG) -> Self {
+        if solid.polygons.is_empty() {
+            return Layer::default();
+        }
+        let bottom = solid.bounding_box().mins.z;
+        let faces = solid
+            .polygons
+            .iter()
+            .filter(|p| p.plane.normal.z < -1.0 + 1e-9 && p.vertices.iter().all(|v| (v.pos.z - bottom).abs() < 1e-6))
+            .cloned()
+            .collect();
+        Layer::from_csg(&CSG::from_polygons(faces))
+    }
+
+    /// Put the layer on `name`
+    pub fn named(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Lower left and upper right corners of the regions
+    fn bounds(&self) -> Option<(Point2<f64>, Point2<f64>)> {
+        self.regions.iter().flat_map(|r| &r.outer).fold(None, |acc, p| match acc {
+            None => Some((*p, *p)),
+            Some((lo, hi)) => Some((lo.inf(p), hi.sup(p))),
+        })
+    }
+}
+
+/// Trace the boundary of a set of anticlockwise polygons that tile a shape.
+///
+/// Edges shared by two polygons run in opposite directions and cancel, once each edge has
+/// been split at any vertex that lies part way along it. What is left chains into the outer
+/// loops and holes.
+fn regions(polygons: &[Vec<Point2<f64>>]) -> Vec<Region> {
+    let mut vertices = Vec::new();
+    let mut index: HashMap<[i64; 2], usize> = HashMap::new();
+    let mut edges = Vec::new();
+    for polygon in polygons {
+        let ids: Vec<usize> = polygon
+            .iter()
+            .map(|p| {
+                *index.entry(weld_key(p)).or_insert_with(|| {
+                    vertices.push(*p);
+                    vertices.len() - 1
+                })
+            })
+            .collect();
+        for (i, &a) in ids.iter().enumerate() {
+            let b = ids[(i + 1) % ids.len()];
+            if a != b {
+                edges.push((a, b));
+            }
+        }
+    }
+    if edges.is_empty() {
+        return Vec::new();
+    }
+
+    let grid = VertexGrid::new(&vertices, 0..vertices.len());
+    // Net uses of each undirected piece of edge, positive when it runs from the lower index.
+    let mut net: HashMap<(usize, usize), i32> = HashMap::new();
+    let mut order = Vec::new();
+    for &(a, b) in &edges {
+        let mut from = a;
+        for to in grid.along(a, b).into_iter().chain([b]) {
+            let piece = (from.min(to), from.max(to));
+            let count = net.entry(piece).or_insert_with(|| {
+                order.push(piece);
+                0
+            });
+            *count += if from < to { 1 } else { -1 };
+            from = to;
+        }
+    }
+
+    // What survives is the boundary. Keep the pieces in the order they were found so the
+    // output doesn't depend on hashing.
+    let mut outgoing: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut boundary = Vec::new();
+    for (a, b) in order {
+        let (from, to) = match net[&(a, b)] {
+            1 => (a, b),
+            -1 => (b, a),
+            _ => continue,
+        };
+        outgoing.entry(from).or_default().push(to);
+        boundary.push((from, to));
+    }
+
+    let mut loops = Vec::new();
+    for (start, first) in boundary {
+        let Some(next) = outgoing.get_mut(&start) else { continue };
+        let Some(position) = next.iter().position(|&v| v == first) else { continue };
+        next.swap_remove(position);
+
+        let mut loop_ = vec![start];
+        let (mut previous, mut current) = (start, first);
+        while current != start {
+            loop_.push(current);
+            let Some(candidates) = outgoing.get_mut(&current).filter(|c| !c.is_empty()) else { break };
+            // Where loops touch at a vertex, turn as far left as possible so each region's
+            // boundary is traced on its own.
+            let incoming = vertices[current] - vertices[previous];
+            let turn = |v: usize| {
+                let out: Vector2<f64> = vertices[v] - vertices[current];
+                (incoming.x * out.y - incoming.y * out.x).atan2(incoming.dot(&out))
+            };
+            let best = (0..candidates.len()).max_by(|&i, &j| turn(candidates[i]).total_cmp(&turn(candidates[j]))).unwrap();
+            previous = current;
+            current = candidates.swap_remove(best);
+        }
+        let loop_ = simplify(loop_.into_iter().map(|i| vertices[i]).collect());
+        if loop_.len() >= 3 {
+            loops.push(loop_);
+        }
+    }
+
+    // Anticlockwise loops are outlines, clockwise ones holes in the smallest outline around them.
+    let (outers, holes): (Vec<_>, Vec<_>) = loops.into_iter().partition(|l| signed_area(l) > 0.0);
+    let mut regions: Vec<Region> = outers.into_iter().map(|outer| Region { outer, holes: Vec::new() }).collect();
+    for hole in holes {
+        let probe = nalgebra::center(&hole[0], &hole[1]);
+        let owner = regions
+            .iter_mut()
+            .filter(|r| contains(&r.outer, &probe))
+            .min_by(|a, b| signed_area(&a.outer).total_cmp(&signed_area(&b.outer)));
+        if let Some(region) = owner {
+            region.holes.push(hole);
+        }
+    }
+    regions
+}
+
+/// Drop vertices part way along straight runs
+fn simplify(mut points: Vec<Point2<f64>>) -> Vec<Point2<f64>> {
+    let mut i = 0;
+    while points.len() >= 3 && i < points.len() {
+        let n = points.len();
+        let (a, b, c) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+        let (u, v) = (b - a, c - b);
+        if (u.x * v.y - u.y * v.x).abs() <= 1e-9 * u.norm() * v.norm() && u.dot(&v) > 0.0 {
+            points.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    points
+}
+
+/// Shoelace area, positive for an anticlockwise loop
+fn signed_area(points: &[Point2<f64>]) -> f64 {
+    let n = points.len();
+    (0..n).map(|i| points[i].x * points[(i + 1) % n].y - points[(i + 1) % n].x * points[i].y).sum::<f64>() / 2.0
+}
+
+/// Even-odd point in polygon test
+fn contains(points: &[Point2<f64>], p: &Point2<f64>) -> bool {
+    let n = points.len();
+    let mut inside = false;
+    for i in 0..n {
+        let (a, b) = (points[i], points[(i + n - 1) % n]);
+        if (a.y > p.y) != (b.y > p.y) && p.x < (b.x - a.x) * (p.y - a.y) / (b.y - a.y) + a.x {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Shortest decimal for a coordinate, rounded to the 1nm welding grid
+fn number(v: f64) -> String {
+    let v = (v * 1e6).round() / 1e6;
+    format!("{}", if v == 0.0 { 0.0 } else { v })
+}
+
+/// SVG of the layers in millimetres, one `<path>` per region with its holes as extra subpaths.
+///
+/// Named layers become Inkscape layers. Y is flipped so the drawing isn't upside down.
+pub fn svg(layers: &[Layer]) -> String {
+    let (lo, hi) = layers
+        .iter()
+        .filter_map(Layer::bounds)
+        .reduce(|(lo, hi), (l, h)| (lo.inf(&l), hi.sup(&h)))
+        .unwrap_or((Point2::origin(), Point2::origin()));
+    let size = hi - lo;
+
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        out,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" xmlns:inkscape=\"http://www.inkscape.org/namespaces/inkscape\" \
+         width=\"{w}mm\" height=\"{h}mm\" viewBox=\"{} {} {w} {h}\">",
+        number(lo.x),
+        number(-hi.y),
+        w = number(size.x),
+        h = number(size.y),
+    );
+    for layer in layers {
+        match &layer.name {
+            Some(name) => {
+                let name = xml_escape(name);
+                let _ = writeln!(out, "  <g id=\"{0}\" inkscape:groupmode=\"layer\" inkscape:label=\"{0}\">", name);
+            }
+            None => out.push_str("  <g>\n"),
+        }
+        for region in &layer.regions {
+            let mut d = String::new();
+            for loop_ in std::iter::once(&region.outer).chain(&region.holes) {
+                for (i, p) in loop_.iter().enumerate() {
+                    let _ = write!(d, "{}{},{} ", if i == 0 { "M" } else { "L" }, number(p.x), number(-p.y));
+                }
+                d.push_str("Z ");
+            }
+            let _ = writeln!(
+                out,
+                "    <path d=\"{}\" fill=\"none\" fill-rule=\"evenodd\" stroke=\"black\" stroke-width=\"0.1\"/>",
+                d.trim_end()
+            );
+        }
+        out.push_str("  </g>\n");
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+/// DXF layer name, with the characters AutoCAD rejects replaced. Unnamed layers go on `0`.
+fn dxf_layer(layer: &Layer) -> String {
+    match &layer.name {
+        Some(name) if !name.is_empty() => {
+            name.chars().map(|c| if "<>/\\\":;?*|=`".contains(c) || c.is_control() { '_' } else { c }).collect()
+        }
+        _ => "0".to_string(),
+    }
+}
+
+/// DXF (AutoCAD R12) of the layers in millimetres, with every outline and hole as a closed
+/// polyline on its layer.
+///
+/// R12 has no header variable for units, so readers have to be told the drawing is in mm.
+pub fn dxf(layers: &[Layer]) -> String {
+    let mut out = String::new();
+    let mut pair = |code: u32, value: &str| {
+        let _ = writeln!(out, "{}\n{}", code, value);
+    };
+
+    pair(0, "SECTION");
+    pair(2, "HEADER");
+    pair(9, "$ACADVER");
+    pair(1, "AC1009");
+    pair(0, "ENDSEC");
+
+    let mut names: Vec<String> = layers.iter().map(dxf_layer).collect();
+    names.sort();
+    names.dedup();
+    pair(0, "SECTION");
+    pair(2, "TABLES");
+    // The solid line type the layers draw with, which strict readers want defined.
+    pair(0, "TABLE");
+    pair(2, "LTYPE");
+    pair(70, "1");
+    pair(0, "LTYPE");
+    pair(2, "CONTINUOUS");
+    pair(70, "0");
+    pair(3, "Solid line");
+    pair(72, "65");
+    pair(73, "0");
+    pair(40, "0.0");
+    pair(0, "ENDTAB");
+    pair(0, "TABLE");
+    pair(2, "LAYER");
+    pair(70, &names.len().to_string());
+    for name in &names {
+        pair(0, "LAYER");
+        pair(2, name);
+        pair(70, "0");
+        pair(62, "7");
+        pair(6, "CONTINUOUS");
+    }
+    pair(0, "ENDTAB");
+    pair(0, "ENDSEC");
+
+    pair(0, "SECTION");
+    pair(2, "ENTITIES");
+    for layer in layers {
+        let name = dxf_layer(layer);
+        for loop_ in layer.regions.iter().flat_map(|r| std::iter::once(&r.outer).chain(&r.holes)) {
+            pair(0, "POLYLINE");
+            pair(8, &name);
+            pair(66, "1");
+            pair(70, "1");
+            for code in [10, 20, 30] {
+                pair(code, "0.0");
+            }
+            for p in loop_ {
+                pair(0, "VERTEX");
+                pair(8, &name);
+                pair(10, &number(p.x));
+                pair(20, &number(p.y));
+                pair(30, "0.0");
+            }
+            pair(0, "SEQEND");
+            pair(8, &name);
+        }
+    }
+    pair(0, "ENDSEC");
+    pair(0, "EOF");
+    out
+}
+
+/// Write layers to `path` in `format`
+pub fn write(path: &Path, format: DrawingFormat, layers: &[Layer]) -> io::Result<()> {
+    match format {
+        DrawingFormat::Svg => fs::write(path, svg(layers)),
+        DrawingFormat::Dxf => fs::write(path, dxf(layers)),
+    }
+}
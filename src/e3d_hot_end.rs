@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an E3D Hot End
 #[derive(Debug, Clone)]
@@ -13,25 +17,41 @@ pub struct E3DHotEnd {
 
 impl E3DHotEnd {
     /// Generate the heat sink
-    pub fn heat_sink(&self) -> CSG<()> {
-        CSG::cylinder_z(self.heat_sink_diameter / 2.0, self.heat_sink_height)
+    pub fn heat_sink(&self) -> CSG {
+        CSG::z_cylinder(self.heat_sink_diameter / 2.0, self.heat_sink_height)
+            .finished(Material::Aluminium)
     }
 
     /// Generate the heat break
-    pub fn heat_break(&self) -> CSG<()> {
-        CSG::cylinder_z(self.heat_break_diameter / 2.0, self.heat_break_length)
-            .translate(Vector3::new(0.0, 0.0, -self.heat_break_length))
+    pub fn heat_break(&self) -> CSG {
+        CSG::z_cylinder(self.heat_break_diameter / 2.0, self.heat_break_length)
+            .translated(Vector3::new(0.0, 0.0, -self.heat_break_length))
+            .finished(Material::StainlessSteel)
     }
 
     /// Generate the nozzle
-    pub fn nozzle(&self) -> CSG<()> {
-        CSG::cylinder_z(self.nozzle_diameter / 2.0, self.nozzle_length)
-            .translate(Vector3::new(0.0, 0.0, -self.nozzle_length - self.heat_break_length))
+    pub fn nozzle(&self) -> CSG {
+        CSG::z_cylinder(self.nozzle_diameter / 2.0, self.nozzle_length)
+            .translated(Vector3::new(0.0, 0.0, -self.nozzle_length - self.heat_break_length))
+            .finished(Material::Brass)
     }
 
     /// Assemble the complete hot end
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.heat_sink().union(&self.heat_break()).union(&self.nozzle())
     }
 }
 
+impl Part for E3DHotEnd {
+    fn name(&self) -> &str {
+        "E3DHotEnd"
+    }
+
+    fn category(&self) -> Category {
+        Category::HotEnd
+    }
+
+    fn assemble(&self) -> CSG {
+        E3DHotEnd::assemble(self)
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Generic Electronic Component
 #[derive(Debug, Clone)]
@@ -13,22 +16,35 @@ pub struct Component {
 
 impl Component {
     /// Generate the main body of the component
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the leads
-    pub fn leads(&self) -> CSG<()> {
-        let lead = CSG::cylinder_z(self.lead_diameter / 2.0, self.lead_length);
+    pub fn leads(&self) -> CSG {
+        let lead = CSG::z_cylinder(self.lead_diameter / 2.0, self.lead_length);
         let offset = self.lead_spacing / 2.0;
 
-        lead.translate(Vector3::new(offset, 0.0, -self.lead_length))
-            .union(&lead.translate(Vector3::new(-offset, 0.0, -self.lead_length)))
+        lead.translated(Vector3::new(offset, 0.0, -self.lead_length))
+            .union(&lead.translated(Vector3::new(-offset, 0.0, -self.lead_length)))
     }
 
     /// Assemble the full electronic component
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.body().union(&self.leads())
     }
 }
 
+impl Part for Component {
+    fn name(&self) -> &str {
+        "Component"
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Component::assemble(self)
+    }
+}
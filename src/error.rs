@@ -0,0 +1,88 @@
+//! Errors from building parts.
+//!
+//! Parts whose dimensions can contradict each other, or leave their geometry empty or
+//! undefined, have a constructor or builder returning `Result<_, PartError>` and a `validate`
+//! for values changed through their public fields afterwards: nuts, washers, screws, fans,
+//! stepper motors, ball and linear bearings, shaft couplings, circlips, OpenGrab magnets and
+//! text. The other parts are plain data like NopSCADLib's type vectors, built by filling in
+//! their fields, and any counts in them only repeat a feature, so a zero count leaves it out.
+
+use crate::catalogue::CatalogueError;
+use crate::tolerance::{Fit, ToleranceError};
+use std::error::Error;
+use std::fmt;
+
+/// Errors from building a part with dimensions that don't make sense.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartError {
+    /// A dimension that must be positive, or a count that must be at least one.
+    InvalidValue { parameter: String, value: f64 },
+    /// Parameters that make sense alone but not together, e.g. a hole wider than its nut.
+    Inconsistent { part: String, reason: String },
+    /// A fit whose tolerance class can't be looked up for the size it is applied to.
+    Fit { part: String, source: ToleranceError },
+    /// An anchor name the part doesn't have.
+    UnknownAnchor { part: String, anchor: String },
+}
+
+impl fmt::Display for PartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PartError::InvalidValue { parameter, value } => {
+                write!(f, "invalid value {} for `{}`", value, parameter)
+            }
+            PartError::Inconsistent { part, reason } => write!(f, "`{}`: {}", part, reason),
+            PartError::Fit { part, source } => write!(f, "`{}`: {}", part, source),
+            PartError::UnknownAnchor { part, anchor } => write!(f, "`{}` has no anchor `{}`", part, anchor),
+        }
+    }
+}
+
+impl Error for PartError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PartError::Fit { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<PartError> for CatalogueError {
+    fn from(error: PartError) -> Self {
+        match error {
+            PartError::InvalidValue { parameter, value } => CatalogueError::InvalidValue { parameter, value },
+            PartError::Inconsistent { part, reason } => CatalogueError::Inconsistent { part, reason },
+            PartError::Fit { part, source } => CatalogueError::Inconsistent { part, reason: source.to_string() },
+            PartError::UnknownAnchor { part, anchor } => CatalogueError::UnknownAnchor { part, anchor },
+        }
+    }
+}
+
+/// `value` if it is finite and greater than zero.
+pub(crate) fn positive(parameter: &str, value: f64) -> Result<f64, PartError> {
+    if value > 0.0 && value.is_finite() {
+        Ok(value)
+    } else {
+        Err(PartError::InvalidValue { parameter: parameter.to_string(), value })
+    }
+}
+
+/// `count` if it is at least one.
+pub(crate) fn at_least_one(parameter: &str, count: u32) -> Result<u32, PartError> {
+    if count >= 1 {
+        Ok(count)
+    } else {
+        Err(PartError::InvalidValue { parameter: parameter.to_string(), value: 0.0 })
+    }
+}
+
+pub(crate) fn inconsistent(part: &str, reason: &str) -> PartError {
+    PartError::Inconsistent { part: part.to_string(), reason: reason.to_string() }
+}
+
+/// Largest size a bore of `nominal` diameter may be made with `fit`.
+pub(crate) fn largest_bore(part: &str, fit: &Fit, nominal: f64) -> Result<f64, PartError> {
+    fit.hole_limits(nominal)
+        .map(|limits| limits.max)
+        .map_err(|source| PartError::Fit { part: part.to_string(), source })
+}
@@ -0,0 +1,699 @@
+use crate::assembly::Assembly;
+use crate::colour::Colour;
+use crate::material::Finish;
+use crate::part::Part;
+use crate::CSG;
+use nalgebra::{Point, Point3, Vector3};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+mod step;
+
+pub use step::{step, step_objects};
+
+/// Mesh file formats that parts and assemblies can be written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    StlBinary,
+    StlAscii,
+    Obj,
+    ThreeMf,
+    /// OpenSCAD source with a `polyhedron()` per object.
+    Scad,
+    /// STEP AP214 with a faceted B-rep per part.
+    Step,
+}
+
+impl Format {
+    /// Pick a format from a file extension. `.stl` means binary STL.
+    pub fn from_extension(ext: &str) -> Option<Format> {
+        match ext.to_ascii_lowercase().as_str() {
+            "stl" => Some(Format::StlBinary),
+            "obj" => Some(Format::Obj),
+            "3mf" => Some(Format::ThreeMf),
+            "scad" => Some(Format::Scad),
+            "step" | "stp" => Some(Format::Step),
+            _ => None,
+        }
+    }
+
+    /// File extension for the format, without the dot
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Format::StlBinary | Format::StlAscii => "stl",
+            Format::Obj => "obj",
+            Format::ThreeMf => "3mf",
+            Format::Scad => "scad",
+            Format::Step => "step",
+        }
+    }
+
+    /// Pick a format from the extension of `path`
+    pub fn from_path(path: &Path) -> Option<Format> {
+        path.extension().and_then(|e| e.to_str()).and_then(Format::from_extension)
+    }
+}
+
+/// A named, optionally coloured solid to be written as a separate body.
+#[derive(Debug, Clone)]
+pub struct ExportObject {
+    pub name: String,
+    /// Colour for polygons that carry no [`Finish`] of their own.
+    pub colour: Option<Colour>,
+    pub solid: CSG,
+}
+
+impl ExportObject {
+    /// A single part as one object
+    pub fn from_part<P: Part + ?Sized>(part: &P) -> Self {
+        ExportObject { name: part.name().to_string(), colour: None, solid: part.assemble() }
+    }
+
+    /// One object per leaf part of an assembly, named by path and placed in assembly coordinates
+    pub fn from_assembly(assembly: &Assembly) -> Vec<Self> {
+        assembly
+            .leaves()
+            .into_iter()
+            .map(|leaf| ExportObject { solid: leaf.solid(), name: leaf.path, colour: leaf.colour })
+            .collect()
+    }
+
+    /// Finish of every triangle of `mesh`, falling back to the object colour
+    fn triangle_finishes(&self, mesh: &Mesh) -> Vec<Option<Finish>> {
+        let fallback = self.colour.map(Finish::colour);
+        mesh.finishes.iter().map(|f| f.or(fallback)).collect()
+    }
+}
+
+/// Indexed triangle mesh with coincident vertices welded together.
+#[derive(Debug, Clone, Default)]
+pub struct Mesh {
+    pub vertices: Vec<Point3<f64>>,
+    pub triangles: Vec<[usize; 3]>,
+    /// Finish of the polygon each triangle came from.
+    pub finishes: Vec<Option<Finish>>,
+}
+
+impl Mesh {
+    /// Triangulate every polygon of `csg`
+    pub fn from_csg(csg: &CSG) -> Self {
+        let mut mesh = Mesh::default();
+        let mut index: HashMap<[i64; 3], usize> = HashMap::new();
+        for poly in &csg.polygons {
+            for tri in poly.triangulate() {
+                let ids = tri.map(|v| {
+                    *index.entry(weld_key(&v.pos)).or_insert_with(|| {
+                        mesh.vertices.push(v.pos);
+                        mesh.vertices.len() - 1
+                    })
+                });
+                if ids[0] != ids[1] && ids[1] != ids[2] && ids[2] != ids[0] {
+                    mesh.triangles.push(ids);
+                    mesh.finishes.push(poly.metadata);
+                }
+            }
+        }
+        mesh
+    }
+
+    /// Unit normal of triangle `t`, following the right hand rule
+    pub fn normal(&self, t: usize) -> Vector3<f64> {
+        let [a, b, c] = self.triangles[t].map(|i| self.vertices[i]);
+        (b - a).cross(&(c - a)).try_normalize(0.0).unwrap_or_else(Vector3::zeros)
+    }
+
+    /// Whether the triangles close up into a consistently wound, manifold surface.
+    ///
+    /// The BSP booleans leave T-junctions, where one polygon's edge runs past a vertex of
+    /// its neighbours, so edges that don't pair up are split at any vertices lying along
+    /// them. The mesh is closed when every piece is then used exactly once in each direction.
+    pub fn is_closed(&self) -> bool {
+        if self.triangles.is_empty() {
+            return false;
+        }
+        let edges = || {
+            self.triangles.iter().flat_map(|t| [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])])
+        };
+        let count = |uses: &mut HashMap<(usize, usize), (u32, u32)>, from: usize, to: usize| {
+            let entry = uses.entry((from.min(to), from.max(to))).or_default();
+            if from < to {
+                entry.0 += 1;
+            } else {
+                entry.1 += 1;
+            }
+        };
+        let paired = |uses: &(u32, u32)| *uses == (1, 1);
+
+        let mut uses = HashMap::new();
+        for (from, to) in edges() {
+            count(&mut uses, from, to);
+        }
+        let unpaired: HashSet<(usize, usize)> =
+            uses.iter().filter(|(_, u)| !paired(u)).map(|(&key, _)| key).collect();
+        if unpaired.is_empty() {
+            return true;
+        }
+
+        // Any vertex in the middle of an edge is the end of some other unpaired edge.
+        let ends: HashSet<usize> = unpaired.iter().flat_map(|&(a, b)| [a, b]).collect();
+        let grid = VertexGrid::new(&self.vertices, ends);
+
+        uses.retain(|_, u| paired(u));
+        for (a, b) in edges().filter(|&(a, b)| unpaired.contains(&(a.min(b), a.max(b)))) {
+            let mut from = a;
+            for to in grid.along(a, b).into_iter().chain([b]) {
+                count(&mut uses, from, to);
+                from = to;
+            }
+        }
+        uses.values().all(paired)
+    }
+}
+
+/// Key of `p` on a 1nm grid, so coincident vertices of neighbouring polygons weld to one index
+pub(crate) fn weld_key<const D: usize>(p: &Point<f64, D>) -> [i64; D] {
+    p.coords.map(|c| (c * 1e6).round() as i64).into()
+}
+
+/// Vertices bucketed on a coarse grid, so the ones lying along an edge can be found quickly.
+///
+/// The BSP booleans leave T-junctions, and both [`Mesh::is_closed`] and the outlines of
+/// [`crate::drawing`] split edges at them before pairing edges up.
+pub(crate) struct VertexGrid<'a, const D: usize> {
+    vertices: &'a [Point<f64, D>],
+    origin: Point<f64, D>,
+    cell: f64,
+    cells: HashMap<[i64; D], Vec<usize>>,
+}
+
+impl<'a, const D: usize> VertexGrid<'a, D> {
+    /// Bucket the vertices at `ids`, the only ones [`VertexGrid::along`] will find
+    pub(crate) fn new(vertices: &'a [Point<f64, D>], ids: impl IntoIterator<Item = usize>) -> Self {
+        let ids: Vec<usize> = ids.into_iter().collect();
+        let (origin, far) = ids.iter().map(|&i| vertices[i]).fold(
+            (Point::from([f64::MAX; D]), Point::from([f64::MIN; D])),
+            |(lo, hi), p| (lo.inf(&p), hi.sup(&p)),
+        );
+        let cell = ((far - origin).max() / 64.0).max(1e-3);
+        let mut grid = VertexGrid { vertices, origin, cell, cells: HashMap::new() };
+        for i in ids {
+            let key = grid.key(&vertices[i]);
+            grid.cells.entry(key).or_default().push(i);
+        }
+        grid
+    }
+
+    fn key(&self, p: &Point<f64, D>) -> [i64; D] {
+        ((p - self.origin) / self.cell).map(|c| c.floor() as i64).into()
+    }
+
+    /// Vertices strictly between `a` and `b` and within 1nm of the edge, in order from `a`
+    pub(crate) fn along(&self, a: usize, b: usize) -> Vec<usize> {
+        let (pa, d) = (self.vertices[a], self.vertices[b] - self.vertices[a]);
+        let length2 = d.norm_squared();
+
+        // Step along the edge a cell at a time, searching the cells around each step.
+        let steps = (d.norm() / self.cell).ceil().max(1.0) as usize;
+        let mut searched = HashSet::new();
+        let mut along = Vec::new();
+        for s in 0..=steps {
+            let centre = self.key(&(pa + d * (s as f64 / steps as f64)));
+            for n in 0..3usize.pow(D as u32) {
+                let offset = |axis: usize| (n / 3usize.pow(axis as u32) % 3) as i64 - 1;
+                let k: [i64; D] = std::array::from_fn(|axis| centre[axis] + offset(axis));
+                if !searched.insert(k) {
+                    continue;
+                }
+                for &v in self.cells.get(&k).into_iter().flatten() {
+                    let t = (self.vertices[v] - pa).dot(&d) / length2;
+                    if v != a && v != b && t > 0.0 && t < 1.0 && (self.vertices[v] - (pa + d * t)).norm() < 1e-6 {
+                        along.push((t, v));
+                    }
+                }
+            }
+        }
+        along.sort_by(|x, y| x.0.total_cmp(&y.0));
+        along.into_iter().map(|(_, v)| v).collect()
+    }
+}
+
+/// ASCII STL of a single solid
+pub fn stl_ascii(csg: &CSG, name: &str) -> String {
+    let mesh = Mesh::from_csg(csg);
+    let mut out = String::new();
+    let _ = writeln!(out, "solid {}", name);
+    for (t, tri) in mesh.triangles.iter().enumerate() {
+        let n = mesh.normal(t);
+        let _ = writeln!(out, "  facet normal {:e} {:e} {:e}", n.x, n.y, n.z);
+        out.push_str("    outer loop\n");
+        for &i in tri {
+            let p = mesh.vertices[i];
+            let _ = writeln!(out, "      vertex {:e} {:e} {:e}", p.x, p.y, p.z);
+        }
+        out.push_str("    endloop\n");
+        out.push_str("  endfacet\n");
+    }
+    let _ = writeln!(out, "endsolid {}", name);
+    out
+}
+
+/// Binary STL of a single solid. `name` goes in the 80 byte header.
+pub fn stl_binary(csg: &CSG, name: &str) -> Vec<u8> {
+    let mesh = Mesh::from_csg(csg);
+    let mut out = Vec::with_capacity(84 + mesh.triangles.len() * 50);
+    let mut header = [0u8; 80];
+    let bytes = name.as_bytes();
+    let len = bytes.len().min(80);
+    header[..len].copy_from_slice(&bytes[..len]);
+    out.extend_from_slice(&header);
+    out.extend_from_slice(&(mesh.triangles.len() as u32).to_le_bytes());
+    for (t, tri) in mesh.triangles.iter().enumerate() {
+        let n = mesh.normal(t);
+        for c in [n.x, n.y, n.z] {
+            out.extend_from_slice(&(c as f32).to_le_bytes());
+        }
+        for &i in tri {
+            let p = mesh.vertices[i];
+            for c in [p.x, p.y, p.z] {
+                out.extend_from_slice(&(c as f32).to_le_bytes());
+            }
+        }
+        out.extend_from_slice(&0u16.to_le_bytes());
+    }
+    out
+}
+
+/// Wavefront OBJ with one `o` group per object, plus the matching MTL material library.
+///
+/// Each distinct finish becomes a material; `mtl_name` is the file name the OBJ refers to
+/// with `mtllib`. The MTL is empty if nothing is coloured.
+pub fn obj(objects: &[ExportObject], mtl_name: &str) -> (String, String) {
+    let meshes: Vec<Mesh> = objects.iter().map(|o| Mesh::from_csg(&o.solid)).collect();
+    let finishes: Vec<Vec<Option<Finish>>> =
+        objects.iter().zip(&meshes).map(|(o, m)| o.triangle_finishes(m)).collect();
+    let materials = distinct_finishes(finishes.iter().flatten());
+
+    let mut out = String::new();
+    let mut mtl = String::new();
+    if !materials.is_empty() {
+        let _ = writeln!(out, "mtllib {}", mtl_name);
+        for finish in &materials {
+            let _ = writeln!(mtl, "newmtl {}", material_name(finish));
+            let _ = writeln!(mtl, "Kd {} {} {}", finish.colour.r, finish.colour.g, finish.colour.b);
+            let _ = writeln!(mtl, "d {}", finish.colour.a);
+            mtl.push('\n');
+        }
+    }
+
+    let mut base = 1;
+    for ((object, mesh), finishes) in objects.iter().zip(&meshes).zip(&finishes) {
+        let _ = writeln!(out, "o {}", object.name.replace(char::is_whitespace, "_"));
+        for p in &mesh.vertices {
+            let _ = writeln!(out, "v {} {} {}", p.x, p.y, p.z);
+        }
+        let mut current = None;
+        for ([a, b, c], finish) in mesh.triangles.iter().zip(finishes) {
+            if *finish != current {
+                if let Some(finish) = finish {
+                    let _ = writeln!(out, "usemtl {}", material_name(finish));
+                }
+                current = *finish;
+            }
+            let _ = writeln!(out, "f {} {} {}", a + base, b + base, c + base);
+        }
+        base += mesh.vertices.len();
+    }
+    (out, mtl)
+}
+
+fn distinct_finishes<'a>(finishes: impl Iterator<Item = &'a Option<Finish>>) -> Vec<Finish> {
+    let mut out: Vec<Finish> = Vec::new();
+    for finish in finishes.flatten() {
+        if !out.contains(finish) {
+            out.push(*finish);
+        }
+    }
+    out
+}
+
+fn material_name(finish: &Finish) -> String {
+    let hex = finish.colour.to_string();
+    let hex = hex.trim_start_matches('#');
+    match finish.material {
+        Some(material) => format!("{}_{}", material.name().replace(' ', "_"), hex),
+        None => format!("colour_{}", hex),
+    }
+}
+
+/// 3MF package with each object as a separate body and finishes as base materials.
+///
+/// Objects take the colour of their first coloured triangle as the object default, and
+/// triangles of any other finish carry their own material reference.
+pub fn three_mf(objects: &[ExportObject]) -> Vec<u8> {
+    let meshes: Vec<Mesh> = objects.iter().map(|o| Mesh::from_csg(&o.solid)).collect();
+    let finishes: Vec<Vec<Option<Finish>>> =
+        objects.iter().zip(&meshes).map(|(o, m)| o.triangle_finishes(m)).collect();
+    let materials = distinct_finishes(finishes.iter().flatten());
+    let index_of = |finish: &Finish| materials.iter().position(|m| m == finish).unwrap();
+
+    let mut model = String::new();
+    model.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    model.push_str(
+        "<model unit=\"millimeter\" xml:lang=\"en-US\" \
+         xmlns=\"http://schemas.microsoft.com/3dmanufacturing/core/2015/02\">\n",
+    );
+    model.push_str("  <resources>\n");
+    // Resource id 1 is the material group, objects follow from 2.
+    if !materials.is_empty() {
+        model.push_str("    <basematerials id=\"1\">\n");
+        for finish in &materials {
+            let _ = writeln!(
+                model,
+                "      <base name=\"{}\" displaycolor=\"{}\" />",
+                material_name(finish),
+                hex_rgba(&finish.colour)
+            );
+        }
+        model.push_str("    </basematerials>\n");
+    }
+    for (i, ((object, mesh), finishes)) in objects.iter().zip(&meshes).zip(&finishes).enumerate() {
+        let _ = write!(
+            model,
+            "    <object id=\"{}\" type=\"model\" name=\"{}\"",
+            i + 2,
+            xml_escape(&object.name)
+        );
+        let default = finishes.iter().flatten().next().copied();
+        if let Some(finish) = &default {
+            let _ = write!(model, " pid=\"1\" pindex=\"{}\"", index_of(finish));
+        }
+        model.push_str(">\n      <mesh>\n        <vertices>\n");
+        for p in &mesh.vertices {
+            let _ = writeln!(model, "          <vertex x=\"{}\" y=\"{}\" z=\"{}\" />", p.x, p.y, p.z);
+        }
+        model.push_str("        </vertices>\n        <triangles>\n");
+        for ([a, b, c], finish) in mesh.triangles.iter().zip(finishes) {
+            let _ = write!(model, "          <triangle v1=\"{}\" v2=\"{}\" v3=\"{}\"", a, b, c);
+            match finish {
+                Some(finish) if Some(*finish) != default => {
+                    let _ = write!(model, " pid=\"1\" p1=\"{}\"", index_of(finish));
+                }
+                _ => {}
+            }
+            model.push_str(" />\n");
+        }
+        model.push_str("        </triangles>\n      </mesh>\n    </object>\n");
+    }
+    model.push_str("  </resources>\n  <build>\n");
+    for i in 0..objects.len() {
+        let _ = writeln!(model, "    <item objectid=\"{}\" />", i + 2);
+    }
+    model.push_str("  </build>\n</model>\n");
+
+    let content_types = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">\n  \
+        <Default Extension=\"rels\" ContentType=\"application/vnd.openxmlformats-package.relationships+xml\" />\n  \
+        <Default Extension=\"model\" ContentType=\"application/vnd.ms-package.3dmanufacturing-3dmodel+xml\" />\n\
+        </Types>\n";
+    let rels = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\n  \
+        <Relationship Target=\"/3D/3dmodel.model\" Id=\"rel0\" \
+        Type=\"http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel\" />\n\
+        </Relationships>\n";
+
+    zip_store(&[
+        ("[Content_Types].xml", content_types.as_bytes()),
+        ("_rels/.rels", rels.as_bytes()),
+        ("3D/3dmodel.model", model.as_bytes()),
+    ])
+}
+
+/// OpenSCAD source with a module per object and a module `name` placing them all.
+///
+/// Each object is a `polyhedron()` of its mesh. Objects of one finish are wrapped in `color()`;
+/// those of several show each finish as a separate piece in preview and are one closed
+/// polyhedron when rendered, as OpenSCAD can only colour whole solids. The file can be
+/// included to draw the whole thing, or `use`d to call the modules from another project.
+pub fn scad(objects: &[ExportObject], name: &str) -> String {
+    let mut taken: HashSet<String> = SCAD_RESERVED.iter().map(|word| word.to_string()).collect();
+    let top = scad_identifier(name, &mut taken);
+    let modules: Vec<String> = objects.iter().map(|o| scad_identifier(&o.name, &mut taken)).collect();
+
+    let mut out = String::new();
+    let _ = writeln!(out, "// {}, exported from partsrs", name);
+    let _ = writeln!(out, "\n{}();\n", top);
+    let _ = writeln!(out, "module {}() {{", top);
+    for module in &modules {
+        let _ = writeln!(out, "    {}();", module);
+    }
+    out.push_str("}\n");
+
+    for (object, module) in objects.iter().zip(&modules) {
+        let mesh = Mesh::from_csg(&object.solid);
+        let finishes = object.triangle_finishes(&mesh);
+        // OpenSCAD wants the faces of a polyhedron clockwise seen from outside.
+        let faces = |keep: &dyn Fn(&Option<Finish>) -> bool| {
+            let faces: Vec<String> = mesh
+                .triangles
+                .iter()
+                .zip(&finishes)
+                .filter(|(_, finish)| keep(finish))
+                .map(|([a, b, c], _)| format!("[{}, {}, {}]", a, c, b))
+                .collect();
+            faces.join(", ")
+        };
+
+        let _ = writeln!(out, "\n// {}\nmodule {}() {{", object.name, module);
+        let points: Vec<String> = mesh.vertices.iter().map(|p| format!("[{}, {}, {}]", p.x, p.y, p.z)).collect();
+        let _ = writeln!(out, "    points = [{}];", points.join(", "));
+        let distinct: Vec<Option<Finish>> = finishes.iter().fold(Vec::new(), |mut distinct, finish| {
+            if !distinct.contains(finish) {
+                distinct.push(*finish);
+            }
+            distinct
+        });
+        match distinct.as_slice() {
+            [] => {}
+            [only] => {
+                let _ = writeln!(out, "    {}polyhedron(points, [{}]);", scad_colour(only), faces(&|_| true));
+            }
+            several => {
+                out.push_str("    if ($preview) {\n");
+                for finish in several {
+                    let _ = writeln!(
+                        out,
+                        "        {}polyhedron(points, [{}]);",
+                        scad_colour(finish),
+                        faces(&|f| f == finish)
+                    );
+                }
+                let _ = writeln!(out, "    }} else {{\n        polyhedron(points, [{}]);\n    }}", faces(&|_| true));
+            }
+        }
+        out.push_str("}\n");
+    }
+    out
+}
+
+/// `color()` call for a finish, or nothing to leave it to OpenSCAD
+fn scad_colour(finish: &Option<Finish>) -> String {
+    match finish {
+        Some(Finish { colour, .. }) => format!("color([{}, {}, {}, {}]) ", colour.r, colour.g, colour.b, colour.a),
+        None => String::new(),
+    }
+}
+
+/// OpenSCAD keywords, and the builtins the exported modules call, which a module named after a
+/// part can't shadow
+const SCAD_RESERVED: &[&str] = &[
+    "module", "function", "if", "else", "for", "intersection_for", "let", "each", "assert", "echo", "include",
+    "use", "true", "false", "undef", "polyhedron", "color",
+];
+
+/// A valid OpenSCAD identifier for `name` that isn't in `taken`, e.g. `motor_screw_1` for
+/// `motor/screw 1`
+fn scad_identifier(name: &str, taken: &mut HashSet<String>) -> String {
+    let mut base: String = name.chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+    if !base.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        base.insert(0, 'm');
+    }
+    let mut identifier = base.clone();
+    let mut n = 2;
+    while !taken.insert(identifier.clone()) {
+        identifier = format!("{}_{}", base, n);
+        n += 1;
+    }
+    identifier
+}
+
+fn hex_rgba(colour: &Colour) -> String {
+    let [r, g, b, a] = colour.to_rgba8();
+    format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+}
+
+pub(crate) fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Write `objects` to `path` in `format`.
+///
+/// STL has no notion of separate bodies, so all objects are merged into one solid.
+/// OBJ also writes a `.mtl` file next to `path` when anything is coloured.
+pub fn write(path: &Path, format: Format, objects: &[ExportObject]) -> io::Result<()> {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("partsrs");
+    match format {
+        Format::StlBinary | Format::StlAscii => {
+            let merged = CSG::from_polygons(objects.iter().flat_map(|o| o.solid.polygons.clone()).collect());
+            if format == Format::StlBinary {
+                fs::write(path, stl_binary(&merged, stem))
+            } else {
+                fs::write(path, stl_ascii(&merged, stem))
+            }
+        }
+        Format::Obj => {
+            let mtl_path = path.with_extension("mtl");
+            let mtl_name = mtl_path.file_name().and_then(|s| s.to_str()).unwrap_or("partsrs.mtl");
+            let (obj_text, mtl_text) = obj(objects, mtl_name);
+            fs::write(path, obj_text)?;
+            if !mtl_text.is_empty() {
+                fs::write(&mtl_path, mtl_text)?;
+            }
+            Ok(())
+        }
+        Format::ThreeMf => fs::write(path, three_mf(objects)),
+        Format::Scad => fs::write(path, scad(objects, stem)),
+        Format::Step => fs::write(path, step_objects(objects, stem)?),
+    }
+}
+
+/// Write a part, choosing the format from the file extension
+pub fn write_part<P: Part + ?Sized>(part: &P, path: &Path) -> io::Result<()> {
+    write(path, format_for(path)?, &[ExportObject::from_part(part)])
+}
+
+/// Write an assembly with one object per leaf part, choosing the format from the file extension.
+///
+/// STEP files keep the assembly tree, see [`step`].
+pub fn write_assembly(assembly: &Assembly, path: &Path) -> io::Result<()> {
+    write_tree(path, format_for(path)?, assembly)
+}
+
+fn write_tree(path: &Path, format: Format, assembly: &Assembly) -> io::Result<()> {
+    match format {
+        Format::Step => {
+            let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("partsrs");
+            fs::write(path, step(assembly, stem)?)
+        }
+        _ => write(path, format, &ExportObject::from_assembly(assembly)),
+    }
+}
+
+/// Write the frames of an assembly animation to `dir` as numbered files, e.g. `gantry_0007.3mf`,
+/// returning their paths in order.
+///
+/// See [`Assembly::frames`] for what each frame shows.
+pub fn write_frames(
+    assembly: &Assembly,
+    dir: &Path,
+    format: Format,
+    frames_per_step: usize,
+) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+    let stem = if assembly.name.is_empty() { "frame" } else { assembly.name.as_str() };
+    let mut paths = Vec::new();
+    for (index, frame) in assembly.frames(frames_per_step).iter().enumerate() {
+        let path = dir.join(format!("{}_{:04}.{}", stem, index, format.extension()));
+        write_tree(&path, format, &frame.assembly)?;
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+fn format_for(path: &Path) -> io::Result<Format> {
+    Format::from_path(path).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unknown mesh format for {}", path.display()),
+        )
+    })
+}
+
+/// Minimal ZIP archive writer using the "stored" (uncompressed) method, enough for 3MF packages.
+fn zip_store(files: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut central = Vec::new();
+    for (name, data) in files {
+        let offset = out.len() as u32;
+        let crc = crc32(data);
+        let size = data.len() as u32;
+
+        // Local file header
+        out.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        out.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        out.extend_from_slice(&0u16.to_le_bytes()); // flags
+        out.extend_from_slice(&0u16.to_le_bytes()); // method: stored
+        out.extend_from_slice(&0u16.to_le_bytes()); // time
+        out.extend_from_slice(&0x21u16.to_le_bytes()); // date: 1980-01-01
+        out.extend_from_slice(&crc.to_le_bytes());
+        out.extend_from_slice(&size.to_le_bytes());
+        out.extend_from_slice(&size.to_le_bytes());
+        out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes()); // extra length
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(data);
+
+        // Central directory entry
+        central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        central.extend_from_slice(&20u16.to_le_bytes()); // version made by
+        central.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        central.extend_from_slice(&0u16.to_le_bytes());
+        central.extend_from_slice(&0u16.to_le_bytes());
+        central.extend_from_slice(&0u16.to_le_bytes());
+        central.extend_from_slice(&0x21u16.to_le_bytes());
+        central.extend_from_slice(&crc.to_le_bytes());
+        central.extend_from_slice(&size.to_le_bytes());
+        central.extend_from_slice(&size.to_le_bytes());
+        central.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        central.extend_from_slice(&0u16.to_le_bytes()); // extra length
+        central.extend_from_slice(&0u16.to_le_bytes()); // comment length
+        central.extend_from_slice(&0u16.to_le_bytes()); // disk number
+        central.extend_from_slice(&0u16.to_le_bytes()); // internal attributes
+        central.extend_from_slice(&0u32.to_le_bytes()); // external attributes
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+    }
+
+    let central_offset = out.len() as u32;
+    out.extend_from_slice(&central);
+
+    // End of central directory record
+    out.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(central.len() as u32).to_le_bytes());
+    out.extend_from_slice(&central_offset.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out
+}
+
+/// CRC-32 checksum as used by ZIP, here for the entries of 3MF packages
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
@@ -0,0 +1,417 @@
+//! STEP export, writing assemblies as AP214 products placed in one another.
+
+use super::{distinct_finishes, ExportObject, Mesh};
+use crate::assembly::{Assembly, Node, Transform};
+use crate::colour::Colour;
+use crate::material::{Finish, Finishing};
+use crate::CSG;
+use nalgebra::{Matrix3, Matrix4, Point3, Vector3};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::sync::Arc;
+
+/// ISO 10303-21 (STEP) file in the AP214 `AUTOMOTIVE_DESIGN` schema, keeping the tree of
+/// `assembly` as products placed in one another.
+///
+/// Parts are faceted B-reps of their triangles, coloured by finish, and are written once and
+/// instanced wherever a part of the same name and shape is placed again. STEP placements can
+/// only move and turn, so a mirrored child has the mirror baked into its geometry instead.
+///
+/// A part with a non-finite coordinate is an `InvalidData` error, as STEP has no way to write it.
+pub fn step(assembly: &Assembly, name: &str) -> io::Result<String> {
+    let mut writer = StepWriter::new();
+    let root = if assembly.name.is_empty() { name } else { assembly.name.as_str() };
+    writer.assembly(root, assembly, None, None)?;
+    Ok(writer.finish(name))
+}
+
+/// STEP file of separate objects, like [`step`] but with each object placed as it is in one
+/// assembly, or on its own if there is only one.
+pub fn step_objects(objects: &[ExportObject], name: &str) -> io::Result<String> {
+    let mut writer = StepWriter::new();
+    let solid = |object: &ExportObject| match object.colour {
+        Some(colour) => object.solid.clone().finished_default(Finish::colour(colour)),
+        None => object.solid.clone(),
+    };
+    if let [object] = objects {
+        writer.part(&object.name, &solid(object))?;
+    } else {
+        let root = writer.product(name);
+        let children = objects
+            .iter()
+            .map(|object| {
+                let product = writer.part(&object.name, &solid(object))?;
+                Ok((object.name.as_str(), product, writer.placement(&Transform::identity())))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        writer.place(name, root, &children);
+    }
+    Ok(writer.finish(name))
+}
+
+/// A product written to a STEP file, with its shape and the origin placement it is placed by.
+#[derive(Debug, Clone, Copy)]
+struct StepProduct {
+    definition: usize,
+    shape: usize,
+    representation: usize,
+    origin: usize,
+}
+
+/// Entities of a STEP file, numbered as they are added.
+struct StepWriter {
+    data: String,
+    count: usize,
+    product_context: usize,
+    definition_context: usize,
+    context: usize,
+    products: Vec<usize>,
+    /// Parts written so far by name and mesh hash, to instance rather than repeat.
+    parts: HashMap<(String, u64), Vec<(Mesh, StepProduct)>>,
+    /// The product each shared part was written as, by the part and its colour, so a part
+    /// placed again isn't assembled again.
+    placed: HashMap<(usize, Option<[u32; 4]>), StepProduct>,
+    /// `PRESENTATION_STYLE_ASSIGNMENT` of each colour used.
+    styles: Vec<(Colour, usize)>,
+    styled: Vec<usize>,
+    occurrences: usize,
+}
+
+impl StepWriter {
+    fn new() -> Self {
+        let mut writer = StepWriter {
+            data: String::new(),
+            count: 0,
+            product_context: 0,
+            definition_context: 0,
+            context: 0,
+            products: Vec::new(),
+            parts: HashMap::new(),
+            placed: HashMap::new(),
+            styles: Vec::new(),
+            styled: Vec::new(),
+            occurrences: 0,
+        };
+        let application = writer.add("APPLICATION_CONTEXT('core data for automotive mechanical design processes')");
+        writer.add(format!(
+            "APPLICATION_PROTOCOL_DEFINITION('international standard','automotive_design',2000,#{})",
+            application
+        ));
+        writer.product_context = writer.add(format!("PRODUCT_CONTEXT('',#{},'mechanical')", application));
+        writer.definition_context =
+            writer.add(format!("PRODUCT_DEFINITION_CONTEXT('part definition',#{},'design')", application));
+        let length = writer.add("(LENGTH_UNIT()NAMED_UNIT(*)SI_UNIT(.MILLI.,.METRE.))");
+        let angle = writer.add("(NAMED_UNIT(*)PLANE_ANGLE_UNIT()SI_UNIT($,.RADIAN.))");
+        let solid_angle = writer.add("(NAMED_UNIT(*)SI_UNIT($,.STERADIAN.)SOLID_ANGLE_UNIT())");
+        // Vertices are welded on the same 1e-6 grid as `Mesh::from_csg`.
+        let uncertainty = writer.add(format!(
+            "UNCERTAINTY_MEASURE_WITH_UNIT(LENGTH_MEASURE(1.E-06),#{},'distance_accuracy_value','')",
+            length
+        ));
+        writer.context = writer.add(format!(
+            "(GEOMETRIC_REPRESENTATION_CONTEXT(3)GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#{}))\
+             GLOBAL_UNIT_ASSIGNED_CONTEXT((#{},#{},#{}))REPRESENTATION_CONTEXT('',''))",
+            uncertainty, length, angle, solid_angle
+        ));
+        writer
+    }
+
+    /// Write an entity, returning its number
+    fn add(&mut self, entity: impl AsRef<str>) -> usize {
+        self.count += 1;
+        let _ = writeln!(self.data, "#{}={};", self.count, entity.as_ref());
+        self.count
+    }
+
+    fn point(&mut self, p: &Point3<f64>) -> usize {
+        self.add(format!("CARTESIAN_POINT('',({},{},{}))", step_real(p.x), step_real(p.y), step_real(p.z)))
+    }
+
+    fn direction(&mut self, v: &Vector3<f64>) -> usize {
+        self.add(format!("DIRECTION('',({},{},{}))", step_real(v.x), step_real(v.y), step_real(v.z)))
+    }
+
+    fn axes(&mut self, origin: &Point3<f64>, z: &Vector3<f64>, x: &Vector3<f64>) -> usize {
+        let (origin, z, x) = (self.point(origin), self.direction(z), self.direction(x));
+        self.add(format!("AXIS2_PLACEMENT_3D('',#{},#{},#{})", origin, z, x))
+    }
+
+    /// Axes for a rigid `transform`, i.e. one without mirroring or scaling
+    fn placement(&mut self, transform: &Transform) -> usize {
+        let m = &transform.matrix;
+        let origin = Point3::new(m[(0, 3)], m[(1, 3)], m[(2, 3)]);
+        let z = m.fixed_view::<3, 1>(0, 2).into_owned();
+        let x = m.fixed_view::<3, 1>(0, 0).into_owned();
+        self.axes(&origin, &z, &x)
+    }
+
+    /// The product, formation, definition and shape of a new product, returning the
+    /// definition and shape
+    fn product(&mut self, name: &str) -> (usize, usize) {
+        let name = step_string(name);
+        let product = self.add(format!("PRODUCT({},{},'',(#{}))", name, name, self.product_context));
+        let formation = self.add(format!("PRODUCT_DEFINITION_FORMATION('','',#{})", product));
+        let definition =
+            self.add(format!("PRODUCT_DEFINITION('design','',#{},#{})", formation, self.definition_context));
+        let shape = self.add(format!("PRODUCT_DEFINITION_SHAPE('','',#{})", definition));
+        self.products.push(product);
+        (definition, shape)
+    }
+
+    /// A part as a faceted B-rep, or the one already written with the same name and mesh
+    fn part(&mut self, name: &str, solid: &CSG) -> io::Result<StepProduct> {
+        // Check before welding, which would fold a NaN vertex into one at the origin.
+        if !solid.polygons.iter().flat_map(|p| &p.vertices).all(|v| v.pos.iter().all(|x| x.is_finite())) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("`{}` has a non-finite coordinate", name),
+            ));
+        }
+        let mesh = Mesh::from_csg(solid);
+        let key = (name.to_string(), mesh_hash(&mesh));
+        let same = |(m, _): &&(Mesh, StepProduct)| {
+            m.vertices == mesh.vertices && m.triangles == mesh.triangles && m.finishes == mesh.finishes
+        };
+        if let Some((_, product)) = self.parts.get(&key).and_then(|parts| parts.iter().find(same)) {
+            return Ok(*product);
+        }
+
+        let (definition, shape) = self.product(name);
+        let points: Vec<usize> = mesh.vertices.iter().map(|p| self.point(p)).collect();
+        let mut faces = Vec::new();
+        for (t, &[a, b, c]) in mesh.triangles.iter().enumerate() {
+            // Slivers have no plane to lie in, and no area to lose.
+            let normal = mesh.normal(t);
+            if normal == Vector3::zeros() {
+                continue;
+            }
+            let polyloop = self.add(format!("POLY_LOOP('',(#{},#{},#{}))", points[a], points[b], points[c]));
+            let bound = self.add(format!("FACE_OUTER_BOUND('',#{},.T.)", polyloop));
+            let along = (mesh.vertices[b] - mesh.vertices[a]).normalize();
+            let axes = self.axes(&mesh.vertices[a], &normal, &along);
+            let plane = self.add(format!("PLANE('',#{})", axes));
+            let face = self.add(format!("FACE_SURFACE('',(#{}),#{},.T.)", bound, plane));
+            faces.push((face, mesh.finishes[t]));
+        }
+        let shell = self.add(format!("CLOSED_SHELL('',({}))", step_list(faces.iter().map(|f| f.0))));
+        let brep = self.add(format!("FACETED_BREP({},#{})", step_string(name), shell));
+        let origin = self.placement(&Transform::identity());
+        let representation = self.add(format!(
+            "FACETED_BREP_SHAPE_REPRESENTATION({},(#{},#{}),#{})",
+            step_string(name),
+            origin,
+            brep,
+            self.context
+        ));
+        self.add(format!("SHAPE_DEFINITION_REPRESENTATION(#{},#{})", shape, representation));
+
+        // Colour the whole solid if it has one finish, or else face by face.
+        match distinct_finishes(faces.iter().map(|f| &f.1)).as_slice() {
+            [only] if faces.iter().all(|f| f.1.is_some()) => self.style(brep, only.colour),
+            _ => {
+                for &(face, finish) in &faces {
+                    if let Some(finish) = finish {
+                        self.style(face, finish.colour);
+                    }
+                }
+            }
+        }
+
+        let product = StepProduct { definition, shape, representation, origin };
+        self.parts.entry(key).or_default().push((mesh, product));
+        Ok(product)
+    }
+
+    /// An assembly and everything below it, each child coloured like the leaves of
+    /// [`Assembly::leaves`], with `baked` applied to the geometry under a mirrored parent
+    fn assembly(
+        &mut self,
+        name: &str,
+        assembly: &Assembly,
+        colour: Option<Colour>,
+        baked: Option<&Transform>,
+    ) -> io::Result<StepProduct> {
+        let parent = self.product(name);
+        let mut children = Vec::new();
+        for child in &assembly.children {
+            let transform = match baked {
+                Some(baked) => child.transform.then(baked),
+                None => child.transform,
+            };
+            let rigid = is_rigid(&transform);
+            let baked = if rigid { None } else { Some(&transform) };
+            let colour = child.colour.or(colour);
+            let product = match &child.node {
+                Node::Part(part) => {
+                    let shared = (Arc::as_ptr(part) as *const () as usize, colour.map(colour_bits));
+                    match self.placed.get(&shared) {
+                        Some(&product) if baked.is_none() => product,
+                        _ => {
+                            let mut solid = part.assemble();
+                            if let Some(baked) = baked {
+                                solid = baked.apply(&solid);
+                            }
+                            if let Some(colour) = colour {
+                                solid = solid.finished_default(Finish::colour(colour));
+                            }
+                            let product = self.part(part.name(), &solid)?;
+                            if baked.is_none() {
+                                self.placed.insert(shared, product);
+                            }
+                            product
+                        }
+                    }
+                }
+                Node::Assembly(sub) => {
+                    let name = if sub.name.is_empty() { child.name.as_str() } else { sub.name.as_str() };
+                    self.assembly(name, sub, colour, baked)?
+                }
+            };
+            let placement = self.placement(&if rigid { transform } else { Transform::identity() });
+            children.push((child.name.as_str(), product, placement));
+        }
+        Ok(self.place(name, parent, &children))
+    }
+
+    /// The shape of an assembly product, with each child placed in it by its axes
+    fn place(
+        &mut self,
+        name: &str,
+        (definition, shape): (usize, usize),
+        children: &[(&str, StepProduct, usize)],
+    ) -> StepProduct {
+        let origin = self.placement(&Transform::identity());
+        let items = step_list(std::iter::once(origin).chain(children.iter().map(|c| c.2)));
+        let representation =
+            self.add(format!("SHAPE_REPRESENTATION({},({}),#{})", step_string(name), items, self.context));
+        self.add(format!("SHAPE_DEFINITION_REPRESENTATION(#{},#{})", shape, representation));
+
+        for &(child_name, child, placement) in children {
+            self.occurrences += 1;
+            let occurrence = self.add(format!(
+                "NEXT_ASSEMBLY_USAGE_OCCURRENCE('NAUO{}',{},'',#{},#{},$)",
+                self.occurrences,
+                step_string(child_name),
+                definition,
+                child.definition
+            ));
+            let occurrence_shape = self.add(format!("PRODUCT_DEFINITION_SHAPE('','',#{})", occurrence));
+            let transformation =
+                self.add(format!("ITEM_DEFINED_TRANSFORMATION('','',#{},#{})", child.origin, placement));
+            let relationship = self.add(format!(
+                "(REPRESENTATION_RELATIONSHIP('','',#{},#{})REPRESENTATION_RELATIONSHIP_WITH_TRANSFORMATION(#{})\
+                 SHAPE_REPRESENTATION_RELATIONSHIP())",
+                child.representation, representation, transformation
+            ));
+            self.add(format!("CONTEXT_DEPENDENT_SHAPE_REPRESENTATION(#{},#{})", relationship, occurrence_shape));
+        }
+        StepProduct { definition, shape, representation, origin }
+    }
+
+    /// Colour a B-rep or face
+    fn style(&mut self, item: usize, colour: Colour) {
+        let style = match self.styles.iter().find(|(c, _)| *c == colour) {
+            Some(&(_, style)) => style,
+            None => {
+                let rgb = self.add(format!(
+                    "COLOUR_RGB('',{},{},{})",
+                    step_real(colour.r),
+                    step_real(colour.g),
+                    step_real(colour.b)
+                ));
+                let fill_colour = self.add(format!("FILL_AREA_STYLE_COLOUR('',#{})", rgb));
+                let fill = self.add(format!("FILL_AREA_STYLE('',(#{}))", fill_colour));
+                let area = self.add(format!("SURFACE_STYLE_FILL_AREA(#{})", fill));
+                let side = self.add(format!("SURFACE_SIDE_STYLE('',(#{}))", area));
+                let usage = self.add(format!("SURFACE_STYLE_USAGE(.BOTH.,#{})", side));
+                let style = self.add(format!("PRESENTATION_STYLE_ASSIGNMENT((#{}))", usage));
+                self.styles.push((colour, style));
+                style
+            }
+        };
+        let styled = self.add(format!("STYLED_ITEM('color',(#{}),#{})", style, item));
+        self.styled.push(styled);
+    }
+
+    /// The complete file, with `name` in its header
+    fn finish(mut self, name: &str) -> String {
+        let products = step_list(self.products.clone());
+        self.add(format!("PRODUCT_RELATED_PRODUCT_CATEGORY('part',$,({}))", products));
+        if !self.styled.is_empty() {
+            let styled = step_list(self.styled.clone());
+            self.add(format!(
+                "MECHANICAL_DESIGN_GEOMETRIC_PRESENTATION_REPRESENTATION('',({}),#{})",
+                styled, self.context
+            ));
+        }
+        format!(
+            "ISO-10303-21;\nHEADER;\nFILE_DESCRIPTION(('faceted model exported from partsrs'),'2;1');\n\
+             FILE_NAME({},'',(''),(''),'partsrs','partsrs','');\n\
+             FILE_SCHEMA(('AUTOMOTIVE_DESIGN {{ 1 0 10303 214 1 1 1 1 }}'));\nENDSEC;\nDATA;\n{}ENDSEC;\n\
+             END-ISO-10303-21;\n",
+            step_string(&format!("{}.step", name)),
+            self.data
+        )
+    }
+}
+
+/// Whether `transform` only moves and turns, so it can be a STEP placement
+fn is_rigid(transform: &Transform) -> bool {
+    let m = &transform.matrix;
+    let rotation = m.fixed_view::<3, 3>(0, 0);
+    (rotation.transpose() * rotation - Matrix3::identity()).norm() < 1e-9
+        && rotation.determinant() > 0.0
+        && m.fixed_view::<1, 4>(3, 0) == Matrix4::identity().fixed_view::<1, 4>(3, 0)
+}
+
+/// Entity references `(#1,#2,...)` without the brackets
+fn step_list(ids: impl IntoIterator<Item = usize>) -> String {
+    ids.into_iter().map(|id| format!("#{}", id)).collect::<Vec<_>>().join(",")
+}
+
+/// A STEP real, which always has a decimal point, e.g. `10.` or `-0.5`
+fn step_real(x: impl ToString) -> String {
+    match x.to_string() {
+        text if text == "-0" => "0.".to_string(),
+        text if text.contains('.') => text,
+        text => text + ".",
+    }
+}
+
+/// A quoted STEP string, with anything but printable ASCII written as UTF-16 `\X2\` escapes
+fn step_string(s: &str) -> String {
+    let mut out = String::from("'");
+    for c in s.chars() {
+        match c {
+            '\'' => out.push_str("''"),
+            '\\' => out.push_str("\\\\"),
+            ' '..='~' => out.push(c),
+            _ => {
+                out.push_str("\\X2\\");
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    let _ = write!(out, "{:04X}", unit);
+                }
+                out.push_str("\\X0\\");
+            }
+        }
+    }
+    out.push('\'');
+    out
+}
+
+/// Hash of the welded vertices and triangles of `mesh`, for finding a part written before
+fn mesh_hash(mesh: &Mesh) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for p in &mesh.vertices {
+        p.coords.map(f64::to_bits).as_slice().hash(&mut hasher);
+    }
+    mesh.triangles.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn colour_bits(colour: Colour) -> [u32; 4] {
+    [colour.r, colour.g, colour.b, colour.a].map(f32::to_bits)
+}
@@ -1,7 +1,12 @@
 use csgrs::float_types::EPSILON;
-use nalgebra::Vector3;
+use std::borrow::Cow;
+use nalgebra::{Isometry3, Matrix4, Translation3, UnitQuaternion, Vector3};
+use crate::anchor::Anchor;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
 
-pub type CSG = csgrs::csg::CSG<()>;
+pub use crate::CSG;
 
 /// For T‐slot extrusions, the “recess” can be `None` or `(width, depth)`.
 /// The SCAD code shows either `false` or `[w, d]`.
@@ -16,7 +21,7 @@ pub enum ChannelRecess {
 /// Mirrors the OpenSCAD parameters such as `E2020`, etc.
 #[derive(Debug, Clone)]
 pub struct ExtrusionProfile {
-    pub name: &'static str,                // e.g. "E2020"
+    pub name: Cow<'static, str>,           // e.g. "E2020"
     pub width: f64,                  // overall width (X)
     pub height: f64,                 // overall height (Y)
     /// If <0, interpret as a circular hole of diameter = |center_hole_wd|.
@@ -51,27 +56,50 @@ impl ExtrusionProfile {
 
         if d < 0.0 {
             // Circle with diameter = |d|
-            let r = d.abs() / 2.0;
-            CSG::circle(Some((r, 32))) // 32 segments for smoothness
+            CSG::z_cylinder(d.abs() / 2.0, 1.0)
         } else {
             // Square with side = d
-            CSG::square(Some(([d, d], true))) // center it about origin
+            Self::square(d, d, true) // center it about origin
         }
     }
 
-    /// Build the **2D cross‐section** in the XY plane (Z=0).  
+    /// A `w` × `h` rectangle as a slab 1mm thick, like `CSG::square`.
+    ///
+    /// The profile is built from slabs rather than flat polygons because csgrs performs 2D
+    /// booleans with its 3D BSP, which loses coplanar shapes. [`Self::profile_slab`] is
+    /// then stretched to length or has its base taken as the 2D section.
+    fn square(w: f64, h: f64, center: bool) -> CSG {
+        if center {
+            CSG::prism(w, h, 1.0).translated(Vector3::new(-w / 2.0, -h / 2.0, 0.0))
+        } else {
+            CSG::prism(w, h, 1.0)
+        }
+    }
+
+    /// Build the **2D cross‐section** in the XY plane (Z=0), facing +Z.  
     /// `corner_holes` indicates whether we open the corner holes in the profile or not.
     ///
     /// The final shape is centered at the origin. Width extends ±(width/2) in X,
-    /// height extends ±(height/2) in Y.  
-    /// This replicates the logic from `extrusion_cross_section(type, cornerHole)` in SCAD.
+    /// height extends ±(height/2) in Y.
     pub fn cross_section_2d(&self, corner_holes: bool) -> CSG {
+        let base = self
+            .profile_slab(corner_holes)
+            .polygons
+            .into_iter()
+            .filter(|p| p.plane.normal.z < -0.5)
+            .map(|mut p| {
+                p.flip();
+                p
+            })
+            .collect();
+        CSG::from_polygons(base)
+    }
+
+    /// The cross-section as a slab from Z=0 to Z=1.  
+    /// This replicates the logic from `extrusion_cross_section(type, cornerHole)` in SCAD.
+    fn profile_slab(&self, corner_holes: bool) -> CSG {
         let w = self.width;
         let h = self.height;
-        let center_sq = self.center_square_wd.abs();
-        let tab_t = self.tab_thickness;
-        let spar_t = self.spar_thickness;
-        let chan_w = self.channel_width;
         let recess = &self.recess;
 
         // The SCAD logic sometimes repeats horizontal “cells” if `height` > `width`.
@@ -104,28 +132,20 @@ impl ExtrusionProfile {
         // Corner holes if requested
         if corner_holes && self.corner_hole_wd.abs() > EPSILON {
             let c_hole = Self::circle_or_square(self.corner_hole_wd);
-            // Each corner is offset from center. The SCAD code’s “cornerSquare” = (width - channel_width_internal)/2, etc.
-            // But simpler: the corner lumps are near ±(w/2), ±(w/2). 
-            // If it’s a circle, diameter=|corner_hole_wd| => radius=…
-            let r = self.corner_hole_wd.abs() / 2.0;
+            // Each hole is in the middle of a corner block.
             let corner_positions = [
                 (-1.0, -1.0),
                 (1.0, -1.0),
                 (1.0, 1.0),
                 (-1.0, 1.0),
             ];
-            // The offset in x,y from the center is ~ the “inside corner” portion. 
-            // By analogy with SCAD, that corner is roughly ( (w-chan_in)/2 , (w-chan_in)/2 ).
-            // But to keep it consistent with the “outer corner,” we can just place it near ±(w/2 - something).
-            // For a 20×20 cell, the T-slot corner lumps are around ±7-8 mm from center.
-            // For simplicity, just place at ±( (w-chan_w)/2 ), or you can reference the SCAD expression:
-            let corner_sq = (w - self.channel_width_internal) / 2.0;
+            let corner_sq = self.corner_center();
 
             for &(sx, sy) in &corner_positions {
                 let tx = sx * corner_sq;
                 let ty = sy * corner_sq;
                 // Subtract the hole from the cell:
-                let hole_t = c_hole.clone().translate(Vector3::new(tx, ty, 0.0));
+                let hole_t = c_hole.clone().translated(Vector3::new(tx, ty, 0.0));
                 cell_2d = cell_2d.subtract(&hole_t);
             }
         }
@@ -140,7 +160,7 @@ impl ExtrusionProfile {
         let start_offset_y = -h/2.0 + w/2.0;
         for i in 0..n_cells {
             let y_off = start_offset_y + (i as f64)*cell_size;
-            let this_cell = cell_2d.clone().translate(Vector3::new(0.0, y_off, 0.0));
+            let this_cell = cell_2d.clone().translated(Vector3::new(0.0, y_off, 0.0));
             full_profile = full_profile.union(&this_cell);
         }
 
@@ -153,7 +173,7 @@ impl ExtrusionProfile {
             for i in 1..n_cells {
                 let y_mid = -h/2.0 + (i as f64)*cell_size;
                 let bridging = self.build_center_bridging_2d();
-                let bridging_t = bridging.translate(Vector3::new(0.0, y_mid, 0.0));
+                let bridging_t = bridging.translated(Vector3::new(0.0, y_mid, 0.0));
                 full_profile = full_profile.union(&bridging_t);
             }
         }
@@ -161,7 +181,11 @@ impl ExtrusionProfile {
         // Lastly, if there is a channel recess (like E2020t or E4040t with `[width, depth]`),
         // we cut little recess rectangles at each open channel. 
         // The SCAD code has 4 channels around the perimeter for each cell.
-        if let ChannelRecess::Some(recess_w, recess_d) = recess {
+        // A recess with only one of its sizes set, part way through being configured, is none.
+        if let &ChannelRecess::Some(recess_w, recess_d) = recess {
+            if recess_w <= 0.0 || recess_d <= 0.0 {
+                return full_profile;
+            }
             // For each cell i, for each of the 4 sides rotated in 90° increments. 
             // We place a small rectangle whose center is at the outer edge. 
             // The SCAD code does: 
@@ -172,15 +196,15 @@ impl ExtrusionProfile {
             // So the recess rectangle is: [width = recess_d *2, height = recess_w].
             // And its center is offset in X by +w/2, then rotate. 
             // We do a difference from the shape.
-            let rect = CSG::square(Some(([*recess_d * 2.0, *recess_w], true)));
+            let rect = Self::square(recess_d * 2.0, recess_w, true);
             for i in 0..n_cells {
                 let y_off = start_offset_y + (i as f64)*cell_size;
                 for rot_k in 0..4 {
                     let angle_deg = 90.0 * (rot_k as f64);
                     let r = rect.clone()
+                        .translated(Vector3::new(w/2.0, 0.0, 0.0))  // SCAD’s final placement
                         .rotate(0.0, 0.0, angle_deg)
-                        .translate(Vector3::new(w/2.0, 0.0, 0.0))  // SCAD’s final placement
-                        .translate(Vector3::new(0.0, y_off, 0.0));
+                        .translated(Vector3::new(0.0, y_off, 0.0));
                     full_profile = full_profile.subtract(&r);
                 }
             }
@@ -190,148 +214,92 @@ impl ExtrusionProfile {
     }
 
     /// Builds the outline for a single T‐slot “cell” without holes.
-    /// Roughly replicates the union/difference logic for corners + center square, etc.
+    /// Replicates the union logic for corners + tabs + spars + center square.
     fn build_main_cell_outline_2d(&self) -> CSG {
-        let w = self.width;
-        let chan_w = self.channel_width;
-        let corner_sq = (w - self.channel_width_internal) / 2.0;
-        let center_sq = self.center_square_wd.abs();
-        let tab_t = self.tab_thickness;
-        let spar_t = self.spar_thickness;
-        let fillet_r = self.fillet_radius;
-
-        // 1) corners
-        // In SCAD, the corners are created by rectangles + circle fillet. 
-        // We’ll approximate by union of squares, then subtract a small fillet circle if fillet>0.
-        // Actually modeling the curved fillet precisely can be done by Minkowski, but let's do a simpler approach:  
-        // - “L‐shaped” corner = union of two rectangles plus a quarter‐circle.  
-        // We'll do a direct approach: we want an “outer box” = (w/2 × w/2) minus the channel opening region.
-        //
-        // We can do this corner shape once, then replicate it around ±X, ±Y.
-        let corner = self.make_corner_2d();
-        // We place it in the top‐right quadrant, then replicate in ±X ±Y.
+        // The corner block, its tabs and the spar back to the center square are built in the
+        // top‐right quadrant, then rotated into the other three.
+        let quadrant = self.make_corner_2d().union(&self.make_center_spars_2d());
         let mut all_corners = CSG::new();
-        let corner_positions = [
-            (1.0, 1.0),
-            (-1.0, 1.0),
-            (-1.0, -1.0),
-            (1.0, -1.0),
-        ];
-        for &(sx, sy) in &corner_positions {
-            let tx = sx * w/2.0;
-            let ty = sy * w/2.0;
-            let mut rot_deg = 0.0;
-            if sx > 0.0 && sy > 0.0 {
-                rot_deg = 0.0;
-            } else if sx < 0.0 && sy > 0.0 {
-                rot_deg = 90.0;
-            } else if sx < 0.0 && sy < 0.0 {
-                rot_deg = 180.0;
-            } else if sx > 0.0 && sy < 0.0 {
-                rot_deg = 270.0;
-            }
-            let c2 = corner.clone().rotate(0.0, 0.0, rot_deg)
-                                  .translate(Vector3::new(tx, ty, 0.0));
-            all_corners = all_corners.union(&c2);
+        for rot_deg in [0.0, 90.0, 180.0, 270.0] {
+            all_corners = all_corners.union(&quadrant.rotate(0.0, 0.0, rot_deg));
         }
 
-        // 2) The center square/tube region
+        // The center square/tube region
         let center_area = Self::circle_or_square(self.center_square_wd);
-        // 3) Possibly add 45° bridging “spar” rectangles that connect center to corners. 
-        // The original SCAD code includes small diagonal or straight segments.  
-        let bridging_spars = self.make_center_spars_2d();
+        all_corners.union(&center_area)
+    }
 
-        // Combine corners + center square + bridging
-        let shape = all_corners.union(&center_area).union(&bridging_spars);
-        shape
+    /// Center of the corner block in the top‐right quadrant, where any corner hole goes.
+    fn corner_center(&self) -> f64 {
+        let corner_sq = (self.width - self.channel_width_internal) / 2.0;
+        (self.width - corner_sq) / 2.0
     }
 
-    /// The “corner piece” for a single quadrant, ignoring fillet or doing an approximate fillet.
+    /// The top‐right “corner piece”: a square block with its outer corner filleted, and the
+    /// two tabs running from it along the outside faces to the edges of the channel openings.
     fn make_corner_2d(&self) -> CSG {
-        let w = self.width;
-        let chan_w = self.channel_width;
+        let half = self.width / 2.0;
         let tab_t = self.tab_thickness;
-        let corner_sq = (w - self.channel_width_internal) / 2.0;
-        let fillet_r = self.fillet_radius;
-
-        // We'll do an L shape = union of two rectangles plus optional corner arc. 
-        // One rectangle: width=(corner_sq), height=tab_t
-        // Another rectangle: width=tab_t, height=(corner_sq).
-        // Both anchored at the corner = (0,0).
-        let rect1 = CSG::square(Some(([corner_sq, tab_t], false)));
-        let rect2 = CSG::square(Some(([tab_t, corner_sq], false)))
-            .translate(Vector3::new(0.0, tab_t, 0.0));
-
-        let union_l = rect1.union(&rect2);
-
-        // Also the “inner block” that forms the corner from the corner_sq × corner_sq region:
-        // SCAD uses 
-        //   translate([fillet, fillet]) square([cornerSquare - fillet, cornerSquare - fillet]);
-        let main_corner_block = CSG::square(Some(([corner_sq, corner_sq], false)))
-            .translate(Vector3::new(0.0, tab_t + tab_t, 0.0)); 
-        // The above line is a bit simplified. If we want to replicate the fillet cut, we can do:
-        //  let fillet_circle = CSG::circle(Some((fillet_r, 16)));
-        //  ... subtract from the corner?
-
-        // For brevity, just union them all. If you want a precise fillet, do a difference with a quarter circle.
-        let corner = union_l.union(&main_corner_block);
-        corner
+        let corner_sq = (self.width - self.channel_width_internal) / 2.0;
+        let inner = half - corner_sq;
+        let f = self.fillet_radius.clamp(0.0, corner_sq);
+
+        // SCAD: translate([fillet, fillet]) square([cornerSquare - fillet, cornerSquare - fillet]) etc.
+        let mut block = Self::square(corner_sq, corner_sq, false).translated(Vector3::new(inner, inner, 0.0));
+        if f > EPSILON {
+            let outer_corner = Self::square(f, f, false).translated(Vector3::new(half - f, half - f, 0.0));
+            let fillet = CSG::z_cylinder(f, 1.0).translated(Vector3::new(half - f, half - f, 0.0));
+            block = block.subtract(&outer_corner).union(&fillet);
+        }
+
+        // Tabs either side of the corner, along the top and right faces.
+        let tab_l = inner - self.channel_width / 2.0;
+        if tab_l <= EPSILON {
+            return block;
+        }
+        let top_tab = Self::square(tab_l, tab_t, false)
+            .translated(Vector3::new(self.channel_width / 2.0, half - tab_t, 0.0));
+        let side_tab = Self::square(tab_t, tab_l, false)
+            .translated(Vector3::new(half - tab_t, self.channel_width / 2.0, 0.0));
+        block.union(&top_tab).union(&side_tab)
     }
 
-    /// The bridging bars/spars between the center square and corners, to fill out the T‐slot shape.
+    /// The diagonal spar from the corner of the center square to the inner corner of the
+    /// top‐right corner block.
     fn make_center_spars_2d(&self) -> CSG {
-        // The SCAD code draws 4 diagonal or horizontal bars from the center square to near the corners.
-        // We do a simplistic approach: 4 rectangles, each bridging from the center out. 
-        let w = self.width;
-        let corner_sq = (w - self.channel_width_internal) / 2.0;
-        let center_sq = self.center_square_wd.abs();
+        let corner_sq = (self.width - self.channel_width_internal) / 2.0;
+        let inner = self.width / 2.0 - corner_sq;
+        let center = self.center_square_wd.abs() / 2.0;
         let spar_t = self.spar_thickness;
-
-        // We place each bar at 45° or horizontally. The code in SCAD is fairly detailed, so we can approximate:
-        // We'll place them at angles 45, 135, 225, 315, with length from center_sq/2 to corner_sq - fillet maybe. 
-        // For simplicity, create a single rectangle that is wide = spar_t, length = (some) and rotate it.
-
-        // We can guess the bar length from the SCAD snippet:
-        //   l = cornerSquare + sparThickness * tan(22.5) - sparThickness / sqrt(2)
-        // That’s a bit arcane. For a simpler approach, we’ll just do a bar from the center's outer boundary to corner's inner boundary.
-        // Let's replicate the formula from SCAD:
-        let l = corner_sq + spar_t * (22.5_f64.to_radians().tan()) - spar_t / std::f64::consts::SQRT_2;
-
-        // Create a rectangle [length x spar_t], centered, then rotate 45°, 135°, etc.
-        let rect = CSG::square(Some(([l, spar_t], true)));
-        let mut union_spars = CSG::new();
-        let angles = [45.0, 135.0, 225.0, 315.0];
-        for a in angles {
-            // Place so that its center is at the midpoint between centerSq/2 and cornerSq in radial direction:
-            // Actually, we can just rotate in place. The rectangle is centered at origin. Then we translate outward.
-            // The radial offset might be (center_sq/2 + corner_sq)/2. Let's do the SCAD approach:
-            //   offset ~ cornerSquare/2 + ?
-            let offset = (corner_sq + center_sq)/4.0; 
-            let bar = rect.clone().rotate(0.0, 0.0, a)
-                          .translate(Vector3::new(offset, 0.0, 0.0));
-            union_spars = union_spars.union(&bar);
+        if inner <= center {
+            return CSG::new();
         }
-        union_spars
+
+        // Long enough to bury both ends in the blocks it joins.
+        let l = (inner - center) * std::f64::consts::SQRT_2 + spar_t;
+        let mid = (inner + center) / 2.0;
+        Self::square(l, spar_t, true)
+            .rotate(0.0, 0.0, 45.0)
+            .translated(Vector3::new(mid, mid, 0.0))
     }
 
     /// For “multi‐cell” extrusions (like 20×40, 20×80), the region between cells
     /// is filled by center bridging: a pair of rectangles bridging the center squares, plus diagonal braces, etc.
     /// The SCAD code calls it `extrusion_center_section(type)`.  
     /// Here we do a simpler approach: two vertical spar strips + diagonal squares. 
-    pub fn build_center_bridging_2d(&self) -> CSG {
+    fn build_center_bridging_2d(&self) -> CSG {
         let w = self.width;
         let corner_sq = (w - self.channel_width_internal) / 2.0;
-        let center_sq = self.center_square_wd.abs();
         let tab_t = self.tab_thickness;
         let spar_t = self.spar_thickness;
 
         // For each side ±X, we have a vertical strip bridging the outside corners:
-        let strip = CSG::square(Some(([tab_t, w - self.channel_width], true)));
+        let strip = Self::square(tab_t, w - self.channel_width, true);
         // then shift it so its center is at x= (width/2 - tab_t/2).
         let mut union_br = CSG::new();
         for side in &[-1.0, 1.0] {
             let x_off = side * (w/2.0 - tab_t/2.0);
-            let st = strip.clone().translate(Vector3::new(x_off, 0.0, 0.0));
+            let st = strip.clone().translated(Vector3::new(x_off, 0.0, 0.0));
             union_br = union_br.union(&st);
         }
 
@@ -340,11 +308,11 @@ impl ExtrusionProfile {
         let bar_l = corner_sq + spar_t*(22.5_f64.to_radians().tan()) - spar_t/(std::f64::consts::SQRT_2);
         for side_x in &[-1.0, 1.0] {
             for side_y in &[-1.0, 1.0] {
-                let bar = CSG::square(Some(([bar_l, spar_t], true)));
+                let bar = Self::square(bar_l, spar_t, true);
                 // rotate if side_y > 0? etc. 
                 let angle = if *side_y > 0.0 { 0.0 } else { 180.0 };
                 let b2 = bar.clone().rotate(0.0, 0.0, angle)
-                            .translate(Vector3::new(side_x * (w/2.0 - bar_l/2.0),
+                            .translated(Vector3::new(side_x * (w/2.0 - bar_l/2.0),
                                                     side_y*(corner_sq - spar_t/2.0),
                                                     0.0));
                 union_br = union_br.union(&b2);
@@ -362,15 +330,14 @@ impl ExtrusionProfile {
     ///
     /// The returned `CSG` is the full T‐slot solid.
     pub fn extrude_3d(&self, length: f64, center: bool, corner_holes: bool) -> CSG {
-        // 1) Build the 2D cross‐section in XY
-        let cross_2d = self.cross_section_2d(corner_holes);
+        // 1) Build the cross‐section as a 1mm slab in XY
+        let slab = self.profile_slab(corner_holes);
 
-        // 2) Extrude it in +Z or ±Z
-        let solid = if center {
-            cross_2d.extrude(length).translate(Vector3::new(0.0, 0.0, -length/2.0))
-        } else {
-            cross_2d.extrude(length)
-        };
+        // 2) Stretch it to length in +Z or ±Z
+        let z0 = if center { -length / 2.0 } else { 0.0 };
+        let stretch = Matrix4::new_translation(&Vector3::new(0.0, 0.0, z0))
+            * Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, 1.0, length));
+        let solid = slab.transformed(&stretch);
 
         // 3) If we want *drilled* corner holes (the code might do that in addition to the open slots),
         //    we can also subtract vertical cylinders at each corner. 
@@ -392,10 +359,10 @@ impl ExtrusionProfile {
         //            let cyl = CSG::cylinder_z(r, length);
         //            let mut c2 = cyl;
         //            if center {
-        //                c2 = c2.translate(Vector3::new(0.0, 0.0, -length/2.0));
+        //                c2 = c2.translated(Vector3::new(0.0, 0.0, -length/2.0));
         //            }
         //            let trans = Vector3::new(x, y, 0.0);
-        //            c2 = c2.translate(trans);
+        //            c2 = c2.translated(trans);
         //            solid = solid.subtract(&c2);
         //        }
         //    }
@@ -404,57 +371,72 @@ impl ExtrusionProfile {
     }
 }
 
-/// Example static definitions akin to the SCAD `E1515`, `E2020`, etc.
-pub const E1515: ExtrusionProfile = ExtrusionProfile {
-    name: "E1515",
-    width: 15.0,
-    height: 15.0,
-    center_hole_wd: -3.3, // negative => circle diameter 3.3
-    corner_hole_wd: 0.0,  // 0 => no corner holes
-    center_square_wd: 5.5,
-    channel_width: 6.2,
-    channel_width_internal: 9.5,
-    tab_thickness: 1.0,
-    spar_thickness: 0.9,
-    fillet_radius: 0.5,
-    recess: ChannelRecess::None,
-};
-
-pub const E2020: ExtrusionProfile = ExtrusionProfile {
-    name: "E2020",
-    width: 20.0,
-    height: 20.0,
-    center_hole_wd: -4.2,
-    corner_hole_wd: -3.0,
-    center_square_wd: 8.0,
-    channel_width: 6.0,
-    channel_width_internal: 12.0,
-    tab_thickness: 2.0,
-    spar_thickness: 2.0,
-    fillet_radius: 1.0,
-    recess: ChannelRecess::None,
-};
-
-pub const E2020T: ExtrusionProfile = ExtrusionProfile {
-    name: "E2020t",
-    width: 20.0,
-    height: 20.0,
-    center_hole_wd: -5.0,
-    corner_hole_wd: -3.0,
-    center_square_wd: 7.8,
-    channel_width: 6.2,
-    channel_width_internal: 11.0,
-    tab_thickness: 1.8,
-    spar_thickness: 1.5,
-    fillet_radius: 1.5,
-    recess: ChannelRecess::Some(7.2, 0.5), // from the SCAD array: [7.2, 0.5]
-};
-
-// E2040, E2060, etc.
-
-pub const ALL_EXTRUSIONS: &[ExtrusionProfile] = &[
-    E1515,
-    E2020,
-    E2020T,
-    // E2040, E2060, ...
-];
+// `E1515`, `E2020`, etc. and `ALL_EXTRUSIONS`, generated by `build.rs` from
+// `data/nopscadlib/extrusions.scad`.
+include!(concat!(env!("OUT_DIR"), "/extrusions.rs"));
+
+/// A cut length of extrusion, the equivalent of `extrusion(type, length)` in SCAD.
+#[derive(Debug, Clone)]
+pub struct Extrusion {
+    pub profile: ExtrusionProfile,
+    pub length: f64,
+    /// If true, the length is centred on Z = 0.
+    pub center: bool,
+    pub corner_holes: bool,
+}
+
+impl Extrusion {
+    /// Generate the extruded length
+    pub fn assemble(&self) -> CSG {
+        self.profile
+            .extrude_3d(self.length, self.center, self.corner_holes)
+            .finished(Material::Aluminium)
+    }
+}
+
+impl Part for Extrusion {
+    fn name(&self) -> &str {
+        &self.profile.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Structural
+    }
+
+    fn assemble(&self) -> CSG {
+        Extrusion::assemble(self)
+    }
+
+    fn description(&self) -> String {
+        format!("Extrusion {} x {}mm", self.profile.name, self.length)
+    }
+
+    /// `end_a` and `end_b` on the ends, and a frame on the centreline of every slot halfway
+    /// along, with Y along the extrusion: `slot_+x_0` up to `slot_+x_{n-1}` for a profile
+    /// `n` cells tall, then the same for `-x`, then `slot_+y_0` and `slot_-y_0`.
+    fn anchors(&self) -> Vec<Anchor> {
+        let z0 = if self.center { -self.length / 2.0 } else { 0.0 };
+        let middle = z0 + self.length / 2.0;
+        let (w, h) = (self.profile.width, self.profile.height);
+        let cells = ((h / w).round() as usize).max(1);
+        let slot = |name: String, x: f64, y: f64, normal: Vector3<f64>| {
+            let rotation = UnitQuaternion::face_towards(&normal, &Vector3::z());
+            Anchor::new(name, Isometry3::from_parts(Translation3::new(x, y, middle), rotation))
+        };
+
+        let mut anchors = vec![
+            Anchor::facing("end_a", Vector3::new(0.0, 0.0, z0), -Vector3::z()),
+            Anchor::facing("end_b", Vector3::new(0.0, 0.0, z0 + self.length), Vector3::z()),
+        ];
+        for (side, sign) in [("+x", 1.0), ("-x", -1.0)] {
+            for i in 0..cells {
+                let y = -h / 2.0 + w / 2.0 + i as f64 * w;
+                anchors.push(slot(format!("slot_{}_{}", side, i), sign * w / 2.0, y, Vector3::x() * sign));
+            }
+        }
+        for (side, sign) in [("+y", 1.0), ("-y", -1.0)] {
+            anchors.push(slot(format!("slot_{}_0", side), 0.0, sign * h / 2.0, Vector3::y() * sign));
+        }
+        anchors
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an Extrusion Bracket
 #[derive(Debug, Clone)]
@@ -12,22 +15,35 @@ pub struct ExtrusionBracket {
 
 impl ExtrusionBracket {
     /// Generate the main bracket shape
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.thickness)
     }
 
     /// Generate the mounting holes
-    pub fn holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.thickness);
+    pub fn holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.thickness);
         let offset = self.hole_spacing / 2.0;
 
-        hole.translate(Vector3::new(offset, 0.0, 0.0))
-            .union(&hole.translate(Vector3::new(-offset, 0.0, 0.0)))
+        hole.translated(Vector3::new(offset, 0.0, 0.0))
+            .union(&hole.translated(Vector3::new(-offset, 0.0, 0.0)))
     }
 
     /// Assemble the extrusion bracket
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.holes())
     }
 }
 
+impl Part for ExtrusionBracket {
+    fn name(&self) -> &str {
+        "ExtrusionBracket"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        ExtrusionBracket::assemble(self)
+    }
+}
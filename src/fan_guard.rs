@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Fan Guard
 #[derive(Debug, Clone)]
@@ -11,13 +14,13 @@ pub struct FanGuard {
 
 impl FanGuard {
     /// Generate the guard body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.thickness)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.thickness)
     }
 
     /// Generate the vent holes
-    pub fn vents(&self) -> CSG<()> {
-        let vent = CSG::cylinder_z(self.vent_diameter / 2.0, self.thickness + 1.0);
+    pub fn vents(&self) -> CSG {
+        let vent = CSG::z_cylinder(self.vent_diameter / 2.0, self.thickness + 1.0);
         let mut holes = CSG::new();
         let angle_step = 360.0 / self.vent_count as f64;
 
@@ -25,15 +28,28 @@ impl FanGuard {
             let angle = i as f64 * angle_step;
             let x_offset = (self.outer_diameter / 3.0) * angle.to_radians().cos();
             let y_offset = (self.outer_diameter / 3.0) * angle.to_radians().sin();
-            holes = holes.union(&vent.translate(Vector3::new(x_offset, y_offset, 0.0)));
+            holes = holes.union(&vent.translated(Vector3::new(x_offset, y_offset, 0.0)));
         }
 
         holes
     }
 
     /// Assemble the complete fan guard
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.vents())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.vents())
     }
 }
 
+impl Part for FanGuard {
+    fn name(&self) -> &str {
+        "FanGuard"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        FanGuard::assemble(self)
+    }
+}
@@ -1,5 +1,10 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::error::{at_least_one, inconsistent, positive, PartError};
+use crate::material::{Finishing, Material};
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a cooling fan
 #[derive(Debug, Clone)]
@@ -15,54 +20,120 @@ pub struct Fan {
     pub mounting_hole_spacing: f64,
 }
 
+/// Builds a [`Fan`] a feature at a time, checking it with [`Fan::validate`] at the end.
+#[derive(Debug, Clone)]
+pub struct FanBuilder {
+    fan: Fan,
+}
+
+impl FanBuilder {
+    pub fn with_frame(mut self, width: f64, height: f64, depth: f64) -> Self {
+        self.fan.frame_width = width;
+        self.fan.frame_height = height;
+        self.fan.frame_depth = depth;
+        self
+    }
+
+    pub fn with_blades(mut self, diameter: f64, count: u32) -> Self {
+        self.fan.blade_diameter = diameter;
+        self.fan.blade_count = count;
+        self
+    }
+
+    pub fn with_hub(mut self, diameter: f64) -> Self {
+        self.fan.hub_diameter = diameter;
+        self
+    }
+
+    /// Four holes `diameter` wide on a square `spacing` apart
+    pub fn with_mounting_holes(mut self, diameter: f64, spacing: f64) -> Self {
+        self.fan.mounting_hole_diameter = diameter;
+        self.fan.mounting_hole_spacing = spacing;
+        self
+    }
+
+    pub fn build(self) -> Result<Fan, PartError> {
+        self.fan.validate()?;
+        Ok(self.fan)
+    }
+}
+
 impl Fan {
+    /// Start building a fan with seven blades and every dimension still to set
+    pub fn builder(name: &str) -> FanBuilder {
+        FanBuilder {
+            fan: Fan {
+                name: name.to_string(),
+                frame_width: 0.0,
+                frame_height: 0.0,
+                frame_depth: 0.0,
+                blade_diameter: 0.0,
+                hub_diameter: 0.0,
+                blade_count: 7,
+                mounting_hole_diameter: 0.0,
+                mounting_hole_spacing: 0.0,
+            },
+        }
+    }
+
+    /// Check the dimensions are positive, there is at least one blade and everything fits in
+    /// the frame
+    pub fn validate(&self) -> Result<(), PartError> {
+        positive("frame_width", self.frame_width)?;
+        positive("frame_height", self.frame_height)?;
+        positive("frame_depth", self.frame_depth)?;
+        positive("blade_diameter", self.blade_diameter)?;
+        positive("hub_diameter", self.hub_diameter)?;
+        at_least_one("blade_count", self.blade_count)?;
+        positive("mounting_hole_diameter", self.mounting_hole_diameter)?;
+        positive("mounting_hole_spacing", self.mounting_hole_spacing)?;
+        let frame = self.frame_width.min(self.frame_height);
+        if self.blade_diameter > frame {
+            return Err(inconsistent(&self.name, "the blades are bigger than the frame"));
+        }
+        if self.hub_diameter >= self.blade_diameter {
+            return Err(inconsistent(&self.name, "the hub is bigger than the blades"));
+        }
+        if self.mounting_hole_spacing + self.mounting_hole_diameter > frame {
+            return Err(inconsistent(&self.name, "the mounting holes are outside the frame"));
+        }
+        Ok(())
+    }
+
     /// Generate the frame of the fan
-    pub fn frame(&self) -> CSG<()> {
+    pub fn frame(&self) -> CSG {
         CSG::prism(self.frame_width, self.frame_height, self.frame_depth)
+            .translated(Vector3::new(-self.frame_width / 2.0, -self.frame_height / 2.0, 0.0))
+            .finished(Material::Plastic)
     }
     
     /// Generate the blades of the fan
-    pub fn blades(&self) -> CSG<()> {
+    pub fn blades(&self) -> CSG {
         let mut blades = CSG::new();
-        let angle_step = 360.0 / self.blade_count as f64;
-        
         for i in 0..self.blade_count {
-            let angle = i as f64 * angle_step;
-            let blade = CSG::cylinder_z(self.blade_diameter / 2.0, self.frame_depth / 2.0)
+            let angle = 360.0 * i as f64 / self.blade_count as f64;
+            let blade = CSG::z_cylinder(self.blade_diameter / 2.0, self.frame_depth / 2.0)
                 .rotate(0.0, 0.0, angle)
-                .translate(Vector3::new(0.0, 0.0, self.frame_depth / 2.0));
+                .translated(Vector3::new(0.0, 0.0, self.frame_depth / 2.0));
             blades = blades.union(&blade);
         }
         
-        blades
+        blades.finished(Material::Plastic)
     }
     
     /// Generate the hub of the fan
-    pub fn hub(&self) -> CSG<()> {
-        CSG::cylinder_z(self.hub_diameter / 2.0, self.frame_depth)
+    pub fn hub(&self) -> CSG {
+        CSG::z_cylinder(self.hub_diameter / 2.0, self.frame_depth)
+            .finished(Material::Plastic)
     }
     
     /// Generate the mounting holes
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-            (-self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.frame_depth)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.frame_depth)
     }
     
     /// Generate the full fan model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut fan = self.frame();
         let blades = self.blades();
         let hub = self.hub();
@@ -72,7 +143,103 @@ impl Fan {
         fan = fan.union(&hub);
         fan = fan.subtract(&holes);
         
-        fan
+        fan.finished_default(Material::Plastic)
+    }
+}
+
+impl Part for Fan {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Cooling
     }
+
+    fn assemble(&self) -> CSG {
+        Fan::assemble(self)
+    }
+}
+
+impl Mountable for Fan {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::square(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+
+    /// The screw holes and a round opening for the air flow the size of the blades.
+    fn panel_cutout(&self, clearance: f64) -> CSG {
+        let centre = mounting::on_bottom(&[(0.0, 0.0)], 0.0);
+        mounting::through_holes(&self.hole_positions(), self.mounting_hole_diameter + 2.0 * clearance)
+            .union(&mounting::through_holes(&centre, self.blade_diameter + 2.0 * clearance))
+    }
+}
+
+/// Square frame fan from NopSCADLib's `fans.scad`, `hole_pitch` being half the hole spacing as there.
+fn fan(name: &str, width: f64, depth: f64, bore: f64, hole_pitch: f64, hole_diameter: f64, hub: f64) -> Fan {
+    Fan {
+        name: name.to_string(),
+        frame_width: width,
+        frame_height: width,
+        frame_depth: depth,
+        blade_diameter: bore,
+        hub_diameter: hub,
+        blade_count: 7,
+        mounting_hole_diameter: hole_diameter,
+        mounting_hole_spacing: 2.0 * hole_pitch,
+    }
+}
+
+pub fn fan25x10() -> Fan {
+    fan("fan25x10", 25.0, 10.0, 24.0, 10.0, 3.4, 16.0)
+}
+
+pub fn fan30x10() -> Fan {
+    fan("fan30x10", 30.0, 10.0, 29.0, 12.0, 3.4, 17.0)
 }
 
+pub fn fan40x11() -> Fan {
+    fan("fan40x11", 40.0, 11.0, 37.5, 16.0, 3.4, 25.0)
+}
+
+pub fn fan50x15() -> Fan {
+    fan("fan50x15", 50.0, 15.0, 48.0, 20.0, 4.5, 25.0)
+}
+
+pub fn fan60x15() -> Fan {
+    fan("fan60x15", 60.0, 15.0, 57.0, 25.0, 4.5, 29.0)
+}
+
+pub fn fan60x25() -> Fan {
+    fan("fan60x25", 60.0, 25.0, 57.0, 25.0, 4.5, 31.0)
+}
+
+pub fn fan70x15() -> Fan {
+    fan("fan70x15", 70.0, 15.0, 66.0, 30.75, 4.5, 29.25)
+}
+
+pub fn fan80x25() -> Fan {
+    fan("fan80x25", 80.0, 25.0, 75.0, 35.75, 4.5, 31.0)
+}
+
+pub fn fan80x38() -> Fan {
+    fan("fan80x38", 80.0, 38.0, 75.0, 35.75, 4.5, 40.0)
+}
+
+/// Every built-in fan, smallest first
+pub fn all_fans() -> Vec<Fan> {
+    vec![
+        fan25x10(),
+        fan30x10(),
+        fan40x11(),
+        fan50x15(),
+        fan60x15(),
+        fan60x25(),
+        fan70x15(),
+        fan80x25(),
+        fan80x38(),
+    ]
+}
@@ -0,0 +1,206 @@
+//! Choosing the screw length for a joint and placing it with its nut, washers or insert, like
+//! NopSCADLib's `screw_longer_than()` and `nut_and_washer()`.
+//!
+//! The layers being clamped are stacked down from Z = 0, so the screw head sits on top at the
+//! origin and the nut or insert is below Z = -grip.
+
+use crate::anchor::{self, Anchor};
+use crate::assembly::{Assembly, Transform};
+use crate::bom::Bom;
+use crate::error::{inconsistent, positive, PartError};
+use crate::insert::Insert;
+use crate::nuts::Nut;
+use crate::part::{Category, Part};
+use crate::screws::{screw_longer_than, Screw};
+use crate::washers::Washer;
+use crate::CSG;
+use nalgebra::{Isometry3, Vector3};
+
+/// What the screw threads into.
+#[derive(Debug, Clone)]
+pub enum Engagement {
+    /// A nut under the layers, which the screw must pass right through.
+    Nut(Nut),
+    /// An insert in the part under the layers, which the screw must reach at least `depth`
+    /// into without bottoming out.
+    Insert { insert: Insert, depth: f64 },
+}
+
+/// A screw family, the thicknesses it clamps and what it threads into, to be
+/// [solved](FastenerStack::solve) for the shortest stock length that holds:
+///
+/// ```ignore
+/// let joint = FastenerStack::new(m3_cap, Engagement::Nut(m3_nut))
+///     .clamping(3.0)
+///     .clamping(5.0)
+///     .with_washers(m3_washer)
+///     .solve()?;
+/// bom.add_part(&joint);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FastenerStack {
+    /// The screw, whose length is replaced by the one chosen.
+    pub screw: Screw,
+    /// Thicknesses of the sheets and printed parts clamped, top down.
+    pub layers: Vec<f64>,
+    pub head_washer: Option<Washer>,
+    /// Washer under the nut, not used with an insert.
+    pub nut_washer: Option<Washer>,
+    pub engagement: Engagement,
+}
+
+impl FastenerStack {
+    pub fn new(screw: Screw, engagement: Engagement) -> Self {
+        FastenerStack { screw, layers: Vec::new(), head_washer: None, nut_washer: None, engagement }
+    }
+
+    /// Add a layer of `thickness` under those already clamped
+    pub fn clamping(mut self, thickness: f64) -> Self {
+        self.layers.push(thickness);
+        self
+    }
+
+    pub fn with_head_washer(mut self, washer: Washer) -> Self {
+        self.head_washer = Some(washer);
+        self
+    }
+
+    pub fn with_nut_washer(mut self, washer: Washer) -> Self {
+        self.nut_washer = Some(washer);
+        self
+    }
+
+    /// The same washer under the head and, with a nut, under the nut
+    pub fn with_washers(self, washer: Washer) -> Self {
+        self.with_head_washer(washer.clone()).with_nut_washer(washer)
+    }
+
+    /// Total thickness of the layers
+    pub fn grip(&self) -> f64 {
+        self.layers.iter().sum()
+    }
+
+    /// Pick the shortest stock length that fully engages the nut or reaches deep enough into
+    /// the insert.
+    ///
+    /// A nut is fully engaged when the tip is at least a thread pitch past it, as the first
+    /// thread of a screw is chamfered away.
+    pub fn solve(&self) -> Result<Fastening, PartError> {
+        for &thickness in &self.layers {
+            positive("layer thickness", thickness)?;
+        }
+        for washer in self.head_washer.iter().chain(&self.nut_washer) {
+            if washer.inner_diameter < self.screw.diameter {
+                return Err(inconsistent(&washer.name, "the washer doesn't fit over the screw"));
+            }
+        }
+        let head_washer = self.head_washer.as_ref().map_or(0.0, |w| w.thickness);
+        let reach = head_washer + self.grip();
+
+        let (length, nut, insert) = match &self.engagement {
+            Engagement::Nut(nut) => {
+                let nut_washer = self.nut_washer.as_ref().map_or(0.0, |w| w.thickness);
+                let shortest = reach + nut_washer + nut.thickness + self.screw.thread_pitch;
+                (screw_longer_than(shortest), Some(nut.clone()), None)
+            }
+            Engagement::Insert { insert, depth } => {
+                positive("depth", *depth)?;
+                if self.nut_washer.is_some() {
+                    return Err(inconsistent(&insert.name, "a nut washer can't be used with an insert"));
+                }
+                let length = screw_longer_than(reach + depth);
+                if length > reach + insert.length {
+                    let reason = format!(
+                        "no stock screw reaches {}mm into the insert without bottoming out, the shortest is {}mm",
+                        depth, length
+                    );
+                    return Err(inconsistent(&insert.name, &reason));
+                }
+                (length, None, Some(insert.clone()))
+            }
+        };
+        Ok(Fastening {
+            screw: self.screw.with_length(length),
+            head_washer: self.head_washer.clone(),
+            nut,
+            nut_washer: if insert.is_none() { self.nut_washer.clone() } else { None },
+            insert,
+            grip: self.grip(),
+        })
+    }
+}
+
+/// A solved [`FastenerStack`]: the screw cut to length and what goes with it.
+///
+/// As a part it places everything around the layers and records each piece in the BOM.
+#[derive(Debug, Clone)]
+pub struct Fastening {
+    pub screw: Screw,
+    pub head_washer: Option<Washer>,
+    pub nut: Option<Nut>,
+    pub nut_washer: Option<Washer>,
+    pub insert: Option<Insert>,
+    /// Total thickness of the layers clamped.
+    pub grip: f64,
+}
+
+impl Fastening {
+    /// The screw, washers and nut or insert in place, with the layers from Z = 0 down to
+    /// Z = -grip
+    pub fn assembly(&self) -> Assembly {
+        let mut assembly = Assembly::new(self.screw.name.clone());
+        let mut top = 0.0;
+        if let Some(washer) = &self.head_washer {
+            assembly.add_part("head_washer", washer.clone(), seat(top, 1.0, washer, "bottom"));
+            top += washer.thickness;
+        }
+        assembly.add_part("screw", self.screw.clone(), seat(top, 1.0, &self.screw, "head_underside"));
+
+        let mut bottom = -self.grip;
+        if let Some(washer) = &self.nut_washer {
+            assembly.add_part("nut_washer", washer.clone(), seat(bottom, -1.0, washer, "bottom"));
+            bottom -= washer.thickness;
+        }
+        if let Some(nut) = &self.nut {
+            assembly.add_part("nut", nut.clone(), seat(bottom, -1.0, nut, "bottom"));
+        }
+        if let Some(insert) = &self.insert {
+            assembly.add_part("insert", insert.clone(), seat(bottom, -1.0, insert, "top"));
+        }
+        assembly
+    }
+}
+
+/// Transform that sits `part`'s anchor `name` on a face at height `z` facing up or down
+fn seat<P: Part>(z: f64, facing: f64, part: &P, name: &str) -> Transform {
+    let face: Isometry3<f64> = Anchor::facing("", Vector3::new(0.0, 0.0, z), Vector3::z() * facing).frame;
+    let frame = part.anchor(name).unwrap_or_else(|| panic!("{} has no `{}` anchor", part.name(), name));
+    anchor::mate(&face, &frame)
+}
+
+impl Part for Fastening {
+    fn name(&self) -> &str {
+        &self.screw.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Fastener
+    }
+
+    fn assemble(&self) -> CSG {
+        self.assembly().flatten()
+    }
+
+    fn description(&self) -> String {
+        self.screw.description()
+    }
+
+    fn anchors(&self) -> Vec<Anchor> {
+        self.assembly().anchors()
+    }
+
+    /// The screw, washers and nut or insert, each on its own line.
+    fn bom(&self, bom: &mut Bom) {
+        self.assembly().bom(bom);
+    }
+}
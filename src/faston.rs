@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Faston Terminal
 #[derive(Debug, Clone)]
@@ -12,19 +15,32 @@ pub struct FastonTerminal {
 
 impl FastonTerminal {
     /// Generate the flat blade of the terminal
-    pub fn blade(&self) -> CSG<()> {
+    pub fn blade(&self) -> CSG {
         CSG::prism(self.width, self.thickness, self.length)
     }
 
     /// Generate the crimp barrel
-    pub fn barrel(&self) -> CSG<()> {
-        CSG::cylinder_z(self.barrel_diameter / 2.0, self.barrel_length)
-            .translate(Vector3::new(0.0, 0.0, self.length))
+    pub fn barrel(&self) -> CSG {
+        CSG::z_cylinder(self.barrel_diameter / 2.0, self.barrel_length)
+            .translated(Vector3::new(0.0, 0.0, self.length))
     }
 
     /// Assemble the full Faston terminal
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.blade().union(&self.barrel())
     }
 }
 
+impl Part for FastonTerminal {
+    fn name(&self) -> &str {
+        "FastonTerminal"
+    }
+
+    fn category(&self) -> Category {
+        Category::Connector
+    }
+
+    fn assemble(&self) -> CSG {
+        FastonTerminal::assemble(self)
+    }
+}
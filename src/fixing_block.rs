@@ -1,32 +1,89 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Translation3, UnitQuaternion, Vector3};
 
-/// Representation of a Simple Box
+/// Representation of a printed fixing block, used to join two panels at right angles.
+///
+/// Two screws come up through the base panel into the bottom and one through the other
+/// panel into the back. The block is centred on X with its back face on Y = 0 and its base on Z = 0.
 #[derive(Debug, Clone)]
-pub struct BoxModel {
+pub struct FixingBlock {
+    pub screw_diameter: f64,
     pub width: f64,
-    pub height: f64,
     pub depth: f64,
-    pub wall_thickness: f64,
+    pub height: f64,
+    /// Distance between the two screws in the base.
+    pub hole_spacing: f64,
 }
 
-impl BoxModel {
-    /// Generate the outer box shape
-    pub fn outer_body(&self) -> CSG<()> {
-        CSG::prism(self.width, self.height, self.depth)
+impl FixingBlock {
+    /// A block sized for screws of `screw_diameter`, in proportion to NopSCADLib's
+    pub fn for_screw(screw_diameter: f64) -> Self {
+        FixingBlock {
+            screw_diameter,
+            width: 10.0 * screw_diameter,
+            depth: 4.0 * screw_diameter,
+            height: 4.0 * screw_diameter,
+            hole_spacing: 7.0 * screw_diameter,
+        }
     }
 
-    /// Generate the hollowed-out interior
-    pub fn inner_body(&self) -> CSG<()> {
-        CSG::prism(
-            self.width - 2.0 * self.wall_thickness,
-            self.height - 2.0 * self.wall_thickness,
-            self.depth - 2.0 * self.wall_thickness,
-        )
+    /// Generate the block with its front top edge bevelled off
+    pub fn body(&self) -> CSG {
+        let block = CSG::prism(self.width, self.depth, self.height)
+            .translated(Vector3::new(-self.width / 2.0, 0.0, 0.0));
+        let bevel = self.depth.min(self.height) / 2.0;
+        let cut = CSG::prism(self.width + 2.0, bevel * std::f64::consts::SQRT_2, bevel * std::f64::consts::SQRT_2)
+            .rotate(45.0, 0.0, 0.0)
+            .translated(Vector3::new(-self.width / 2.0 - 1.0, self.depth, self.height - bevel));
+        block.subtract(&cut)
     }
 
-    /// Assemble the complete box
-    pub fn assemble(&self) -> CSG<()> {
-        self.outer_body().difference(&self.inner_body())
+    /// Generate the screw holes, each running half way into the block
+    pub fn holes(&self) -> CSG {
+        let positions = self.hole_positions();
+        let base = mounting::holes(&positions[..2], self.screw_diameter, self.height / 2.0);
+        let back = mounting::holes(&positions[2..], self.screw_diameter, self.depth / 2.0);
+        base.union(&back)
+    }
+
+    /// Assemble the complete fixing block
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.holes())
     }
 }
 
+impl Part for FixingBlock {
+    fn name(&self) -> &str {
+        "FixingBlock"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        FixingBlock::assemble(self)
+    }
+
+    fn description(&self) -> String {
+        format!("Fixing block M{}", self.screw_diameter)
+    }
+}
+
+impl Mountable for FixingBlock {
+    /// The two base screws, then the one in the back face pointing out along -Y.
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        let y = self.depth / 2.0;
+        let mut positions = mounting::on_bottom(&[(-self.hole_spacing / 2.0, y), (self.hole_spacing / 2.0, y)], 0.0);
+        let outwards = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), std::f64::consts::FRAC_PI_2);
+        positions.push(Isometry3::from_parts(Translation3::new(0.0, 0.0, self.height / 2.0), outwards));
+        positions
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.screw_diameter
+    }
+}
@@ -1,4 +1,6 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
 
 /// Representation of a Foot Pad
 #[derive(Debug, Clone)]
@@ -10,18 +12,31 @@ pub struct FootPad {
 
 impl FootPad {
     /// Generate the foot body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.diameter / 2.0, self.height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.diameter / 2.0, self.height)
     }
 
     /// Generate the mounting hole
-    pub fn hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.hole_diameter / 2.0, self.height + 1.0)
+    pub fn hole(&self) -> CSG {
+        CSG::z_cylinder(self.hole_diameter / 2.0, self.height + 1.0)
     }
 
     /// Assemble the complete foot pad
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.hole())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.hole())
     }
 }
 
+impl Part for FootPad {
+    fn name(&self) -> &str {
+        "FootPad"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        FootPad::assemble(self)
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Fuse Holder
 #[derive(Debug, Clone)]
@@ -13,25 +16,38 @@ pub struct FuseHolder {
 
 impl FuseHolder {
     /// Generate the main body of the fuse holder
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_length)
     }
 
     /// Generate the cap of the fuse holder
-    pub fn cap(&self) -> CSG<()> {
-        CSG::cylinder_z(self.cap_diameter / 2.0, self.cap_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_length))
+    pub fn cap(&self) -> CSG {
+        CSG::z_cylinder(self.cap_diameter / 2.0, self.cap_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_length))
     }
 
     /// Generate the fuse slot
-    pub fn fuse_slot(&self) -> CSG<()> {
-        CSG::cylinder_z(self.fuse_diameter / 2.0, self.fuse_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_length / 2.0))
+    pub fn fuse_slot(&self) -> CSG {
+        CSG::z_cylinder(self.fuse_diameter / 2.0, self.fuse_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_length / 2.0))
     }
 
     /// Assemble the complete fuse holder
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().union(&self.cap()).difference(&self.fuse_slot())
+    pub fn assemble(&self) -> CSG {
+        self.body().union(&self.cap()).subtract(&self.fuse_slot())
     }
 }
 
+impl Part for FuseHolder {
+    fn name(&self) -> &str {
+        "FuseHolder"
+    }
+
+    fn category(&self) -> Category {
+        Category::Power
+    }
+
+    fn assemble(&self) -> CSG {
+        FuseHolder::assemble(self)
+    }
+}
@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a fuse module
 #[derive(Debug, Clone)]
@@ -13,22 +15,22 @@ pub struct Fuse {
 
 impl Fuse {
     /// Generate the cylindrical body of the fuse
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_length)
     }
     
     /// Generate the caps of the fuse
-    pub fn caps(&self) -> CSG<()> {
-        let cap1 = CSG::cylinder_z(self.cap_diameter / 2.0, self.cap_height)
-            .translate(Vector3::new(0.0, 0.0, -self.cap_height));
-        let cap2 = CSG::cylinder_z(self.cap_diameter / 2.0, self.cap_height)
-            .translate(Vector3::new(0.0, 0.0, self.body_length));
+    pub fn caps(&self) -> CSG {
+        let cap1 = CSG::z_cylinder(self.cap_diameter / 2.0, self.cap_height)
+            .translated(Vector3::new(0.0, 0.0, -self.cap_height));
+        let cap2 = CSG::z_cylinder(self.cap_diameter / 2.0, self.cap_height)
+            .translated(Vector3::new(0.0, 0.0, self.body_length));
         
         cap1.union(&cap2)
     }
     
     /// Generate the full fuse model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut fuse = self.body();
         let caps = self.caps();
         
@@ -38,3 +40,16 @@ impl Fuse {
     }
 }
 
+impl Part for Fuse {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Power
+    }
+
+    fn assemble(&self) -> CSG {
+        Fuse::assemble(self)
+    }
+}
@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a Gear Motor module
 #[derive(Debug, Clone)]
@@ -16,41 +20,29 @@ pub struct GearMotor {
 
 impl GearMotor {
     /// Generate the cylindrical body of the gear motor
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_length)
     }
     
     /// Generate the shaft of the gear motor
-    pub fn shaft(&self) -> CSG<()> {
-        CSG::cylinder_z(self.shaft_diameter / 2.0, self.shaft_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_length))
+    pub fn shaft(&self) -> CSG {
+        CSG::z_cylinder(self.shaft_diameter / 2.0, self.shaft_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_length))
     }
     
     /// Generate the gear of the gear motor
-    pub fn gear(&self) -> CSG<()> {
-        CSG::cylinder_z(self.gear_diameter / 2.0, self.gear_width)
-            .translate(Vector3::new(0.0, 0.0, self.body_length + self.shaft_length))
+    pub fn gear(&self) -> CSG {
+        CSG::z_cylinder(self.gear_diameter / 2.0, self.gear_width)
+            .translated(Vector3::new(0.0, 0.0, self.body_length + self.shaft_length))
     }
     
     /// Generate the mounting holes of the gear motor
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, 0.0),
-            (self.mounting_hole_spacing / 2.0, 0.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.body_length)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.body_length)
     }
     
     /// Generate the full gear motor model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut motor = self.body();
         let shaft = self.shaft();
         let gear = self.gear();
@@ -64,3 +56,26 @@ impl GearMotor {
     }
 }
 
+impl Part for GearMotor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motor
+    }
+
+    fn assemble(&self) -> CSG {
+        GearMotor::assemble(self)
+    }
+}
+
+impl Mountable for GearMotor {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::pair(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Geared Stepper Motor
 #[derive(Debug, Clone)]
@@ -13,25 +16,38 @@ pub struct GearedStepperMotor {
 
 impl GearedStepperMotor {
     /// Generate the motor body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_length)
     }
 
     /// Generate the gear
-    pub fn gear(&self) -> CSG<()> {
-        CSG::cylinder_z(self.gear_diameter / 2.0, self.gear_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_length))
+    pub fn gear(&self) -> CSG {
+        CSG::z_cylinder(self.gear_diameter / 2.0, self.gear_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_length))
     }
 
     /// Generate the motor shaft
-    pub fn shaft(&self) -> CSG<()> {
-        CSG::cylinder_z(self.shaft_diameter / 2.0, self.shaft_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_length + self.gear_length))
+    pub fn shaft(&self) -> CSG {
+        CSG::z_cylinder(self.shaft_diameter / 2.0, self.shaft_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_length + self.gear_length))
     }
 
     /// Assemble the complete geared stepper motor
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.body().union(&self.gear()).union(&self.shaft())
     }
 }
 
+impl Part for GearedStepperMotor {
+    fn name(&self) -> &str {
+        "GearedStepperMotor"
+    }
+
+    fn category(&self) -> Category {
+        Category::Motor
+    }
+
+    fn assemble(&self) -> CSG {
+        GearedStepperMotor::assemble(self)
+    }
+}
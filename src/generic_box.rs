@@ -1,4 +1,5 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
 
 /// Representation of a Simple Box
 #[derive(Debug, Clone)]
@@ -11,12 +12,12 @@ pub struct BoxModel {
 
 impl BoxModel {
     /// Generate the outer box shape
-    pub fn outer_body(&self) -> CSG<()> {
+    pub fn outer_body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the hollowed-out interior
-    pub fn inner_body(&self) -> CSG<()> {
+    pub fn inner_body(&self) -> CSG {
         CSG::prism(
             self.width - 2.0 * self.wall_thickness,
             self.height - 2.0 * self.wall_thickness,
@@ -25,8 +26,21 @@ impl BoxModel {
     }
 
     /// Assemble the complete box
-    pub fn assemble(&self) -> CSG<()> {
-        self.outer_body().difference(&self.inner_body())
+    pub fn assemble(&self) -> CSG {
+        self.outer_body().subtract(&self.inner_body())
     }
 }
 
+impl Part for BoxModel {
+    fn name(&self) -> &str {
+        "BoxModel"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structural
+    }
+
+    fn assemble(&self) -> CSG {
+        BoxModel::assemble(self)
+    }
+}
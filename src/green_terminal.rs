@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Green Terminal Block
 #[derive(Debug, Clone)]
@@ -13,27 +16,40 @@ pub struct GreenTerminal {
 
 impl GreenTerminal {
     /// Generate the terminal body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the wire holes
-    pub fn holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.depth + 1.0);
+    pub fn holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.depth + 1.0);
         let mut holes = CSG::new();
         let start_x = -((self.hole_count as f64 - 1.0) / 2.0) * self.hole_spacing;
 
         for i in 0..self.hole_count {
             let x_offset = start_x + (i as f64) * self.hole_spacing;
-            holes = holes.union(&hole.translate(Vector3::new(x_offset, 0.0, 0.0)));
+            holes = holes.union(&hole.translated(Vector3::new(x_offset, 0.0, 0.0)));
         }
 
         holes
     }
 
     /// Assemble the complete terminal block
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.holes())
     }
 }
 
+impl Part for GreenTerminal {
+    fn name(&self) -> &str {
+        "GreenTerminal"
+    }
+
+    fn category(&self) -> Category {
+        Category::Connector
+    }
+
+    fn assemble(&self) -> CSG {
+        GreenTerminal::assemble(self)
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Handle
 #[derive(Debug, Clone)]
@@ -11,20 +14,33 @@ pub struct Handle {
 
 impl Handle {
     /// Generate the main handle body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.diameter / 2.0, self.length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.diameter / 2.0, self.length)
     }
 
     /// Generate the screw holes
-    pub fn screw_holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.screw_diameter / 2.0, self.height);
-        hole.translate(Vector3::new(self.length / 2.0, 0.0, 0.0))
-            .union(&hole.translate(Vector3::new(-self.length / 2.0, 0.0, 0.0)))
+    pub fn screw_holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.screw_diameter / 2.0, self.height);
+        hole.translated(Vector3::new(self.length / 2.0, 0.0, 0.0))
+            .union(&hole.translated(Vector3::new(-self.length / 2.0, 0.0, 0.0)))
     }
 
     /// Assemble the complete handle
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.screw_holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.screw_holes())
     }
 }
 
+impl Part for Handle {
+    fn name(&self) -> &str {
+        "Handle"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        Handle::assemble(self)
+    }
+}
@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Heat Pipe module
 #[derive(Debug, Clone)]
@@ -14,29 +18,30 @@ pub struct HeatPipe {
 
 impl HeatPipe {
     /// Generate the outer shell of the heat pipe
-    pub fn outer_shell(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.length)
+    pub fn outer_shell(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.length)
+            .finished(Material::Copper)
     }
     
     /// Generate the inner bore of the heat pipe
-    pub fn inner_bore(&self) -> CSG<()> {
-        CSG::cylinder_z(self.inner_diameter / 2.0, self.length)
+    pub fn inner_bore(&self) -> CSG {
+        CSG::z_cylinder(self.inner_diameter / 2.0, self.length)
     }
     
     /// Generate the cooling fins
-    pub fn fins(&self) -> CSG<()> {
+    pub fn fins(&self) -> CSG {
         let mut fins = CSG::new();
         for i in 0..self.fin_count {
             let z_offset = (i as f64) * (self.length / self.fin_count as f64);
-            let fin = CSG::cylinder_z(self.fin_diameter / 2.0, self.fin_thickness)
-                .translate(Vector3::new(0.0, 0.0, z_offset));
+            let fin = CSG::z_cylinder(self.fin_diameter / 2.0, self.fin_thickness)
+                .translated(Vector3::new(0.0, 0.0, z_offset));
             fins = fins.union(&fin);
         }
-        fins
+        fins.finished(Material::Aluminium)
     }
     
     /// Generate the full heat pipe model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut pipe = self.outer_shell();
         let bore = self.inner_bore();
         let fins = self.fins();
@@ -44,7 +49,20 @@ impl HeatPipe {
         pipe = pipe.subtract(&bore);
         pipe = pipe.union(&fins);
         
-        pipe
+        pipe.finished_default(Material::Copper)
     }
 }
 
+impl Part for HeatPipe {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Cooling
+    }
+
+    fn assemble(&self) -> CSG {
+        HeatPipe::assemble(self)
+    }
+}
@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Hot End module for 3D printers
 #[derive(Debug, Clone)]
@@ -15,24 +19,27 @@ pub struct HotEnd {
 
 impl HotEnd {
     /// Generate the heater block of the hot end
-    pub fn heater_block(&self) -> CSG<()> {
+    pub fn heater_block(&self) -> CSG {
         CSG::prism(self.heater_block_width, self.heater_block_height, self.heater_block_depth)
+            .finished(Material::Aluminium)
     }
     
     /// Generate the nozzle of the hot end
-    pub fn nozzle(&self) -> CSG<()> {
-        CSG::cylinder_z(self.nozzle_diameter / 2.0, self.nozzle_length)
-            .translate(Vector3::new(0.0, 0.0, -self.nozzle_length))
+    pub fn nozzle(&self) -> CSG {
+        CSG::z_cylinder(self.nozzle_diameter / 2.0, self.nozzle_length)
+            .translated(Vector3::new(0.0, 0.0, -self.nozzle_length))
+            .finished(Material::Brass)
     }
     
     /// Generate the heat break of the hot end
-    pub fn heat_break(&self) -> CSG<()> {
-        CSG::cylinder_z(self.heat_break_diameter / 2.0, self.heat_break_length)
-            .translate(Vector3::new(0.0, 0.0, self.heater_block_height))
+    pub fn heat_break(&self) -> CSG {
+        CSG::z_cylinder(self.heat_break_diameter / 2.0, self.heat_break_length)
+            .translated(Vector3::new(0.0, 0.0, self.heater_block_height))
+            .finished(Material::StainlessSteel)
     }
     
     /// Generate the full hot end model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut hot_end = self.heater_block();
         let nozzle = self.nozzle();
         let heat_break = self.heat_break();
@@ -44,3 +51,16 @@ impl HotEnd {
     }
 }
 
+impl Part for HotEnd {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::HotEnd
+    }
+
+    fn assemble(&self) -> CSG {
+        HotEnd::assemble(self)
+    }
+}
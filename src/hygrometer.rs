@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Hygrometer Module
 #[derive(Debug, Clone)]
@@ -11,19 +14,32 @@ pub struct Hygrometer {
 
 impl Hygrometer {
     /// Generate the main casing of the hygrometer
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the sensor hole
-    pub fn sensor_hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.sensor_diameter / 2.0, self.depth)
-            .translate(Vector3::new(0.0, 0.0, self.depth / 2.0))
+    pub fn sensor_hole(&self) -> CSG {
+        CSG::z_cylinder(self.sensor_diameter / 2.0, self.depth)
+            .translated(Vector3::new(0.0, 0.0, self.depth / 2.0))
     }
 
     /// Assemble the complete hygrometer
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.sensor_hole())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.sensor_hole())
     }
 }
 
+impl Part for Hygrometer {
+    fn name(&self) -> &str {
+        "Hygrometer"
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Hygrometer::assemble(self)
+    }
+}
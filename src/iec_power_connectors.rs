@@ -1,4 +1,9 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of an IEC Power Connector
 #[derive(Debug, Clone)]
@@ -8,24 +13,60 @@ pub struct IECConnector {
     pub height: f64,
     pub depth: f64,
     pub mount_hole_spacing: f64,
+    pub mount_hole_diameter: f64,
 }
 
 impl IECConnector {
     /// Generate the main body of the IEC connector
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
+            .translated(Vector3::new(-self.width / 2.0, -self.height / 2.0, 0.0))
+            .finished(Material::Plastic)
     }
 
     /// Generate the mounting holes
-    pub fn mount_holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(3.0, self.depth); // Assuming M3 screws
-        hole.translate(Vector3::new(self.mount_hole_spacing / 2.0, 0.0, 0.0))
-            .union(&hole.translate(Vector3::new(-self.mount_hole_spacing / 2.0, 0.0, 0.0)))
+    pub fn mount_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mount_hole_diameter, self.depth)
     }
 
     /// Assemble the final IEC connector
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.mount_holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.mount_holes())
+            .finished_default(Material::Plastic)
     }
 }
 
+impl Part for IECConnector {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Connector
+    }
+
+    fn assemble(&self) -> CSG {
+        IECConnector::assemble(self)
+    }
+}
+
+impl Mountable for IECConnector {
+    /// Screws through the panel into the front face, either side of the socket.
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_top(&mounting::pair(self.mount_hole_spacing), self.depth)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mount_hole_diameter
+    }
+
+    /// The screw holes and a rectangular opening the body passes through.
+    fn panel_cutout(&self, clearance: f64) -> CSG {
+        let opening = CSG::cube(Some((
+            &[0.0, 0.0, self.depth],
+            &[self.width / 2.0 + clearance, self.height / 2.0 + clearance, mounting::CUTOUT_DEPTH / 2.0],
+        )));
+        mounting::through_holes(&self.hole_positions(), self.mount_hole_diameter + 2.0 * clearance)
+            .union(&opening)
+    }
+}
@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an inductor module
 #[derive(Debug, Clone)]
@@ -14,19 +16,19 @@ pub struct Inductor {
 
 impl Inductor {
     /// Generate the cylindrical core of the inductor
-    pub fn core(&self) -> CSG<()> {
-        CSG::cylinder_z(self.core_diameter / 2.0, self.core_height)
+    pub fn core(&self) -> CSG {
+        CSG::z_cylinder(self.core_diameter / 2.0, self.core_height)
     }
     
     /// Generate the windings of the inductor
-    pub fn windings(&self) -> CSG<()> {
+    pub fn windings(&self) -> CSG {
         let mut windings = CSG::new();
         let start_z = -self.core_height / 2.0;
         
         for i in 0..self.winding_count {
             let z_offset = start_z + i as f64 * self.winding_spacing;
             let winding = CSG::torus(self.winding_diameter / 2.0, self.winding_spacing / 2.0)
-                .translate(Vector3::new(0.0, 0.0, z_offset));
+                .translated(Vector3::new(0.0, 0.0, z_offset));
             windings = windings.union(&winding);
         }
         
@@ -34,7 +36,7 @@ impl Inductor {
     }
     
     /// Generate the full inductor model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut inductor = self.core();
         let windings = self.windings();
         
@@ -44,3 +46,16 @@ impl Inductor {
     }
 }
 
+impl Part for Inductor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Inductor::assemble(self)
+    }
+}
@@ -1,4 +1,10 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::cache;
+use crate::anchor::Anchor;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an Insert module
 #[derive(Debug, Clone)]
@@ -12,23 +18,38 @@ pub struct Insert {
 
 impl Insert {
     /// Generate the outer shell of the insert
-    pub fn outer_shell(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.length)
+    pub fn outer_shell(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.length)
+            .finished(Material::Brass)
     }
     
     /// Generate the inner bore of the insert
-    pub fn inner_bore(&self) -> CSG<()> {
-        CSG::cylinder_z(self.inner_diameter / 2.0, self.length)
+    pub fn inner_bore(&self) -> CSG {
+        CSG::z_cylinder(self.inner_diameter / 2.0, self.length)
     }
     
     /// Generate the threading of the insert
-    pub fn threading(&self) -> CSG<()> {
-        // Simplified representation of threading using a helical pattern
-        CSG::helix(self.inner_diameter / 2.0, self.length, self.thread_pitch)
+    ///
+    /// One ring per pitch standing proud of the bore, rather than a true helix, which the
+    /// BSP booleans cannot union in any reasonable time or memory.
+    pub fn threading(&self) -> CSG {
+        let r = self.inner_diameter / 2.0;
+        if self.thread_pitch <= 0.0 {
+            return CSG::new();
+        }
+        let depth = self.thread_pitch / 4.0;
+        let thickness = self.thread_pitch / 2.0;
+        let ring = CSG::z_cylinder(r + depth, thickness).subtract(&CSG::z_cylinder(r - depth, thickness));
+        // One pitch apart, the first half a pitch up, as many as fit in the length.
+        let count = (self.length / self.thread_pitch + 0.25).floor().max(0.0) as usize;
+        (0..count)
+            .map(|i| ring.translated(Vector3::new(0.0, 0.0, (i as f64 + 0.5) * self.thread_pitch - thickness / 2.0)))
+            .fold(CSG::new(), |acc, ring| acc.union(&ring))
+            .finished(Material::Brass)
     }
     
     /// Generate the full insert model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut insert = self.outer_shell();
         let bore = self.inner_bore();
         let threads = self.threading();
@@ -36,7 +57,32 @@ impl Insert {
         insert = insert.subtract(&bore);
         insert = insert.union(&threads);
         
-        insert
+        insert.finished_default(Material::Brass)
     }
 }
 
+impl Part for Insert {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Fastener
+    }
+
+    fn assemble(&self) -> CSG {
+        Insert::assemble(self)
+    }
+
+    fn cache_key(&self) -> Option<String> {
+        Some(cache::debug_key(self))
+    }
+
+    /// `bottom` and `top` faces, on the axis.
+    fn anchors(&self) -> Vec<Anchor> {
+        vec![
+            Anchor::facing("bottom", Vector3::zeros(), -Vector3::z()),
+            Anchor::facing("top", Vector3::new(0.0, 0.0, self.length), Vector3::z()),
+        ]
+    }
+}
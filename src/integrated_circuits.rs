@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an integrated circuit (IC) module
 #[derive(Debug, Clone)]
@@ -16,19 +18,19 @@ pub struct IC {
 
 impl IC {
     /// Generate the rectangular body of the IC
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
     }
     
     /// Generate the pins of the IC
-    pub fn pins(&self) -> CSG<()> {
+    pub fn pins(&self) -> CSG {
         let mut pins = CSG::new();
         let start_x = -((self.pin_count as f64 - 1.0) * self.pin_spacing) / 2.0;
         
         for i in 0..self.pin_count {
             let x_offset = start_x + i as f64 * self.pin_spacing;
-            let pin = CSG::cylinder_z(self.pin_diameter / 2.0, self.pin_length)
-                .translate(Vector3::new(x_offset, 0.0, -self.pin_length));
+            let pin = CSG::z_cylinder(self.pin_diameter / 2.0, self.pin_length)
+                .translated(Vector3::new(x_offset, 0.0, -self.pin_length));
             pins = pins.union(&pin);
         }
         
@@ -36,7 +38,7 @@ impl IC {
     }
     
     /// Generate the full IC model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut ic = self.body();
         let pins = self.pins();
         
@@ -46,3 +48,16 @@ impl IC {
     }
 }
 
+impl Part for IC {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        IC::assemble(self)
+    }
+}
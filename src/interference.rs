@@ -0,0 +1,198 @@
+use crate::assembly::{Assembly, PlacedPart};
+use crate::mass::MassProperties;
+use crate::CSG;
+use csgrs::float_types::parry3d::bounding_volume::{Aabb, BoundingVolume};
+use csgrs::float_types::parry3d::math::Isometry;
+use csgrs::float_types::parry3d::query;
+use csgrs::float_types::parry3d::shape::SharedShape;
+use std::fmt;
+
+/// Overlaps smaller than this, in mm³, are parts touching face to face rather than colliding.
+pub const VOLUME_TOLERANCE: f64 = 1e-3;
+
+/// Two placed parts whose solids intersect.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Overlap {
+    pub a: String,
+    pub b: String,
+    /// Volume of the intersection in mm³.
+    pub volume: f64,
+}
+
+/// Two placed parts that do not intersect but are closer than the required clearance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NearMiss {
+    pub a: String,
+    pub b: String,
+    /// Shortest distance between the surfaces, zero if they touch.
+    pub distance: f64,
+}
+
+/// Result of an [`InterferenceCheck`], with pairs in the order their parts appear in the assembly.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Report {
+    pub overlaps: Vec<Overlap>,
+    pub near_misses: Vec<NearMiss>,
+}
+
+impl Report {
+    /// True if no pair of parts collides or is too close
+    pub fn is_clear(&self) -> bool {
+        self.overlaps.is_empty() && self.near_misses.is_empty()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_clear() {
+            return writeln!(f, "no interference");
+        }
+        for o in &self.overlaps {
+            writeln!(f, "{} overlaps {} by {:.3} mm³", o.a, o.b, o.volume)?;
+        }
+        for n in &self.near_misses {
+            writeln!(f, "{} is {:.3} mm from {}", n.a, n.distance, n.b)?;
+        }
+        Ok(())
+    }
+}
+
+/// Intersects every pair of parts in an assembly, reporting collisions and parts closer than
+/// a required clearance.
+///
+/// Intended contacts, like a screw in its hole or a nut on its screw, are excluded with
+/// [`allow`](InterferenceCheck::allow):
+///
+/// ```ignore
+/// let report = InterferenceCheck::new()
+///     .with_clearance(0.5)
+///     .allow("motor/screw_*", "motor/body")
+///     .check(&assembly);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InterferenceCheck {
+    /// Smallest gap allowed between parts that do not touch, zero to only report overlaps.
+    pub clearance: f64,
+    /// Pairs of path patterns whose parts may touch or overlap.
+    pub allowed: Vec<(String, String)>,
+}
+
+impl InterferenceCheck {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_clearance(mut self, clearance: f64) -> Self {
+        self.clearance = clearance.max(0.0);
+        self
+    }
+
+    /// Allow the parts matching `a` to touch or overlap those matching `b`, in either order.
+    ///
+    /// Patterns are matched against the `/` separated paths of [`Assembly::leaves`] a segment
+    /// at a time, `*` standing for any run of characters within a segment. A pattern also
+    /// matches everything below it, so `"motor"` covers `"motor/body"` and `"motor/screw_1"`.
+    pub fn allow(mut self, a: impl Into<String>, b: impl Into<String>) -> Self {
+        self.allowed.push((a.into(), b.into()));
+        self
+    }
+
+    /// True if the parts at paths `a` and `b` are allowed to touch
+    pub fn is_allowed(&self, a: &str, b: &str) -> bool {
+        self.allowed.iter().any(|(pa, pb)| {
+            (path_matches(pa, a) && path_matches(pb, b)) || (path_matches(pa, b) && path_matches(pb, a))
+        })
+    }
+
+    /// Check every pair of leaf parts in `assembly`
+    pub fn check(&self, assembly: &Assembly) -> Report {
+        self.check_parts(&assembly.leaves())
+    }
+
+    /// Check every pair of already placed parts
+    pub fn check_parts(&self, parts: &[PlacedPart]) -> Report {
+        let solids: Vec<Placed> = parts
+            .iter()
+            .map(|p| Placed::new(&p.path, p.solid()))
+            .filter(|p| !p.solid.polygons.is_empty())
+            .collect();
+
+        let mut report = Report::default();
+        for (i, a) in solids.iter().enumerate() {
+            for b in &solids[i + 1..] {
+                if self.is_allowed(&a.path, &b.path) || !a.bounds.loosened(self.clearance).intersects(&b.bounds) {
+                    continue;
+                }
+                let volume = overlap_volume(&a.solid, &b.solid);
+                if volume > VOLUME_TOLERANCE {
+                    report.overlaps.push(Overlap { a: a.path.clone(), b: b.path.clone(), volume });
+                } else if self.clearance > 0.0 {
+                    let distance = a.distance(b);
+                    if distance < self.clearance {
+                        report.near_misses.push(NearMiss { a: a.path.clone(), b: b.path.clone(), distance });
+                    }
+                }
+            }
+        }
+        report
+    }
+}
+
+/// Volume of the intersection of two solids in mm³
+pub fn overlap_volume(a: &CSG, b: &CSG) -> f64 {
+    let common = a.intersect(b);
+    if common.polygons.is_empty() {
+        0.0
+    } else {
+        MassProperties::of_solid(&common, 1.0).volume.max(0.0)
+    }
+}
+
+/// A part's solid in assembly coordinates, with its bounds and a collision mesh built on demand.
+struct Placed {
+    path: String,
+    solid: CSG,
+    bounds: Aabb,
+    mesh: std::cell::OnceCell<SharedShape>,
+}
+
+impl Placed {
+    fn new(path: &str, solid: CSG) -> Self {
+        let bounds = solid.bounding_box();
+        Placed { path: path.to_string(), solid, bounds, mesh: Default::default() }
+    }
+
+    fn mesh(&self) -> &SharedShape {
+        self.mesh.get_or_init(|| self.solid.to_trimesh())
+    }
+
+    /// Shortest distance between the two surfaces
+    fn distance(&self, other: &Placed) -> f64 {
+        let identity = Isometry::identity();
+        query::distance(&identity, self.mesh().as_ref(), &identity, other.mesh().as_ref())
+            .expect("triangle meshes always support distance queries")
+    }
+}
+
+/// True if `pattern` matches `path` or one of its ancestors
+fn path_matches(pattern: &str, path: &str) -> bool {
+    let mut segments = path.split('/');
+    pattern
+        .split('/')
+        .all(|p| segments.next().is_some_and(|s| glob(p, s)))
+}
+
+/// Match `text` against `pattern`, where `*` matches any run of characters
+fn glob(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((head, tail)) => {
+            let Some(rest) = text.strip_prefix(head) else {
+                return false;
+            };
+            (0..=rest.len())
+                .filter(|&i| rest.is_char_boundary(i))
+                .any(|i| glob(tail, &rest[i..]))
+        }
+    }
+}
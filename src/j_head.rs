@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a J-Head Hot End
 #[derive(Debug, Clone)]
@@ -13,25 +16,38 @@ pub struct JHead {
 
 impl JHead {
     /// Generate the heat sink
-    pub fn heat_sink(&self) -> CSG<()> {
-        CSG::cylinder_z(self.heat_sink_diameter / 2.0, self.heat_sink_height)
+    pub fn heat_sink(&self) -> CSG {
+        CSG::z_cylinder(self.heat_sink_diameter / 2.0, self.heat_sink_height)
     }
 
     /// Generate the heat break
-    pub fn heat_break(&self) -> CSG<()> {
-        CSG::cylinder_z(self.heat_break_diameter / 2.0, self.heat_break_length)
-            .translate(Vector3::new(0.0, 0.0, -self.heat_break_length))
+    pub fn heat_break(&self) -> CSG {
+        CSG::z_cylinder(self.heat_break_diameter / 2.0, self.heat_break_length)
+            .translated(Vector3::new(0.0, 0.0, -self.heat_break_length))
     }
 
     /// Generate the nozzle
-    pub fn nozzle(&self) -> CSG<()> {
-        CSG::cylinder_z(self.nozzle_diameter / 2.0, self.nozzle_length)
-            .translate(Vector3::new(0.0, 0.0, -self.nozzle_length - self.heat_break_length))
+    pub fn nozzle(&self) -> CSG {
+        CSG::z_cylinder(self.nozzle_diameter / 2.0, self.nozzle_length)
+            .translated(Vector3::new(0.0, 0.0, -self.nozzle_length - self.heat_break_length))
     }
 
     /// Assemble the complete hot end
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.heat_sink().union(&self.heat_break()).union(&self.nozzle())
     }
 }
 
+impl Part for JHead {
+    fn name(&self) -> &str {
+        "JHead"
+    }
+
+    fn category(&self) -> Category {
+        Category::HotEnd
+    }
+
+    fn assemble(&self) -> CSG {
+        JHead::assemble(self)
+    }
+}
@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a joystick module
 #[derive(Debug, Clone)]
@@ -15,24 +17,24 @@ pub struct Joystick {
 
 impl Joystick {
     /// Generate the cylindrical base of the joystick
-    pub fn base(&self) -> CSG<()> {
-        CSG::cylinder_z(self.base_diameter / 2.0, self.base_height)
+    pub fn base(&self) -> CSG {
+        CSG::z_cylinder(self.base_diameter / 2.0, self.base_height)
     }
     
     /// Generate the stick of the joystick
-    pub fn stick(&self) -> CSG<()> {
-        CSG::cylinder_z(self.stick_diameter / 2.0, self.stick_length)
-            .translate(Vector3::new(0.0, 0.0, self.base_height))
+    pub fn stick(&self) -> CSG {
+        CSG::z_cylinder(self.stick_diameter / 2.0, self.stick_length)
+            .translated(Vector3::new(0.0, 0.0, self.base_height))
     }
     
     /// Generate the top handle of the joystick
-    pub fn top(&self) -> CSG<()> {
-        CSG::sphere(self.top_diameter / 2.0)
-            .translate(Vector3::new(0.0, 0.0, self.base_height + self.stick_length + self.top_height / 2.0))
+    pub fn top(&self) -> CSG {
+        CSG::ball(self.top_diameter / 2.0)
+            .translated(Vector3::new(0.0, 0.0, self.base_height + self.stick_length + self.top_height / 2.0))
     }
     
     /// Generate the full joystick model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut joystick = self.base();
         let stick = self.stick();
         let top = self.top();
@@ -44,3 +46,16 @@ impl Joystick {
     }
 }
 
+impl Part for Joystick {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Joystick::assemble(self)
+    }
+}
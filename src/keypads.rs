@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a keypad module
 #[derive(Debug, Clone)]
@@ -18,12 +20,12 @@ pub struct Keypad {
 
 impl Keypad {
     /// Generate the rectangular base of the keypad
-    pub fn base(&self) -> CSG<()> {
+    pub fn base(&self) -> CSG {
         CSG::prism(self.base_width, self.base_height, self.base_depth)
     }
     
     /// Generate the keys of the keypad
-    pub fn keys(&self) -> CSG<()> {
+    pub fn keys(&self) -> CSG {
         let mut keys = CSG::new();
         let start_x = -((self.columns as f64 - 1.0) * self.key_spacing) / 2.0;
         let start_y = -((self.rows as f64 - 1.0) * self.key_spacing) / 2.0;
@@ -33,7 +35,7 @@ impl Keypad {
                 let x_offset = start_x + col as f64 * self.key_spacing;
                 let y_offset = start_y + row as f64 * self.key_spacing;
                 let key = CSG::prism(self.key_width, self.key_height, self.key_depth)
-                    .translate(Vector3::new(x_offset, y_offset, self.base_depth));
+                    .translated(Vector3::new(x_offset, y_offset, self.base_depth));
                 keys = keys.union(&key);
             }
         }
@@ -42,7 +44,7 @@ impl Keypad {
     }
     
     /// Generate the full keypad model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut keypad = self.base();
         let keys = self.keys();
         
@@ -52,3 +54,16 @@ impl Keypad {
     }
 }
 
+impl Part for Keypad {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Keypad::assemble(self)
+    }
+}
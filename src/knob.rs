@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use crate::tolerance::Fit;
 
 /// Representation of a Knob
 #[derive(Debug, Clone)]
@@ -7,22 +10,37 @@ pub struct Knob {
     pub height: f64,
     pub shaft_diameter: f64,
     pub shaft_depth: f64,
+    /// Fit of the shaft in its hole.
+    pub shaft_fit: Fit,
 }
 
 impl Knob {
     /// Generate the main knob body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.height)
     }
 
     /// Generate the shaft hole
-    pub fn shaft_hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.shaft_diameter / 2.0, self.shaft_depth)
+    pub fn shaft_hole(&self) -> CSG {
+        CSG::z_cylinder(self.shaft_fit.hole_diameter(self.shaft_diameter) / 2.0, self.shaft_depth)
     }
 
     /// Assemble the complete knob
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.shaft_hole())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.shaft_hole())
     }
 }
 
+impl Part for Knob {
+    fn name(&self) -> &str {
+        "Knob"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        Knob::assemble(self)
+    }
+}
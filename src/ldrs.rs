@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an LDR (Light Dependent Resistor) module
 #[derive(Debug, Clone)]
@@ -13,12 +16,12 @@ pub struct LDR {
 
 impl LDR {
     /// Generate the cylindrical body of the LDR
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_height)
     }
     
     /// Generate the leads of the LDR
-    pub fn leads(&self) -> CSG<()> {
+    pub fn leads(&self) -> CSG {
         let mut leads = CSG::new();
         let offsets = [
             (-self.lead_spacing / 2.0, 0.0),
@@ -26,8 +29,8 @@ impl LDR {
         ];
         
         for &(x, y) in &offsets {
-            let lead = CSG::cylinder_z(self.lead_diameter / 2.0, self.lead_length)
-                .translate(Vector3::new(x, y, -self.lead_length));
+            let lead = CSG::z_cylinder(self.lead_diameter / 2.0, self.lead_length)
+                .translated(Vector3::new(x, y, -self.lead_length));
             leads = leads.union(&lead);
         }
         
@@ -35,7 +38,7 @@ impl LDR {
     }
     
     /// Generate the full LDR model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut ldr = self.body();
         let leads = self.leads();
         
@@ -45,3 +48,16 @@ impl LDR {
     }
 }
 
+impl Part for LDR {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        LDR::assemble(self)
+    }
+}
@@ -1,4 +1,9 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a Leadnut model
 #[derive(Debug, Clone)]
@@ -14,40 +19,30 @@ pub struct Leadnut {
 
 impl Leadnut {
     /// Generate the cylindrical body of the leadnut
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.length)
+            .finished(Material::Brass)
     }
     
     /// Generate the inner bore of the leadnut
-    pub fn bore(&self) -> CSG<()> {
-        CSG::cylinder_z(self.inner_diameter / 2.0, self.length)
+    pub fn bore(&self) -> CSG {
+        CSG::z_cylinder(self.inner_diameter / 2.0, self.length)
     }
     
     /// Generate the flange of the leadnut
-    pub fn flange(&self) -> CSG<()> {
-        CSG::cylinder_z(self.flange_diameter / 2.0, self.flange_thickness)
-            .translate(Vector3::new(0.0, 0.0, self.length))
+    pub fn flange(&self) -> CSG {
+        CSG::z_cylinder(self.flange_diameter / 2.0, self.flange_thickness)
+            .translated(Vector3::new(0.0, 0.0, self.length))
+            .finished(Material::Brass)
     }
     
     /// Generate the mounting holes of the leadnut
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, 0.0),
-            (self.mounting_hole_spacing / 2.0, 0.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.flange_thickness)
-                .translate(Vector3::new(x, y, self.length));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.flange_thickness)
     }
     
     /// Generate the full leadnut model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut leadnut = self.body();
         let bore = self.bore();
         let flange = self.flange();
@@ -57,7 +52,30 @@ impl Leadnut {
         leadnut = leadnut.union(&flange);
         leadnut = leadnut.subtract(&holes);
         
-        leadnut
+        leadnut.finished_default(Material::Brass)
     }
 }
 
+impl Part for Leadnut {
+    fn name(&self) -> &str {
+        "Leadnut"
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        Leadnut::assemble(self)
+    }
+}
+
+impl Mountable for Leadnut {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::pair(self.mounting_hole_spacing), self.length)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
@@ -1,4 +1,6 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
 
 /// Representation of an LED Bezel
 #[derive(Debug, Clone)]
@@ -10,18 +12,31 @@ pub struct LEDBezel {
 
 impl LEDBezel {
     /// Generate the bezel body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.height)
     }
 
     /// Generate the LED hole
-    pub fn led_hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.inner_diameter / 2.0, self.height + 1.0)
+    pub fn led_hole(&self) -> CSG {
+        CSG::z_cylinder(self.inner_diameter / 2.0, self.height + 1.0)
     }
 
     /// Assemble the complete LED bezel
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.led_hole())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.led_hole())
     }
 }
 
+impl Part for LEDBezel {
+    fn name(&self) -> &str {
+        "LEDBezel"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        LEDBezel::assemble(self)
+    }
+}
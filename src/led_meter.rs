@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an LED Meter
 #[derive(Debug, Clone)]
@@ -13,27 +16,40 @@ pub struct LEDMeter {
 
 impl LEDMeter {
     /// Generate the main panel body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the LED holes
-    pub fn led_holes(&self) -> CSG<()> {
-        let led_hole = CSG::cylinder_z(self.led_diameter / 2.0, self.depth + 1.0);
+    pub fn led_holes(&self) -> CSG {
+        let led_hole = CSG::z_cylinder(self.led_diameter / 2.0, self.depth + 1.0);
         let mut holes = CSG::new();
         let start_x = -((self.led_count as f64 - 1.0) / 2.0) * self.led_spacing;
 
         for i in 0..self.led_count {
             let x_offset = start_x + (i as f64) * self.led_spacing;
-            holes = holes.union(&led_hole.translate(Vector3::new(x_offset, 0.0, 0.0)));
+            holes = holes.union(&led_hole.translated(Vector3::new(x_offset, 0.0, 0.0)));
         }
 
         holes
     }
 
     /// Assemble the full LED meter
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.led_holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.led_holes())
     }
 }
 
+impl Part for LEDMeter {
+    fn name(&self) -> &str {
+        "LEDMeter"
+    }
+
+    fn category(&self) -> Category {
+        Category::Display
+    }
+
+    fn assemble(&self) -> CSG {
+        LEDMeter::assemble(self)
+    }
+}
@@ -1,5 +1,9 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::colour::Colour;
+use crate::material::{Finish, Finishing, Material, TINNED_COPPER};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an LED component
 #[derive(Debug, Clone)]
@@ -14,12 +18,13 @@ pub struct LED {
 
 impl LED {
     /// Generate the cylindrical body of the LED
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_height)
+            .finished(Finish::new(Colour::rgba(1.0, 0.0, 0.0, 0.6), Material::Plastic))
     }
     
     /// Generate the pins of the LED
-    pub fn pins(&self) -> CSG<()> {
+    pub fn pins(&self) -> CSG {
         let mut pins = CSG::new();
         let offsets = [
             (-self.pin_spacing / 2.0, 0.0),
@@ -27,16 +32,16 @@ impl LED {
         ];
         
         for &(x, y) in &offsets {
-            let pin = CSG::cylinder_z(self.pin_diameter / 2.0, self.pin_length)
-                .translate(Vector3::new(x, y, -self.pin_length));
+            let pin = CSG::z_cylinder(self.pin_diameter / 2.0, self.pin_length)
+                .translated(Vector3::new(x, y, -self.pin_length));
             pins = pins.union(&pin);
         }
         
-        pins
+        pins.finished(TINNED_COPPER)
     }
     
     /// Generate the full LED model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut led = self.body();
         let pins = self.pins();
         
@@ -46,3 +51,16 @@ impl LED {
     }
 }
 
+impl Part for LED {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        LED::assemble(self)
+    }
+}
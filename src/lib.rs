@@ -1,4 +1,173 @@
 #![allow(dead_code)]
 #![forbid(unsafe_code)]
 
+/// The solid type every part in this crate is built from, with each polygon tagged by its finish.
+pub type CSG = csgrs::csg::CSG<material::Finish>;
+
+pub mod anchor;
+pub mod assembly;
+pub mod bom;
+pub mod cache;
+pub mod catalogue;
+pub mod colour;
+pub mod drawing;
+pub mod error;
+pub mod export;
+pub mod fastening;
+pub mod interference;
+pub mod mass;
+pub mod material;
+pub mod mounting;
+pub mod nopscadlib;
+pub mod part;
+pub mod pattern;
+pub mod primitives;
+pub mod printability;
+pub mod resolution;
+pub mod text;
+pub mod tolerance;
+
+pub mod antennas;
+pub mod audio_jack;
+pub mod axial_capacitor;
+pub mod ball_bearing;
+pub mod batteries;
+pub mod bearing_clock;
+pub mod bearings;
+pub mod belts;
+pub mod bldc_motors;
+pub mod blowers;
+pub mod box_assembly;
+pub mod box_section;
+pub mod bulldogs;
+pub mod butt_box;
+pub mod button;
+pub mod buttons;
+pub mod buzzers;
+pub mod cable_clip;
+pub mod cable_grommets;
+pub mod cable_strip;
+pub mod camera_housing;
+pub mod cameras;
+pub mod capacitors;
+pub mod carriers;
+pub mod circlip;
+pub mod connectors;
+pub mod corner_block;
+pub mod crystals;
+pub mod d_connectors;
+pub mod diodes;
+pub mod dip;
+pub mod displays;
+pub mod door_hinge;
+pub mod door_latch;
+pub mod dot_matrix_displays;
+pub mod drag_chain;
+pub mod e3d_hot_end;
+pub mod eink_displays;
+pub mod electronic_components;
 pub mod extrusion;
+pub mod extrusion_brackets;
+pub mod fan_guard;
+pub mod fans;
+pub mod faston;
+pub mod fixing_block;
+pub mod flat_hinge;
+pub mod foot;
+pub mod fuse_holder;
+pub mod fuses;
+pub mod gear_motor;
+pub mod geared_stepper;
+pub mod generic_box;
+pub mod green_terminal;
+pub mod handle;
+pub mod heat_pipes;
+pub mod hot_end;
+pub mod hygrometer;
+pub mod iec_power_connectors;
+pub mod inductors;
+pub mod insert;
+pub mod integrated_circuits;
+pub mod j_head;
+pub mod joysticks;
+pub mod keypads;
+pub mod knob;
+pub mod lcd_displays;
+pub mod ldrs;
+pub mod leadnuts;
+pub mod led_bezel;
+pub mod led_meter;
+pub mod leds;
+pub mod light_strips;
+pub mod linear_bearing;
+pub mod magnet;
+pub mod mains_sockets;
+pub mod microphones;
+pub mod microswitches;
+pub mod microview_oled_display;
+pub mod module;
+pub mod motors;
+pub mod nuts;
+pub mod o_ring;
+pub mod oled_displays;
+pub mod opengrab;
+pub mod panel_meters;
+pub mod pcb;
+pub mod pcb_mount;
+pub mod photo_interrupter;
+pub mod pillars;
+pub mod pillow_blocks;
+pub mod pin_headers;
+pub mod pocket_handle;
+pub mod potentiometers;
+pub mod power_supplies;
+pub mod press_fit;
+pub mod printed_box;
+pub mod printed_pulleys;
+pub mod psu_shroud;
+pub mod pulleys;
+pub mod rails;
+pub mod relays;
+pub mod resistors;
+pub mod ribbon_clamp;
+pub mod ring_terminal;
+pub mod rocker_switch;
+pub mod rod;
+pub mod rod_ends;
+pub mod rotary_encoders;
+pub mod sbr_rails;
+pub mod screw_knob;
+pub mod screws;
+pub mod sealing_strip;
+pub mod sensors;
+pub mod servo_motor;
+pub mod seven_segment_displays;
+pub mod shaft_coupling;
+pub mod sheets;
+pub mod sk_brackets;
+pub mod smd;
+pub mod socket_box;
+pub mod spade;
+pub mod speakers;
+pub mod speakers_large;
+pub mod spools;
+pub mod spring;
+pub mod ssr_shroud;
+pub mod ssrs;
+pub mod stepper_motors;
+pub mod strap_handle;
+pub mod swiss_clip;
+pub mod switches;
+pub mod t_tracks;
+pub mod toggle_switches;
+pub mod touchscreens;
+pub mod transformers;
+pub mod transistors;
+pub mod tubing;
+pub mod variacs;
+pub mod veroboard;
+pub mod vfd_displays;
+pub mod vibration_motors;
+pub mod washers;
+pub mod wire;
+pub mod ziptie;
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an LED Light Strip module
 #[derive(Debug, Clone)]
@@ -14,19 +17,19 @@ pub struct LightStrip {
 
 impl LightStrip {
     /// Generate the base strip of the light strip
-    pub fn base_strip(&self) -> CSG<()> {
+    pub fn base_strip(&self) -> CSG {
         CSG::prism(self.strip_length, self.strip_width, self.strip_thickness)
     }
     
     /// Generate the LEDs on the strip
-    pub fn leds(&self) -> CSG<()> {
+    pub fn leds(&self) -> CSG {
         let mut leds = CSG::new();
         let start_x = -((self.led_count as f64 - 1.0) * self.led_spacing) / 2.0;
         
         for i in 0..self.led_count {
             let x_offset = start_x + i as f64 * self.led_spacing;
-            let led = CSG::cylinder_z(self.led_diameter / 2.0, self.strip_thickness)
-                .translate(Vector3::new(x_offset, 0.0, self.strip_thickness / 2.0));
+            let led = CSG::z_cylinder(self.led_diameter / 2.0, self.strip_thickness)
+                .translated(Vector3::new(x_offset, 0.0, self.strip_thickness / 2.0));
             leds = leds.union(&led);
         }
         
@@ -34,7 +37,7 @@ impl LightStrip {
     }
     
     /// Generate the full LED light strip model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut light_strip = self.base_strip();
         let leds = self.leds();
         
@@ -44,3 +47,16 @@ impl LightStrip {
     }
 }
 
+impl Part for LightStrip {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Display
+    }
+
+    fn assemble(&self) -> CSG {
+        LightStrip::assemble(self)
+    }
+}
@@ -1,4 +1,9 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::pattern;
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Linear Bearing module
 #[derive(Debug, Clone)]
@@ -15,36 +20,31 @@ pub struct LinearBearing {
 
 impl LinearBearing {
     /// Generate the outer shell of the bearing
-    pub fn outer_shell(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.length)
+    pub fn outer_shell(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.length)
+            .finished(Material::Steel)
     }
     
     /// Generate the inner bore of the bearing
-    pub fn inner_bore(&self) -> CSG<()> {
-        CSG::cylinder_z(self.inner_diameter / 2.0, self.length)
+    pub fn inner_bore(&self) -> CSG {
+        CSG::z_cylinder(self.inner_diameter / 2.0, self.length)
     }
     
     /// Generate the ball bearing rows
-    pub fn balls(&self) -> CSG<()> {
-        let mut balls = CSG::new();
-        let start_x = -((self.ball_columns as f64 - 1.0) * self.ball_spacing) / 2.0;
-        let start_z = -((self.ball_rows as f64 - 1.0) * self.ball_spacing) / 2.0;
-        
-        for row in 0..self.ball_rows {
-            for col in 0..self.ball_columns {
-                let x_offset = start_x + col as f64 * self.ball_spacing;
-                let z_offset = start_z + row as f64 * self.ball_spacing;
-                let ball = CSG::sphere(self.ball_diameter / 2.0)
-                    .translate(Vector3::new(x_offset, self.outer_diameter / 2.0, z_offset));
-                balls = balls.union(&ball);
-            }
-        }
-        
-        balls
+    pub fn balls(&self) -> CSG {
+        let ball = CSG::ball(self.ball_diameter / 2.0)
+            .translated(Vector3::new(0.0, self.outer_diameter / 2.0, 0.0));
+        let placements = pattern::grid(
+            self.ball_columns as usize,
+            self.ball_rows as usize,
+            Vector3::new(self.ball_spacing, 0.0, 0.0),
+            Vector3::new(0.0, 0.0, self.ball_spacing),
+        );
+        pattern::place(&ball, &placements).finished(Material::Steel)
     }
     
     /// Generate the full linear bearing model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut bearing = self.outer_shell();
         let bore = self.inner_bore();
         let balls = self.balls();
@@ -52,7 +52,20 @@ impl LinearBearing {
         bearing = bearing.subtract(&bore);
         bearing = bearing.union(&balls);
         
-        bearing
+        bearing.finished_default(Material::Steel)
     }
 }
 
+impl Part for LinearBearing {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        LinearBearing::assemble(self)
+    }
+}
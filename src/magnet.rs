@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
 
 /// Representation of a Magnet
 #[derive(Debug, Clone)]
@@ -10,18 +13,33 @@ pub struct Magnet {
 
 impl Magnet {
     /// Generate the magnet body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.diameter / 2.0, self.thickness)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.diameter / 2.0, self.thickness)
+            .finished(Material::Magnet)
     }
 
     /// Generate the center hole
-    pub fn hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.hole_diameter / 2.0, self.thickness + 1.0)
+    pub fn hole(&self) -> CSG {
+        CSG::z_cylinder(self.hole_diameter / 2.0, self.thickness + 1.0)
     }
 
     /// Assemble the complete magnet
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.hole())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.hole())
+            .finished_default(Material::Magnet)
     }
 }
 
+impl Part for Magnet {
+    fn name(&self) -> &str {
+        "Magnet"
+    }
+
+    fn category(&self) -> Category {
+        Category::Misc
+    }
+
+    fn assemble(&self) -> CSG {
+        Magnet::assemble(self)
+    }
+}
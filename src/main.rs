@@ -1,6 +1,228 @@
-use partsrs::extrusion::E2020;
+use partsrs::bom::Bom;
+use partsrs::catalogue::{Catalogue, Entry};
+use partsrs::drawing::{self, DrawingFormat, Layer};
+use partsrs::export::{self, ExportObject, Format};
+use partsrs::resolution::{self, Resolution};
+use std::path::Path;
+use std::process::ExitCode;
 
-fn main() {
-    let e2020_2d = E2020.cross_section_2d(false);
-    let e2020_3d = E2020.extrude_3d(100.0, true, false);
+const USAGE: &str = "\
+usage:
+  partsrs [--catalogue FILE ...] COMMAND ...
+      --catalogue adds or replaces parts from a TOML, JSON or NopSCADLib .scad data file, and may be repeated.
+  partsrs list [FAMILY]
+      List catalogue parts, optionally only those of one family.
+  partsrs params NAME
+      Show the parameters of a part and their default values.
+  partsrs export NAME OUTPUT [KEY=VALUE ...] [--ascii] [--layer NAME] [--fn N] [--fa DEG] [--fs MM]
+      Write a part to OUTPUT (.stl, .obj, .3mf, .scad or .step), overriding any parameters.
+      An .svg or .dxf OUTPUT gets the outline of the part's underside instead, on the layer
+      given by --layer NAME.
+      --fn, --fa and --fs set the curve resolution as $fn, $fa and $fs do in OpenSCAD.
+  partsrs info NAME [KEY=VALUE ...] [--bom csv|json|md] [--fn N] [--fa DEG] [--fs MM]
+      Print a part's bounding box, mass and bill of materials.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (catalogue, args) = take_catalogue(args)?;
+    let (command, rest) = match args.split_first() {
+        Some((command, rest)) => (command.as_str(), rest),
+        None => return Err(format!("missing command\n{}", USAGE)),
+    };
+    match command {
+        "list" => list(&catalogue, rest.first().map(String::as_str)),
+        "params" => params(&catalogue, rest),
+        "export" => export_part(&catalogue, rest),
+        "info" => info(&catalogue, rest),
+        "help" | "-h" | "--help" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => Err(format!("unknown command `{}`\n{}", command, USAGE)),
+    }
+}
+
+/// Load the built-in parts and any `--catalogue FILE` data files, returning the remaining arguments.
+fn take_catalogue(args: &[String]) -> Result<(Catalogue, Vec<String>), String> {
+    let mut catalogue = Catalogue::builtin();
+    let mut rest = Vec::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg != "--catalogue" {
+            rest.push(arg.clone());
+            continue;
+        }
+        let file = iter.next().ok_or("`--catalogue` needs a file")?;
+        catalogue.load_file(Path::new(file)).map_err(|e| e.to_string())?;
+    }
+    Ok((catalogue, rest))
+}
+
+fn list(catalogue: &Catalogue, family: Option<&str>) -> Result<(), String> {
+    for entry in catalogue.entries() {
+        if family.is_none_or(|f| f.eq_ignore_ascii_case(entry.family)) {
+            println!("{:<12} {:<12} {}", entry.name, entry.family, entry.part.category());
+        }
+    }
+    Ok(())
+}
+
+fn params(catalogue: &Catalogue, args: &[String]) -> Result<(), String> {
+    let name = args.first().ok_or("missing part name")?;
+    let entry = catalogue.find(name).map_err(|e| e.to_string())?;
+    for (key, value) in entry.part.parameters() {
+        println!("{:<24} {}", key, value);
+    }
+    Ok(())
+}
+
+/// Look up `name` and apply any `KEY=VALUE` overrides, returning the remaining arguments.
+fn configure(catalogue: &Catalogue, name: &str, args: &[String]) -> Result<(Entry, Vec<String>), String> {
+    let mut entry = catalogue.find(name).map_err(|e| e.to_string())?;
+    let mut rest = Vec::new();
+    for arg in args {
+        match arg.split_once('=') {
+            Some((key, value)) if !arg.starts_with('-') => {
+                let value = parse_value(value).ok_or_else(|| format!("`{}` is not a number", value))?;
+                entry.part.set_parameter(key, value).map_err(|e| e.to_string())?;
+            }
+            _ => rest.push(arg.clone()),
+        }
+    }
+    entry.part.validate().map_err(|e| e.to_string())?;
+    Ok((entry, rest))
+}
+
+fn parse_value(value: &str) -> Option<f64> {
+    match value {
+        "true" => Some(1.0),
+        "false" => Some(0.0),
+        _ => value.parse().ok(),
+    }
+}
+
+/// Remove `--fn N`, `--fa DEG` and `--fs MM` from `flags`, returning the resolution they set.
+///
+/// As in OpenSCAD, `--fa` and `--fs` only apply when no fixed segment count is given.
+fn take_resolution(flags: &mut Vec<String>) -> Result<Resolution, String> {
+    let mut segments = None;
+    let mut adaptive = None;
+    let mut rest = Vec::new();
+    let mut iter = flags.drain(..);
+    while let Some(flag) = iter.next() {
+        if !matches!(flag.as_str(), "--fn" | "--fa" | "--fs") {
+            rest.push(flag);
+            continue;
+        }
+        let value = iter.next().ok_or_else(|| format!("`{}` needs a value", flag))?;
+        let invalid = || format!("`{}` is not a valid value for `{}`", value, flag);
+        if flag == "--fn" {
+            // A whole number of segments, as a fraction would be silently rounded down.
+            segments = Some(value.parse::<usize>().map_err(|_| invalid())?);
+            continue;
+        }
+        let number: f64 = value.parse().ok().filter(|v: &f64| v.is_finite() && *v >= 0.0).ok_or_else(invalid)?;
+        match flag.as_str() {
+            _ if number == 0.0 => return Err(format!("`{}` must be greater than zero", flag)),
+            "--fa" => adaptive.get_or_insert(Resolution::PREVIEW).min_angle = number,
+            _ => adaptive.get_or_insert(Resolution::PREVIEW).min_size = number,
+        }
+    }
+    drop(iter);
+    *flags = rest;
+
+    Ok(match (segments, adaptive) {
+        (Some(n), _) if n > 0 => Resolution::fixed(n),
+        (_, Some(res)) => res,
+        _ => resolution::current(),
+    })
+}
+
+fn export_part(catalogue: &Catalogue, args: &[String]) -> Result<(), String> {
+    let [name, output, overrides @ ..] = args else {
+        return Err("expected a part name and an output file".to_string());
+    };
+    let (entry, mut flags) = configure(catalogue, name, overrides)?;
+    resolution::set_global(take_resolution(&mut flags)?);
+    let path = Path::new(output);
+    if let Some(format) = DrawingFormat::from_path(path) {
+        return export_drawing(entry, path, format, &flags);
+    }
+    let mut format = Format::from_path(path).ok_or_else(|| format!("unknown mesh format for {}", output))?;
+    for flag in &flags {
+        match flag.as_str() {
+            "--ascii" if format == Format::StlBinary => format = Format::StlAscii,
+            _ => return Err(format!("unexpected argument `{}`", flag)),
+        }
+    }
+    export::write(path, format, &[ExportObject::from_part(entry.part.as_ref())])
+        .map_err(|e| format!("writing {}: {}", output, e))
+}
+
+/// Write the outline of a part laid flat on its underside
+fn export_drawing(entry: Entry, path: &Path, format: DrawingFormat, flags: &[String]) -> Result<(), String> {
+    let mut layer = Layer::footprint(&entry.part.assemble());
+    match flags {
+        [] => {}
+        [flag, name] if flag == "--layer" => layer = layer.named(name),
+        _ => return Err(format!("unexpected arguments {:?}", flags)),
+    }
+    if layer.regions.is_empty() {
+        return Err(format!("{} has no flat underside to draw", entry.name));
+    }
+    drawing::write(path, format, &[layer]).map_err(|e| format!("writing {}: {}", path.display(), e))
+}
+
+fn info(catalogue: &Catalogue, args: &[String]) -> Result<(), String> {
+    let [name, rest @ ..] = args else {
+        return Err("missing part name".to_string());
+    };
+    let (entry, mut flags) = configure(catalogue, name, rest)?;
+    resolution::set_global(take_resolution(&mut flags)?);
+    let bom_format = match flags.as_slice() {
+        [] => "md",
+        [flag, format] if flag == "--bom" => format.as_str(),
+        _ => return Err(format!("unexpected arguments {:?}", flags)),
+    };
+    // Checked before anything is printed, so a bad format doesn't leave half a report.
+    let report: fn(&Bom) -> String = match bom_format {
+        "csv" => Bom::to_csv,
+        "json" => Bom::to_json,
+        "md" => Bom::to_markdown,
+        other => return Err(format!("unknown BOM format `{}`", other)),
+    };
+
+    let part = entry.part.as_ref();
+    println!("name:     {}", part.name());
+    println!("family:   {}", entry.family);
+    println!("category: {}", part.category());
+    let bb = part.bounding_box();
+    if bb.mins.x > bb.maxs.x {
+        println!("bounds:   empty");
+    } else {
+        let size = bb.maxs - bb.mins;
+        println!("bounds:   [{}, {}, {}] to [{}, {}, {}]", bb.mins.x, bb.mins.y, bb.mins.z, bb.maxs.x, bb.maxs.y, bb.maxs.z);
+        println!("size:     {} x {} x {}", size.x, size.y, size.z);
+    }
+    let mass = part.mass_properties();
+    let com = mass.centre_of_mass;
+    println!("volume:   {:.1} mm³", mass.volume);
+    println!("mass:     {:.1} g", mass.mass);
+    println!("centre:   [{:.3}, {:.3}, {:.3}]", com.x, com.y, com.z);
+    println!();
+
+    let mut bom = Bom::new();
+    bom.add_part(part);
+    print!("{}", report(&bom));
+    Ok(())
 }
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Mains Socket
 #[derive(Debug, Clone)]
@@ -12,20 +15,33 @@ pub struct MainsSocket {
 
 impl MainsSocket {
     /// Generate the socket body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the plug holes
-    pub fn plug_holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.depth + 1.0);
-        hole.translate(Vector3::new(-self.hole_spacing / 2.0, 0.0, 0.0))
-            .union(&hole.translate(Vector3::new(self.hole_spacing / 2.0, 0.0, 0.0)))
+    pub fn plug_holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.depth + 1.0);
+        hole.translated(Vector3::new(-self.hole_spacing / 2.0, 0.0, 0.0))
+            .union(&hole.translated(Vector3::new(self.hole_spacing / 2.0, 0.0, 0.0)))
     }
 
     /// Assemble the complete mains socket
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.plug_holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.plug_holes())
     }
 }
 
+impl Part for MainsSocket {
+    fn name(&self) -> &str {
+        "MainsSocket"
+    }
+
+    fn category(&self) -> Category {
+        Category::Connector
+    }
+
+    fn assemble(&self) -> CSG {
+        MainsSocket::assemble(self)
+    }
+}
@@ -0,0 +1,259 @@
+use crate::assembly::Transform;
+use crate::bom::Bom;
+use crate::material::Material;
+use crate::part::{Category, Part};
+use crate::CSG;
+use csgrs::float_types::parry3d::bounding_volume::Aabb;
+use nalgebra::{Matrix3, Point3, Vector3};
+use std::collections::HashMap;
+
+/// Density assumed for polygons with no material, that of moulded or printed plastic.
+pub const DEFAULT_DENSITY: f64 = 1.2;
+
+/// Mass, volume, centre of mass and inertia of a part or assembly.
+///
+/// Units follow the rest of the library: millimetres and grams, so densities are in g/cm³,
+/// volumes in mm³ and the inertia tensor in g·mm².
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MassProperties {
+    pub mass: f64,
+    pub volume: f64,
+    pub centre_of_mass: Point3<f64>,
+    /// Inertia tensor about the centre of mass, in the part's or assembly's axes.
+    pub inertia: Matrix3<f64>,
+}
+
+impl Default for MassProperties {
+    fn default() -> Self {
+        MassProperties {
+            mass: 0.0,
+            volume: 0.0,
+            centre_of_mass: Point3::origin(),
+            inertia: Matrix3::zeros(),
+        }
+    }
+}
+
+impl MassProperties {
+    /// Integrate a closed solid of uniform `density` in g/cm³
+    pub fn of_solid(solid: &CSG, density: f64) -> Self {
+        // Sum signed tetrahedra from the origin to every triangle of the surface,
+        // collecting the volume, its first moment and its second moment ∫ x xᵀ dV.
+        let canonical = Matrix3::new(2.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 2.0) / 120.0;
+        let mut volume = 0.0;
+        let mut first = Vector3::zeros();
+        let mut second = Matrix3::zeros();
+        for poly in &solid.polygons {
+            let Some(a) = poly.vertices.first().map(|v| v.pos.coords) else {
+                continue;
+            };
+            for pair in poly.vertices[1..].windows(2) {
+                let (b, c) = (pair[0].pos.coords, pair[1].pos.coords);
+                let m = Matrix3::from_columns(&[a, b, c]);
+                let det = m.determinant();
+                volume += det / 6.0;
+                first += det / 24.0 * (a + b + c);
+                second += det * m * canonical * m.transpose();
+            }
+        }
+        if volume.abs() < 1e-12 {
+            return MassProperties::default();
+        }
+
+        let centre = first / volume;
+        let central = second - volume * centre * centre.transpose();
+        let grams_per_mm3 = density / 1000.0;
+        MassProperties {
+            mass: volume * grams_per_mm3,
+            volume,
+            centre_of_mass: Point3::from(centre),
+            inertia: inertia_from_second_moment(&(central * grams_per_mm3)),
+        }
+    }
+
+    /// The same distribution of mass scaled to weigh `mass` grams, for parts with a catalogue weight
+    pub fn scaled_to(&self, mass: f64) -> Self {
+        if self.mass <= 0.0 {
+            return MassProperties { mass, ..*self };
+        }
+        let k = mass / self.mass;
+        MassProperties { mass, inertia: self.inertia * k, ..*self }
+    }
+
+    /// Density in g/cm³ that the mass and volume imply
+    pub fn density(&self) -> f64 {
+        if self.volume > 0.0 {
+            self.mass / self.volume * 1000.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Move into a parent's coordinates.
+    ///
+    /// Only the rotation and translation of the transform are used, so scaling does not change the mass.
+    pub fn transformed(&self, transform: &Transform) -> Self {
+        let linear = transform.matrix.fixed_view::<3, 3>(0, 0).into_owned();
+        let rotation = linear.svd(true, true);
+        let rotation = match (rotation.u, rotation.v_t) {
+            (Some(u), Some(v_t)) => u * v_t,
+            _ => Matrix3::identity(),
+        };
+        MassProperties {
+            centre_of_mass: transform.transform_point(&self.centre_of_mass),
+            inertia: rotation * self.inertia * rotation.transpose(),
+            ..*self
+        }
+    }
+
+    /// Combined properties of two bodies, using the parallel axis theorem
+    pub fn combine(&self, other: &MassProperties) -> Self {
+        let mass = self.mass + other.mass;
+        if mass <= 0.0 {
+            return MassProperties { volume: self.volume + other.volume, ..Default::default() };
+        }
+        let centre = Point3::from(
+            (self.centre_of_mass.coords * self.mass + other.centre_of_mass.coords * other.mass) / mass,
+        );
+        let shifted = |p: &MassProperties| {
+            let d = p.centre_of_mass - centre;
+            p.inertia + p.mass * (Matrix3::identity() * d.dot(&d) - d * d.transpose())
+        };
+        MassProperties {
+            mass,
+            volume: self.volume + other.volume,
+            centre_of_mass: centre,
+            inertia: shifted(self) + shifted(other),
+        }
+    }
+
+    /// Principal moments of inertia, smallest first
+    pub fn principal_moments(&self) -> Vector3<f64> {
+        let mut moments: Vec<f64> = self.inertia.symmetric_eigenvalues().iter().copied().collect();
+        moments.sort_by(f64::total_cmp);
+        Vector3::new(moments[0], moments[1], moments[2])
+    }
+}
+
+/// Convert a second moment `∫ x xᵀ dm` to an inertia tensor.
+fn inertia_from_second_moment(second: &Matrix3<f64>) -> Matrix3<f64> {
+    Matrix3::identity() * second.trace() - second
+}
+
+/// Density of the material covering most of the surface of `solid`, or [`DEFAULT_DENSITY`]
+///
+/// Only the outer surface survives a union, so materials buried inside `solid` don't count.
+pub fn surface_density(solid: &CSG) -> f64 {
+    let mut areas: HashMap<Option<Material>, f64> = HashMap::new();
+    for poly in &solid.polygons {
+        let Some(a) = poly.vertices.first().map(|v| v.pos) else {
+            continue;
+        };
+        let area: f64 = poly.vertices[1..]
+            .windows(2)
+            .map(|pair| (pair[0].pos - a).cross(&(pair[1].pos - a)).norm() / 2.0)
+            .sum();
+        *areas.entry(poly.metadata.and_then(|f| f.material)).or_insert(0.0) += area;
+    }
+    areas
+        .into_iter()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .and_then(|(material, _)| material)
+        .map_or(DEFAULT_DENSITY, |m| m.density())
+}
+
+/// A part with a density, catalogue mass or price that its geometry alone does not give.
+///
+/// ```ignore
+/// let motor = Specified::new(nema17).with_mass(280.0).with_unit_cost(12.5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Specified<P> {
+    pub part: P,
+    /// Density in g/cm³, overriding the one implied by the part's materials.
+    pub density: Option<f64>,
+    /// Catalogue mass in grams, overriding any density.
+    pub mass: Option<f64>,
+    pub unit_cost: Option<f64>,
+}
+
+impl<P: Part> Specified<P> {
+    pub fn new(part: P) -> Self {
+        Specified { part, density: None, mass: None, unit_cost: None }
+    }
+
+    pub fn with_density(mut self, density: f64) -> Self {
+        self.density = Some(density);
+        self
+    }
+
+    pub fn with_mass(mut self, mass: f64) -> Self {
+        self.mass = Some(mass);
+        self
+    }
+
+    pub fn with_unit_cost(mut self, cost: f64) -> Self {
+        self.unit_cost = Some(cost);
+        self
+    }
+}
+
+impl<P: Part> Part for Specified<P> {
+    fn name(&self) -> &str {
+        self.part.name()
+    }
+
+    fn category(&self) -> Category {
+        self.part.category()
+    }
+
+    fn assemble(&self) -> CSG {
+        self.part.assemble()
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        self.part.bounding_box()
+    }
+
+    /// The wrapped part's key, extended with the override, since the cache memoises mass too.
+    fn cache_key(&self) -> Option<String> {
+        self.part.cache_key().map(|key| format!("{} {:?} {:?}", key, self.density, self.mass))
+    }
+
+    fn description(&self) -> String {
+        self.part.description()
+    }
+
+    fn density(&self) -> Option<f64> {
+        self.density.or_else(|| self.part.density())
+    }
+
+    fn mass_properties(&self) -> MassProperties {
+        let properties = match self.density {
+            Some(density) => MassProperties::of_solid(&self.assemble(), density),
+            None => self.part.mass_properties(),
+        };
+        match self.mass {
+            Some(mass) => properties.scaled_to(mass),
+            None => properties,
+        }
+    }
+
+    fn catalogue_mass(&self) -> Option<f64> {
+        self.mass.or_else(|| self.part.catalogue_mass())
+    }
+
+    fn unit_cost(&self) -> Option<f64> {
+        self.unit_cost.or_else(|| self.part.unit_cost())
+    }
+
+    /// Lines recorded by the wrapped part, priced at this unit cost if it records a single line.
+    fn bom(&self, bom: &mut Bom) {
+        let mut own = Bom::new();
+        self.part.bom(&mut own);
+        match (self.unit_cost, own.items().as_slice()) {
+            (Some(cost), [item]) => bom.add_costed(item.description.clone(), item.category, item.quantity, cost),
+            _ => bom.merge(&own),
+        }
+    }
+}
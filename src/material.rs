@@ -0,0 +1,152 @@
+use crate::colour::{self, Colour};
+use crate::CSG;
+
+/// What a sub-solid is made of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Material {
+    /// Moulded or printed plastic, e.g. fan frames, connector housings.
+    Plastic,
+    Steel,
+    StainlessSteel,
+    Aluminium,
+    Brass,
+    Copper,
+    Rubber,
+    Glass,
+    /// FR4 circuit board.
+    Fr4,
+    /// Neodymium or ferrite magnet.
+    Magnet,
+    Nylon,
+    Wood,
+}
+
+impl Material {
+    /// Every material, in declaration order.
+    pub const ALL: [Material; 12] = [
+        Material::Plastic,
+        Material::Steel,
+        Material::StainlessSteel,
+        Material::Aluminium,
+        Material::Brass,
+        Material::Copper,
+        Material::Rubber,
+        Material::Glass,
+        Material::Fr4,
+        Material::Magnet,
+        Material::Nylon,
+        Material::Wood,
+    ];
+
+    /// The colour NopSCADLib usually draws this material in
+    pub fn colour(&self) -> Colour {
+        match self {
+            Material::Plastic => Colour::grey(20.0),
+            Material::Steel => Colour::grey(60.0),
+            Material::StainlessSteel => colour::SILVER,
+            Material::Aluminium => Colour::grey(80.0),
+            Material::Brass => colour::BRASS,
+            Material::Copper => Colour::rgb(0.72, 0.45, 0.20),
+            Material::Rubber => Colour::grey(10.0),
+            Material::Glass => Colour::rgba(0.9, 0.95, 1.0, 0.3),
+            Material::Fr4 => colour::GREEN,
+            Material::Magnet => Colour::grey(70.0),
+            Material::Nylon => Colour::grey(95.0),
+            Material::Wood => Colour::rgb(0.65, 0.5, 0.3),
+        }
+    }
+
+    /// Typical density in g/cm³
+    pub fn density(&self) -> f64 {
+        match self {
+            Material::Plastic => 1.2,
+            Material::Steel => 7.85,
+            Material::StainlessSteel => 8.0,
+            Material::Aluminium => 2.7,
+            Material::Brass => 8.5,
+            Material::Copper => 8.96,
+            Material::Rubber => 1.2,
+            Material::Glass => 2.5,
+            Material::Fr4 => 1.85,
+            Material::Magnet => 7.5,
+            Material::Nylon => 1.14,
+            Material::Wood => 0.6,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Material::Plastic => "plastic",
+            Material::Steel => "steel",
+            Material::StainlessSteel => "stainless steel",
+            Material::Aluminium => "aluminium",
+            Material::Brass => "brass",
+            Material::Copper => "copper",
+            Material::Rubber => "rubber",
+            Material::Glass => "glass",
+            Material::Fr4 => "FR4",
+            Material::Magnet => "magnet",
+            Material::Nylon => "nylon",
+            Material::Wood => "wood",
+        }
+    }
+}
+
+/// Colour and material carried by every polygon of a tagged solid.
+///
+/// This is the metadata type of [`CSG`], so tags survive booleans: polygons split by a
+/// union or difference keep the finish of the solid they came from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Finish {
+    pub colour: Colour,
+    pub material: Option<Material>,
+}
+
+impl Finish {
+    pub const fn new(colour: Colour, material: Material) -> Self {
+        Finish { colour, material: Some(material) }
+    }
+
+    /// Colour only, e.g. from `Child::colour` in an assembly
+    pub const fn colour(colour: Colour) -> Self {
+        Finish { colour, material: None }
+    }
+}
+
+/// Tinned component leads and pins.
+pub const TINNED_COPPER: Finish = Finish::new(colour::SILVER, Material::Copper);
+
+impl From<Material> for Finish {
+    /// The material in its usual colour
+    fn from(material: Material) -> Self {
+        Finish::new(material.colour(), material)
+    }
+}
+
+/// Tagging of solids with a [`Finish`].
+pub trait Finishing {
+    /// Tag every polygon, replacing any existing finish.
+    fn finished(self, finish: impl Into<Finish>) -> Self;
+
+    /// Tag only polygons that have no finish yet, so finishes applied deeper in a part win,
+    /// as the innermost `color()` does in OpenSCAD.
+    fn finished_default(self, finish: impl Into<Finish>) -> Self;
+}
+
+impl Finishing for CSG {
+    fn finished(mut self, finish: impl Into<Finish>) -> Self {
+        let finish = finish.into();
+        for poly in &mut self.polygons {
+            poly.metadata = Some(finish);
+        }
+        self
+    }
+
+    fn finished_default(mut self, finish: impl Into<Finish>) -> Self {
+        let finish = finish.into();
+        for poly in self.polygons.iter_mut().filter(|p| p.metadata.is_none()) {
+            poly.metadata = Some(finish);
+        }
+        self
+    }
+}
@@ -1,5 +1,8 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a microphone
 #[derive(Debug, Clone)]
@@ -15,37 +18,23 @@ pub struct Microphone {
 
 impl Microphone {
     /// Generate the cylindrical body of the microphone
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_height)
     }
     
     /// Generate the grille of the microphone
-    pub fn grille(&self) -> CSG<()> {
-        CSG::cylinder_z(self.grille_diameter / 2.0, self.grille_height)
-            .translate(Vector3::new(0.0, 0.0, self.body_height))
+    pub fn grille(&self) -> CSG {
+        CSG::z_cylinder(self.grille_diameter / 2.0, self.grille_height)
+            .translated(Vector3::new(0.0, 0.0, self.body_height))
     }
     
     /// Generate the mounting holes of the microphone
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-            (-self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.body_height)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.body_height)
     }
     
     /// Generate the full microphone model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut microphone = self.body();
         let grille = self.grille();
         let holes = self.mounting_holes();
@@ -57,3 +46,26 @@ impl Microphone {
     }
 }
 
+impl Part for Microphone {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Microphone::assemble(self)
+    }
+}
+
+impl Mountable for Microphone {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::square(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
@@ -1,4 +1,9 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a Microswitch module
 #[derive(Debug, Clone)]
@@ -16,35 +21,25 @@ pub struct Microswitch {
 
 impl Microswitch {
     /// Generate the rectangular body of the microswitch
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
+            .finished(Material::Plastic)
     }
     
     /// Generate the lever of the microswitch
-    pub fn lever(&self) -> CSG<()> {
+    pub fn lever(&self) -> CSG {
         CSG::prism(self.lever_length, self.lever_width, self.lever_thickness)
-            .translate(Vector3::new(0.0, self.body_height / 2.0, self.body_depth / 2.0))
+            .translated(Vector3::new(0.0, self.body_height / 2.0, self.body_depth / 2.0))
+            .finished(Material::StainlessSteel)
     }
     
     /// Generate the mounting holes
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, 0.0),
-            (self.mounting_hole_spacing / 2.0, 0.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.body_depth)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.body_depth)
     }
     
     /// Generate the full microswitch model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut microswitch = self.body();
         let lever = self.lever();
         let holes = self.mounting_holes();
@@ -52,7 +47,30 @@ impl Microswitch {
         microswitch = microswitch.union(&lever);
         microswitch = microswitch.subtract(&holes);
         
-        microswitch
+        microswitch.finished_default(Material::Plastic)
     }
 }
 
+impl Part for Microswitch {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Switch
+    }
+
+    fn assemble(&self) -> CSG {
+        Microswitch::assemble(self)
+    }
+}
+
+impl Mountable for Microswitch {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::pair(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a MicroView OLED Display
 #[derive(Debug, Clone)]
@@ -12,19 +15,32 @@ pub struct MicroView {
 
 impl MicroView {
     /// Generate the main housing
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the screen cutout
-    pub fn screen(&self) -> CSG<()> {
+    pub fn screen(&self) -> CSG {
         CSG::prism(self.screen_width, self.screen_height, self.depth + 1.0)
-            .translate(Vector3::new(0.0, 0.0, self.depth / 2.0))
+            .translated(Vector3::new(0.0, 0.0, self.depth / 2.0))
     }
 
     /// Assemble the full MicroView display
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.screen())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.screen())
     }
 }
 
+impl Part for MicroView {
+    fn name(&self) -> &str {
+        "MicroView"
+    }
+
+    fn category(&self) -> Category {
+        Category::Display
+    }
+
+    fn assemble(&self) -> CSG {
+        MicroView::assemble(self)
+    }
+}
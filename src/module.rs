@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an Electronic Module Enclosure
 #[derive(Debug, Clone)]
@@ -13,27 +16,40 @@ pub struct ModuleEnclosure {
 
 impl ModuleEnclosure {
     /// Generate the enclosure body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the vent holes
-    pub fn vent_holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.vent_hole_diameter / 2.0, self.depth + 1.0);
+    pub fn vent_holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.vent_hole_diameter / 2.0, self.depth + 1.0);
         let mut holes = CSG::new();
         let start_x = -((self.vent_hole_count as f64 - 1.0) / 2.0) * self.vent_hole_spacing;
 
         for i in 0..self.vent_hole_count {
             let x_offset = start_x + (i as f64) * self.vent_hole_spacing;
-            holes = holes.union(&hole.translate(Vector3::new(x_offset, 0.0, self.height / 2.0)));
+            holes = holes.union(&hole.translated(Vector3::new(x_offset, 0.0, self.height / 2.0)));
         }
 
         holes
     }
 
     /// Assemble the complete module enclosure
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.vent_holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.vent_holes())
     }
 }
 
+impl Part for ModuleEnclosure {
+    fn name(&self) -> &str {
+        "ModuleEnclosure"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        ModuleEnclosure::assemble(self)
+    }
+}
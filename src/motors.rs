@@ -1,5 +1,9 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of an electric motor
 #[derive(Debug, Clone)]
@@ -15,37 +19,25 @@ pub struct Motor {
 
 impl Motor {
     /// Generate the cylindrical body of the motor
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_length)
+            .finished(Material::Steel)
     }
     
     /// Generate the shaft of the motor
-    pub fn shaft(&self) -> CSG<()> {
-        CSG::cylinder_z(self.shaft_diameter / 2.0, self.shaft_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_length))
+    pub fn shaft(&self) -> CSG {
+        CSG::z_cylinder(self.shaft_diameter / 2.0, self.shaft_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_length))
+            .finished(Material::StainlessSteel)
     }
     
     /// Generate the mounting holes of the motor
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-            (-self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.body_length)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.body_length)
     }
     
     /// Generate the full motor model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut motor = self.body();
         let shaft = self.shaft();
         let holes = self.mounting_holes();
@@ -53,6 +45,30 @@ impl Motor {
         motor = motor.union(&shaft);
         motor = motor.subtract(&holes);
         
-        motor
+        motor.finished_default(Material::Steel)
+    }
+}
+
+impl Part for Motor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motor
+    }
+
+    fn assemble(&self) -> CSG {
+        Motor::assemble(self)
+    }
+}
+
+impl Mountable for Motor {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::square(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
     }
 }
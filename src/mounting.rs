@@ -0,0 +1,74 @@
+use crate::part::Part;
+use crate::pattern;
+use crate::primitives::Primitives;
+use crate::CSG;
+use nalgebra::{Isometry3, Translation3, UnitQuaternion, Vector3};
+
+/// Length of the holes and openings in a panel cutout, centred on the mounting face so it
+/// goes right through any sensible panel.
+pub const CUTOUT_DEPTH: f64 = 100.0;
+
+/// A part that is fixed to a panel or printed part with screws, or pushed through a hole in one,
+/// like NopSCADLib's `*_hole_positions()` and `*_holes()` / `*_cutout()` modules.
+///
+/// Hole positions are frames in the part's own coordinates: the origin is the centre of the
+/// hole on the mounting face and +Z points out of the part, through the panel. A screw placed
+/// at `position * translation(0, 0, panel_thickness)` with its shank down -Z drives into the part.
+pub trait Mountable: Part {
+    /// Where each fixing screw goes
+    fn hole_positions(&self) -> Vec<Isometry3<f64>>;
+
+    /// Diameter of the fixing holes in the part
+    fn hole_diameter(&self) -> f64;
+
+    /// Solid to subtract from a panel placed against the mounting face, with every hole and
+    /// opening grown by `clearance` on each side.
+    ///
+    /// Placed with the same transform as the part itself.
+    fn panel_cutout(&self, clearance: f64) -> CSG {
+        through_holes(&self.hole_positions(), self.hole_diameter() + 2.0 * clearance)
+    }
+}
+
+/// Four holes on the corners of a square of side `spacing` centred on the origin
+pub fn square(spacing: f64) -> Vec<(f64, f64)> {
+    let s = spacing / 2.0;
+    vec![(-s, -s), (s, -s), (s, s), (-s, s)]
+}
+
+/// Two holes `spacing` apart along X, centred on the origin
+pub fn pair(spacing: f64) -> Vec<(f64, f64)> {
+    vec![(-spacing / 2.0, 0.0), (spacing / 2.0, 0.0)]
+}
+
+/// Hole frames on a face at height `z` that faces down -Z, e.g. the base of a part
+pub fn on_bottom(points: &[(f64, f64)], z: f64) -> Vec<Isometry3<f64>> {
+    let flip = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), std::f64::consts::PI);
+    points
+        .iter()
+        .map(|&(x, y)| Isometry3::from_parts(Translation3::new(x, y, z), flip))
+        .collect()
+}
+
+/// Hole frames on a face at height `z` that faces up +Z, e.g. the front of a motor
+pub fn on_top(points: &[(f64, f64)], z: f64) -> Vec<Isometry3<f64>> {
+    points
+        .iter()
+        .map(|&(x, y)| Isometry3::translation(x, y, z))
+        .collect()
+}
+
+/// Holes of `diameter` running `depth` into the part from each position
+pub fn holes(positions: &[Isometry3<f64>], diameter: f64, depth: f64) -> CSG {
+    place(&CSG::z_cylinder(diameter / 2.0, depth), positions, -depth)
+}
+
+/// Holes of `diameter` and [`CUTOUT_DEPTH`] long, centred on each position
+pub fn through_holes(positions: &[Isometry3<f64>], diameter: f64) -> CSG {
+    place(&CSG::z_cylinder(diameter / 2.0, CUTOUT_DEPTH), positions, -CUTOUT_DEPTH / 2.0)
+}
+
+/// Union copies of `solid`, raised by `z`, at each position
+fn place(solid: &CSG, positions: &[Isometry3<f64>], z: f64) -> CSG {
+    pattern::place(&solid.translated(Vector3::new(0.0, 0.0, z)), positions)
+}
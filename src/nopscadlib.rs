@@ -0,0 +1,30 @@
+//! Reading NopSCADLib's `*.scad` data files, the type vectors like
+//! `E2020 = ["E2020", 20, 20, -4.2, ...];` that describe each size of a part.
+//!
+//! The built-in extrusion profiles are generated from `data/nopscadlib/extrusions.scad` by the
+//! build script with this same parser, and [`Catalogue::load_file`] reads `.scad` files at run
+//! time, so whole families can be populated from upstream data:
+//!
+//! ```ignore
+//! let mut catalogue = Catalogue::builtin();
+//! catalogue.load_file(Path::new("NopSCADlib/vitamins/screws.scad"))?;
+//! catalogue.load_file(Path::new("NopSCADlib/vitamins/fans.scad"))?;
+//! ```
+//!
+//! [`Catalogue::load_file`]: crate::catalogue::Catalogue::load_file
+
+mod layout;
+mod scad;
+
+pub use layout::{Field, Layout, Record, EXTRUSIONS, FANS, LAYOUTS, STEPPER_MOTORS};
+pub use scad::{parse, ScadError, ScadValue, Scope};
+
+/// The layout of the file that populates catalogue `family`
+pub fn layout(family: &str) -> Option<&'static Layout> {
+    LAYOUTS.iter().find(|layout| layout.family == family)
+}
+
+/// The layout of NopSCADLib file `stem`, e.g. `"fans"` for `fans.scad`
+pub fn layout_for_file(stem: &str) -> Option<&'static Layout> {
+    LAYOUTS.iter().find(|layout| layout.file.eq_ignore_ascii_case(stem))
+}
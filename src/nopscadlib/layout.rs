@@ -0,0 +1,179 @@
+//! Where each catalogue parameter is found in NopSCADLib's type vectors.
+//!
+//! This file is shared with the build script, so it only uses `std`.
+
+use super::scad::ScadValue;
+
+/// One catalogue parameter taken from a type vector.
+#[derive(Debug, Clone, Copy)]
+pub struct Field {
+    /// Indices into the vector and any vectors nested in it, e.g. `[11, 0]` for the width in a
+    /// `[width, depth]` recess.
+    pub path: &'static [usize],
+    pub parameter: &'static str,
+    /// Conversion from the SCAD value, e.g. from a radius to a diameter.
+    pub convert: fn(f64) -> f64,
+}
+
+/// How the type vectors of one NopSCADLib file map to the parameters of a catalogue family.
+#[derive(Debug, Clone, Copy)]
+pub struct Layout {
+    /// Catalogue family, as in `Entry::family`.
+    pub family: &'static str,
+    /// Stem of the NopSCADLib file, e.g. `"fans"` for `fans.scad`.
+    pub file: &'static str,
+    /// Index of the name string, or `None` where the types are only named by their variable.
+    pub name: Option<usize>,
+    /// Shortest vector that is a type rather than e.g. a list of them.
+    pub length: usize,
+    pub fields: &'static [Field],
+    /// Parameters the SCAD types don't have or may leave out, set before the fields.
+    pub defaults: &'static [(&'static str, f64)],
+}
+
+/// A type vector read with a [`Layout`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub name: String,
+    /// Parameter values, defaults first, with later ones overriding earlier.
+    pub parameters: Vec<(&'static str, f64)>,
+}
+
+impl Layout {
+    /// Read the assignment `variable = value` if it is a type vector of this layout.
+    ///
+    /// Fields that are missing, `undef` or `false` are left out, so e.g. a `false` recess
+    /// keeps its default of none. A vector where a number is expected counts as its first
+    /// element, like the `[length, flat]` of a shaft.
+    pub fn record(&self, variable: &str, value: &ScadValue) -> Option<Record> {
+        match value {
+            ScadValue::Vector(items) if items.len() >= self.length => {}
+            _ => return None,
+        }
+        let name = match self.name {
+            Some(index) => value.get(index).as_text()?.to_string(),
+            None => {
+                value.get(0).as_number()?;
+                variable.to_string()
+            }
+        };
+        let mut parameters: Vec<(&'static str, f64)> = self.defaults.to_vec();
+        for field in self.fields {
+            let found = field.path.iter().fold(value, |v, &i| v.get(i));
+            let number = match found {
+                ScadValue::Number(n) => *n,
+                ScadValue::Bool(true) => 1.0,
+                ScadValue::Vector(items) => match items.first() {
+                    Some(ScadValue::Number(n)) => *n,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            parameters.push((field.parameter, (field.convert)(number)));
+        }
+        Some(Record { name, parameters })
+    }
+}
+
+fn same(x: f64) -> f64 {
+    x
+}
+
+fn double(x: f64) -> f64 {
+    2.0 * x
+}
+
+/// ISO 273 medium clearance hole for a screw of nominal diameter `d`, or 10% over for
+/// sizes not in the table
+fn clearance(d: f64) -> f64 {
+    const HOLES: [(f64, f64); 9] = [
+        (2.0, 2.4),
+        (2.5, 2.9),
+        (3.0, 3.4),
+        (4.0, 4.5),
+        (5.0, 5.5),
+        (6.0, 6.6),
+        (8.0, 9.0),
+        (10.0, 11.0),
+        (12.0, 13.5),
+    ];
+    HOLES.iter().find(|(size, _)| (size - d).abs() < 1e-9).map_or(d * 1.1, |&(_, hole)| hole)
+}
+
+const fn field(path: &'static [usize], parameter: &'static str) -> Field {
+    Field { path, parameter, convert: same }
+}
+
+/// `extrusions.scad`: `[name, width, height, center_hole_wd, corner_hole_wd,
+/// center_square_wd, channel_width, channel_width_internal, tab_thickness, spar_thickness,
+/// fillet_radius, recess]`, the recess being `false` or `[width, depth]`.
+pub const EXTRUSIONS: Layout = Layout {
+    family: "extrusion",
+    file: "extrusions",
+    name: Some(0),
+    length: 12,
+    fields: &[
+        field(&[1], "width"),
+        field(&[2], "height"),
+        field(&[3], "center_hole_wd"),
+        field(&[4], "corner_hole_wd"),
+        field(&[5], "center_square_wd"),
+        field(&[6], "channel_width"),
+        field(&[7], "channel_width_internal"),
+        field(&[8], "tab_thickness"),
+        field(&[9], "spar_thickness"),
+        field(&[10], "fillet_radius"),
+        field(&[11, 0], "recess_width"),
+        field(&[11, 1], "recess_depth"),
+    ],
+    // The length is `catalogue::DEFAULT_EXTRUSION_LENGTH`.
+    defaults: &[
+        ("length", 100.0),
+        ("center", 0.0),
+        ("corner_holes", 0.0),
+        ("recess_width", 0.0),
+        ("recess_depth", 0.0),
+    ],
+};
+
+/// `fans.scad`: `[width, depth, bore, hole_pitch, screw, hub, ...]`, named by variable, with
+/// `hole_pitch` half the hole spacing and the holes a clearance for the screw's diameter.
+pub const FANS: Layout = Layout {
+    family: "fans",
+    file: "fans",
+    name: None,
+    length: 6,
+    fields: &[
+        field(&[0], "frame_width"),
+        field(&[0], "frame_height"),
+        field(&[1], "frame_depth"),
+        field(&[2], "blade_diameter"),
+        Field { path: &[3], parameter: "mounting_hole_spacing", convert: double },
+        Field { path: &[4, 3], parameter: "mounting_hole_diameter", convert: clearance },
+        field(&[5], "hub_diameter"),
+    ],
+    defaults: &[("blade_count", 7.0)],
+};
+
+/// `stepper_motors.scad`: `[name, width, length, radius, body_radius, boss_radius,
+/// boss_height, shaft_dia, shaft_length, hole_pitch, cap_heights, thread_d, ...]`.
+pub const STEPPER_MOTORS: Layout = Layout {
+    family: "stepper_motors",
+    file: "stepper_motors",
+    name: Some(0),
+    length: 12,
+    fields: &[
+        field(&[1], "side_length"),
+        field(&[2], "body_length"),
+        Field { path: &[5], parameter: "boss_diameter", convert: double },
+        field(&[6], "boss_height"),
+        field(&[7], "shaft_diameter"),
+        field(&[8], "shaft_length"),
+        field(&[9], "mount_hole_spacing"),
+        field(&[11], "mount_hole_diameter"),
+    ],
+    defaults: &[],
+};
+
+/// Every file that can populate a catalogue family.
+pub const LAYOUTS: &[Layout] = &[EXTRUSIONS, FANS, STEPPER_MOTORS];
@@ -0,0 +1,475 @@
+//! Evaluator for the top level assignments of OpenSCAD data files.
+//!
+//! Only what NopSCADLib's `*.scad` tables use is understood: numbers, strings, booleans,
+//! `undef`, vectors, references to earlier assignments, indexing, `+ - * / %` and a few
+//! built in functions. `include` and `use` lines, function and module definitions and module
+//! calls are skipped. As in OpenSCAD, unknown names and functions evaluate to `undef`.
+//!
+//! This file is shared with the build script, so it only uses `std`.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+/// A value in a SCAD file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScadValue {
+    Number(f64),
+    Bool(bool),
+    Text(String),
+    Vector(Vec<ScadValue>),
+    Undef,
+}
+
+impl ScadValue {
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            ScadValue::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            ScadValue::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Element `index` of a vector, `undef` when out of range or not a vector
+    pub fn get(&self, index: usize) -> &ScadValue {
+        match self {
+            ScadValue::Vector(items) => items.get(index).unwrap_or(&ScadValue::Undef),
+            _ => &ScadValue::Undef,
+        }
+    }
+}
+
+/// A SCAD file that could not be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScadError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ScadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ScadError {}
+
+/// The variables assigned so far, so one file can refer to those of another it would
+/// `include`, e.g. fans to the screws they are held by.
+#[derive(Debug, Clone, Default)]
+pub struct Scope {
+    values: HashMap<String, ScadValue>,
+}
+
+impl Scope {
+    pub fn new() -> Self {
+        Scope::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ScadValue> {
+        self.values.get(name)
+    }
+
+    /// Evaluate the top level assignments in `text`, returning them in file order
+    pub fn run(&mut self, text: &str) -> Result<Vec<(String, ScadValue)>, ScadError> {
+        let mut parser = Parser { chars: text.chars().collect(), pos: 0, line: 1, scope: self };
+        parser.file()
+    }
+}
+
+/// The assignments in a standalone SCAD file, in file order.
+pub fn parse(text: &str) -> Result<Vec<(String, ScadValue)>, ScadError> {
+    Scope::new().run(text)
+}
+
+struct Parser<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    scope: &'a mut Scope,
+}
+
+impl Parser<'_> {
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, ScadError> {
+        Err(ScadError { line: self.line, message: message.into() })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    /// Skip whitespace and comments
+    fn skip_space(&mut self) -> Result<(), ScadError> {
+        loop {
+            match (self.peek(), self.peek_at(1)) {
+                (Some(c), _) if c.is_whitespace() => {
+                    self.bump();
+                }
+                (Some('/'), Some('/')) => {
+                    while self.peek().is_some_and(|c| c != '\n') {
+                        self.bump();
+                    }
+                }
+                (Some('/'), Some('*')) => {
+                    let line = self.line;
+                    self.pos += 2;
+                    while !(self.peek() == Some('*') && self.peek_at(1) == Some('/')) {
+                        if self.bump().is_none() {
+                            return Err(ScadError { line, message: "unterminated comment".to_string() });
+                        }
+                    }
+                    self.pos += 2;
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// Consume `c` after any space, if it is next
+    fn eat(&mut self, c: char) -> Result<bool, ScadError> {
+        self.skip_space()?;
+        if self.peek() == Some(c) {
+            self.bump();
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ScadError> {
+        if self.eat(c)? {
+            Ok(())
+        } else {
+            match self.peek() {
+                Some(found) => self.error(format!("expected `{}`, found `{}`", c, found)),
+                None => self.error(format!("expected `{}` at the end of the file", c)),
+            }
+        }
+    }
+
+    fn identifier(&mut self) -> Option<String> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '$') {
+            self.pos += 1;
+        }
+        let word: String = self.chars[start..self.pos].iter().collect();
+        if word.is_empty() || word.starts_with(|c: char| c.is_ascii_digit()) {
+            self.pos = start;
+            None
+        } else {
+            Some(word)
+        }
+    }
+
+    /// `=` but not `==`
+    fn at_assignment(&mut self) -> Result<bool, ScadError> {
+        self.skip_space()?;
+        Ok(self.peek() == Some('=') && self.peek_at(1) != Some('='))
+    }
+
+    fn file(&mut self) -> Result<Vec<(String, ScadValue)>, ScadError> {
+        let mut assignments = Vec::new();
+        loop {
+            self.skip_space()?;
+            if self.peek().is_none() {
+                return Ok(assignments);
+            }
+            let start = self.pos;
+            match self.identifier().as_deref() {
+                Some("include" | "use") => {
+                    self.expect('<')?;
+                    while self.bump().is_some_and(|c| c != '>') {}
+                    self.eat(';')?;
+                }
+                Some("function" | "module") => self.skip_statement()?,
+                Some(name) if self.at_assignment()? => {
+                    let name = name.to_string();
+                    self.bump();
+                    let value = self.expression()?;
+                    self.expect(';')?;
+                    self.scope.values.insert(name.clone(), value.clone());
+                    assignments.push((name, value));
+                }
+                _ => {
+                    self.pos = start;
+                    self.skip_statement()?;
+                }
+            }
+        }
+    }
+
+    /// Skip to the end of a statement: a `;` or a `{ }` block outside any brackets
+    fn skip_statement(&mut self) -> Result<(), ScadError> {
+        let line = self.line;
+        let mut depth = 0usize;
+        loop {
+            self.skip_space()?;
+            match self.bump() {
+                None => return Err(ScadError { line, message: "unterminated statement".to_string() }),
+                Some('"') => {
+                    self.pos -= 1;
+                    self.string()?;
+                }
+                Some('(' | '[' | '{') => depth += 1,
+                Some(c @ (')' | ']' | '}')) => {
+                    depth = match depth.checked_sub(1) {
+                        Some(depth) => depth,
+                        None => return self.error(format!("unbalanced `{}`", c)),
+                    };
+                    if c == '}' && depth == 0 {
+                        return Ok(());
+                    }
+                }
+                Some(';') if depth == 0 => return Ok(()),
+                Some(_) => {}
+            }
+        }
+    }
+
+    fn expression(&mut self) -> Result<ScadValue, ScadError> {
+        let mut value = self.term()?;
+        loop {
+            let op = if self.eat('+')? {
+                '+'
+            } else if self.eat('-')? {
+                '-'
+            } else {
+                return Ok(value);
+            };
+            value = arithmetic(op, value, self.term()?);
+        }
+    }
+
+    fn term(&mut self) -> Result<ScadValue, ScadError> {
+        let mut value = self.unary()?;
+        loop {
+            let op = if self.eat('*')? {
+                '*'
+            } else if self.eat('/')? {
+                '/'
+            } else if self.eat('%')? {
+                '%'
+            } else {
+                return Ok(value);
+            };
+            value = arithmetic(op, value, self.unary()?);
+        }
+    }
+
+    fn unary(&mut self) -> Result<ScadValue, ScadError> {
+        if self.eat('-')? {
+            Ok(arithmetic('*', ScadValue::Number(-1.0), self.unary()?))
+        } else if self.eat('+')? {
+            self.unary()
+        } else {
+            self.postfix()
+        }
+    }
+
+    fn postfix(&mut self) -> Result<ScadValue, ScadError> {
+        let mut value = self.primary()?;
+        loop {
+            if self.eat('[')? {
+                let index = self.expression()?;
+                self.expect(']')?;
+                value = match (&value, index) {
+                    (ScadValue::Vector(_), ScadValue::Number(i)) if i >= 0.0 => value.get(i as usize).clone(),
+                    (ScadValue::Text(text), ScadValue::Number(i)) if i >= 0.0 => {
+                        text.chars().nth(i as usize).map_or(ScadValue::Undef, |c| ScadValue::Text(c.to_string()))
+                    }
+                    _ => ScadValue::Undef,
+                };
+            } else if self.eat('.')? {
+                let axis = match self.identifier().as_deref() {
+                    Some("x") => 0,
+                    Some("y") => 1,
+                    Some("z") => 2,
+                    _ => return self.error("expected `x`, `y` or `z` after `.`"),
+                };
+                value = value.get(axis).clone();
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn primary(&mut self) -> Result<ScadValue, ScadError> {
+        self.skip_space()?;
+        match self.peek() {
+            Some('"') => self.string(),
+            Some('[') => {
+                self.bump();
+                let items = self.list(']')?;
+                Ok(ScadValue::Vector(items))
+            }
+            Some('(') => {
+                self.bump();
+                let value = self.expression()?;
+                self.expect(')')?;
+                Ok(value)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => self.number(),
+            Some(c) => match self.identifier() {
+                Some(name) => {
+                    if self.eat('(')? {
+                        let arguments = self.arguments()?;
+                        return Ok(call(&name, &arguments));
+                    }
+                    Ok(match name.as_str() {
+                        "true" => ScadValue::Bool(true),
+                        "false" => ScadValue::Bool(false),
+                        "undef" => ScadValue::Undef,
+                        "PI" => ScadValue::Number(std::f64::consts::PI),
+                        _ => self.scope.values.get(&name).cloned().unwrap_or(ScadValue::Undef),
+                    })
+                }
+                None => self.error(format!("unexpected `{}`", c)),
+            },
+            None => self.error("expected a value at the end of the file"),
+        }
+    }
+
+    /// Comma separated expressions up to `close`, allowing a trailing comma
+    fn list(&mut self, close: char) -> Result<Vec<ScadValue>, ScadError> {
+        let mut items = Vec::new();
+        loop {
+            if self.eat(close)? {
+                return Ok(items);
+            }
+            items.push(self.expression()?);
+            if self.eat(':')? {
+                return self.error("ranges are not supported");
+            }
+            if !self.eat(',')? {
+                self.expect(close)?;
+                return Ok(items);
+            }
+        }
+    }
+
+    /// Function call arguments, dropping the names of any named ones
+    fn arguments(&mut self) -> Result<Vec<ScadValue>, ScadError> {
+        let mut arguments = Vec::new();
+        loop {
+            if self.eat(')')? {
+                return Ok(arguments);
+            }
+            self.skip_space()?;
+            let start = self.pos;
+            if self.identifier().is_some() && self.at_assignment()? {
+                self.bump();
+            } else {
+                self.pos = start;
+            }
+            arguments.push(self.expression()?);
+            if !self.eat(',')? {
+                self.expect(')')?;
+                return Ok(arguments);
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<ScadValue, ScadError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+            self.pos += 1;
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            let mantissa = self.pos;
+            self.pos += 1;
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.pos += 1;
+            }
+            if !self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                self.pos = mantissa;
+            }
+            while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+                self.pos += 1;
+            }
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        match text.parse() {
+            Ok(n) => Ok(ScadValue::Number(n)),
+            Err(_) => self.error(format!("bad number `{}`", text)),
+        }
+    }
+
+    fn string(&mut self) -> Result<ScadValue, ScadError> {
+        let line = self.line;
+        self.bump();
+        let mut text = String::new();
+        loop {
+            match self.bump() {
+                None => return Err(ScadError { line, message: "unterminated string".to_string() }),
+                Some('"') => return Ok(ScadValue::Text(text)),
+                Some('\\') => match self.bump() {
+                    Some('n') => text.push('\n'),
+                    Some('t') => text.push('\t'),
+                    Some('r') => text.push('\r'),
+                    Some(c) => text.push(c),
+                    None => return Err(ScadError { line, message: "unterminated string".to_string() }),
+                },
+                Some(c) => text.push(c),
+            }
+        }
+    }
+}
+
+/// `a op b` for numbers, vectors element by element and vectors scaled by numbers.
+/// Anything else is `undef`.
+fn arithmetic(op: char, a: ScadValue, b: ScadValue) -> ScadValue {
+    use ScadValue::{Number, Undef, Vector};
+    match (a, b) {
+        (Number(a), Number(b)) => Number(match op {
+            '+' => a + b,
+            '-' => a - b,
+            '*' => a * b,
+            '/' => a / b,
+            _ => a % b,
+        }),
+        (Vector(a), Vector(b)) if matches!(op, '+' | '-') && a.len() == b.len() => {
+            Vector(a.into_iter().zip(b).map(|(a, b)| arithmetic(op, a, b)).collect())
+        }
+        (Vector(a), Number(b)) if matches!(op, '*' | '/') => {
+            Vector(a.into_iter().map(|a| arithmetic(op, a, Number(b))).collect())
+        }
+        (Number(a), Vector(b)) if op == '*' => Vector(b.into_iter().map(|b| arithmetic(op, Number(a), b)).collect()),
+        _ => Undef,
+    }
+}
+
+/// The built in functions data files use, with angles in degrees as in OpenSCAD
+fn call(name: &str, arguments: &[ScadValue]) -> ScadValue {
+    let numbers: Option<Vec<f64>> = arguments.iter().map(ScadValue::as_number).collect();
+    let result = match (name, numbers.as_deref()) {
+        ("inch", Some(&[x])) => x * 25.4,
+        ("sqrt", Some(&[x])) => x.sqrt(),
+        ("abs", Some(&[x])) => x.abs(),
+        ("sin", Some(&[x])) => x.to_radians().sin(),
+        ("cos", Some(&[x])) => x.to_radians().cos(),
+        ("tan", Some(&[x])) => x.to_radians().tan(),
+        ("max", Some(xs)) if !xs.is_empty() => xs.iter().copied().fold(f64::MIN, f64::max),
+        ("min", Some(xs)) if !xs.is_empty() => xs.iter().copied().fold(f64::MAX, f64::min),
+        _ => return ScadValue::Undef,
+    };
+    ScadValue::Number(result)
+}
@@ -1,5 +1,11 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::cache;
+use crate::anchor::Anchor;
+use crate::error::{inconsistent, positive, PartError};
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a hexagonal nut
 #[derive(Debug, Clone)]
@@ -11,23 +17,67 @@ pub struct Nut {
 }
 
 impl Nut {
+    /// A nut checked by [`validate`](Nut::validate)
+    pub fn new(name: &str, diameter: f64, thickness: f64, hole_diameter: f64) -> Result<Nut, PartError> {
+        let nut = Nut { name: name.to_string(), diameter, thickness, hole_diameter };
+        nut.validate()?;
+        Ok(nut)
+    }
+
+    /// Check the dimensions are positive and the hole is inside the flats
+    pub fn validate(&self) -> Result<(), PartError> {
+        positive("diameter", self.diameter)?;
+        positive("thickness", self.thickness)?;
+        positive("hole_diameter", self.hole_diameter)?;
+        if self.hole_diameter >= self.diameter * 3f64.sqrt() / 2.0 {
+            return Err(inconsistent(&self.name, "the hole breaks through the flats"));
+        }
+        Ok(())
+    }
+
     /// Generate the hexagonal shape of the nut
-    pub fn hex_body(&self) -> CSG<()> {
+    pub fn hex_body(&self) -> CSG {
         CSG::hex_prism(self.diameter / 2.0, self.thickness)
+            .finished(Material::Steel)
     }
     
     /// Generate the hole in the nut
-    pub fn hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.hole_diameter / 2.0, self.thickness)
+    pub fn hole(&self) -> CSG {
+        CSG::z_cylinder(self.hole_diameter / 2.0, self.thickness)
     }
     
     /// Generate the full nut model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut nut = self.hex_body();
         let hole = self.hole();
         nut = nut.subtract(&hole);
         
-        nut
+        nut.finished_default(Material::Steel)
     }
 }
 
+impl Part for Nut {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Fastener
+    }
+
+    fn assemble(&self) -> CSG {
+        Nut::assemble(self)
+    }
+
+    fn cache_key(&self) -> Option<String> {
+        Some(cache::debug_key(self))
+    }
+
+    /// `bottom` and `top` faces, on the axis.
+    fn anchors(&self) -> Vec<Anchor> {
+        vec![
+            Anchor::facing("bottom", Vector3::zeros(), -Vector3::z()),
+            Anchor::facing("top", Vector3::new(0.0, 0.0, self.thickness), Vector3::z()),
+        ]
+    }
+}
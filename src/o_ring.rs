@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
 
 /// Representation of an O-Ring
 #[derive(Debug, Clone)]
@@ -9,13 +12,27 @@ pub struct ORing {
 
 impl ORing {
     /// Generate the toroidal body of the O-Ring
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::torus(self.outer_diameter / 2.0, self.thickness / 2.0)
+            .finished(Material::Rubber)
     }
 
     /// Assemble the complete O-Ring
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.body()
     }
 }
 
+impl Part for ORing {
+    fn name(&self) -> &str {
+        "ORing"
+    }
+
+    fn category(&self) -> Category {
+        Category::Fastener
+    }
+
+    fn assemble(&self) -> CSG {
+        ORing::assemble(self)
+    }
+}
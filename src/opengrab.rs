@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::error::{inconsistent, positive, PartError};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an OpenGrab Electro-Permanent Magnet
 #[derive(Debug, Clone)]
@@ -12,28 +16,76 @@ pub struct OpenGrabMagnet {
 }
 
 impl OpenGrabMagnet {
+    /// A magnet checked by [`validate`](OpenGrabMagnet::validate)
+    pub fn new(
+        width: f64,
+        depth: f64,
+        magnet_height: f64,
+        pole_width: f64,
+        pole_length: f64,
+        poles: usize,
+    ) -> Result<Self, PartError> {
+        let magnet = OpenGrabMagnet { width, depth, magnet_height, pole_width, pole_length, poles };
+        magnet.validate()?;
+        Ok(magnet)
+    }
+
+    /// Check the dimensions are positive, there is at least one pole and the poles fit side
+    /// by side across the base
+    pub fn validate(&self) -> Result<(), PartError> {
+        positive("width", self.width)?;
+        positive("depth", self.depth)?;
+        positive("magnet_height", self.magnet_height)?;
+        positive("pole_width", self.pole_width)?;
+        positive("pole_length", self.pole_length)?;
+        if self.poles == 0 {
+            return Err(PartError::InvalidValue { parameter: "poles".to_string(), value: 0.0 });
+        }
+        if self.poles as f64 * self.pole_width > self.width {
+            return Err(inconsistent(self.name(), "the poles are wider than the base"));
+        }
+        Ok(())
+    }
+
     /// Generate the magnet base
-    pub fn base(&self) -> CSG<()> {
+    pub fn base(&self) -> CSG {
         CSG::prism(self.width, self.width, self.magnet_height)
     }
 
     /// Generate the poles
-    pub fn poles(&self) -> CSG<()> {
+    pub fn poles(&self) -> CSG {
         let mut pole_array = CSG::new();
-        let gap = (self.width - (self.poles as f64 * self.pole_width)) / ((self.poles - 1) as f64);
+        // A single pole has no gaps to share the spare width between.
+        let gap = match self.poles {
+            0 | 1 => 0.0,
+            n => (self.width - (n as f64 * self.pole_width)) / ((n - 1) as f64),
+        };
 
         for i in 0..self.poles {
             let x_offset = (i as f64) * (self.pole_width + gap) - self.width / 2.0;
             pole_array = pole_array.union(&CSG::prism(self.pole_width, self.pole_length, 1.0)
-                .translate(Vector3::new(x_offset, 0.0, self.magnet_height / 2.0)));
+                .translated(Vector3::new(x_offset, 0.0, self.magnet_height / 2.0)));
         }
 
         pole_array
     }
 
     /// Assemble the complete OpenGrab module
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.base().union(&self.poles())
     }
 }
 
+impl Part for OpenGrabMagnet {
+    fn name(&self) -> &str {
+        "OpenGrabMagnet"
+    }
+
+    fn category(&self) -> Category {
+        Category::Misc
+    }
+
+    fn assemble(&self) -> CSG {
+        OpenGrabMagnet::assemble(self)
+    }
+}
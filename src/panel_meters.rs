@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a Panel Meter module
 #[derive(Debug, Clone)]
@@ -16,37 +20,23 @@ pub struct PanelMeter {
 
 impl PanelMeter {
     /// Generate the rectangular body of the panel meter
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
     }
     
     /// Generate the display of the panel meter
-    pub fn display(&self) -> CSG<()> {
+    pub fn display(&self) -> CSG {
         CSG::prism(self.display_width, self.display_height, self.display_depth)
-            .translate(Vector3::new(0.0, 0.0, self.body_depth - self.display_depth))
+            .translated(Vector3::new(0.0, 0.0, self.body_depth - self.display_depth))
     }
     
     /// Generate the mounting holes
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-            (-self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.body_depth)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.body_depth)
     }
     
     /// Generate the full panel meter model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut meter = self.body();
         let display = self.display();
         let holes = self.mounting_holes();
@@ -58,3 +48,26 @@ impl PanelMeter {
     }
 }
 
+impl Part for PanelMeter {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Display
+    }
+
+    fn assemble(&self) -> CSG {
+        PanelMeter::assemble(self)
+    }
+}
+
+impl Mountable for PanelMeter {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::square(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
@@ -0,0 +1,172 @@
+use crate::anchor::Anchor;
+use crate::bom::Bom;
+use crate::mass::{self, MassProperties};
+use crate::resolution::{self, Resolution};
+use crate::CSG;
+use csgrs::float_types::parry3d::bounding_volume::Aabb;
+use nalgebra::Isometry3;
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// Broad family a part belongs to, following the grouping of the NopSCADLib vitamins and printed parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Category {
+    /// Screws, nuts, washers, inserts, clips and ties.
+    Fastener,
+    /// Bearings, rails, rods, belts, pulleys and other linear or rotary motion hardware.
+    Motion,
+    /// Steppers, DC, BLDC, geared and servo motors.
+    Motor,
+    /// Fans, blowers and heat pipes.
+    Cooling,
+    /// Hot ends and their parts.
+    HotEnd,
+    /// Discrete components, boards and modules.
+    Electronic,
+    /// Displays, meters and indicators.
+    Display,
+    /// Buttons and switches.
+    Switch,
+    /// Connectors, terminals and wire.
+    Connector,
+    /// Batteries, power supplies, transformers and fuses.
+    Power,
+    /// Extrusions, sheets, tubes and other frame stock and hardware.
+    Structural,
+    /// Parts intended to be 3D printed.
+    Printed,
+    /// Anything that fits none of the above.
+    Misc,
+}
+
+impl Category {
+    /// Lower case label used in reports, e.g. `"hot end"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Fastener => "fastener",
+            Category::Motion => "motion",
+            Category::Motor => "motor",
+            Category::Cooling => "cooling",
+            Category::HotEnd => "hot end",
+            Category::Electronic => "electronic",
+            Category::Display => "display",
+            Category::Switch => "switch",
+            Category::Connector => "connector",
+            Category::Power => "power",
+            Category::Structural => "structural",
+            Category::Printed => "printed",
+            Category::Misc => "misc",
+        }
+    }
+}
+
+/// Serialized as its report label, e.g. `"hot end"`.
+impl Serialize for Category {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Common interface implemented by every part in the library, so tooling can handle any
+/// part generically.
+pub trait Part {
+    /// Human readable name of the part, e.g. `"E2020"` or `"StepperMotor"`.
+    fn name(&self) -> &str;
+
+    /// Family the part belongs to.
+    fn category(&self) -> Category;
+
+    /// Build the complete solid for the part.
+    fn assemble(&self) -> CSG;
+
+    /// Build the solid with curves faceted at `resolution` instead of the current one.
+    fn assemble_at(&self, resolution: Resolution) -> CSG {
+        resolution::with(resolution, || self.assemble())
+    }
+
+    /// Everything the solid depends on apart from the resolution, for parts that opt in to the
+    /// [geometry cache](crate::cache).
+    ///
+    /// Parts without a key are built every time they are placed. [`cache::debug_key`] suits parts
+    /// whose `Debug` output lists every field that changes their shape, bounds or mass.
+    ///
+    /// [`cache::debug_key`]: crate::cache::debug_key
+    fn cache_key(&self) -> Option<String> {
+        None
+    }
+
+    /// Axis aligned bounding box of the assembled part.
+    fn bounding_box(&self) -> Aabb {
+        self.assemble().bounding_box()
+    }
+
+    /// Description used for the part's line in a bill of materials.
+    ///
+    /// Parts cut to length include the length so different sizes get separate lines.
+    fn description(&self) -> String {
+        self.name().to_string()
+    }
+
+    /// Density in g/cm³, if the part knows better than the materials its polygons are tagged with.
+    ///
+    /// Worth giving for parts of several materials, which otherwise weigh as if made entirely of
+    /// the one on most of their surface.
+    fn density(&self) -> Option<f64> {
+        None
+    }
+
+    /// Catalogue mass in grams, which takes precedence over any density.
+    fn catalogue_mass(&self) -> Option<f64> {
+        None
+    }
+
+    /// Price of one part, in whatever currency the caller works in.
+    fn unit_cost(&self) -> Option<f64> {
+        None
+    }
+
+    /// Mass, volume, centre of mass and inertia of the assembled part.
+    ///
+    /// Without a [`density`](Part::density) the density of the material covering most of the
+    /// surface is used, and a [`catalogue_mass`](Part::catalogue_mass) rescales the result.
+    ///
+    /// That is not a volume-weighted density: the unioned solid only keeps its outer surface, so a
+    /// steel core inside a large plastic shell weighs as all plastic. Such parts want a density or
+    /// catalogue mass, or each material placed as its own part of an [`Assembly`](crate::assembly::Assembly).
+    fn mass_properties(&self) -> MassProperties {
+        let solid = self.assemble();
+        let density = self.density().unwrap_or_else(|| mass::surface_density(&solid));
+        let properties = MassProperties::of_solid(&solid, density);
+        match self.catalogue_mass() {
+            Some(mass) => properties.scaled_to(mass),
+            None => properties,
+        }
+    }
+
+    /// Named frames other parts can be [attached](crate::anchor::attach) to, in the part's
+    /// own coordinates.
+    fn anchors(&self) -> Vec<Anchor> {
+        Vec::new()
+    }
+
+    /// The frame of the anchor called `name`.
+    fn anchor(&self, name: &str) -> Option<Isometry3<f64>> {
+        self.anchors().into_iter().find(|a| a.name == name).map(|a| a.frame)
+    }
+
+    /// Record one placement of the part in `bom`.
+    ///
+    /// Parts built from several vitamins override this to record each of them.
+    fn bom(&self, bom: &mut Bom) {
+        match self.unit_cost() {
+            Some(cost) => bom.add_costed(self.description(), self.category(), 1, cost),
+            None => bom.add(self.description(), self.category(), 1),
+        }
+    }
+}
@@ -0,0 +1,299 @@
+//! Arrays of copies of a feature, e.g. the holes of a stripboard or the teeth of a belt.
+//!
+//! Unioning copies one at a time makes each boolean bigger than the last, so the cost grows
+//! with the square of the count. [`union_all`] only runs booleans between copies that can
+//! touch, pairing them up as a balanced tree, and just collects the polygons of the rest.
+//! [`subtract_all`] cuts the body into pieces between the tools, so each boolean only sees
+//! the few tools and the small part of the body that meet.
+
+use crate::primitives::Primitives;
+use crate::CSG;
+use csgrs::polygon::Polygon;
+use csgrs::float_types::parry3d::bounding_volume::{Aabb, BoundingVolume};
+use nalgebra::{Isometry3, Translation3, UnitQuaternion, Vector3};
+use std::f64::consts::TAU;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, Scope, ScopedJoinHandle};
+
+/// Gap below which bounding boxes count as touching, so copies that share a face are merged.
+const TOUCHING: f64 = 1e-9;
+
+/// Distance from a cut within which a polygon counts as lying on it.
+const CUT_TOLERANCE: f64 = 1e-6;
+
+/// Stack for the threads booleans run on.
+///
+/// csgrs builds and clips its BSP trees recursively, one level per splitting plane, so a badly
+/// balanced tree over the tens of thousands of polygons of a large board can recurse that deep,
+/// which overflows the default 2 MiB thread stack in debug builds. The stack is only address
+/// space until it is touched, so a generous one costs nothing for the shallow trees of most
+/// parts.
+const STACK_SIZE: usize = 256 << 20;
+
+/// A tool to subtract with its bounding box.
+type Tool = (CSG, Aabb);
+
+/// Most tools [`subtract_all`] cuts from one piece of the body with a single boolean.
+const TOOLS_PER_PIECE: usize = 4;
+
+/// `count` placements `step` apart, centred on the origin.
+pub fn linear(count: usize, step: Vector3<f64>) -> Vec<Isometry3<f64>> {
+    let start = -step * (count as f64 - 1.0) / 2.0;
+    (0..count)
+        .map(|i| Isometry3::from_parts(Translation3::from(start + step * i as f64), UnitQuaternion::identity()))
+        .collect()
+}
+
+/// `columns` by `rows` placements centred on the origin, row by row.
+pub fn grid(columns: usize, rows: usize, column_step: Vector3<f64>, row_step: Vector3<f64>) -> Vec<Isometry3<f64>> {
+    let columns = linear(columns, column_step);
+    linear(rows, row_step)
+        .iter()
+        .flat_map(|row| columns.iter().map(move |column| row * column))
+        .collect()
+}
+
+/// `count` placements evenly spaced around a circle of `radius` about Z, starting on +X, each
+/// turned so its own +X faces outwards.
+pub fn polar(count: usize, radius: f64) -> Vec<Isometry3<f64>> {
+    (0..count)
+        .map(|i| {
+            let rotation = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), TAU * i as f64 / count as f64);
+            Isometry3::from_parts(Translation3::from(rotation * Vector3::new(radius, 0.0, 0.0)), rotation)
+        })
+        .collect()
+}
+
+/// Copies of `solid` at each placement.
+pub fn copies(solid: &CSG, placements: &[Isometry3<f64>]) -> Vec<CSG> {
+    placements.iter().map(|p| solid.transformed(&p.to_homogeneous())).collect()
+}
+
+/// Copies of `solid` at each placement, as one solid.
+pub fn place(solid: &CSG, placements: &[Isometry3<f64>]) -> CSG {
+    union_all(copies(solid, placements))
+}
+
+/// Union of every solid.
+///
+/// Solids whose bounding boxes neither overlap nor touch are collected without a boolean.
+/// Those that may meet are unioned in groups, each as a balanced tree, with the groups spread
+/// over the available cores. The result is the same solid as unioning one at a time.
+pub fn union_all(solids: Vec<CSG>) -> CSG {
+    let solids: Vec<CSG> = solids.into_iter().filter(|s| !s.polygons.is_empty()).collect();
+    let (single, groups): (Vec<Vec<CSG>>, Vec<Vec<CSG>>) = touching_groups(solids).into_iter().partition(|g| g.len() == 1);
+
+    let threads = thread::available_parallelism().map_or(1, |n| n.get()).min(groups.len());
+    let merged: Vec<CSG> = if threads > 1 {
+        let chunk = groups.len().div_ceil(threads);
+        let mut groups = groups;
+        thread::scope(|scope| {
+            let mut tasks = Vec::new();
+            while !groups.is_empty() {
+                let batch: Vec<Vec<CSG>> = groups.drain(..chunk.min(groups.len())).collect();
+                tasks.push(spawn(scope, move || batch.into_iter().map(balanced_union).collect::<Vec<CSG>>()));
+            }
+            tasks.into_iter().flat_map(Task::join).collect()
+        })
+    } else {
+        groups.into_iter().map(balanced_union).collect()
+    };
+
+    let polygons = single.into_iter().flatten().chain(merged).flat_map(|s| s.polygons).collect();
+    CSG::from_polygons(polygons)
+}
+
+/// `body` with every tool cut away, e.g. a board and the copies of its hole.
+///
+/// While there are more than a few tools, the body is cut in two across a gap between them
+/// and each half has its own tools subtracted, on another core if there is one. The faces
+/// left on the cut are then dropped and the halves joined without a boolean. Tools that
+/// can't be separated by a plane are subtracted together, which is no worse than one boolean.
+pub fn subtract_all(body: &CSG, tools: Vec<CSG>) -> CSG {
+    let tools: Vec<Tool> = tools
+        .into_iter()
+        .filter(|t| !t.polygons.is_empty())
+        .map(|t| {
+            let bounds = t.bounding_box();
+            (t, bounds)
+        })
+        .collect();
+    let depth = thread::available_parallelism().map_or(1, |n| n.get()).ilog2();
+    subtract_split(body.clone(), tools, depth)
+}
+
+fn subtract_split(body: CSG, tools: Vec<Tool>, parallel_depth: u32) -> CSG {
+    // Tools clear of the body are dropped, which also keeps every cut between the tools
+    // inside the body.
+    let bounds = body.bounding_box();
+    let mut tools: Vec<Tool> = tools.into_iter().filter(|(_, b)| meet(b, &bounds)).collect();
+    if tools.is_empty() || body.polygons.is_empty() {
+        return body;
+    }
+    let cut = if tools.len() > TOOLS_PER_PIECE { split_tools(&body, &mut tools) } else { None };
+    let Some((axis, at, right_tools)) = cut else {
+        let tools = union_all(tools.into_iter().map(|(t, _)| t).collect());
+        return body.subtract(&tools);
+    };
+
+    let (mut mins, maxs) = (bounds.mins - Vector3::repeat(1.0), bounds.maxs + Vector3::repeat(1.0));
+    let mut left_max = maxs;
+    left_max[axis] = at;
+    let left = body.intersect(&cuboid(mins.coords, left_max.coords));
+    mins[axis] = at;
+    let right = body.intersect(&cuboid(mins.coords, maxs.coords));
+
+    let (left, right) = if parallel_depth > 0 {
+        thread::scope(|scope| {
+            let left = spawn(scope, || subtract_split(left, tools, parallel_depth - 1));
+            let right = subtract_split(right, right_tools, parallel_depth - 1);
+            (left.join(), right)
+        })
+    } else {
+        (subtract_split(left, tools, 0), subtract_split(right, right_tools, 0))
+    };
+
+    let on_cut = |p: &Polygon<_>| p.vertices.iter().all(|v| (v.pos[axis] - at).abs() < CUT_TOLERANCE);
+    let polygons = left.polygons.into_iter().chain(right.polygons).filter(|p| !on_cut(p)).collect();
+    CSG::from_polygons(polygons)
+}
+
+/// Work started by [`spawn`], on its own thread or already done.
+enum Task<'scope, T> {
+    Running(ScopedJoinHandle<'scope, T>),
+    Done(T),
+}
+
+impl<T> Task<'_, T> {
+    fn join(self) -> T {
+        match self {
+            Task::Running(handle) => handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)),
+            Task::Done(result) => result,
+        }
+    }
+}
+
+/// Start `task` on a thread of [`STACK_SIZE`], or run it on this one if no thread can be
+/// started, e.g. when the address space for the stack can't be reserved.
+fn spawn<'scope, T, F>(scope: &'scope Scope<'scope, '_>, task: F) -> Task<'scope, T>
+where
+    T: Send + 'scope,
+    F: FnOnce() -> T + Send + 'scope,
+{
+    // Held outside the thread so a failed spawn, which drops its closure, doesn't lose the task.
+    let slot = Arc::new(Mutex::new(Some(task)));
+    let queued = Arc::clone(&slot);
+    let spawned = thread::Builder::new().stack_size(STACK_SIZE).spawn_scoped(scope, move || {
+        let task = queued.lock().unwrap().take().expect("task taken twice");
+        task()
+    });
+    match spawned {
+        Ok(handle) => Task::Running(handle),
+        Err(_) => {
+            let task = slot.lock().unwrap().take().expect("task taken twice");
+            Task::Done(task())
+        }
+    }
+}
+
+/// Split the tools, leaving those below the cut in `tools` and returning the axis and position
+/// of a cut through a gap between them with the tools above it, or `None` if there is no gap
+/// the body has no faces in
+fn split_tools(body: &CSG, tools: &mut Vec<Tool>) -> Option<(usize, f64, Vec<Tool>)> {
+    let extent = tools.iter().fold(Aabb::new_invalid(), |acc, (_, b)| acc.merged(b)).extents();
+    let mut axes = [0, 1, 2];
+    axes.sort_by(|&a, &b| extent[b].total_cmp(&extent[a]));
+    for axis in axes {
+        tools.sort_by(|(_, a), (_, b)| a.center()[axis].total_cmp(&b.center()[axis]));
+        // How far the tools up to each one reach, to find gaps nearest the middle first so
+        // the halves are about the same size.
+        let reach: Vec<f64> = tools
+            .iter()
+            .scan(f64::NEG_INFINITY, |reach, (_, b)| {
+                *reach = b.maxs[axis].max(*reach);
+                Some(*reach)
+            })
+            .collect();
+        let middle = tools.len() / 2;
+        let mut splits: Vec<usize> = (1..tools.len()).collect();
+        splits.sort_by_key(|&i| i.abs_diff(middle));
+        for i in splits {
+            let next = tools[i..].iter().map(|(_, b)| b.mins[axis]).fold(f64::INFINITY, f64::min);
+            if next - reach[i - 1] < 2.0 * CUT_TOLERANCE {
+                continue;
+            }
+            let at = (reach[i - 1] + next) / 2.0;
+            let touches = body.polygons.iter().any(|p| p.vertices.iter().all(|v| (v.pos[axis] - at).abs() < CUT_TOLERANCE));
+            if !touches {
+                return Some((axis, at, tools.split_off(i)));
+            }
+        }
+    }
+    None
+}
+
+/// Whether two boxes overlap or touch
+fn meet(a: &Aabb, b: &Aabb) -> bool {
+    (0..3).all(|k| a.mins[k] <= b.maxs[k] + TOUCHING && b.mins[k] <= a.maxs[k] + TOUCHING)
+}
+
+fn cuboid(mins: Vector3<f64>, maxs: Vector3<f64>) -> CSG {
+    let size = maxs - mins;
+    CSG::prism(size.x, size.y, size.z).translated(mins)
+}
+
+/// Union a group by pairing neighbours, so every boolean is between solids of similar size
+fn balanced_union(mut solids: Vec<CSG>) -> CSG {
+    while solids.len() > 1 {
+        let mut next = Vec::with_capacity(solids.len().div_ceil(2));
+        let mut iter = solids.into_iter();
+        while let Some(a) = iter.next() {
+            next.push(match iter.next() {
+                Some(b) => a.union(&b),
+                None => a,
+            });
+        }
+        solids = next;
+    }
+    solids.pop().unwrap_or_else(CSG::new)
+}
+
+/// Split solids into groups whose bounding boxes connect, keeping the order within each group
+fn touching_groups(solids: Vec<CSG>) -> Vec<Vec<CSG>> {
+    let boxes: Vec<Aabb> = solids.iter().map(|s| s.bounding_box()).collect();
+    let mut parent: Vec<usize> = (0..solids.len()).collect();
+    fn root(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+
+    // Sweep along X, only comparing boxes whose X ranges overlap.
+    let mut order: Vec<usize> = (0..solids.len()).collect();
+    order.sort_by(|&a, &b| boxes[a].mins.x.total_cmp(&boxes[b].mins.x));
+    let mut active: Vec<usize> = Vec::new();
+    for &i in &order {
+        active.retain(|&j| boxes[j].maxs.x >= boxes[i].mins.x - TOUCHING);
+        for &j in &active {
+            if meet(&boxes[i], &boxes[j]) {
+                let (a, b) = (root(&mut parent, i), root(&mut parent, j));
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+        active.push(i);
+    }
+
+    let mut groups: Vec<Vec<CSG>> = Vec::new();
+    let mut slot = vec![usize::MAX; solids.len()];
+    for (i, solid) in solids.into_iter().enumerate() {
+        let r = root(&mut parent, i);
+        if slot[r] == usize::MAX {
+            slot[r] = groups.len();
+            groups.push(Vec::new());
+        }
+        groups[slot[r]].push(solid);
+    }
+    groups
+}
@@ -1,4 +1,10 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::anchor::{self, Anchor};
+use crate::material::{Finishing, Material};
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a Printed Circuit Board (PCB)
 #[derive(Debug, Clone)]
@@ -12,24 +18,56 @@ pub struct PCB {
 
 impl PCB {
     /// Generate the PCB body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.thickness)
+            .translated(Vector3::new(-self.width / 2.0, -self.height / 2.0, 0.0))
+            .finished(Material::Fr4)
     }
 
     /// Generate the mounting holes
-    pub fn holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.thickness);
-        let offset = self.hole_spacing / 2.0;
-
-        hole.translate(Vector3::new(offset, offset, 0.0))
-            .union(&hole.translate(Vector3::new(-offset, -offset, 0.0)))
-            .union(&hole.translate(Vector3::new(offset, -offset, 0.0)))
-            .union(&hole.translate(Vector3::new(-offset, offset, 0.0)))
+    pub fn holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.hole_diameter, self.thickness)
     }
 
     /// Assemble the complete PCB model
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.holes())
+            .finished_default(Material::Fr4)
+    }
+}
+
+impl Part for PCB {
+    fn name(&self) -> &str {
+        "PCB"
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        PCB::assemble(self)
+    }
+
+    /// `bottom` and `top` faces at the centre of the board and `hole_0` to `hole_3` on the
+    /// mounting holes.
+    fn anchors(&self) -> Vec<Anchor> {
+        let mut anchors = vec![
+            Anchor::facing("bottom", Vector3::zeros(), -Vector3::z()),
+            Anchor::facing("top", Vector3::new(0.0, 0.0, self.thickness), Vector3::z()),
+        ];
+        anchors.extend(anchor::holes(self));
+        anchors
     }
 }
 
+impl Mountable for PCB {
+    /// Screws up through the board into standoffs below it.
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::square(self.hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.hole_diameter
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Photo Interrupter
 #[derive(Debug, Clone)]
@@ -15,26 +18,39 @@ pub struct PhotoInterrupter {
 
 impl PhotoInterrupter {
     /// Generate the main body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
     }
 
     /// Generate the slot in the interrupter
-    pub fn slot(&self) -> CSG<()> {
+    pub fn slot(&self) -> CSG {
         CSG::prism(self.slot_width, self.slot_depth, self.slot_height)
-            .translate(Vector3::new(0.0, 0.0, self.body_height / 2.0))
+            .translated(Vector3::new(0.0, 0.0, self.body_height / 2.0))
     }
 
     /// Generate the connector pins
-    pub fn pins(&self) -> CSG<()> {
-        let pin = CSG::cylinder_z(self.pin_diameter / 2.0, self.pin_length);
-        pin.translate(Vector3::new(-self.body_width / 4.0, 0.0, -self.pin_length))
-            .union(&pin.translate(Vector3::new(self.body_width / 4.0, 0.0, -self.pin_length)))
+    pub fn pins(&self) -> CSG {
+        let pin = CSG::z_cylinder(self.pin_diameter / 2.0, self.pin_length);
+        pin.translated(Vector3::new(-self.body_width / 4.0, 0.0, -self.pin_length))
+            .union(&pin.translated(Vector3::new(self.body_width / 4.0, 0.0, -self.pin_length)))
     }
 
     /// Assemble the complete photo interrupter
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.slot()).union(&self.pins())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.slot()).union(&self.pins())
     }
 }
 
+impl Part for PhotoInterrupter {
+    fn name(&self) -> &str {
+        "PhotoInterrupter"
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        PhotoInterrupter::assemble(self)
+    }
+}
@@ -1,4 +1,6 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
 
 /// Representation of a Pillar (Spacer)
 #[derive(Debug, Clone)]
@@ -10,18 +12,31 @@ pub struct Pillar {
 
 impl Pillar {
     /// Generate the pillar body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.diameter / 2.0, self.height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.diameter / 2.0, self.height)
     }
 
     /// Generate the center hole
-    pub fn hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.hole_diameter / 2.0, self.height + 1.0)
+    pub fn hole(&self) -> CSG {
+        CSG::z_cylinder(self.hole_diameter / 2.0, self.height + 1.0)
     }
 
     /// Assemble the complete pillar
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.hole())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.hole())
     }
 }
 
+impl Part for Pillar {
+    fn name(&self) -> &str {
+        "Pillar"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        Pillar::assemble(self)
+    }
+}
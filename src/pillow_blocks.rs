@@ -1,4 +1,10 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use crate::tolerance::Fit;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a Pillow Block Bearing module
 #[derive(Debug, Clone)]
@@ -8,6 +14,8 @@ pub struct PillowBlock {
     pub base_length: f64,
     pub base_height: f64,
     pub bore_diameter: f64,
+    /// Fit of the shaft in the bore.
+    pub bore_fit: Fit,
     pub housing_diameter: f64,
     pub housing_height: f64,
     pub mounting_hole_diameter: f64,
@@ -16,40 +24,30 @@ pub struct PillowBlock {
 
 impl PillowBlock {
     /// Generate the base of the pillow block
-    pub fn base(&self) -> CSG<()> {
+    pub fn base(&self) -> CSG {
         CSG::prism(self.base_length, self.base_width, self.base_height)
+            .finished(Material::Steel)
     }
     
     /// Generate the bearing housing
-    pub fn housing(&self) -> CSG<()> {
-        CSG::cylinder_z(self.housing_diameter / 2.0, self.housing_height)
-            .translate(Vector3::new(0.0, 0.0, self.base_height))
+    pub fn housing(&self) -> CSG {
+        CSG::z_cylinder(self.housing_diameter / 2.0, self.housing_height)
+            .translated(Vector3::new(0.0, 0.0, self.base_height))
+            .finished(Material::Steel)
     }
     
     /// Generate the inner bore of the bearing
-    pub fn bore(&self) -> CSG<()> {
-        CSG::cylinder_z(self.bore_diameter / 2.0, self.housing_height + self.base_height)
+    pub fn bore(&self) -> CSG {
+        CSG::z_cylinder(self.bore_fit.hole_diameter(self.bore_diameter) / 2.0, self.housing_height + self.base_height)
     }
     
     /// Generate the mounting holes
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, 0.0),
-            (self.mounting_hole_spacing / 2.0, 0.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.base_height)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.base_height)
     }
     
     /// Generate the full pillow block model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut pillow_block = self.base();
         let housing = self.housing();
         let bore = self.bore();
@@ -59,7 +57,30 @@ impl PillowBlock {
         pillow_block = pillow_block.subtract(&bore);
         pillow_block = pillow_block.subtract(&holes);
         
-        pillow_block
+        pillow_block.finished_default(Material::Steel)
     }
 }
 
+impl Part for PillowBlock {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        PillowBlock::assemble(self)
+    }
+}
+
+impl Mountable for PillowBlock {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::pair(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
@@ -1,4 +1,11 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::cache;
+use crate::colour;
+use crate::material::{Finish, Finishing, Material};
+use crate::part::{Category, Part};
+use crate::pattern;
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Pin Header
 #[derive(Debug, Clone)]
@@ -14,27 +21,41 @@ pub struct PinHeader {
 
 impl PinHeader {
     /// Generate the plastic base
-    pub fn base(&self) -> CSG<()> {
+    pub fn base(&self) -> CSG {
         CSG::prism(self.base_width, self.base_length, self.base_thickness)
+            .finished(Material::Plastic)
     }
 
     /// Generate the pins
-    pub fn pins(&self) -> CSG<()> {
-        let pin = CSG::cylinder_z(self.pin_diameter / 2.0, self.pin_length);
-        let mut pin_array = CSG::new();
-        let spacing = self.row_spacing;
-
-        for i in 0..self.pin_count {
-            let x_offset = (i as f64 - (self.pin_count as f64 - 1.0) / 2.0) * spacing;
-            pin_array = pin_array.union(&pin.translate(Vector3::new(x_offset, 0.0, self.base_thickness)));
-        }
-
-        pin_array
+    pub fn pins(&self) -> CSG {
+        let key = format!("pin header pin {:?} {:?}", self.pin_diameter, self.pin_length);
+        let pin = cache::shared()
+            .get_or_build(&key, || CSG::z_cylinder(self.pin_diameter / 2.0, self.pin_length))
+            .translated(Vector3::new(0.0, 0.0, self.base_thickness));
+        let placements = pattern::linear(self.pin_count, Vector3::new(self.row_spacing, 0.0, 0.0));
+        pattern::place(&pin, &placements).finished(Finish::new(colour::GOLD, Material::Brass))
     }
 
     /// Assemble the full pin header
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.base().union(&self.pins())
     }
 }
 
+impl Part for PinHeader {
+    fn name(&self) -> &str {
+        "PinHeader"
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        PinHeader::assemble(self)
+    }
+
+    fn cache_key(&self) -> Option<String> {
+        Some(cache::debug_key(self))
+    }
+}
@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a potentiometer module
 #[derive(Debug, Clone)]
@@ -16,18 +18,18 @@ pub struct Potentiometer {
 
 impl Potentiometer {
     /// Generate the cylindrical body of the potentiometer
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_height)
     }
     
     /// Generate the shaft of the potentiometer
-    pub fn shaft(&self) -> CSG<()> {
-        CSG::cylinder_z(self.shaft_diameter / 2.0, self.shaft_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_height))
+    pub fn shaft(&self) -> CSG {
+        CSG::z_cylinder(self.shaft_diameter / 2.0, self.shaft_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_height))
     }
     
     /// Generate the pins of the potentiometer
-    pub fn pins(&self) -> CSG<()> {
+    pub fn pins(&self) -> CSG {
         let mut pins = CSG::new();
         let offsets = [
             (-self.pin_spacing, 0.0),
@@ -36,8 +38,8 @@ impl Potentiometer {
         ];
         
         for &(x, y) in &offsets {
-            let pin = CSG::cylinder_z(self.pin_diameter / 2.0, self.pin_length)
-                .translate(Vector3::new(x, y, -self.pin_length));
+            let pin = CSG::z_cylinder(self.pin_diameter / 2.0, self.pin_length)
+                .translated(Vector3::new(x, y, -self.pin_length));
             pins = pins.union(&pin);
         }
         
@@ -45,7 +47,7 @@ impl Potentiometer {
     }
     
     /// Generate the full potentiometer model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut potentiometer = self.body();
         let shaft = self.shaft();
         let pins = self.pins();
@@ -57,3 +59,16 @@ impl Potentiometer {
     }
 }
 
+impl Part for Potentiometer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Potentiometer::assemble(self)
+    }
+}
@@ -1,5 +1,9 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a power supply module
 #[derive(Debug, Clone)]
@@ -16,19 +20,20 @@ pub struct PowerSupply {
 
 impl PowerSupply {
     /// Generate the rectangular body of the power supply
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
+            .finished(Material::Aluminium)
     }
     
     /// Generate the vent holes of the power supply
-    pub fn vent_holes(&self) -> CSG<()> {
+    pub fn vent_holes(&self) -> CSG {
         let mut holes = CSG::new();
         let num_holes = (self.body_width / self.vent_hole_spacing).floor() as i32;
         
         for i in 0..num_holes {
             let x_offset = (i as f64 * self.vent_hole_spacing) - (self.body_width / 2.0);
-            let hole = CSG::cylinder_z(self.vent_hole_diameter / 2.0, self.body_height)
-                .translate(Vector3::new(x_offset, 0.0, self.body_depth / 2.0));
+            let hole = CSG::z_cylinder(self.vent_hole_diameter / 2.0, self.body_height)
+                .translated(Vector3::new(x_offset, 0.0, self.body_depth / 2.0));
             holes = holes.union(&hole);
         }
         
@@ -36,26 +41,12 @@ impl PowerSupply {
     }
     
     /// Generate the mounting holes of the power supply
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-            (-self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.body_depth)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.body_depth)
     }
     
     /// Generate the full power supply model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut power_supply = self.body();
         let vent_holes = self.vent_holes();
         let mounting_holes = self.mounting_holes();
@@ -63,7 +54,30 @@ impl PowerSupply {
         power_supply = power_supply.subtract(&vent_holes);
         power_supply = power_supply.subtract(&mounting_holes);
         
-        power_supply
+        power_supply.finished_default(Material::Aluminium)
     }
 }
 
+impl Part for PowerSupply {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Power
+    }
+
+    fn assemble(&self) -> CSG {
+        PowerSupply::assemble(self)
+    }
+}
+
+impl Mountable for PowerSupply {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::square(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
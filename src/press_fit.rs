@@ -1,39 +1,68 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
-/// Representation of a Power Supply Unit (PSU) Shroud
+/// Representation of a printed press fit peg, which is pushed into a plain hole to join
+/// two printed parts without screws.
+///
+/// The peg stands on Z = 0 with a chamfered tip to lead it into its socket.
 #[derive(Debug, Clone)]
-pub struct PSUShroud {
-    pub width: f64,
-    pub height: f64,
-    pub depth: f64,
-    pub vent_hole_diameter: f64,
-    pub vent_hole_spacing: f64,
-    pub vent_hole_count: usize,
+pub struct PressFitPeg {
+    /// Nominal diameter, that of the socket.
+    pub diameter: f64,
+    pub length: f64,
+    /// How much bigger the peg is than its socket.
+    pub interference: f64,
+    pub chamfer: f64,
 }
 
-impl PSUShroud {
-    /// Generate the shroud body
-    pub fn body(&self) -> CSG<()> {
-        CSG::prism(self.width, self.height, self.depth)
+impl PressFitPeg {
+    fn radius(&self) -> f64 {
+        (self.diameter + self.interference) / 2.0
     }
 
-    /// Generate vent holes
-    pub fn vent_holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.vent_hole_diameter / 2.0, self.depth + 1.0);
-        let mut holes = CSG::new();
-        let start_x = -((self.vent_hole_count as f64 - 1.0) / 2.0) * self.vent_hole_spacing;
+    /// Generate the straight part of the peg
+    pub fn shank(&self) -> CSG {
+        CSG::z_cylinder(self.radius(), self.length - self.chamfer)
+    }
 
-        for i in 0..self.vent_hole_count {
-            let x_offset = start_x + (i as f64) * self.vent_hole_spacing;
-            holes = holes.union(&hole.translate(Vector3::new(x_offset, 0.0, self.height / 2.0)));
-        }
+    /// Generate the 45° lead-in at the tip
+    pub fn tip(&self) -> CSG {
+        let r = self.radius();
+        CSG::cone(r, r)
+            .intersect(&CSG::z_cylinder(r, self.chamfer))
+            .translated(Vector3::new(0.0, 0.0, self.length - self.chamfer))
+    }
 
-        holes
+    /// Generate the hole the peg fits, `clearance` deeper than the peg so it bottoms out on its shoulder
+    pub fn socket(&self, clearance: f64) -> CSG {
+        CSG::z_cylinder(self.diameter / 2.0, self.length + clearance)
     }
 
-    /// Assemble the complete PSU shroud
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.vent_holes())
+    /// Assemble the complete peg
+    pub fn assemble(&self) -> CSG {
+        if self.chamfer <= 0.0 {
+            return CSG::z_cylinder(self.radius(), self.length);
+        }
+        self.shank().union(&self.tip())
     }
 }
 
+impl Part for PressFitPeg {
+    fn name(&self) -> &str {
+        "PressFitPeg"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        PressFitPeg::assemble(self)
+    }
+
+    fn description(&self) -> String {
+        format!("Press fit peg {}mm x {}mm", self.diameter, self.length)
+    }
+}
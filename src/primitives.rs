@@ -0,0 +1,177 @@
+use crate::resolution;
+use crate::CSG;
+use nalgebra::{Matrix4, Translation3, Vector3};
+use std::f64::consts::TAU;
+
+/// Primitive solids used by the part modules that `csgrs` does not provide directly.
+///
+/// Mirrors the OpenSCAD idioms NopSCADLib relies on (`cylinder(r1, r2 = 0)`,
+/// `rotate_extrude() translate([R, 0]) circle(r)`, `cylinder($fn = 6)`).
+/// Curved surfaces are faceted at the [current](resolution::current) resolution.
+pub trait Primitives: Sized {
+    /// Cylinder along Z from `z = 0` to `z = height`.
+    ///
+    /// Replaces `CSG::cylinder_z`, which is only defined for solids without metadata.
+    fn z_cylinder(radius: f64, height: f64) -> Self;
+
+    /// Sphere of `radius` centred on the origin.
+    fn ball(radius: f64) -> Self;
+
+    /// Hexagonal prism standing on the XY plane, `radius` measured across the corners.
+    fn hex_prism(radius: f64, height: f64) -> Self;
+
+    /// Cone with its base on the XY plane and its apex at `(0, 0, height)`.
+    fn cone(radius: f64, height: f64) -> Self;
+
+    /// Torus around the Z axis with centre-line radius `major` and tube radius `minor`.
+    fn torus(major: f64, minor: f64) -> Self;
+
+    /// Round wire coiled around the Z axis from `z = 0` to `z = length`.
+    ///
+    /// `radius` is the centre-line radius of the coil and `pitch` the rise per turn. Turns
+    /// touch or overlap when `wire_diameter` reaches the pitch, which makes a bad solid.
+    fn helix(radius: f64, length: f64, pitch: f64, wire_diameter: f64) -> Self;
+
+    /// Apply an affine transform, keeping every polygon facing outwards.
+    ///
+    /// `CSG::transform` turns the planes of some polygons inside out whenever the matrix
+    /// contains a translation, which upsets later booleans, and leaves mirrored solids with
+    /// the wrong winding.
+    ///
+    /// A singular matrix, e.g. a scale of zero, gives an empty solid.
+    fn transformed(&self, matrix: &Matrix4<f64>) -> Self;
+
+    /// Move by `v`, like `CSG::translate` but keeping the planes outward facing.
+    fn translated(&self, v: Vector3<f64>) -> Self {
+        self.transformed(&Translation3::from(v).to_homogeneous())
+    }
+}
+
+impl Primitives for CSG {
+    fn z_cylinder(radius: f64, height: f64) -> Self {
+        let segments = resolution::current().fragments(radius);
+        CSG::cylinder(Some((&[0.0, 0.0, 0.0], &[0.0, 0.0, height], radius, segments)))
+    }
+
+    fn ball(radius: f64) -> Self {
+        // As OpenSCAD's sphere(), with half as many rings as segments around.
+        let segments = resolution::current().fragments(radius);
+        CSG::sphere(Some((&[0.0, 0.0, 0.0], radius, segments, segments.div_ceil(2))))
+    }
+
+    fn hex_prism(radius: f64, height: f64) -> Self {
+        CSG::circle(Some((radius, 6))).extrude(height)
+    }
+
+    fn cone(radius: f64, height: f64) -> Self {
+        let segments = resolution::current().fragments(radius);
+        let mut points: Vec<[f64; 3]> = (0..segments)
+            .map(|i| {
+                let a = TAU * i as f64 / segments as f64;
+                [radius * a.cos(), radius * a.sin(), 0.0]
+            })
+            .collect();
+        points.push([0.0, 0.0, height]);
+
+        let apex = segments;
+        let mut faces: Vec<Vec<usize>> = (0..segments)
+            .map(|i| vec![i, (i + 1) % segments, apex])
+            .collect();
+        faces.push((0..segments).rev().collect());
+
+        CSG::polyhedron(&points, &faces)
+    }
+
+    fn torus(major: f64, minor: f64) -> Self {
+        let resolution = resolution::current();
+        let segments = resolution.fragments(major + minor);
+        let tube_segments = resolution.fragments(minor);
+        let mut points = Vec::with_capacity(segments * tube_segments);
+        for i in 0..segments {
+            let u = TAU * i as f64 / segments as f64;
+            for j in 0..tube_segments {
+                let v = TAU * j as f64 / tube_segments as f64;
+                let r = major + minor * v.cos();
+                points.push([r * u.cos(), r * u.sin(), minor * v.sin()]);
+            }
+        }
+
+        let index = |i: usize, j: usize| (i % segments) * tube_segments + j % tube_segments;
+        let mut faces = Vec::with_capacity(segments * tube_segments);
+        for i in 0..segments {
+            for j in 0..tube_segments {
+                faces.push(vec![index(i, j), index(i + 1, j), index(i + 1, j + 1), index(i, j + 1)]);
+            }
+        }
+
+        CSG::polyhedron(&points, &faces)
+    }
+
+    fn helix(radius: f64, length: f64, pitch: f64, wire_diameter: f64) -> Self {
+        if pitch <= 0.0 || length <= 0.0 || wire_diameter <= 0.0 {
+            return CSG::new();
+        }
+
+        let wire_r = wire_diameter / 2.0;
+        let resolution = resolution::current();
+        let segments = resolution.fragments(radius + wire_r);
+        let tube_segments = resolution.fragments(wire_r);
+        // Keep the wire inside 0..length rather than poking out of either end.
+        let rise = (length - 2.0 * wire_r).max(0.0);
+        let steps = ((rise / pitch) * segments as f64).ceil().max(1.0) as usize;
+        let sweep = TAU * rise / pitch;
+
+        let mut points = Vec::with_capacity((steps + 1) * tube_segments);
+        for k in 0..=steps {
+            let u = sweep * k as f64 / steps as f64;
+            let z = wire_r + rise * k as f64 / steps as f64;
+            for j in 0..tube_segments {
+                let v = TAU * j as f64 / tube_segments as f64;
+                let r = radius + wire_r * v.cos();
+                points.push([r * u.cos(), r * u.sin(), z + wire_r * v.sin()]);
+            }
+        }
+
+        let index = |k: usize, j: usize| k * tube_segments + j % tube_segments;
+        let mut faces = Vec::with_capacity(2 * steps * tube_segments + 2);
+        for k in 0..steps {
+            for j in 0..tube_segments {
+                // The quads twist along the wire, so split them into triangles to keep every
+                // face planar, otherwise the BSP keeps splitting them without end.
+                faces.push(vec![index(k, j), index(k + 1, j), index(k + 1, j + 1)]);
+                faces.push(vec![index(k, j), index(k + 1, j + 1), index(k, j + 1)]);
+            }
+        }
+        // End caps: the start faces back along the wire, the end faces forward.
+        faces.push((0..tube_segments).map(|j| index(0, j)).collect());
+        faces.push((0..tube_segments).rev().map(|j| index(steps, j)).collect());
+
+        CSG::polyhedron(&points, &faces)
+    }
+
+    fn transformed(&self, matrix: &Matrix4<f64>) -> Self {
+        let linear = matrix.fixed_view::<3, 3>(0, 0).into_owned();
+        // A singular matrix squashes the solid flat, leaving nothing with any volume.
+        let Some(inverse) = linear.try_inverse() else {
+            return CSG::new();
+        };
+        let normal_matrix = inverse.transpose();
+        let mirrored = linear.determinant() < 0.0;
+
+        let mut csg = self.clone();
+        for poly in &mut csg.polygons {
+            for vertex in &mut poly.vertices {
+                vertex.pos = matrix.transform_point(&vertex.pos);
+                vertex.normal = (normal_matrix * vertex.normal).normalize();
+            }
+            if mirrored {
+                poly.vertices.reverse();
+            }
+            poly.plane.normal = (normal_matrix * poly.plane.normal).normalize();
+            if let Some(first) = poly.vertices.first() {
+                poly.plane.w = poly.plane.normal.dot(&first.pos.coords);
+            }
+        }
+        csg
+    }
+}
@@ -0,0 +1,311 @@
+use crate::export::Mesh;
+use crate::part::Part;
+use crate::primitives::Primitives;
+use crate::CSG;
+use csgrs::float_types::parry3d::query::{Ray, RayCast};
+use csgrs::float_types::parry3d::shape::TriMesh;
+use csgrs::polygon::Polygon;
+use csgrs::vertex::Vertex;
+use nalgebra::{Point2, Point3, UnitQuaternion, Vector3};
+use std::fmt;
+
+/// How close to the bed, in mm, a face must be to count as part of the first layer.
+const BED_TOLERANCE: f64 = 1e-6;
+
+/// Faces within this many degrees of facing straight down are printed as bridges.
+const FLAT_ANGLE: f64 = 1.0;
+
+/// A face of a part that is thinner than the minimum wall.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThinWall {
+    /// Index into [`PrintReport::mesh`]'s triangles.
+    pub triangle: usize,
+    /// Distance through the material behind the face, in mm.
+    pub thickness: f64,
+}
+
+/// A connected patch of downward facing, horizontal faces printed in mid air.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bridge {
+    pub triangles: Vec<usize>,
+    /// Longest distance across the patch to the walls holding it up, in mm.
+    pub span: f64,
+}
+
+/// Result of a [`PrintCheck`].
+///
+/// Faces are indices into the triangles of [`mesh`](PrintReport::mesh), which is the part as
+/// it sits on the bed. Any set of them can be turned back into a solid with
+/// [`faces`](PrintReport::faces) to highlight or export.
+#[derive(Debug, Clone, Default)]
+pub struct PrintReport {
+    pub mesh: Mesh,
+    /// Downward facing faces steeper than the overhang limit, other than bridges.
+    pub overhangs: Vec<usize>,
+    pub thin_walls: Vec<ThinWall>,
+    /// Bridges longer than the limit.
+    pub bridges: Vec<Bridge>,
+    /// Faces lying on the bed.
+    pub first_layer: Vec<usize>,
+    /// Area of the part in contact with the bed, in mm².
+    pub first_layer_area: f64,
+}
+
+impl PrintReport {
+    /// True if nothing needs support, nothing is too thin and the part touches the bed
+    pub fn is_printable(&self) -> bool {
+        self.overhangs.is_empty() && self.thin_walls.is_empty() && self.bridges.is_empty() && self.first_layer_area > 0.0
+    }
+
+    /// The triangles of the thin walls
+    pub fn thin_wall_faces(&self) -> Vec<usize> {
+        self.thin_walls.iter().map(|w| w.triangle).collect()
+    }
+
+    /// The triangles of the long bridges
+    pub fn bridge_faces(&self) -> Vec<usize> {
+        self.bridges.iter().flat_map(|b| b.triangles.iter().copied()).collect()
+    }
+
+    /// A set of faces as polygons, keeping the finish of the part they came from
+    pub fn faces(&self, triangles: &[usize]) -> CSG {
+        let polygons = triangles
+            .iter()
+            .map(|&t| {
+                let normal = self.mesh.normal(t);
+                let vertices = self.mesh.triangles[t].iter().map(|&i| Vertex::new(self.mesh.vertices[i], normal)).collect();
+                Polygon::new(vertices, false, self.mesh.finishes[t])
+            })
+            .collect();
+        CSG::from_polygons(polygons)
+    }
+}
+
+impl fmt::Display for PrintReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "first layer: {:.1} mm²", self.first_layer_area)?;
+        if self.first_layer_area <= 0.0 {
+            writeln!(f, "nothing touches the bed")?;
+        }
+        if !self.overhangs.is_empty() {
+            let area: f64 = self.overhangs.iter().map(|&t| triangle_area(&self.mesh, t)).sum();
+            writeln!(f, "{} overhanging faces, {:.1} mm²", self.overhangs.len(), area)?;
+        }
+        if let Some(thinnest) = self.thin_walls.iter().map(|w| w.thickness).reduce(f64::min) {
+            writeln!(f, "{} faces on thin walls, down to {:.3} mm", self.thin_walls.len(), thinnest)?;
+        }
+        for bridge in &self.bridges {
+            writeln!(f, "bridge spanning {:.1} mm", bridge.span)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks a part printed in a given orientation for overhangs, thin walls and long bridges.
+///
+/// ```ignore
+/// let report = PrintCheck::new()
+///     .with_max_overhang(50.0)
+///     .check_part(&corner_block, &UnitQuaternion::identity());
+/// assert!(report.overhangs.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrintCheck {
+    /// Steepest overhang that prints without support, in degrees from vertical.
+    pub max_overhang: f64,
+    /// Thinnest wall that prints reliably, in mm.
+    pub min_wall: f64,
+    /// Longest span that bridges cleanly, in mm.
+    pub max_bridge: f64,
+}
+
+impl Default for PrintCheck {
+    fn default() -> Self {
+        PrintCheck { max_overhang: 45.0, min_wall: 0.8, max_bridge: 10.0 }
+    }
+}
+
+impl PrintCheck {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_overhang(mut self, degrees: f64) -> Self {
+        self.max_overhang = degrees.clamp(0.0, 90.0);
+        self
+    }
+
+    pub fn with_min_wall(mut self, thickness: f64) -> Self {
+        self.min_wall = thickness.max(0.0);
+        self
+    }
+
+    pub fn with_max_bridge(mut self, span: f64) -> Self {
+        self.max_bridge = span.max(0.0);
+        self
+    }
+
+    /// Check a part rotated by `orientation` and dropped onto the bed
+    pub fn check_part<P: Part + ?Sized>(&self, part: &P, orientation: &UnitQuaternion<f64>) -> PrintReport {
+        self.check(&part.assemble(), orientation)
+    }
+
+    /// Check a solid rotated by `orientation` and dropped onto the bed
+    pub fn check(&self, solid: &CSG, orientation: &UnitQuaternion<f64>) -> PrintReport {
+        if solid.polygons.is_empty() {
+            return PrintReport::default();
+        }
+        let rotated = solid.transformed(&orientation.to_homogeneous());
+        let bed = rotated.bounding_box().mins.z;
+        let mesh = Mesh::from_csg(&rotated.translated(Vector3::new(0.0, 0.0, -bed)));
+
+        let overhang = -self.max_overhang.to_radians().sin();
+        let flat = -FLAT_ANGLE.to_radians().cos();
+        let mut report = PrintReport::default();
+        let mut flat_faces = Vec::new();
+        for t in 0..mesh.triangles.len() {
+            let nz = mesh.normal(t).z;
+            if nz >= overhang && nz >= flat {
+                continue;
+            }
+            let on_bed = mesh.triangles[t].iter().all(|&i| mesh.vertices[i].z.abs() < BED_TOLERANCE);
+            if on_bed && nz < flat {
+                report.first_layer.push(t);
+                report.first_layer_area += triangle_area(&mesh, t);
+            } else if nz < flat {
+                flat_faces.push(t);
+            } else if !on_bed {
+                report.overhangs.push(t);
+            }
+        }
+
+        report.bridges = patches(&mesh, &flat_faces)
+            .into_iter()
+            .map(|triangles| Bridge { span: span(&mesh, &triangles), triangles })
+            .filter(|b| b.span > self.max_bridge)
+            .collect();
+        report.thin_walls = self.thin_walls(&mesh);
+        report.mesh = mesh;
+        report
+    }
+
+    /// Faces with less than the minimum wall of material behind them, found by casting a ray
+    /// inwards from the middle of each
+    fn thin_walls(&self, mesh: &Mesh) -> Vec<ThinWall> {
+        if self.min_wall <= 0.0 || mesh.triangles.is_empty() {
+            return Vec::new();
+        }
+        let indices = mesh.triangles.iter().map(|t| t.map(|i| i as u32)).collect();
+        let Ok(trimesh) = TriMesh::new(mesh.vertices.clone(), indices) else {
+            return Vec::new();
+        };
+        let start = 1e-6;
+        (0..mesh.triangles.len())
+            .filter_map(|t| {
+                let [a, b, c] = mesh.triangles[t].map(|i| mesh.vertices[i].coords);
+                let inwards = -mesh.normal(t);
+                let origin = ((a + b + c) / 3.0 + inwards * start).into();
+                trimesh
+                    .cast_local_ray(&Ray::new(origin, inwards), self.min_wall - start, false)
+                    .map(|toi| ThinWall { triangle: t, thickness: toi + start })
+            })
+            .collect()
+    }
+}
+
+fn triangle_area(mesh: &Mesh, t: usize) -> f64 {
+    let [a, b, c] = mesh.triangles[t].map(|i| mesh.vertices[i]);
+    (b - a).cross(&(c - a)).norm() / 2.0
+}
+
+/// Group level triangles into patches that touch, including where one's corner lies part way
+/// along another's edge, as the BSP booleans often leave them
+fn patches(mesh: &Mesh, triangles: &[usize]) -> Vec<Vec<usize>> {
+    let corners = |t: usize| mesh.triangles[t].map(|i| mesh.vertices[i]);
+    let on_edge = |p: &Point3<f64>, t: usize| {
+        let c = corners(t);
+        (0..3).any(|e| {
+            let (a, b) = (c[e], c[(e + 1) % 3]);
+            let d = b - a;
+            let s = ((p - a).dot(&d) / d.norm_squared()).clamp(0.0, 1.0);
+            (p - (a + d * s)).norm() < BED_TOLERANCE
+        })
+    };
+    let touch = |i: usize, j: usize| {
+        (corners(i)[0].z - corners(j)[0].z).abs() < BED_TOLERANCE
+            && (corners(i).iter().any(|p| on_edge(p, j)) || corners(j).iter().any(|p| on_edge(p, i)))
+    };
+
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut unvisited: Vec<usize> = triangles.to_vec();
+    while let Some(seed) = unvisited.pop() {
+        let mut group = vec![seed];
+        let mut k = 0;
+        while k < group.len() {
+            let t = group[k];
+            let (near, far): (Vec<usize>, Vec<usize>) = unvisited.iter().partition(|&&u| touch(t, u));
+            group.extend(near);
+            unvisited = far;
+            k += 1;
+        }
+        group.sort_unstable();
+        groups.push(group);
+    }
+    groups.reverse();
+    groups
+}
+
+/// Distance a patch has to bridge: twice the furthest any point of it is from the top edge of
+/// a wall holding it up. A patch held up by nothing is measured corner to corner.
+fn span(mesh: &Mesh, triangles: &[usize]) -> f64 {
+    let corners = |t: usize| mesh.triangles[t].map(|i| mesh.vertices[i]);
+    let z = corners(triangles[0])[0].z;
+    let (lo, hi) = triangles
+        .iter()
+        .flat_map(|&t| corners(t))
+        .fold((Point2::from([f64::MAX; 2]), Point2::from([f64::MIN; 2])), |(lo, hi), p| {
+            (lo.inf(&p.xy()), hi.sup(&p.xy()))
+        });
+
+    // Edges at the height of the patch whose face runs down from them, near enough to hold it.
+    let level = |p: &Point3<f64>| (p.z - z).abs() < BED_TOLERANCE;
+    let near = |p: &Point2<f64>| {
+        p.x > lo.x - BED_TOLERANCE && p.x < hi.x + BED_TOLERANCE && p.y > lo.y - BED_TOLERANCE && p.y < hi.y + BED_TOLERANCE
+    };
+    let anchors: Vec<(Point2<f64>, Point2<f64>)> = (0..mesh.triangles.len())
+        .flat_map(|t| {
+            let c = corners(t);
+            (0..3).filter_map(move |e| {
+                let (a, b, other) = (c[e], c[(e + 1) % 3], c[(e + 2) % 3]);
+                (level(&a) && level(&b) && other.z < z - BED_TOLERANCE).then(|| (a.xy(), b.xy()))
+            })
+        })
+        .filter(|(a, b)| near(a) || near(b))
+        .collect();
+    if anchors.is_empty() {
+        return (hi - lo).norm();
+    }
+
+    // Sample each triangle on a grid fine enough to find the point furthest from support.
+    let step = (hi - lo).max().max(BED_TOLERANCE) / 64.0;
+    let mut furthest: f64 = 0.0;
+    for &t in triangles {
+        let [a, b, c] = corners(t).map(|p| p.xy());
+        let n = ((b - a).norm().max((c - b).norm()).max((a - c).norm()) / step).ceil().max(1.0) as usize;
+        for i in 0..=n {
+            for j in 0..=n - i {
+                let (u, v) = (i as f64 / n as f64, j as f64 / n as f64);
+                let p = a + (b - a) * u + (c - a) * v;
+                let nearest = anchors.iter().map(|(s, e)| segment_distance(&p, s, e)).fold(f64::MAX, f64::min);
+                furthest = furthest.max(nearest);
+            }
+        }
+    }
+    2.0 * furthest
+}
+
+fn segment_distance(p: &Point2<f64>, a: &Point2<f64>, b: &Point2<f64>) -> f64 {
+    let d = b - a;
+    let t = if d.norm_squared() > 0.0 { ((p - a).dot(&d) / d.norm_squared()).clamp(0.0, 1.0) } else { 0.0 };
+    (p - (a + d * t)).norm()
+}
@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::anchor::{self, Anchor};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Printed Box
 #[derive(Debug, Clone)]
@@ -11,22 +15,41 @@ pub struct PrintedBox {
 
 impl PrintedBox {
     /// Generate the outer box body
-    pub fn outer_body(&self) -> CSG<()> {
+    pub fn outer_body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the hollow interior
-    pub fn inner_body(&self) -> CSG<()> {
+    pub fn inner_body(&self) -> CSG {
         CSG::prism(
             self.width - 2.0 * self.wall_thickness,
             self.height - 2.0 * self.wall_thickness,
             self.depth - 2.0 * self.wall_thickness,
         )
+        .translated(Vector3::repeat(self.wall_thickness))
     }
 
     /// Assemble the complete printed box
-    pub fn assemble(&self) -> CSG<()> {
-        self.outer_body().difference(&self.inner_body())
+    pub fn assemble(&self) -> CSG {
+        self.outer_body().subtract(&self.inner_body())
     }
 }
 
+impl Part for PrintedBox {
+    fn name(&self) -> &str {
+        "PrintedBox"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        PrintedBox::assemble(self)
+    }
+
+    /// `top`, `bottom`, `front`, `back`, `left` and `right` on the outside faces.
+    fn anchors(&self) -> Vec<Anchor> {
+        anchor::box_faces(Vector3::zeros(), Vector3::new(self.width, self.height, self.depth))
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use crate::tolerance::Fit;
 
 /// Representation of a Printed Pulley
 #[derive(Debug, Clone)]
@@ -6,23 +9,25 @@ pub struct PrintedPulley {
     pub outer_diameter: f64,
     pub width: f64,
     pub bore_diameter: f64,
+    /// Fit of the motor shaft in the bore, usually [`Fit::Printed`].
+    pub bore_fit: Fit,
     pub tooth_count: usize,
     pub tooth_depth: f64,
 }
 
 impl PrintedPulley {
     /// Generate the pulley body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.width)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.width)
     }
 
     /// Generate the bore hole
-    pub fn bore(&self) -> CSG<()> {
-        CSG::cylinder_z(self.bore_diameter / 2.0, self.width + 1.0)
+    pub fn bore(&self) -> CSG {
+        CSG::z_cylinder(self.bore_fit.hole_diameter(self.bore_diameter) / 2.0, self.width + 1.0)
     }
 
     /// Generate the teeth
-    pub fn teeth(&self) -> CSG<()> {
+    pub fn teeth(&self) -> CSG {
         let tooth = CSG::prism(self.tooth_depth, self.width, self.tooth_depth);
         let mut teeth = CSG::new();
         let angle_step = 360.0 / self.tooth_count as f64;
@@ -36,8 +41,21 @@ impl PrintedPulley {
     }
 
     /// Assemble the complete pulley
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.bore()).union(&self.teeth())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.bore()).union(&self.teeth())
     }
 }
 
+impl Part for PrintedPulley {
+    fn name(&self) -> &str {
+        "PrintedPulley"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        PrintedPulley::assemble(self)
+    }
+}
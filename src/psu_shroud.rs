@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::anchor::{self, Anchor};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Power Supply Unit (PSU) Shroud
 #[derive(Debug, Clone)]
@@ -13,27 +17,44 @@ pub struct PSUShroud {
 
 impl PSUShroud {
     /// Generate the shroud body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate vent holes
-    pub fn vent_holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.vent_hole_diameter / 2.0, self.depth + 1.0);
+    pub fn vent_holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.vent_hole_diameter / 2.0, self.depth + 1.0);
         let mut holes = CSG::new();
         let start_x = -((self.vent_hole_count as f64 - 1.0) / 2.0) * self.vent_hole_spacing;
 
         for i in 0..self.vent_hole_count {
             let x_offset = start_x + (i as f64) * self.vent_hole_spacing;
-            holes = holes.union(&hole.translate(Vector3::new(x_offset, 0.0, self.height / 2.0)));
+            holes = holes.union(&hole.translated(Vector3::new(x_offset, 0.0, self.height / 2.0)));
         }
 
         holes
     }
 
     /// Assemble the complete PSU shroud
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.vent_holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.vent_holes())
     }
 }
 
+impl Part for PSUShroud {
+    fn name(&self) -> &str {
+        "PSUShroud"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        PSUShroud::assemble(self)
+    }
+
+    fn anchors(&self) -> Vec<Anchor> {
+        anchor::box_faces(Vector3::zeros(), Vector3::new(self.width, self.height, self.depth))
+    }
+}
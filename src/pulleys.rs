@@ -1,5 +1,8 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a pulley
 #[derive(Debug, Clone)]
@@ -14,23 +17,24 @@ pub struct Pulley {
 
 impl Pulley {
     /// Generate the outer cylindrical body of the pulley
-    pub fn outer_body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.width)
+    pub fn outer_body(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.width)
+            .finished(Material::Aluminium)
     }
     
     /// Generate the inner hole of the pulley
-    pub fn hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.inner_diameter / 2.0, self.width)
+    pub fn hole(&self) -> CSG {
+        CSG::z_cylinder(self.inner_diameter / 2.0, self.width)
     }
     
     /// Generate the grooves on the pulley
-    pub fn grooves(&self) -> CSG<()> {
+    pub fn grooves(&self) -> CSG {
         let mut grooves = CSG::new();
         let spacing = self.width / (self.groove_count as f64 + 1.0);
         
         for i in 1..=self.groove_count {
-            let groove = CSG::cylinder_z((self.outer_diameter / 2.0) - self.groove_depth, spacing / 2.0)
-                .translate(Vector3::new(0.0, 0.0, i as f64 * spacing - (self.width / 2.0)));
+            let groove = CSG::z_cylinder((self.outer_diameter / 2.0) - self.groove_depth, spacing / 2.0)
+                .translated(Vector3::new(0.0, 0.0, i as f64 * spacing - (self.width / 2.0)));
             grooves = grooves.union(&groove);
         }
         
@@ -38,7 +42,7 @@ impl Pulley {
     }
     
     /// Generate the full pulley model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut pulley = self.outer_body();
         let hole = self.hole();
         let grooves = self.grooves();
@@ -46,6 +50,20 @@ impl Pulley {
         pulley = pulley.subtract(&hole);
         pulley = pulley.subtract(&grooves);
         
-        pulley
+        pulley.finished_default(Material::Aluminium)
+    }
+}
+
+impl Part for Pulley {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        Pulley::assemble(self)
     }
 }
@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a linear rail
 #[derive(Debug, Clone)]
@@ -14,18 +16,18 @@ pub struct Rail {
 
 impl Rail {
     /// Generate the main rectangular body of the rail
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.length)
     }
     
     /// Generate the mounting holes along the rail
-    pub fn holes(&self) -> CSG<()> {
+    pub fn holes(&self) -> CSG {
         let mut holes = CSG::new();
         let num_holes = (self.length / self.hole_spacing).floor() as i32;
         
         for i in 0..num_holes {
-            let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.height)
-                .translate(Vector3::new(0.0, 0.0, i as f64 * self.hole_spacing - (self.length / 2.0)));
+            let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.height)
+                .translated(Vector3::new(0.0, 0.0, i as f64 * self.hole_spacing - (self.length / 2.0)));
             holes = holes.union(&hole);
         }
         
@@ -33,7 +35,7 @@ impl Rail {
     }
     
     /// Generate the full rail model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut rail = self.body();
         let holes = self.holes();
         
@@ -43,3 +45,16 @@ impl Rail {
     }
 }
 
+impl Part for Rail {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        Rail::assemble(self)
+    }
+}
@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a relay module
 #[derive(Debug, Clone)]
@@ -16,18 +18,18 @@ pub struct Relay {
 
 impl Relay {
     /// Generate the rectangular body of the relay
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
     }
     
     /// Generate the coil of the relay
-    pub fn coil(&self) -> CSG<()> {
-        CSG::cylinder_z(self.coil_diameter / 2.0, self.coil_height)
-            .translate(Vector3::new(0.0, 0.0, self.body_depth))
+    pub fn coil(&self) -> CSG {
+        CSG::z_cylinder(self.coil_diameter / 2.0, self.coil_height)
+            .translated(Vector3::new(0.0, 0.0, self.body_depth))
     }
     
     /// Generate the contacts of the relay
-    pub fn contacts(&self) -> CSG<()> {
+    pub fn contacts(&self) -> CSG {
         let mut contacts = CSG::new();
         let offsets = [
             (-self.contact_spacing / 2.0, 0.0),
@@ -35,8 +37,8 @@ impl Relay {
         ];
         
         for &(x, y) in &offsets {
-            let contact = CSG::cylinder_z(self.contact_diameter / 2.0, self.body_depth / 2.0)
-                .translate(Vector3::new(x, y, -self.body_depth / 2.0));
+            let contact = CSG::z_cylinder(self.contact_diameter / 2.0, self.body_depth / 2.0)
+                .translated(Vector3::new(x, y, -self.body_depth / 2.0));
             contacts = contacts.union(&contact);
         }
         
@@ -44,7 +46,7 @@ impl Relay {
     }
     
     /// Generate the full relay model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut relay = self.body();
         let coil = self.coil();
         let contacts = self.contacts();
@@ -56,3 +58,16 @@ impl Relay {
     }
 }
 
+impl Part for Relay {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Relay::assemble(self)
+    }
+}
@@ -1,5 +1,9 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::colour::Colour;
+use crate::material::{Finish, Finishing, Material, TINNED_COPPER};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a resistor module
 #[derive(Debug, Clone)]
@@ -13,22 +17,24 @@ pub struct Resistor {
 
 impl Resistor {
     /// Generate the cylindrical body of the resistor
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_length)
+            .finished(Finish::new(Colour::rgb(0.82, 0.71, 0.55), Material::Plastic))
     }
     
     /// Generate the leads of the resistor
-    pub fn leads(&self) -> CSG<()> {
-        let lead1 = CSG::cylinder_z(self.lead_diameter / 2.0, self.lead_length)
-            .translate(Vector3::new(0.0, 0.0, -self.lead_length));
-        let lead2 = CSG::cylinder_z(self.lead_diameter / 2.0, self.lead_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_length));
+    pub fn leads(&self) -> CSG {
+        let lead1 = CSG::z_cylinder(self.lead_diameter / 2.0, self.lead_length)
+            .translated(Vector3::new(0.0, 0.0, -self.lead_length));
+        let lead2 = CSG::z_cylinder(self.lead_diameter / 2.0, self.lead_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_length));
         
         lead1.union(&lead2)
+            .finished(TINNED_COPPER)
     }
     
     /// Generate the full resistor model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut resistor = self.body();
         let leads = self.leads();
         
@@ -38,3 +44,16 @@ impl Resistor {
     }
 }
 
+impl Part for Resistor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Resistor::assemble(self)
+    }
+}
@@ -0,0 +1,90 @@
+use std::cell::Cell;
+use std::f64::consts::TAU;
+use std::sync::RwLock;
+
+/// How finely curved surfaces are faceted, the equivalent of OpenSCAD's `$fn`, `$fa` and `$fs`.
+///
+/// Every curved primitive asks the [current](current) resolution how many segments to use, so
+/// the same part can be built as a quick low-poly preview or a smooth export mesh.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Resolution {
+    /// Fixed number of segments for every circle, like `$fn`. Zero uses `min_angle` and `min_size`.
+    pub segments: usize,
+    /// Largest angle in degrees a single segment may span, like `$fa`.
+    pub min_angle: f64,
+    /// Shortest a segment needs to be, like `$fs`.
+    pub min_size: f64,
+}
+
+impl Resolution {
+    /// 32 segments on every circle, which is what the library has always used.
+    pub const DEFAULT: Resolution = Resolution::fixed(32);
+
+    /// OpenSCAD's own defaults, `$fa = 12, $fs = 2`, coarse enough for quick previews.
+    pub const PREVIEW: Resolution = Resolution::adaptive(12.0, 2.0);
+
+    /// NopSCADLib's `$fa = 6, $fs = 0.5`, for final renders and exported meshes.
+    pub const FINE: Resolution = Resolution::adaptive(6.0, 0.5);
+
+    /// The same number of segments regardless of size, like setting `$fn`.
+    pub const fn fixed(segments: usize) -> Self {
+        Resolution { segments, min_angle: 12.0, min_size: 2.0 }
+    }
+
+    /// Segments sized from the radius, like setting `$fa` and `$fs` with `$fn = 0`.
+    pub const fn adaptive(min_angle: f64, min_size: f64) -> Self {
+        Resolution { segments: 0, min_angle, min_size }
+    }
+
+    /// Number of segments for a circle of radius `r`, following OpenSCAD's `get_fragments_from_r()`.
+    pub fn fragments(&self, r: f64) -> usize {
+        if r < 1e-6 {
+            3
+        } else if self.segments > 0 {
+            self.segments.max(3)
+        } else {
+            (360.0 / self.min_angle).min(r * TAU / self.min_size).max(5.0).ceil() as usize
+        }
+    }
+}
+
+impl Default for Resolution {
+    fn default() -> Self {
+        Resolution::DEFAULT
+    }
+}
+
+static GLOBAL: RwLock<Resolution> = RwLock::new(Resolution::DEFAULT);
+
+thread_local! {
+    static SCOPED: Cell<Option<Resolution>> = const { Cell::new(None) };
+}
+
+/// Resolution used by parts built from now on, on every thread, unless overridden with [`with`].
+pub fn set_global(resolution: Resolution) {
+    *GLOBAL.write().unwrap_or_else(|e| e.into_inner()) = resolution;
+}
+
+/// The resolution in effect for the current thread.
+pub fn current() -> Resolution {
+    SCOPED
+        .get()
+        .unwrap_or_else(|| *GLOBAL.read().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Run `f` with a different resolution on this thread, e.g. `with(Resolution::PREVIEW, || fan.assemble())`.
+///
+/// Like `$fn` in an OpenSCAD module call, the override applies to everything `f` builds and is
+/// undone afterwards, even if `f` panics.
+pub fn with<T>(resolution: Resolution, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<Resolution>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SCOPED.set(self.0);
+        }
+    }
+
+    let _restore = Restore(SCOPED.replace(Some(resolution)));
+    f()
+}
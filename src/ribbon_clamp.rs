@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Ribbon Cable Clamp
 #[derive(Debug, Clone)]
@@ -12,19 +15,32 @@ pub struct RibbonClamp {
 
 impl RibbonClamp {
     /// Generate the clamp body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the ribbon slot
-    pub fn slot(&self) -> CSG<()> {
+    pub fn slot(&self) -> CSG {
         CSG::prism(self.slot_width, self.height, self.slot_height)
-            .translate(Vector3::new(0.0, 0.0, self.depth / 2.0))
+            .translated(Vector3::new(0.0, 0.0, self.depth / 2.0))
     }
 
     /// Assemble the complete ribbon clamp
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.slot())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.slot())
     }
 }
 
+impl Part for RibbonClamp {
+    fn name(&self) -> &str {
+        "RibbonClamp"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        RibbonClamp::assemble(self)
+    }
+}
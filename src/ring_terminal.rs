@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Ring Terminal module
 #[derive(Debug, Clone)]
@@ -13,21 +16,22 @@ pub struct RingTerminal {
 
 impl RingTerminal {
     /// Generate the ring of the terminal
-    pub fn ring(&self) -> CSG<()> {
-        let outer = CSG::cylinder_z(self.outer_diameter / 2.0, self.terminal_thickness);
-        let inner = CSG::cylinder_z(self.inner_diameter / 2.0, self.terminal_thickness);
+    pub fn ring(&self) -> CSG {
+        let outer = CSG::z_cylinder(self.outer_diameter / 2.0, self.terminal_thickness);
+        let inner = CSG::z_cylinder(self.inner_diameter / 2.0, self.terminal_thickness);
         
         outer.subtract(&inner)
     }
     
-    /// Generate the terminal tab
-    pub fn tab(&self) -> CSG<()> {
+    /// Generate the terminal tab, running out along +X from the middle of the ring
+    pub fn tab(&self) -> CSG {
+        let start = (self.outer_diameter + self.inner_diameter) / 4.0;
         CSG::prism(self.terminal_length, self.terminal_width, self.terminal_thickness)
-            .translate(Vector3::new(0.0, self.outer_diameter / 2.0, 0.0))
+            .translated(Vector3::new(start, -self.terminal_width / 2.0, 0.0))
     }
     
     /// Generate the full ring terminal model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut terminal = self.ring();
         let tab = self.tab();
         
@@ -37,3 +41,16 @@ impl RingTerminal {
     }
 }
 
+impl Part for RingTerminal {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Connector
+    }
+
+    fn assemble(&self) -> CSG {
+        RingTerminal::assemble(self)
+    }
+}
@@ -1,4 +1,9 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a Rocker Switch
 #[derive(Debug, Clone)]
@@ -12,19 +17,53 @@ pub struct RockerSwitch {
 
 impl RockerSwitch {
     /// Generate the outer casing of the switch
-    pub fn casing(&self) -> CSG<()> {
+    pub fn casing(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
+            .finished(Material::Plastic)
     }
 
     /// Generate the rocker part
-    pub fn rocker(&self) -> CSG<()> {
+    pub fn rocker(&self) -> CSG {
         CSG::prism(self.rocker_width, self.rocker_height, self.depth / 2.0)
-            .translate(Vector3::new(0.0, 0.0, self.depth / 4.0))
+            .translated(Vector3::new(0.0, 0.0, self.depth / 4.0))
+            .finished(Material::Plastic)
     }
 
     /// Assemble the full rocker switch
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.casing().union(&self.rocker())
     }
 }
 
+impl Part for RockerSwitch {
+    fn name(&self) -> &str {
+        "RockerSwitch"
+    }
+
+    fn category(&self) -> Category {
+        Category::Switch
+    }
+
+    fn assemble(&self) -> CSG {
+        RockerSwitch::assemble(self)
+    }
+}
+
+impl Mountable for RockerSwitch {
+    /// Rocker switches snap into their panel hole, so there are no screws.
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        Vec::new()
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        0.0
+    }
+
+    /// A rectangular hole the casing pushes through.
+    fn panel_cutout(&self, clearance: f64) -> CSG {
+        CSG::cube(Some((
+            &[self.width / 2.0, self.height / 2.0, self.depth],
+            &[self.width / 2.0 + clearance, self.height / 2.0 + clearance, mounting::CUTOUT_DEPTH / 2.0],
+        )))
+    }
+}
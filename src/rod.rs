@@ -1,21 +1,45 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use crate::tolerance::Fit;
 
 /// Representation of a Cylindrical Rod
 #[derive(Debug, Clone)]
 pub struct Rod {
     pub diameter: f64,
     pub length: f64,
+    /// Fit in the bores the rod runs through, which sets its made diameter.
+    pub fit: Fit,
 }
 
 impl Rod {
     /// Generate the rod body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.diameter / 2.0, self.length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.fit.shaft_diameter(self.diameter) / 2.0, self.length)
+            .finished(Material::StainlessSteel)
     }
 
     /// Assemble the complete rod
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.body()
     }
 }
 
+impl Part for Rod {
+    fn name(&self) -> &str {
+        "Rod"
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        Rod::assemble(self)
+    }
+
+    fn description(&self) -> String {
+        format!("Rod {}mm x {}mm", self.diameter, self.length)
+    }
+}
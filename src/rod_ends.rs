@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Rod End Bearing module
 #[derive(Debug, Clone)]
@@ -13,23 +17,26 @@ pub struct RodEnd {
 
 impl RodEnd {
     /// Generate the spherical ball of the rod end bearing
-    pub fn ball(&self) -> CSG<()> {
-        CSG::sphere(self.ball_diameter / 2.0)
+    pub fn ball(&self) -> CSG {
+        CSG::ball(self.ball_diameter / 2.0)
+            .finished(Material::StainlessSteel)
     }
     
     /// Generate the housing of the rod end bearing
-    pub fn housing(&self) -> CSG<()> {
-        CSG::cylinder_z(self.housing_diameter / 2.0, self.housing_thickness)
+    pub fn housing(&self) -> CSG {
+        CSG::z_cylinder(self.housing_diameter / 2.0, self.housing_thickness)
+            .finished(Material::Steel)
     }
     
     /// Generate the shank of the rod end bearing
-    pub fn shank(&self) -> CSG<()> {
-        CSG::cylinder_z(self.shank_diameter / 2.0, self.shank_length)
-            .translate(Vector3::new(0.0, 0.0, -self.shank_length))
+    pub fn shank(&self) -> CSG {
+        CSG::z_cylinder(self.shank_diameter / 2.0, self.shank_length)
+            .translated(Vector3::new(0.0, 0.0, -self.shank_length))
+            .finished(Material::Steel)
     }
     
     /// Generate the full rod end bearing model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut rod_end = self.housing();
         let ball = self.ball();
         let shank = self.shank();
@@ -41,3 +48,16 @@ impl RodEnd {
     }
 }
 
+impl Part for RodEnd {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        RodEnd::assemble(self)
+    }
+}
@@ -1,29 +1,121 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
-/// Representation of a Variac Transformer
+/// Representation of an SBR supported linear rail: a hardened rod on an aluminium support
+/// that is screwed down along its whole length.
+///
+/// The rail runs along X from the origin, with the base on Z = 0.
 #[derive(Debug, Clone)]
-pub struct Variac {
-    pub base_diameter: f64,
-    pub height: f64,
-    pub knob_diameter: f64,
-    pub knob_height: f64,
+pub struct SbrRail {
+    pub name: String,
+    pub length: f64,
+    pub rod_diameter: f64,
+    /// Height of the rod centre above the base.
+    pub rod_height: f64,
+    pub base_width: f64,
+    pub base_thickness: f64,
+    /// Width of the web that holds the rod.
+    pub web_width: f64,
+    /// Distance between the two rows of screw holes.
+    pub hole_spacing: f64,
+    /// Distance between screws along the rail.
+    pub hole_pitch: f64,
+    pub hole_diameter: f64,
 }
 
-impl Variac {
-    /// Generate the transformer base
-    pub fn base(&self) -> CSG<()> {
-        CSG::cylinder_z(self.base_diameter / 2.0, self.height)
+impl SbrRail {
+    /// Generate the aluminium support
+    pub fn support(&self) -> CSG {
+        let base = CSG::prism(self.length, self.base_width, self.base_thickness)
+            .translated(Vector3::new(0.0, -self.base_width / 2.0, 0.0));
+        let web = CSG::prism(self.length, self.web_width, self.rod_height)
+            .translated(Vector3::new(0.0, -self.web_width / 2.0, 0.0));
+        base.union(&web)
+            .subtract(&self.rod())
+            .subtract(&mounting::holes(&self.hole_positions(), self.hole_diameter, self.base_thickness))
+            .finished(Material::Aluminium)
     }
 
-    /// Generate the adjustment knob
-    pub fn knob(&self) -> CSG<()> {
-        CSG::cylinder_z(self.knob_diameter / 2.0, self.knob_height)
-            .translate(Vector3::new(0.0, 0.0, self.height))
+    /// Generate the hardened rod
+    pub fn rod(&self) -> CSG {
+        CSG::z_cylinder(self.rod_diameter / 2.0, self.length)
+            .rotate(0.0, 90.0, 0.0)
+            .translated(Vector3::new(0.0, 0.0, self.rod_height))
+            .finished(Material::Steel)
     }
 
-    /// Assemble the full Variac transformer
-    pub fn assemble(&self) -> CSG<()> {
-        self.base().union(&self.knob())
+    /// Assemble the complete rail
+    pub fn assemble(&self) -> CSG {
+        self.support().union(&self.rod())
     }
 }
 
+/// SBR rail in NopSCADLib's `sbr_rails.scad` sizes
+#[allow(clippy::too_many_arguments)]
+fn sbr(name: &str, rod_diameter: f64, rod_height: f64, base_width: f64, web_width: f64, hole_spacing: f64, hole_diameter: f64, length: f64) -> SbrRail {
+    SbrRail {
+        name: name.to_string(),
+        length,
+        rod_diameter,
+        rod_height,
+        base_width,
+        base_thickness: 5.0,
+        web_width,
+        hole_spacing,
+        hole_pitch: 150.0,
+        hole_diameter,
+    }
+}
+
+pub fn sbr12(length: f64) -> SbrRail {
+    sbr("SBR12", 12.0, 22.0, 34.0, 8.0, 24.0, 4.5, length)
+}
+
+pub fn sbr16(length: f64) -> SbrRail {
+    sbr("SBR16", 16.0, 25.0, 40.0, 10.0, 30.0, 4.5, length)
+}
+
+pub fn sbr20(length: f64) -> SbrRail {
+    sbr("SBR20", 20.0, 27.0, 45.0, 12.0, 30.0, 5.5, length)
+}
+
+impl Part for SbrRail {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        SbrRail::assemble(self)
+    }
+
+    fn description(&self) -> String {
+        format!("{} rail x {}mm", self.name, self.length)
+    }
+}
+
+impl Mountable for SbrRail {
+    /// Pairs of screws either side of the web, a pitch apart and centred along the rail.
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        let count = (self.length / self.hole_pitch).floor().max(0.0) as usize + 1;
+        let start = (self.length - (count - 1) as f64 * self.hole_pitch) / 2.0;
+        let points: Vec<(f64, f64)> = (0..count)
+            .flat_map(|i| {
+                let x = start + i as f64 * self.hole_pitch;
+                [(x, -self.hole_spacing / 2.0), (x, self.hole_spacing / 2.0)]
+            })
+            .collect();
+        mounting::on_bottom(&points, 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.hole_diameter
+    }
+}
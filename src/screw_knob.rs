@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Screw Knob
 #[derive(Debug, Clone)]
@@ -11,19 +14,32 @@ pub struct ScrewKnob {
 
 impl ScrewKnob {
     /// Generate the main knob body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.height)
     }
 
     /// Generate the screw shaft
-    pub fn screw(&self) -> CSG<()> {
-        CSG::cylinder_z(self.screw_diameter / 2.0, self.screw_length)
-            .translate(Vector3::new(0.0, 0.0, -self.screw_length))
+    pub fn screw(&self) -> CSG {
+        CSG::z_cylinder(self.screw_diameter / 2.0, self.screw_length)
+            .translated(Vector3::new(0.0, 0.0, -self.screw_length))
     }
 
     /// Assemble the complete screw knob
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.body().union(&self.screw())
     }
 }
 
+impl Part for ScrewKnob {
+    fn name(&self) -> &str {
+        "ScrewKnob"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        ScrewKnob::assemble(self)
+    }
+}
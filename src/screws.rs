@@ -1,5 +1,25 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::cache;
+use crate::anchor::Anchor;
+use crate::error::{inconsistent, positive, PartError};
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
+
+/// Stock lengths up to 16mm, after which screws come in 5mm steps.
+pub const SHORT_LENGTHS: [f64; 8] = [3.0, 4.0, 5.0, 6.0, 8.0, 10.0, 12.0, 16.0];
+
+/// Shortest stock screw length of at least `length`, like NopSCADLib's `screw_longer_than()`
+pub fn screw_longer_than(length: f64) -> f64 {
+    // Lengths that come out a rounding error over a stock size still get that size.
+    let length = length - 1e-9;
+    SHORT_LENGTHS
+        .iter()
+        .copied()
+        .find(|&stock| stock >= length)
+        .unwrap_or_else(|| (length / 5.0).ceil() * 5.0)
+}
 
 /// Representation of a screw
 #[derive(Debug, Clone)]
@@ -13,22 +33,87 @@ pub struct Screw {
 }
 
 impl Screw {
+    /// A screw checked by [`validate`](Screw::validate)
+    pub fn new(
+        name: &str,
+        diameter: f64,
+        length: f64,
+        head_diameter: f64,
+        head_height: f64,
+        thread_pitch: f64,
+    ) -> Result<Screw, PartError> {
+        let screw = Screw { name: name.to_string(), diameter, length, head_diameter, head_height, thread_pitch };
+        screw.validate()?;
+        Ok(screw)
+    }
+
+    /// Check the dimensions are positive and the head is wider than the shank
+    pub fn validate(&self) -> Result<(), PartError> {
+        positive("diameter", self.diameter)?;
+        positive("length", self.length)?;
+        positive("head_diameter", self.head_diameter)?;
+        positive("head_height", self.head_height)?;
+        positive("thread_pitch", self.thread_pitch)?;
+        if self.head_diameter <= self.diameter {
+            return Err(inconsistent(&self.name, "the head is no wider than the shank"));
+        }
+        Ok(())
+    }
+
+    /// The same screw in another length
+    pub fn with_length(&self, length: f64) -> Screw {
+        Screw { length, ..self.clone() }
+    }
+
     /// Generate a cylindrical screw body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.diameter / 2.0, self.length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.diameter / 2.0, self.length)
+            .finished(Material::Steel)
     }
     
     /// Generate the screw head
-    pub fn head(&self) -> CSG<()> {
-        CSG::cylinder_z(self.head_diameter / 2.0, self.head_height)
+    pub fn head(&self) -> CSG {
+        CSG::z_cylinder(self.head_diameter / 2.0, self.head_height)
+            .finished(Material::Steel)
     }
     
     /// Generate the full screw model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut screw = self.body();
-        let head = self.head().translate(Vector3::new(0.0, 0.0, self.length));
+        let head = self.head().translated(Vector3::new(0.0, 0.0, self.length));
         screw = screw.union(&head);
         
         screw
     }
 }
+
+impl Part for Screw {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Fastener
+    }
+
+    fn assemble(&self) -> CSG {
+        Screw::assemble(self)
+    }
+
+    fn cache_key(&self) -> Option<String> {
+        Some(cache::debug_key(self))
+    }
+
+    fn description(&self) -> String {
+        format!("Screw {} x {}mm", self.name, self.length)
+    }
+
+    /// `tip` at the end of the shank, `head_underside` where the head seats and `head_top`.
+    fn anchors(&self) -> Vec<Anchor> {
+        vec![
+            Anchor::facing("tip", Vector3::zeros(), -Vector3::z()),
+            Anchor::facing("head_underside", Vector3::new(0.0, 0.0, self.length), -Vector3::z()),
+            Anchor::facing("head_top", Vector3::new(0.0, 0.0, self.length + self.head_height), Vector3::z()),
+        ]
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Sealing Strip
 #[derive(Debug, Clone)]
@@ -12,19 +15,32 @@ pub struct SealingStrip {
 
 impl SealingStrip {
     /// Generate the strip body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.length, self.height)
     }
 
     /// Generate the groove in the strip
-    pub fn groove(&self) -> CSG<()> {
+    pub fn groove(&self) -> CSG {
         CSG::prism(self.groove_width, self.length, self.groove_depth)
-            .translate(Vector3::new(0.0, 0.0, -self.groove_depth / 2.0))
+            .translated(Vector3::new(0.0, 0.0, -self.groove_depth / 2.0))
     }
 
     /// Assemble the complete sealing strip
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.groove())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.groove())
     }
 }
 
+impl Part for SealingStrip {
+    fn name(&self) -> &str {
+        "SealingStrip"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structural
+    }
+
+    fn assemble(&self) -> CSG {
+        SealingStrip::assemble(self)
+    }
+}
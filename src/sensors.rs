@@ -1,5 +1,8 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a sensor
 #[derive(Debug, Clone)]
@@ -16,37 +19,23 @@ pub struct Sensor {
 
 impl Sensor {
     /// Generate the body of the sensor
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
     }
     
     /// Generate the lens of the sensor
-    pub fn lens(&self) -> CSG<()> {
-        CSG::cylinder_z(self.lens_diameter / 2.0, self.lens_depth)
-            .translate(Vector3::new(0.0, 0.0, self.body_depth))
+    pub fn lens(&self) -> CSG {
+        CSG::z_cylinder(self.lens_diameter / 2.0, self.lens_depth)
+            .translated(Vector3::new(0.0, 0.0, self.body_depth))
     }
     
     /// Generate the mounting holes
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-            (-self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.body_depth)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.body_depth)
     }
     
     /// Generate the full sensor model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut sensor = self.body();
         let lens = self.lens();
         let holes = self.mounting_holes();
@@ -58,3 +47,26 @@ impl Sensor {
     }
 }
 
+impl Part for Sensor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Sensor::assemble(self)
+    }
+}
+
+impl Mountable for Sensor {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::square(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Servo Motor
 #[derive(Debug, Clone)]
@@ -13,25 +16,38 @@ pub struct ServoMotor {
 
 impl ServoMotor {
     /// Generate the main servo body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
     }
 
     /// Generate the rotating shaft
-    pub fn shaft(&self) -> CSG<()> {
-        CSG::cylinder_z(self.shaft_diameter / 2.0, self.shaft_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_height / 2.0))
+    pub fn shaft(&self) -> CSG {
+        CSG::z_cylinder(self.shaft_diameter / 2.0, self.shaft_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_height / 2.0))
     }
 
     /// Generate the horn
-    pub fn horn(&self) -> CSG<()> {
-        CSG::cylinder_z(self.horn_diameter / 2.0, self.shaft_length / 2.0)
-            .translate(Vector3::new(0.0, 0.0, self.body_height / 2.0 + self.shaft_length))
+    pub fn horn(&self) -> CSG {
+        CSG::z_cylinder(self.horn_diameter / 2.0, self.shaft_length / 2.0)
+            .translated(Vector3::new(0.0, 0.0, self.body_height / 2.0 + self.shaft_length))
     }
 
     /// Assemble the complete servo motor
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.body().union(&self.shaft()).union(&self.horn())
     }
 }
 
+impl Part for ServoMotor {
+    fn name(&self) -> &str {
+        "ServoMotor"
+    }
+
+    fn category(&self) -> Category {
+        Category::Motor
+    }
+
+    fn assemble(&self) -> CSG {
+        ServoMotor::assemble(self)
+    }
+}
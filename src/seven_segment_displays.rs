@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a seven-segment display module
 #[derive(Debug, Clone)]
@@ -17,12 +19,12 @@ pub struct SevenSegmentDisplay {
 
 impl SevenSegmentDisplay {
     /// Generate a single digit of the display
-    pub fn digit(&self) -> CSG<()> {
+    pub fn digit(&self) -> CSG {
         CSG::prism(self.digit_width, self.digit_height, self.digit_depth)
     }
     
     /// Generate the segments within a digit
-    pub fn segments(&self) -> CSG<()> {
+    pub fn segments(&self) -> CSG {
         let mut segments = CSG::new();
         let positions = [
             (-self.segment_width, self.segment_height / 2.0),
@@ -33,7 +35,7 @@ impl SevenSegmentDisplay {
         
         for &(x, y) in &positions {
             let segment = CSG::prism(self.segment_width, self.segment_height, self.segment_depth)
-                .translate(Vector3::new(x, y, self.digit_depth / 2.0));
+                .translated(Vector3::new(x, y, self.digit_depth / 2.0));
             segments = segments.union(&segment);
         }
         
@@ -41,14 +43,14 @@ impl SevenSegmentDisplay {
     }
     
     /// Generate the full seven-segment display model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut display = CSG::new();
         let start_x = -((self.num_digits as f64 - 1.0) * self.digit_spacing) / 2.0;
         
         for i in 0..self.num_digits {
             let x_offset = start_x + i as f64 * self.digit_spacing;
-            let digit = self.digit().translate(Vector3::new(x_offset, 0.0, 0.0));
-            let segments = self.segments().translate(Vector3::new(x_offset, 0.0, 0.0));
+            let digit = self.digit().translated(Vector3::new(x_offset, 0.0, 0.0));
+            let segments = self.segments().translated(Vector3::new(x_offset, 0.0, 0.0));
             
             display = display.union(&digit);
             display = display.union(&segments);
@@ -58,3 +60,16 @@ impl SevenSegmentDisplay {
     }
 }
 
+impl Part for SevenSegmentDisplay {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Display
+    }
+
+    fn assemble(&self) -> CSG {
+        SevenSegmentDisplay::assemble(self)
+    }
+}
@@ -1,4 +1,9 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::error::{inconsistent, largest_bore, positive, PartError};
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use crate::tolerance::Fit;
 
 /// Representation of a Shaft Coupling
 #[derive(Debug, Clone)]
@@ -6,22 +11,57 @@ pub struct ShaftCoupling {
     pub outer_diameter: f64,
     pub length: f64,
     pub bore_diameter: f64,
+    /// Fit of the shafts in the bore.
+    pub bore_fit: Fit,
 }
 
 impl ShaftCoupling {
+    /// A coupling checked by [`validate`](ShaftCoupling::validate)
+    pub fn new(outer_diameter: f64, length: f64, bore_diameter: f64, bore_fit: Fit) -> Result<ShaftCoupling, PartError> {
+        let coupling = ShaftCoupling { outer_diameter, length, bore_diameter, bore_fit };
+        coupling.validate()?;
+        Ok(coupling)
+    }
+
+    /// Check the dimensions are positive and the bore, opened up by its fit, is inside the body
+    pub fn validate(&self) -> Result<(), PartError> {
+        positive("outer_diameter", self.outer_diameter)?;
+        positive("length", self.length)?;
+        positive("bore_diameter", self.bore_diameter)?;
+        if largest_bore(self.name(), &self.bore_fit, self.bore_diameter)? >= self.outer_diameter {
+            return Err(inconsistent(self.name(), "the bore is wider than the coupling"));
+        }
+        Ok(())
+    }
+
     /// Generate the outer body of the coupling
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.length)
+            .finished(Material::Aluminium)
     }
 
     /// Generate the bore hole
-    pub fn bore(&self) -> CSG<()> {
-        CSG::cylinder_z(self.bore_diameter / 2.0, self.length + 1.0)
+    pub fn bore(&self) -> CSG {
+        CSG::z_cylinder(self.bore_fit.hole_diameter(self.bore_diameter) / 2.0, self.length + 1.0)
     }
 
     /// Assemble the shaft coupling
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.bore())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.bore())
+            .finished_default(Material::Aluminium)
     }
 }
 
+impl Part for ShaftCoupling {
+    fn name(&self) -> &str {
+        "ShaftCoupling"
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        ShaftCoupling::assemble(self)
+    }
+}
@@ -1,30 +1,95 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::colour::Colour;
+use crate::material::{Finish, Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
-/// Representation of an Axial Capacitor
-#[derive(Debug, Clone)]
-pub struct AxialCapacitor {
-    pub body_diameter: f64,
-    pub body_length: f64,
-    pub lead_diameter: f64,
-    pub lead_length: f64,
+/// A sheet material such as `MDF6` or `AL2`, from NopSCADLib's `sheets.scad`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SheetType {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub thickness: f64,
+    pub material: Material,
+    pub colour: Colour,
 }
 
-impl AxialCapacitor {
-    /// Generate the capacitor body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_length)
+impl SheetType {
+    const fn new(name: &'static str, description: &'static str, thickness: f64, material: Material, colour: Colour) -> Self {
+        SheetType { name, description, thickness, material, colour }
     }
+}
+
+pub const MDF6: SheetType = SheetType::new("MDF6", "Sheet MDF", 6.0, Material::Wood, Colour::rgb(0.8, 0.8, 0.5));
+pub const MDF10: SheetType = SheetType::new("MDF10", "Sheet MDF", 10.0, Material::Wood, Colour::rgb(0.8, 0.8, 0.5));
+pub const MDF12: SheetType = SheetType::new("MDF12", "Sheet MDF", 12.0, Material::Wood, Colour::rgb(0.8, 0.8, 0.5));
+pub const PLY9: SheetType = SheetType::new("PLY9", "Sheet plywood", 9.0, Material::Wood, Colour::rgb(0.85, 0.7, 0.45));
+pub const PMMA3: SheetType = SheetType::new("PMMA3", "Sheet acrylic", 3.0, Material::Plastic, Colour::rgba(1.0, 1.0, 1.0, 0.5));
+pub const PMMA6: SheetType = SheetType::new("PMMA6", "Sheet acrylic", 6.0, Material::Plastic, Colour::rgba(1.0, 1.0, 1.0, 0.5));
+pub const AL2: SheetType = SheetType::new("AL2", "Aluminium tooling plate", 2.0, Material::Aluminium, Colour::rgb(0.9, 0.9, 0.9));
+pub const AL3: SheetType = SheetType::new("AL3", "Aluminium tooling plate", 3.0, Material::Aluminium, Colour::rgb(0.9, 0.9, 0.9));
+pub const AL6: SheetType = SheetType::new("AL6", "Aluminium tooling plate", 6.0, Material::Aluminium, Colour::rgb(0.9, 0.9, 0.9));
+pub const STEEL06: SheetType = SheetType::new("Steel06", "Sheet mild steel", 0.6, Material::Steel, Colour::rgb(0.9, 0.9, 0.9));
+pub const FR4: SheetType = SheetType::new("FR4", "Sheet FR4", 1.6, Material::Fr4, Colour::rgb(0.4, 0.6, 0.2));
+pub const GLASS2: SheetType = SheetType::new("glass2", "Sheet glass", 2.0, Material::Glass, Colour::rgba(0.9, 0.95, 1.0, 0.3));
+
+pub const ALL_SHEETS: &[SheetType] = &[MDF6, MDF10, MDF12, PLY9, PMMA3, PMMA6, AL2, AL3, AL6, STEEL06, FR4, GLASS2];
+
+/// A rectangular panel cut from a sheet, the equivalent of `sheet(type, w, d, corners)` in SCAD.
+///
+/// Centred on the origin in all three axes, as `linear_extrude(center = true)` leaves it.
+#[derive(Debug, Clone)]
+pub struct Sheet {
+    pub kind: SheetType,
+    pub width: f64,
+    pub depth: f64,
+    /// Radius of the rounded corners, zero for square ones.
+    pub corner_radius: f64,
+}
+
+impl Sheet {
+    /// Generate the panel outline as a solid of the sheet thickness
+    pub fn body(&self) -> CSG {
+        let t = self.kind.thickness;
+        let r = self.corner_radius.clamp(0.0, self.width.min(self.depth) / 2.0);
+        if r <= 0.0 {
+            return CSG::prism(self.width, self.depth, t)
+                .translated(Vector3::new(-self.width / 2.0, -self.depth / 2.0, -t / 2.0));
+        }
 
-    /// Generate the leads
-    pub fn leads(&self) -> CSG<()> {
-        let lead = CSG::cylinder_z(self.lead_diameter / 2.0, self.lead_length);
-        lead.translate(Vector3::new(0.0, 0.0, -self.lead_length))
-            .union(&lead.translate(Vector3::new(0.0, 0.0, self.body_length)))
+        // Two overlapping rectangles and a cylinder on each corner.
+        let (x, y) = (self.width / 2.0 - r, self.depth / 2.0 - r);
+        let long = CSG::prism(self.width, 2.0 * y, t).translated(Vector3::new(-self.width / 2.0, -y, -t / 2.0));
+        let short = CSG::prism(2.0 * x, self.depth, t).translated(Vector3::new(-x, -self.depth / 2.0, -t / 2.0));
+        let corner = CSG::z_cylinder(r, t);
+        [(x, y), (-x, y), (-x, -y), (x, -y)]
+            .iter()
+            .fold(long.union(&short), |acc, &(cx, cy)| {
+                acc.union(&corner.translated(Vector3::new(cx, cy, -t / 2.0)))
+            })
     }
 
-    /// Assemble the complete axial capacitor
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().union(&self.leads())
+    /// Assemble the complete sheet
+    pub fn assemble(&self) -> CSG {
+        self.body().finished(Finish::new(self.kind.colour, self.kind.material))
     }
 }
 
+impl Part for Sheet {
+    fn name(&self) -> &str {
+        self.kind.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Structural
+    }
+
+    fn assemble(&self) -> CSG {
+        Sheet::assemble(self)
+    }
+
+    fn description(&self) -> String {
+        format!("{} {}mm {} x {}", self.kind.description, self.kind.thickness, self.width, self.depth)
+    }
+}
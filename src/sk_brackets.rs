@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an SK Bracket
 #[derive(Debug, Clone)]
@@ -13,20 +16,33 @@ pub struct SKBracket {
 
 impl SKBracket {
     /// Generate the base of the SK bracket
-    pub fn base(&self) -> CSG<()> {
+    pub fn base(&self) -> CSG {
         CSG::prism(self.base_width, self.base_length, self.height)
     }
 
     /// Generate the mount holes
-    pub fn holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.height);
-        hole.translate(Vector3::new(self.mount_hole_spacing / 2.0, 0.0, 0.0))
-            .union(&hole.translate(Vector3::new(-self.mount_hole_spacing / 2.0, 0.0, 0.0)))
+    pub fn holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.height);
+        hole.translated(Vector3::new(self.mount_hole_spacing / 2.0, 0.0, 0.0))
+            .union(&hole.translated(Vector3::new(-self.mount_hole_spacing / 2.0, 0.0, 0.0)))
     }
 
     /// Generate the complete bracket
-    pub fn assemble(&self) -> CSG<()> {
-        self.base().difference(&self.holes())
+    pub fn assemble(&self) -> CSG {
+        self.base().subtract(&self.holes())
     }
 }
 
+impl Part for SKBracket {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        SKBracket::assemble(self)
+    }
+}
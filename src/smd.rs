@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an SMD (Surface Mount Device) module
 #[derive(Debug, Clone)]
@@ -15,12 +18,12 @@ pub struct SMD {
 
 impl SMD {
     /// Generate the rectangular body of the SMD
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_length, self.body_width, self.body_height)
     }
     
     /// Generate the pads of the SMD
-    pub fn pads(&self) -> CSG<()> {
+    pub fn pads(&self) -> CSG {
         let mut pads = CSG::new();
         let offsets = [
             (-self.pad_spacing / 2.0, 0.0),
@@ -29,7 +32,7 @@ impl SMD {
         
         for &(x, y) in &offsets {
             let pad = CSG::prism(self.pad_length, self.pad_width, self.pad_height)
-                .translate(Vector3::new(x, y, -self.pad_height));
+                .translated(Vector3::new(x, y, -self.pad_height));
             pads = pads.union(&pad);
         }
         
@@ -37,7 +40,7 @@ impl SMD {
     }
     
     /// Generate the full SMD model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut smd = self.body();
         let pads = self.pads();
         
@@ -47,3 +50,16 @@ impl SMD {
     }
 }
 
+impl Part for SMD {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        SMD::assemble(self)
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Socket Box
 #[derive(Debug, Clone)]
@@ -14,12 +17,12 @@ pub struct SocketBox {
 
 impl SocketBox {
     /// Generate the outer box body
-    pub fn outer_body(&self) -> CSG<()> {
+    pub fn outer_body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.depth)
     }
 
     /// Generate the hollow interior
-    pub fn inner_body(&self) -> CSG<()> {
+    pub fn inner_body(&self) -> CSG {
         CSG::prism(
             self.width - 2.0 * self.wall_thickness,
             self.height - 2.0 * self.wall_thickness,
@@ -28,22 +31,35 @@ impl SocketBox {
     }
 
     /// Generate the socket holes
-    pub fn holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.depth + 1.0);
+    pub fn holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.depth + 1.0);
         let mut hole_array = CSG::new();
         let start_x = -((self.hole_count as f64 - 1.0) / 2.0) * self.hole_spacing;
 
         for i in 0..self.hole_count {
             let x_offset = start_x + (i as f64) * self.hole_spacing;
-            hole_array = hole_array.union(&hole.translate(Vector3::new(x_offset, 0.0, self.height / 2.0)));
+            hole_array = hole_array.union(&hole.translated(Vector3::new(x_offset, 0.0, self.height / 2.0)));
         }
 
         hole_array
     }
 
     /// Assemble the complete socket box
-    pub fn assemble(&self) -> CSG<()> {
-        self.outer_body().difference(&self.inner_body()).difference(&self.holes())
+    pub fn assemble(&self) -> CSG {
+        self.outer_body().subtract(&self.inner_body()).subtract(&self.holes())
     }
 }
 
+impl Part for SocketBox {
+    fn name(&self) -> &str {
+        "SocketBox"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        SocketBox::assemble(self)
+    }
+}
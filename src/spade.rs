@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Spade Terminal module
 #[derive(Debug, Clone)]
@@ -13,18 +16,18 @@ pub struct SpadeTerminal {
 
 impl SpadeTerminal {
     /// Generate the blade of the spade terminal
-    pub fn blade(&self) -> CSG<()> {
+    pub fn blade(&self) -> CSG {
         CSG::prism(self.blade_width, self.blade_thickness, self.blade_length)
     }
     
     /// Generate the barrel of the spade terminal
-    pub fn barrel(&self) -> CSG<()> {
-        CSG::cylinder_z(self.barrel_diameter / 2.0, self.barrel_length)
-            .translate(Vector3::new(0.0, 0.0, self.blade_length))
+    pub fn barrel(&self) -> CSG {
+        CSG::z_cylinder(self.barrel_diameter / 2.0, self.barrel_length)
+            .translated(Vector3::new(0.0, 0.0, self.blade_length))
     }
     
     /// Generate the full spade terminal model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut terminal = self.blade();
         let barrel = self.barrel();
         
@@ -34,3 +37,16 @@ impl SpadeTerminal {
     }
 }
 
+impl Part for SpadeTerminal {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Connector
+    }
+
+    fn assemble(&self) -> CSG {
+        SpadeTerminal::assemble(self)
+    }
+}
@@ -1,5 +1,8 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a large speaker module
 #[derive(Debug, Clone)]
@@ -15,37 +18,23 @@ pub struct LargeSpeaker {
 
 impl LargeSpeaker {
     /// Generate the cylindrical body of the speaker
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_height)
     }
     
     /// Generate the speaker cone
-    pub fn cone(&self) -> CSG<()> {
+    pub fn cone(&self) -> CSG {
         CSG::cone(self.cone_diameter / 2.0, self.cone_depth)
-            .translate(Vector3::new(0.0, 0.0, self.body_height))
+            .translated(Vector3::new(0.0, 0.0, self.body_height))
     }
     
     /// Generate the mounting holes of the speaker
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-            (-self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.body_height)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.body_height)
     }
     
     /// Generate the full large speaker model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut speaker = self.body();
         let cone = self.cone();
         let holes = self.mounting_holes();
@@ -57,3 +46,26 @@ impl LargeSpeaker {
     }
 }
 
+impl Part for LargeSpeaker {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        LargeSpeaker::assemble(self)
+    }
+}
+
+impl Mountable for LargeSpeaker {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::square(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
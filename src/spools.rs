@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Spool module
 #[derive(Debug, Clone)]
@@ -13,23 +16,23 @@ pub struct Spool {
 
 impl Spool {
     /// Generate the outer shell of the spool
-    pub fn outer_shell(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.width)
+    pub fn outer_shell(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.width)
     }
     
     /// Generate the inner bore of the spool
-    pub fn inner_bore(&self) -> CSG<()> {
-        CSG::cylinder_z(self.inner_diameter / 2.0, self.width)
+    pub fn inner_bore(&self) -> CSG {
+        CSG::z_cylinder(self.inner_diameter / 2.0, self.width)
     }
     
     /// Generate the hub of the spool
-    pub fn hub(&self) -> CSG<()> {
-        CSG::cylinder_z(self.hub_diameter / 2.0, self.hub_width)
-            .translate(Vector3::new(0.0, 0.0, (self.width - self.hub_width) / 2.0))
+    pub fn hub(&self) -> CSG {
+        CSG::z_cylinder(self.hub_diameter / 2.0, self.hub_width)
+            .translated(Vector3::new(0.0, 0.0, (self.width - self.hub_width) / 2.0))
     }
     
     /// Generate the full spool model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut spool = self.outer_shell();
         let bore = self.inner_bore();
         let hub = self.hub();
@@ -41,3 +44,16 @@ impl Spool {
     }
 }
 
+impl Part for Spool {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Misc
+    }
+
+    fn assemble(&self) -> CSG {
+        Spool::assemble(self)
+    }
+}
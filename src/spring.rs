@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
 
 /// Representation of a Spring
 #[derive(Debug, Clone)]
@@ -10,27 +13,31 @@ pub struct Spring {
 }
 
 impl Spring {
-    /// Generate the spring coil
-    pub fn coil(&self) -> CSG<()> {
-        let mut coil = CSG::new();
-        let step = self.height / self.turns as f64;
-
-        for i in 0..self.turns {
-            let angle = i as f64 * 360.0 / self.turns as f64;
-            let z_offset = i as f64 * step;
-            coil = coil.union(
-                &CSG::torus(self.coil_diameter / 2.0, self.wire_diameter / 2.0)
-                    .rotate(0.0, angle, 0.0)
-                    .translate(Vector3::new(0.0, 0.0, z_offset)),
-            );
+    /// Generate the spring coil, wound from the bottom to the top of `height`
+    pub fn coil(&self) -> CSG {
+        if self.turns == 0 {
+            return CSG::new();
         }
-
-        coil
+        let pitch = self.height / self.turns as f64;
+        CSG::helix(self.coil_diameter / 2.0, self.height, pitch, self.wire_diameter).finished(Material::Steel)
     }
 
     /// Assemble the complete spring
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.coil()
     }
 }
 
+impl Part for Spring {
+    fn name(&self) -> &str {
+        "Spring"
+    }
+
+    fn category(&self) -> Category {
+        Category::Motion
+    }
+
+    fn assemble(&self) -> CSG {
+        Spring::assemble(self)
+    }
+}
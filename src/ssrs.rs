@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use nalgebra::Isometry3;
 
 /// Representation of a Solid State Relay (SSR) module
 #[derive(Debug, Clone)]
@@ -15,29 +18,17 @@ pub struct SSR {
 
 impl SSR {
     /// Generate the main body of the SSR
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.length, self.width, self.height)
     }
     
     /// Generate the mounting holes of the SSR
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.hole_spacing / 2.0, 0.0),
-            (self.hole_spacing / 2.0, 0.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.height)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.hole_diameter, self.height)
     }
     
     /// Generate the full SSR model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut ssr = self.body();
         let holes = self.mounting_holes();
         
@@ -47,3 +38,26 @@ impl SSR {
     }
 }
 
+impl Part for SSR {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        SSR::assemble(self)
+    }
+}
+
+impl Mountable for SSR {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::pair(self.hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.hole_diameter
+    }
+}
@@ -1,4 +1,12 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::anchor::{self, Anchor};
+use crate::colour;
+use crate::error::{inconsistent, positive, PartError};
+use crate::material::{Finish, Finishing, Material};
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a Stepper Motor
 #[derive(Debug, Clone)]
@@ -9,23 +17,164 @@ pub struct StepperMotor {
     pub shaft_diameter: f64,
     pub shaft_length: f64,
     pub mount_hole_spacing: f64,
+    /// Diameter of the tapped screw holes in the front face, e.g. M3 for NEMA 17.
+    pub mount_hole_diameter: f64,
+    /// Locating boss around the shaft, which needs a hole in the panel the motor mounts on.
+    pub boss_diameter: f64,
+    pub boss_height: f64,
 }
 
 impl StepperMotor {
+    /// Check the dimensions are positive and the boss, shaft and mounting holes fit on the face
+    pub fn validate(&self) -> Result<(), PartError> {
+        positive("side_length", self.side_length)?;
+        positive("body_length", self.body_length)?;
+        positive("shaft_diameter", self.shaft_diameter)?;
+        positive("shaft_length", self.shaft_length)?;
+        positive("mount_hole_spacing", self.mount_hole_spacing)?;
+        positive("mount_hole_diameter", self.mount_hole_diameter)?;
+        positive("boss_diameter", self.boss_diameter)?;
+        positive("boss_height", self.boss_height)?;
+        if self.mount_hole_spacing + self.mount_hole_diameter > self.side_length {
+            return Err(inconsistent(&self.name, "the mounting holes are outside the body"));
+        }
+        if self.boss_diameter >= self.mount_hole_spacing {
+            return Err(inconsistent(&self.name, "the boss overlaps the mounting holes"));
+        }
+        if self.shaft_diameter >= self.boss_diameter {
+            return Err(inconsistent(&self.name, "the shaft is wider than the boss"));
+        }
+        Ok(())
+    }
+
     /// Generate the motor body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.side_length, self.side_length, self.body_length)
+            .translated(Vector3::new(-self.side_length / 2.0, -self.side_length / 2.0, 0.0))
+            .finished(Finish::new(colour::BLACK, Material::Steel))
+    }
+
+    /// Generate the locating boss on the front face
+    pub fn boss(&self) -> CSG {
+        CSG::z_cylinder(self.boss_diameter / 2.0, self.boss_height)
+            .translated(Vector3::new(0.0, 0.0, self.body_length))
+            .finished(Material::Aluminium)
+    }
+
+    /// Generate the tapped screw holes in the front face
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mount_hole_diameter, self.body_length / 4.0)
     }
 
     /// Generate the motor shaft
-    pub fn shaft(&self) -> CSG<()> {
-        CSG::cylinder_z(self.shaft_diameter / 2.0, self.shaft_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_length))
+    pub fn shaft(&self) -> CSG {
+        CSG::z_cylinder(self.shaft_diameter / 2.0, self.shaft_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_length))
+            .finished(Material::StainlessSteel)
     }
 
     /// Generate the motor with shaft
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().union(&self.shaft())
+    pub fn assemble(&self) -> CSG {
+        self.body()
+            .subtract(&self.mounting_holes())
+            .finished_default(Finish::new(colour::BLACK, Material::Steel))
+            .union(&self.boss())
+            .union(&self.shaft())
+    }
+}
+
+impl Part for StepperMotor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motor
+    }
+
+    fn assemble(&self) -> CSG {
+        StepperMotor::assemble(self)
+    }
+
+    /// `back` and `face` on the body, `boss` on top of the locating boss, `shaft` at the end
+    /// of the shaft and `hole_0` to `hole_3` on the screw holes.
+    fn anchors(&self) -> Vec<Anchor> {
+        let face = self.body_length;
+        let mut anchors = vec![
+            Anchor::facing("back", Vector3::zeros(), -Vector3::z()),
+            Anchor::facing("face", Vector3::new(0.0, 0.0, face), Vector3::z()),
+            Anchor::facing("boss", Vector3::new(0.0, 0.0, face + self.boss_height), Vector3::z()),
+            Anchor::facing("shaft", Vector3::new(0.0, 0.0, face + self.shaft_length), Vector3::z()),
+        ];
+        anchors.extend(anchor::holes(self));
+        anchors
+    }
+}
+
+impl Mountable for StepperMotor {
+    /// The four screws in the front face, around the shaft.
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_top(&mounting::square(self.mount_hole_spacing), self.body_length)
     }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mount_hole_diameter
+    }
+
+    /// The screw holes and a hole for the boss, which the shaft passes through.
+    fn panel_cutout(&self, clearance: f64) -> CSG {
+        let boss = mounting::on_top(&[(0.0, 0.0)], self.body_length);
+        mounting::through_holes(&self.hole_positions(), self.mount_hole_diameter + 2.0 * clearance)
+            .union(&mounting::through_holes(&boss, self.boss_diameter + 2.0 * clearance))
+    }
+}
+
+/// NEMA motor from NopSCADLib's `stepper_motors.scad`.
+#[allow(clippy::too_many_arguments)]
+fn nema(
+    name: &str,
+    side_length: f64,
+    body_length: f64,
+    boss_diameter: f64,
+    shaft_diameter: f64,
+    shaft_length: f64,
+    mount_hole_spacing: f64,
+    mount_hole_diameter: f64,
+) -> StepperMotor {
+    StepperMotor {
+        name: name.to_string(),
+        side_length,
+        body_length,
+        shaft_diameter,
+        shaft_length,
+        mount_hole_spacing,
+        mount_hole_diameter,
+        boss_diameter,
+        boss_height: 2.0,
+    }
+}
+
+pub fn nema14() -> StepperMotor {
+    nema("NEMA14", 35.2, 36.0, 22.0, 5.0, 21.0, 26.0, 3.0)
 }
 
+pub fn nema17_34() -> StepperMotor {
+    nema("NEMA17_34", 42.3, 34.0, 22.0, 5.0, 24.0, 31.0, 3.0)
+}
+
+pub fn nema17_40() -> StepperMotor {
+    nema("NEMA17_40", 42.3, 40.0, 22.0, 5.0, 24.0, 31.0, 3.0)
+}
+
+pub fn nema17_47() -> StepperMotor {
+    nema("NEMA17_47", 42.3, 47.0, 22.0, 5.0, 24.0, 31.0, 3.0)
+}
+
+pub fn nema23() -> StepperMotor {
+    nema("NEMA23", 56.4, 51.2, 38.1, 6.35, 21.0, 47.1, 5.0)
+}
+
+/// Every built-in stepper motor, smallest first
+pub fn all_stepper_motors() -> Vec<StepperMotor> {
+    vec![nema14(), nema17_34(), nema17_40(), nema17_47(), nema23()]
+}
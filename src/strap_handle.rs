@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Strap Handle
 #[derive(Debug, Clone)]
@@ -11,20 +14,33 @@ pub struct StrapHandle {
 
 impl StrapHandle {
     /// Generate the strap body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.length, self.thickness)
     }
 
     /// Generate the mounting holes
-    pub fn holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.thickness + 1.0);
-        hole.translate(self.width / 3.0, 0.0, 0.0)
-            .union(&hole.translate(Vector3::new(-self.width / 3.0, 0.0, 0.0)))
+    pub fn holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.thickness + 1.0);
+        hole.translated(Vector3::new(self.width / 3.0, 0.0, 0.0))
+            .union(&hole.translated(Vector3::new(-self.width / 3.0, 0.0, 0.0)))
     }
 
     /// Assemble the complete strap handle
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.holes())
     }
 }
 
+impl Part for StrapHandle {
+    fn name(&self) -> &str {
+        "StrapHandle"
+    }
+
+    fn category(&self) -> Category {
+        Category::Printed
+    }
+
+    fn assemble(&self) -> CSG {
+        StrapHandle::assemble(self)
+    }
+}
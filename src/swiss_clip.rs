@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Swiss Clip (for securing panels)
 #[derive(Debug, Clone)]
@@ -12,22 +15,35 @@ pub struct SwissClip {
 
 impl SwissClip {
     /// Generate the main clip body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.thickness)
     }
 
     /// Generate the mounting holes
-    pub fn holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.thickness);
+    pub fn holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.thickness);
         let offset = self.hole_spacing / 2.0;
 
-        hole.translate(Vector3::new(offset, 0.0, 0.0))
-            .union(&hole.translate(Vector3::new(-offset, 0.0, 0.0)))
+        hole.translated(Vector3::new(offset, 0.0, 0.0))
+            .union(&hole.translated(Vector3::new(-offset, 0.0, 0.0)))
     }
 
     /// Assemble the complete Swiss Clip
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.holes())
+    pub fn assemble(&self) -> CSG {
+        self.body().subtract(&self.holes())
     }
 }
 
+impl Part for SwissClip {
+    fn name(&self) -> &str {
+        "SwissClip"
+    }
+
+    fn category(&self) -> Category {
+        Category::Fastener
+    }
+
+    fn assemble(&self) -> CSG {
+        SwissClip::assemble(self)
+    }
+}
@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of an electrical switch
 #[derive(Debug, Clone)]
@@ -16,18 +18,18 @@ pub struct Switch {
 
 impl Switch {
     /// Generate the rectangular body of the switch
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
     }
     
     /// Generate the button of the switch
-    pub fn button(&self) -> CSG<()> {
-        CSG::cylinder_z(self.button_diameter / 2.0, self.button_height)
-            .translate(Vector3::new(0.0, 0.0, self.body_depth))
+    pub fn button(&self) -> CSG {
+        CSG::z_cylinder(self.button_diameter / 2.0, self.button_height)
+            .translated(Vector3::new(0.0, 0.0, self.body_depth))
     }
     
     /// Generate the terminals of the switch
-    pub fn terminals(&self) -> CSG<()> {
+    pub fn terminals(&self) -> CSG {
         let mut terminals = CSG::new();
         let offsets = [
             (-self.terminal_spacing / 2.0, 0.0),
@@ -35,8 +37,8 @@ impl Switch {
         ];
         
         for &(x, y) in &offsets {
-            let terminal = CSG::cylinder_z(self.terminal_diameter / 2.0, self.body_depth / 2.0)
-                .translate(Vector3::new(x, y, -self.body_depth / 2.0));
+            let terminal = CSG::z_cylinder(self.terminal_diameter / 2.0, self.body_depth / 2.0)
+                .translated(Vector3::new(x, y, -self.body_depth / 2.0));
             terminals = terminals.union(&terminal);
         }
         
@@ -44,7 +46,7 @@ impl Switch {
     }
     
     /// Generate the full switch model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut switch = self.body();
         let button = self.button();
         let terminals = self.terminals();
@@ -55,3 +57,17 @@ impl Switch {
         switch
     }
 }
+
+impl Part for Switch {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Switch
+    }
+
+    fn assemble(&self) -> CSG {
+        Switch::assemble(self)
+    }
+}
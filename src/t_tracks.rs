@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a T-Track
 #[derive(Debug, Clone)]
@@ -12,19 +15,32 @@ pub struct TTrack {
 
 impl TTrack {
     /// Generate the base of the T-track
-    pub fn base(&self) -> CSG<()> {
+    pub fn base(&self) -> CSG {
         CSG::prism(self.base_width, self.length, self.base_height)
     }
 
     /// Generate the T-slot
-    pub fn slot(&self) -> CSG<()> {
+    pub fn slot(&self) -> CSG {
         CSG::prism(self.slot_width, self.length, self.slot_depth)
-            .translate(Vector3::new(0.0, 0.0, self.base_height / 2.0))
+            .translated(Vector3::new(0.0, 0.0, self.base_height / 2.0))
     }
 
     /// Assemble the complete T-track
-    pub fn assemble(&self) -> CSG<()> {
-        self.base().difference(&self.slot())
+    pub fn assemble(&self) -> CSG {
+        self.base().subtract(&self.slot())
     }
 }
 
+impl Part for TTrack {
+    fn name(&self) -> &str {
+        "TTrack"
+    }
+
+    fn category(&self) -> Category {
+        Category::Structural
+    }
+
+    fn assemble(&self) -> CSG {
+        TTrack::assemble(self)
+    }
+}
@@ -0,0 +1,416 @@
+//! Text as geometry, for part numbers and legends embossed on or debossed into printed parts.
+//!
+//! Glyphs are drawn with a built-in stroke font in the manner of the Hershey fonts, so no font
+//! files are needed. Each stroke is a rounded bar, and lower case letters are drawn as capitals.
+
+use crate::anchor::Anchor;
+use crate::bom::Bom;
+use crate::error::{positive, PartError};
+use crate::mass::MassProperties;
+use crate::part::{Category, Part};
+use crate::pattern;
+use crate::primitives::Primitives;
+use crate::resolution;
+use crate::CSG;
+use csgrs::float_types::parry3d::bounding_volume::{Aabb, BoundingVolume};
+use nalgebra::{Isometry3, Point2, Vector2, Vector3};
+use std::f64::consts::TAU;
+
+/// Height of a capital in font units.
+const CAP_HEIGHT: f64 = 6.0;
+
+/// Distance from one character to the next in font units.
+const ADVANCE: f64 = 6.0;
+
+/// Distance from one line to the next in font units.
+const LINE_SPACING: f64 = 10.0;
+
+/// Depth a debossed label starts above the face, so the cut doesn't leave a skin on it.
+const OVERCUT: f64 = 0.01;
+
+/// Glyphs of the stroke font on a grid four units wide, as strokes separated by spaces, each
+/// a run of two digit points. The second digit is one above the height over the baseline, so
+/// `1` is on the baseline and `7` at the top of the capitals.
+const GLYPHS: &[(char, &str)] = &[
+    (' ', ""),
+    ('!', "2723 21"),
+    ('"', "1715 3735"),
+    ('#', "1117 3137 0343 0545"),
+    ('%', "0147 0717160607 3242413132"),
+    ('\'', "2725"),
+    ('(', "3726142231"),
+    (')', "1726342211"),
+    ('*', "2226 0345 0543"),
+    ('+', "0444 2226"),
+    (',', "222110"),
+    ('-', "1434"),
+    ('.', "21"),
+    ('/', "0147"),
+    ('0', "113142463717060211 4602"),
+    ('1', "162721 1131"),
+    ('2', "06173746450141"),
+    ('3', "0617374645344342311102 1434"),
+    ('4', "31370343"),
+    ('5', "470704344342311102"),
+    ('6', "37170602113142433404"),
+    ('7', "074711"),
+    ('8', "14050617374645341403021131424334"),
+    ('9', "11314246371706051444"),
+    (':', "21 25"),
+    (';', "25 222110"),
+    ('<', "460442"),
+    ('=', "0343 0545"),
+    ('>', "064402"),
+    ('?', "06173746452423 21"),
+    ('A', "012741 1434"),
+    ('B', "01073746453404 3443423101"),
+    ('C', "4637170602113142"),
+    ('D', "01072745432101"),
+    ('E', "47070141 0434"),
+    ('F', "470701 0434"),
+    ('G', "46371706021131424424"),
+    ('H', "0107 4147 0444"),
+    ('I', "1737 2721 1131"),
+    ('J', "4742311102"),
+    ('K', "0107 4703 1441"),
+    ('L', "070141"),
+    ('M', "0107244741"),
+    ('N', "01074147"),
+    ('O', "113142463717060211"),
+    ('P', "01073746453404"),
+    ('Q', "113142463717060211 2341"),
+    ('R', "01073746453404 2441"),
+    ('S', "463717060514344342311102"),
+    ('T', "0747 2721"),
+    ('U', "070211314247"),
+    ('V', "072147"),
+    ('W', "0711253147"),
+    ('X', "0147 0741"),
+    ('Y', "072447 2421"),
+    ('Z', "07470141"),
+    ('[', "37171131"),
+    (']', "17373111"),
+    ('_', "0040"),
+    ('°', "1727261617"),
+];
+
+/// Horizontal alignment of text to its origin, as `halign` of OpenSCAD's `text()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Vertical alignment of text to its origin, as `valign` of OpenSCAD's `text()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VAlign {
+    /// The baseline of the first line.
+    #[default]
+    Baseline,
+    /// The baseline of the last line.
+    Bottom,
+    /// Halfway between the top of the first line and the baseline of the last.
+    Center,
+    /// The top of the capitals of the first line.
+    Top,
+}
+
+/// A piece of text to turn into a solid, one line per `\n`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Text {
+    pub text: String,
+    /// Height of the capitals in mm.
+    pub size: f64,
+    /// Width of the strokes in mm.
+    pub stroke: f64,
+    /// Factor on the distance from one character to the next.
+    pub spacing: f64,
+    pub halign: HAlign,
+    pub valign: VAlign,
+}
+
+impl Text {
+    /// `text` with capitals `size` high and strokes a seventh of that wide, starting at the
+    /// origin on the baseline
+    pub fn new(text: impl Into<String>, size: f64) -> Self {
+        Text {
+            text: text.into(),
+            size,
+            stroke: size / 7.0,
+            spacing: 1.0,
+            halign: HAlign::default(),
+            valign: VAlign::default(),
+        }
+    }
+
+    pub fn with_stroke(mut self, stroke: f64) -> Self {
+        self.stroke = stroke;
+        self
+    }
+
+    pub fn with_spacing(mut self, spacing: f64) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    pub fn with_halign(mut self, halign: HAlign) -> Self {
+        self.halign = halign;
+        self
+    }
+
+    pub fn with_valign(mut self, valign: VAlign) -> Self {
+        self.valign = valign;
+        self
+    }
+
+    /// Check the size and stroke are positive
+    pub fn validate(&self) -> Result<(), PartError> {
+        positive("size", self.size)?;
+        positive("stroke", self.stroke)?;
+        positive("spacing", self.spacing)?;
+        Ok(())
+    }
+
+    /// Centre lines of the strokes in mm, laid out and aligned. A stroke of one point is a dot.
+    ///
+    /// Characters the font doesn't have are drawn as `?`.
+    pub fn strokes(&self) -> Vec<Vec<Point2<f64>>> {
+        let scale = self.size / CAP_HEIGHT;
+        let advance = ADVANCE * self.spacing;
+        let lines: Vec<&str> = self.text.lines().collect();
+        let last = lines.len().saturating_sub(1) as f64 * LINE_SPACING;
+        let rise = match self.valign {
+            VAlign::Baseline => 0.0,
+            VAlign::Bottom => last,
+            VAlign::Center => (last - CAP_HEIGHT) / 2.0,
+            VAlign::Top => -CAP_HEIGHT,
+        };
+
+        let mut strokes = Vec::new();
+        for (row, line) in lines.iter().enumerate() {
+            let count = line.chars().count();
+            let width = if count == 0 { 0.0 } else { (count - 1) as f64 * advance + 4.0 };
+            let start = match self.halign {
+                HAlign::Left => 0.0,
+                HAlign::Center => -width / 2.0,
+                HAlign::Right => -width,
+            };
+            let baseline = rise - row as f64 * LINE_SPACING;
+            for (column, c) in line.chars().enumerate() {
+                let x = start + column as f64 * advance;
+                for stroke in glyph(c).split_whitespace() {
+                    let points = stroke
+                        .as_bytes()
+                        .chunks(2)
+                        .map(|xy| {
+                            let (gx, gy) = ((xy[0] - b'0') as f64, (xy[1] - b'0') as f64 - 1.0);
+                            Point2::new((x + gx) * scale, (baseline + gy) * scale)
+                        })
+                        .collect();
+                    strokes.push(points);
+                }
+            }
+        }
+        strokes
+    }
+
+    /// The text standing on the XY plane, reading along X, `depth` high
+    pub fn extrude(&self, depth: f64) -> CSG {
+        let radius = self.stroke / 2.0;
+        let bars = self
+            .strokes()
+            .iter()
+            .flat_map(|stroke| match stroke.as_slice() {
+                [dot] => vec![bar(*dot, *dot, radius, depth)],
+                points => points.windows(2).map(|ends| bar(ends[0], ends[1], radius, depth)).collect(),
+            })
+            .collect();
+        pattern::union_all(bars)
+    }
+}
+
+/// Strokes of `c`, or of `?` if the font doesn't have it
+fn glyph(c: char) -> &'static str {
+    let c = c.to_ascii_uppercase();
+    let find = |c: char| GLYPHS.iter().find(|(g, _)| *g == c).map(|(_, strokes)| *strokes);
+    find(c).or_else(|| find('?')).unwrap()
+}
+
+/// Straight stroke from `a` to `b` with round ends, standing `depth` high.
+///
+/// The ends are polygons turned the same way on every stroke, so strokes meeting at a point
+/// share the vertices around it and their union leaves no slivers.
+fn bar(a: Point2<f64>, b: Point2<f64>, radius: f64, depth: f64) -> CSG {
+    let segments = resolution::current().fragments(radius);
+    let mut points: Vec<Point2<f64>> = [a, b]
+        .iter()
+        .flat_map(|centre| {
+            (0..segments).map(move |i| {
+                let t = TAU * i as f64 / segments as f64;
+                centre + Vector2::new(t.cos(), t.sin()) * radius
+            })
+        })
+        .collect();
+    let outline: Vec<[f64; 2]> = convex_hull(&mut points).iter().map(|p| [p.x, p.y]).collect();
+    CSG::polygon_2d(&outline).extrude(depth)
+}
+
+/// Corners of the convex hull of `points`, anticlockwise, by Andrew's monotone chain
+fn convex_hull(points: &mut [Point2<f64>]) -> Vec<Point2<f64>> {
+    points.sort_by(|p, q| p.x.total_cmp(&q.x).then(p.y.total_cmp(&q.y)));
+    let turns_left = |hull: &[Point2<f64>], p: &Point2<f64>| {
+        let (o, q) = (hull[hull.len() - 2], hull[hull.len() - 1]);
+        (q - o).perp(&(p - o)) > 1e-12
+    };
+    let mut hull: Vec<Point2<f64>> = Vec::with_capacity(points.len() + 1);
+    for p in points.iter() {
+        while hull.len() >= 2 && !turns_left(&hull, p) {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+    let lower = hull.len() + 1;
+    for p in points.iter().rev().skip(1) {
+        while hull.len() >= lower && !turns_left(&hull, p) {
+            hull.pop();
+        }
+        hull.push(*p);
+    }
+    hull.pop();
+    hull
+}
+
+/// Whether a label stands proud of its face or is cut into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relief {
+    Embossed,
+    Debossed,
+}
+
+/// Text on a face of a part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub text: Text,
+    /// The face, in the part's coordinates: the text reads along X with Y up, and Z points
+    /// out of the part, like an [`Anchor`].
+    pub frame: Isometry3<f64>,
+    /// Height above the face or depth into it.
+    pub depth: f64,
+    pub relief: Relief,
+}
+
+impl Label {
+    /// The text in place, from the face out for embossing or from inside the part to just
+    /// above the face for debossing
+    pub fn solid(&self) -> CSG {
+        let (bottom, height) = match self.relief {
+            Relief::Embossed => (0.0, self.depth),
+            Relief::Debossed => (-self.depth, self.depth + OVERCUT),
+        };
+        self.text.extrude(height).translated(Vector3::new(0.0, 0.0, bottom)).transformed(&self.frame.to_homogeneous())
+    }
+
+    /// `solid` with the label added or cut away
+    pub fn apply(&self, solid: &CSG) -> CSG {
+        match self.relief {
+            Relief::Embossed => solid.union(&self.solid()),
+            Relief::Debossed => solid.subtract(&self.solid()),
+        }
+    }
+}
+
+/// A part with labels on its faces:
+///
+/// ```ignore
+/// let shroud = Labelled::new(shroud)
+///     .deboss("top", Text::new("MAINS", 6.0).with_halign(HAlign::Center), 0.6)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Labelled<P> {
+    pub part: P,
+    pub labels: Vec<Label>,
+}
+
+impl<P: Part> Labelled<P> {
+    pub fn new(part: P) -> Self {
+        Labelled { part, labels: Vec::new() }
+    }
+
+    pub fn with_label(mut self, label: Label) -> Self {
+        self.labels.push(label);
+        self
+    }
+
+    /// Raise `text` `height` above the face at the part's anchor `face`
+    pub fn emboss(self, face: &str, text: Text, height: f64) -> Result<Self, PartError> {
+        self.label(face, text, height, Relief::Embossed)
+    }
+
+    /// Cut `text` `depth` into the face at the part's anchor `face`
+    pub fn deboss(self, face: &str, text: Text, depth: f64) -> Result<Self, PartError> {
+        self.label(face, text, depth, Relief::Debossed)
+    }
+
+    fn label(self, face: &str, text: Text, depth: f64, relief: Relief) -> Result<Self, PartError> {
+        text.validate()?;
+        positive("depth", depth)?;
+        let frame = self
+            .part
+            .anchor(face)
+            .ok_or_else(|| PartError::UnknownAnchor { part: self.part.name().to_string(), anchor: face.to_string() })?;
+        Ok(self.with_label(Label { text, frame, depth, relief }))
+    }
+}
+
+impl<P: Part> Part for Labelled<P> {
+    fn name(&self) -> &str {
+        self.part.name()
+    }
+
+    fn category(&self) -> Category {
+        self.part.category()
+    }
+
+    fn assemble(&self) -> CSG {
+        self.labels.iter().fold(self.part.assemble(), |solid, label| label.apply(&solid))
+    }
+
+    /// The part's own box, grown by any embossed labels standing proud of it.
+    fn bounding_box(&self) -> Aabb {
+        self.labels
+            .iter()
+            .filter(|label| label.relief == Relief::Embossed)
+            .fold(self.part.bounding_box(), |bounds, label| bounds.merged(&label.solid().bounding_box()))
+    }
+
+    fn description(&self) -> String {
+        self.part.description()
+    }
+
+    fn density(&self) -> Option<f64> {
+        self.part.density()
+    }
+
+    fn catalogue_mass(&self) -> Option<f64> {
+        self.part.catalogue_mass()
+    }
+
+    /// The part's own properties, as labels are too thin to change them noticeably.
+    fn mass_properties(&self) -> MassProperties {
+        self.part.mass_properties()
+    }
+
+    fn unit_cost(&self) -> Option<f64> {
+        self.part.unit_cost()
+    }
+
+    fn bom(&self, bom: &mut Bom) {
+        self.part.bom(bom)
+    }
+
+    fn anchors(&self) -> Vec<Anchor> {
+        self.part.anchors()
+    }
+}
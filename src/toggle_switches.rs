@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a toggle switch module
 #[derive(Debug, Clone)]
@@ -17,18 +19,18 @@ pub struct ToggleSwitch {
 
 impl ToggleSwitch {
     /// Generate the rectangular body of the toggle switch
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.body_width, self.body_height, self.body_depth)
     }
     
     /// Generate the lever of the toggle switch
-    pub fn lever(&self) -> CSG<()> {
-        CSG::cylinder_z(self.lever_diameter / 2.0, self.lever_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_depth))
+    pub fn lever(&self) -> CSG {
+        CSG::z_cylinder(self.lever_diameter / 2.0, self.lever_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_depth))
     }
     
     /// Generate the pins of the toggle switch
-    pub fn pins(&self) -> CSG<()> {
+    pub fn pins(&self) -> CSG {
         let mut pins = CSG::new();
         let offsets = [
             (-self.pin_spacing, 0.0),
@@ -37,8 +39,8 @@ impl ToggleSwitch {
         ];
         
         for &(x, y) in &offsets {
-            let pin = CSG::cylinder_z(self.pin_diameter / 2.0, self.pin_length)
-                .translate(Vector3::new(x, y, -self.pin_length));
+            let pin = CSG::z_cylinder(self.pin_diameter / 2.0, self.pin_length)
+                .translated(Vector3::new(x, y, -self.pin_length));
             pins = pins.union(&pin);
         }
         
@@ -46,7 +48,7 @@ impl ToggleSwitch {
     }
     
     /// Generate the full toggle switch model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut toggle_switch = self.body();
         let lever = self.lever();
         let pins = self.pins();
@@ -58,3 +60,16 @@ impl ToggleSwitch {
     }
 }
 
+impl Part for ToggleSwitch {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Switch
+    }
+
+    fn assemble(&self) -> CSG {
+        ToggleSwitch::assemble(self)
+    }
+}
@@ -0,0 +1,272 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Upper limits of the ISO 286 nominal size ranges, in mm. Each range runs from above the
+/// previous limit up to and including its own.
+const RANGES: [f64; 13] = [3.0, 6.0, 10.0, 18.0, 30.0, 50.0, 80.0, 120.0, 180.0, 250.0, 315.0, 400.0, 500.0];
+
+/// Standard tolerance grades IT5 to IT11 for each size range, in µm.
+const IT: [[f64; 13]; 7] = [
+    [4.0, 5.0, 6.0, 8.0, 9.0, 11.0, 13.0, 15.0, 18.0, 20.0, 23.0, 25.0, 27.0],
+    [6.0, 8.0, 9.0, 11.0, 13.0, 16.0, 19.0, 22.0, 25.0, 29.0, 32.0, 36.0, 40.0],
+    [10.0, 12.0, 15.0, 18.0, 21.0, 25.0, 30.0, 35.0, 40.0, 46.0, 52.0, 57.0, 63.0],
+    [14.0, 18.0, 22.0, 27.0, 33.0, 39.0, 46.0, 54.0, 63.0, 72.0, 81.0, 89.0, 97.0],
+    [25.0, 30.0, 36.0, 43.0, 52.0, 62.0, 74.0, 87.0, 100.0, 115.0, 130.0, 140.0, 155.0],
+    [40.0, 48.0, 58.0, 70.0, 84.0, 100.0, 120.0, 140.0, 160.0, 185.0, 210.0, 230.0, 250.0],
+    [60.0, 75.0, 90.0, 110.0, 130.0, 160.0, 190.0, 220.0, 250.0, 290.0, 320.0, 360.0, 400.0],
+];
+
+/// Lowest grade in [`IT`].
+const FIRST_GRADE: u8 = 5;
+
+/// Fundamental deviations of shafts for each size range, in µm: the upper deviation for `d` to
+/// `h`, the lower one for `k` to `p`.
+const SHAFT_DEVIATIONS: [(&str, [f64; 13]); 8] = [
+    ("d", [-20.0, -30.0, -40.0, -50.0, -65.0, -80.0, -100.0, -120.0, -145.0, -170.0, -190.0, -210.0, -230.0]),
+    ("e", [-14.0, -20.0, -25.0, -32.0, -40.0, -50.0, -60.0, -72.0, -85.0, -100.0, -110.0, -125.0, -135.0]),
+    ("f", [-6.0, -10.0, -13.0, -16.0, -20.0, -25.0, -30.0, -36.0, -43.0, -50.0, -56.0, -62.0, -68.0]),
+    ("g", [-2.0, -4.0, -5.0, -6.0, -7.0, -9.0, -10.0, -12.0, -14.0, -15.0, -17.0, -18.0, -20.0]),
+    ("h", [0.0; 13]),
+    ("m", [2.0, 4.0, 6.0, 7.0, 8.0, 9.0, 11.0, 13.0, 15.0, 17.0, 20.0, 21.0, 23.0]),
+    ("n", [4.0, 8.0, 10.0, 12.0, 15.0, 17.0, 20.0, 23.0, 27.0, 31.0, 34.0, 37.0, 40.0]),
+    ("p", [6.0, 12.0, 15.0, 18.0, 22.0, 26.0, 32.0, 37.0, 43.0, 50.0, 56.0, 62.0, 68.0]),
+];
+
+/// Lower deviation of `k` shafts in grades up to IT7, in µm. Coarser grades have none.
+const K_DEVIATION: [f64; 13] = [0.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 4.0, 5.0];
+
+/// Errors from parsing or looking up a tolerance class.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToleranceError {
+    /// Text that isn't a class like `H7` or `g6`, or a fit like `H7/g6`.
+    Syntax(String),
+    /// A deviation letter outside those tabulated: `D` to `H` and `JS` for holes, `d` to `h`,
+    /// `js`, `k`, `m`, `n` and `p` for shafts.
+    UnknownDeviation(String),
+    /// A grade outside IT5 to IT11.
+    UnknownGrade(u8),
+    /// A nominal size outside the 0 to 500mm the tables cover.
+    SizeOutOfRange(f64),
+}
+
+impl fmt::Display for ToleranceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ToleranceError::Syntax(text) => write!(f, "`{}` is not a tolerance class or fit", text),
+            ToleranceError::UnknownDeviation(letters) => write!(f, "no fundamental deviation `{}`", letters),
+            ToleranceError::UnknownGrade(grade) => write!(f, "IT{} is outside IT5 to IT11", grade),
+            ToleranceError::SizeOutOfRange(size) => write!(f, "{}mm is outside the 0 to 500mm of ISO 286", size),
+        }
+    }
+}
+
+impl std::error::Error for ToleranceError {}
+
+/// Smallest and largest size a feature may be made, in mm.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limits {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Limits {
+    /// The middle of the tolerance band, which is the size to model
+    pub fn mean(&self) -> f64 {
+        (self.min + self.max) / 2.0
+    }
+}
+
+/// An ISO 286 tolerance class such as `H7` for a hole or `g6` for a shaft.
+///
+/// Upper case letters are holes and lower case shafts, as on drawings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToleranceClass {
+    /// One or two letters, e.g. `'H'` or `"js"`.
+    letters: [u8; 2],
+    pub grade: u8,
+}
+
+impl ToleranceClass {
+    /// A class from one or two letters of the same case, already checked by the caller
+    const fn new(letters: &str, grade: u8) -> Self {
+        let bytes = letters.as_bytes();
+        let second = if bytes.len() > 1 { bytes[1] } else { 0 };
+        ToleranceClass { letters: [bytes[0], second], grade }
+    }
+
+    /// The deviation letters, e.g. `"H"` or `"js"`
+    pub fn letters(&self) -> &str {
+        let len = if self.letters[1] == 0 { 1 } else { 2 };
+        std::str::from_utf8(&self.letters[..len]).unwrap_or("?")
+    }
+
+    /// True for a hole class, written in upper case
+    pub fn is_hole(&self) -> bool {
+        self.letters[0].is_ascii_uppercase()
+    }
+
+    /// Limits of size for a feature of `nominal` diameter
+    pub fn limits(&self, nominal: f64) -> Result<Limits, ToleranceError> {
+        let range = RANGES
+            .iter()
+            .position(|&limit| nominal <= limit)
+            .filter(|_| nominal > 0.0)
+            .ok_or(ToleranceError::SizeOutOfRange(nominal))?;
+        let it = self
+            .grade
+            .checked_sub(FIRST_GRADE)
+            .and_then(|i| IT.get(i as usize))
+            .ok_or(ToleranceError::UnknownGrade(self.grade))?[range];
+
+        // Holes from D to H mirror the shafts: their lower deviation is minus the shaft's upper one.
+        let letters = self.letters().to_ascii_lowercase();
+        let unknown = || ToleranceError::UnknownDeviation(self.letters().to_string());
+        let (lower, upper) = match letters.as_str() {
+            "js" => (-it / 2.0, it / 2.0),
+            "k" if !self.is_hole() => {
+                let ei = if self.grade <= 7 { K_DEVIATION[range] } else { 0.0 };
+                (ei, ei + it)
+            }
+            _ => {
+                let (_, table) = SHAFT_DEVIATIONS.iter().find(|(l, _)| *l == letters).ok_or_else(unknown)?;
+                let deviation = table[range];
+                match (self.is_hole(), letters.as_str()) {
+                    (true, "d" | "e" | "f" | "g" | "h") => (-deviation, -deviation + it),
+                    (true, _) => return Err(unknown()),
+                    (false, "d" | "e" | "f" | "g" | "h") => (deviation - it, deviation),
+                    (false, _) => (deviation, deviation + it),
+                }
+            }
+        };
+        Ok(Limits { min: nominal + lower / 1000.0, max: nominal + upper / 1000.0 })
+    }
+}
+
+impl FromStr for ToleranceClass {
+    type Err = ToleranceError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let split = text.find(|c: char| c.is_ascii_digit()).unwrap_or(text.len());
+        let (letters, grade) = text.split_at(split);
+        let syntax = || ToleranceError::Syntax(text.to_string());
+        let case = |upper: bool| letters.chars().all(|c| c.is_ascii_alphabetic() && c.is_ascii_uppercase() == upper);
+        if letters.is_empty() || letters.len() > 2 || !(case(true) || case(false)) {
+            return Err(syntax());
+        }
+        let grade = grade.parse().map_err(|_| syntax())?;
+        Ok(ToleranceClass::new(letters, grade))
+    }
+}
+
+impl fmt::Display for ToleranceClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.letters(), self.grade)
+    }
+}
+
+/// How a hole and the shaft or pin in it are sized relative to their shared nominal diameter.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Fit {
+    /// Both at exactly the nominal size.
+    #[default]
+    Nominal,
+    /// Machined to an ISO 286 hole and shaft class, e.g. `H7/g6`.
+    Iso { hole: ToleranceClass, shaft: ToleranceClass },
+    /// A printed hole, opened up by `clearance` over the shaft plus `offset` to make up for the
+    /// printer closing holes up, both on the diameter in mm. The shaft stays nominal.
+    Printed { clearance: f64, offset: f64 },
+}
+
+impl Fit {
+    /// Close running fit for shafts that turn or slide in their bore.
+    pub const SLIDING: Fit = Fit::iso_const("H", 7, "g", 6);
+    /// Locational clearance fit: a snug slip fit that can still be assembled by hand.
+    pub const SLIP: Fit = Fit::iso_const("H", 7, "h", 6);
+    /// Locational transition fit, from a slight clearance to a slight interference.
+    pub const TRANSITION: Fit = Fit::iso_const("H", 7, "k", 6);
+    /// Locational interference fit for parts pressed together.
+    pub const PRESS: Fit = Fit::iso_const("H", 7, "p", 6);
+
+    const fn iso_const(hole: &str, hole_grade: u8, shaft: &str, shaft_grade: u8) -> Fit {
+        Fit::Iso { hole: ToleranceClass::new(hole, hole_grade), shaft: ToleranceClass::new(shaft, shaft_grade) }
+    }
+
+    /// A printed clearance fit, e.g. `Fit::printed(0.2, 0.1)`
+    pub fn printed(clearance: f64, offset: f64) -> Fit {
+        Fit::Printed { clearance, offset }
+    }
+
+    /// Limits of size for the hole
+    pub fn hole_limits(&self, nominal: f64) -> Result<Limits, ToleranceError> {
+        match self {
+            Fit::Nominal => Ok(Limits { min: nominal, max: nominal }),
+            Fit::Iso { hole, .. } => hole.limits(nominal),
+            Fit::Printed { clearance, offset } => {
+                let size = nominal + clearance + offset;
+                Ok(Limits { min: size, max: size })
+            }
+        }
+    }
+
+    /// Limits of size for the shaft
+    pub fn shaft_limits(&self, nominal: f64) -> Result<Limits, ToleranceError> {
+        match self {
+            Fit::Iso { shaft, .. } => shaft.limits(nominal),
+            Fit::Nominal | Fit::Printed { .. } => Ok(Limits { min: nominal, max: nominal }),
+        }
+    }
+
+    /// Smallest and largest gap between hole and shaft on the diameter, negative for interference
+    pub fn clearance(&self, nominal: f64) -> Result<Limits, ToleranceError> {
+        let (hole, shaft) = (self.hole_limits(nominal)?, self.shaft_limits(nominal)?);
+        let compensation = match self {
+            Fit::Printed { offset, .. } => *offset,
+            _ => 0.0,
+        };
+        Ok(Limits { min: hole.min - compensation - shaft.max, max: hole.max - compensation - shaft.min })
+    }
+
+    /// Diameter to model a hole at, the middle of its tolerance band.
+    ///
+    /// Sizes outside the ISO tables are left nominal rather than failing, as the geometry is
+    /// still useful; check them with [`hole_limits`](Fit::hole_limits).
+    pub fn hole_diameter(&self, nominal: f64) -> f64 {
+        self.hole_limits(nominal).map_or(nominal, |l| l.mean())
+    }
+
+    /// Diameter to model a shaft or pin at, the middle of its tolerance band
+    pub fn shaft_diameter(&self, nominal: f64) -> f64 {
+        self.shaft_limits(nominal).map_or(nominal, |l| l.mean())
+    }
+}
+
+impl FromStr for Fit {
+    type Err = ToleranceError;
+
+    /// Parse an ISO fit such as `H7/g6`, or `nominal`
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if text.trim().eq_ignore_ascii_case("nominal") {
+            return Ok(Fit::Nominal);
+        }
+        let (hole, shaft) = text.split_once('/').ok_or_else(|| ToleranceError::Syntax(text.to_string()))?;
+        let (hole, shaft): (ToleranceClass, ToleranceClass) = (hole.parse()?, shaft.parse()?);
+        if !hole.is_hole() || shaft.is_hole() {
+            return Err(ToleranceError::Syntax(text.to_string()));
+        }
+        // Check the letters and grade are tabulated now rather than on first use.
+        hole.limits(1.0)?;
+        shaft.limits(1.0)?;
+        Ok(Fit::Iso { hole, shaft })
+    }
+}
+
+impl fmt::Display for Fit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fit::Nominal => write!(f, "nominal"),
+            Fit::Iso { hole, shaft } => write!(f, "{}/{}", hole, shaft),
+            Fit::Printed { clearance, offset } => write!(f, "printed +{}mm (offset {}mm)", clearance, offset),
+        }
+    }
+}
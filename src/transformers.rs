@@ -1,5 +1,8 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::mounting::{self, Mountable};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a transformer module
 #[derive(Debug, Clone)]
@@ -16,37 +19,23 @@ pub struct Transformer {
 
 impl Transformer {
     /// Generate the rectangular core of the transformer
-    pub fn core(&self) -> CSG<()> {
+    pub fn core(&self) -> CSG {
         CSG::prism(self.core_width, self.core_height, self.core_depth)
     }
     
     /// Generate the windings of the transformer
-    pub fn windings(&self) -> CSG<()> {
-        CSG::cylinder_z(self.winding_diameter / 2.0, self.winding_height)
-            .translate(Vector3::new(0.0, 0.0, self.core_depth / 2.0))
+    pub fn windings(&self) -> CSG {
+        CSG::z_cylinder(self.winding_diameter / 2.0, self.winding_height)
+            .translated(Vector3::new(0.0, 0.0, self.core_depth / 2.0))
     }
     
     /// Generate the mounting holes
-    pub fn mounting_holes(&self) -> CSG<()> {
-        let mut holes = CSG::new();
-        let offsets = [
-            (-self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, -self.mounting_hole_spacing / 2.0),
-            (self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-            (-self.mounting_hole_spacing / 2.0, self.mounting_hole_spacing / 2.0),
-        ];
-        
-        for &(x, y) in &offsets {
-            let hole = CSG::cylinder_z(self.mounting_hole_diameter / 2.0, self.core_depth)
-                .translate(Vector3::new(x, y, 0.0));
-            holes = holes.union(&hole);
-        }
-        
-        holes
+    pub fn mounting_holes(&self) -> CSG {
+        mounting::holes(&self.hole_positions(), self.mounting_hole_diameter, self.core_depth)
     }
     
     /// Generate the full transformer model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut transformer = self.core();
         let windings = self.windings();
         let holes = self.mounting_holes();
@@ -58,3 +47,26 @@ impl Transformer {
     }
 }
 
+impl Part for Transformer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Power
+    }
+
+    fn assemble(&self) -> CSG {
+        Transformer::assemble(self)
+    }
+}
+
+impl Mountable for Transformer {
+    fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        mounting::on_bottom(&mounting::square(self.mounting_hole_spacing), 0.0)
+    }
+
+    fn hole_diameter(&self) -> f64 {
+        self.mounting_hole_diameter
+    }
+}
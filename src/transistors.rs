@@ -1,5 +1,8 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::material::{Finishing, Material, TINNED_COPPER};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a transistor module
 #[derive(Debug, Clone)]
@@ -14,12 +17,13 @@ pub struct Transistor {
 
 impl Transistor {
     /// Generate the cylindrical body of the transistor
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_height)
+            .finished(Material::Plastic)
     }
     
     /// Generate the leads of the transistor
-    pub fn leads(&self) -> CSG<()> {
+    pub fn leads(&self) -> CSG {
         let mut leads = CSG::new();
         let offsets = [
             (-self.lead_spacing, 0.0),
@@ -28,16 +32,16 @@ impl Transistor {
         ];
         
         for &(x, y) in &offsets {
-            let lead = CSG::cylinder_z(self.lead_diameter / 2.0, self.lead_length)
-                .translate(Vector3::new(x, y, -self.lead_length));
+            let lead = CSG::z_cylinder(self.lead_diameter / 2.0, self.lead_length)
+                .translated(Vector3::new(x, y, -self.lead_length));
             leads = leads.union(&lead);
         }
         
-        leads
+        leads.finished(TINNED_COPPER)
     }
     
     /// Generate the full transistor model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut transistor = self.body();
         let leads = self.leads();
         
@@ -47,3 +51,16 @@ impl Transistor {
     }
 }
 
+impl Part for Transistor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Transistor::assemble(self)
+    }
+}
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
 
 /// Representation of a Tubing module
 #[derive(Debug, Clone)]
@@ -11,22 +14,41 @@ pub struct Tubing {
 
 impl Tubing {
     /// Generate the outer shell of the tubing
-    pub fn outer_shell(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.length)
+    pub fn outer_shell(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.length)
+            .finished(Material::Nylon)
     }
     
     /// Generate the inner bore of the tubing
-    pub fn inner_bore(&self) -> CSG<()> {
-        CSG::cylinder_z(self.inner_diameter / 2.0, self.length)
+    pub fn inner_bore(&self) -> CSG {
+        CSG::z_cylinder(self.inner_diameter / 2.0, self.length)
     }
     
     /// Generate the full tubing model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut tubing = self.outer_shell();
         let bore = self.inner_bore();
         
         tubing = tubing.subtract(&bore);
         
-        tubing
+        tubing.finished_default(Material::Nylon)
+    }
+}
+
+impl Part for Tubing {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Structural
+    }
+
+    fn assemble(&self) -> CSG {
+        Tubing::assemble(self)
+    }
+
+    fn description(&self) -> String {
+        format!("Tubing {} x {}mm", self.name, self.length)
     }
 }
@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Variac Transformer
 #[derive(Debug, Clone)]
@@ -11,19 +14,32 @@ pub struct Variac {
 
 impl Variac {
     /// Generate the transformer base
-    pub fn base(&self) -> CSG<()> {
-        CSG::cylinder_z(self.base_diameter / 2.0, self.height)
+    pub fn base(&self) -> CSG {
+        CSG::z_cylinder(self.base_diameter / 2.0, self.height)
     }
 
     /// Generate the adjustment knob
-    pub fn knob(&self) -> CSG<()> {
-        CSG::cylinder_z(self.knob_diameter / 2.0, self.knob_height)
-            .translate(Vector3::new(0.0, 0.0, self.height))
+    pub fn knob(&self) -> CSG {
+        CSG::z_cylinder(self.knob_diameter / 2.0, self.knob_height)
+            .translated(Vector3::new(0.0, 0.0, self.height))
     }
 
     /// Assemble the full Variac transformer
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.base().union(&self.knob())
     }
 }
 
+impl Part for Variac {
+    fn name(&self) -> &str {
+        "Variac"
+    }
+
+    fn category(&self) -> Category {
+        Category::Power
+    }
+
+    fn assemble(&self) -> CSG {
+        Variac::assemble(self)
+    }
+}
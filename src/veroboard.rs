@@ -1,4 +1,11 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::cache;
+use crate::colour::Colour;
+use crate::material::{Finish, Finishing, Material};
+use crate::part::{Category, Part};
+use crate::pattern;
+use crate::primitives::Primitives;
+use nalgebra::{Isometry3, Vector3};
 
 /// Representation of a Veroboard (Stripboard)
 #[derive(Debug, Clone)]
@@ -14,32 +21,50 @@ pub struct Veroboard {
 
 impl Veroboard {
     /// Generate the board body
-    pub fn body(&self) -> CSG<()> {
+    pub fn body(&self) -> CSG {
         CSG::prism(self.width, self.height, self.thickness)
+            .finished(Finish::new(Colour::rgb(0.76, 0.55, 0.33), Material::Fr4))
     }
 
     /// Generate the hole pattern
-    pub fn holes(&self) -> CSG<()> {
-        let hole = CSG::cylinder_z(self.hole_diameter / 2.0, self.thickness);
-        let mut holes = CSG::new();
-
-        let x_start = -((self.hole_count_x as f64 - 1.0) / 2.0) * self.hole_spacing;
-        let y_start = -((self.hole_count_y as f64 - 1.0) / 2.0) * self.hole_spacing;
-
-        for x in 0..self.hole_count_x {
-            for y in 0..self.hole_count_y {
-                let x_offset = x_start + (x as f64) * self.hole_spacing;
-                let y_offset = y_start + (y as f64) * self.hole_spacing;
-                holes = holes.union(&hole.translate(Vector3::new(x_offset, y_offset, 0.0)));
-            }
-        }
+    pub fn holes(&self) -> CSG {
+        let hole = CSG::z_cylinder(self.hole_diameter / 2.0, self.thickness);
+        pattern::place(&hole, &self.hole_positions())
+    }
 
-        holes
+    /// Centre of every hole, on a grid centred on the board
+    pub fn hole_positions(&self) -> Vec<Isometry3<f64>> {
+        pattern::grid(
+            self.hole_count_x,
+            self.hole_count_y,
+            Vector3::new(self.hole_spacing, 0.0, 0.0),
+            Vector3::new(0.0, self.hole_spacing, 0.0),
+        )
     }
 
     /// Assemble the complete veroboard
-    pub fn assemble(&self) -> CSG<()> {
-        self.body().difference(&self.holes())
+    pub fn assemble(&self) -> CSG {
+        let key = format!("veroboard hole {:?} {:?}", self.hole_diameter, self.thickness);
+        let hole = cache::shared().get_or_build(&key, || CSG::z_cylinder(self.hole_diameter / 2.0, self.thickness));
+        pattern::subtract_all(&self.body(), pattern::copies(&hole, &self.hole_positions()))
+            .finished_default(Finish::new(Colour::rgb(0.76, 0.55, 0.33), Material::Fr4))
     }
 }
 
+impl Part for Veroboard {
+    fn name(&self) -> &str {
+        "Veroboard"
+    }
+
+    fn category(&self) -> Category {
+        Category::Electronic
+    }
+
+    fn assemble(&self) -> CSG {
+        Veroboard::assemble(self)
+    }
+
+    fn cache_key(&self) -> Option<String> {
+        Some(cache::debug_key(self))
+    }
+}
@@ -1,5 +1,7 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a vibration motor module
 #[derive(Debug, Clone)]
@@ -15,24 +17,24 @@ pub struct VibrationMotor {
 
 impl VibrationMotor {
     /// Generate the cylindrical body of the motor
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.body_diameter / 2.0, self.body_height)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.body_diameter / 2.0, self.body_height)
     }
     
     /// Generate the eccentric mass of the motor
-    pub fn eccentric_mass(&self) -> CSG<()> {
-        CSG::cylinder_z(self.eccentric_mass_diameter / 2.0, self.eccentric_mass_thickness)
-            .translate(Vector3::new(self.body_diameter / 4.0, 0.0, self.body_height / 2.0))
+    pub fn eccentric_mass(&self) -> CSG {
+        CSG::z_cylinder(self.eccentric_mass_diameter / 2.0, self.eccentric_mass_thickness)
+            .translated(Vector3::new(self.body_diameter / 4.0, 0.0, self.body_height / 2.0))
     }
     
     /// Generate the shaft of the motor
-    pub fn shaft(&self) -> CSG<()> {
-        CSG::cylinder_z(self.shaft_diameter / 2.0, self.shaft_length)
-            .translate(Vector3::new(0.0, 0.0, self.body_height))
+    pub fn shaft(&self) -> CSG {
+        CSG::z_cylinder(self.shaft_diameter / 2.0, self.shaft_length)
+            .translated(Vector3::new(0.0, 0.0, self.body_height))
     }
     
     /// Generate the full vibration motor model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut motor = self.body();
         let eccentric_mass = self.eccentric_mass();
         let shaft = self.shaft();
@@ -44,3 +46,16 @@ impl VibrationMotor {
     }
 }
 
+impl Part for VibrationMotor {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Motor
+    }
+
+    fn assemble(&self) -> CSG {
+        VibrationMotor::assemble(self)
+    }
+}
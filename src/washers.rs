@@ -1,5 +1,11 @@
-use csgrs::CSG;
-use nalgebra::{Point3, Vector3};
+use crate::CSG;
+use crate::cache;
+use crate::anchor::Anchor;
+use crate::error::{inconsistent, positive, PartError};
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a washer
 #[derive(Debug, Clone)]
@@ -11,22 +17,67 @@ pub struct Washer {
 }
 
 impl Washer {
+    /// A washer checked by [`validate`](Washer::validate)
+    pub fn new(name: &str, outer_diameter: f64, inner_diameter: f64, thickness: f64) -> Result<Washer, PartError> {
+        let washer = Washer { name: name.to_string(), outer_diameter, inner_diameter, thickness };
+        washer.validate()?;
+        Ok(washer)
+    }
+
+    /// Check the dimensions are positive and the hole is smaller than the washer
+    pub fn validate(&self) -> Result<(), PartError> {
+        positive("outer_diameter", self.outer_diameter)?;
+        positive("inner_diameter", self.inner_diameter)?;
+        positive("thickness", self.thickness)?;
+        if self.inner_diameter >= self.outer_diameter {
+            return Err(inconsistent(&self.name, "the hole is wider than the washer"));
+        }
+        Ok(())
+    }
+
     /// Generate the outer ring of the washer
-    pub fn outer_ring(&self) -> CSG<()> {
-        CSG::cylinder_z(self.outer_diameter / 2.0, self.thickness)
+    pub fn outer_ring(&self) -> CSG {
+        CSG::z_cylinder(self.outer_diameter / 2.0, self.thickness)
+            .finished(Material::Steel)
     }
     
     /// Generate the hole in the washer
-    pub fn hole(&self) -> CSG<()> {
-        CSG::cylinder_z(self.inner_diameter / 2.0, self.thickness)
+    pub fn hole(&self) -> CSG {
+        CSG::z_cylinder(self.inner_diameter / 2.0, self.thickness)
     }
     
     /// Generate the full washer model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut washer = self.outer_ring();
         let hole = self.hole();
         washer = washer.subtract(&hole);
         
-        washer
+        washer.finished_default(Material::Steel)
+    }
+}
+
+impl Part for Washer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Fastener
+    }
+
+    fn assemble(&self) -> CSG {
+        Washer::assemble(self)
+    }
+
+    fn cache_key(&self) -> Option<String> {
+        Some(cache::debug_key(self))
+    }
+
+    /// `bottom` and `top` faces, on the axis.
+    fn anchors(&self) -> Vec<Anchor> {
+        vec![
+            Anchor::facing("bottom", Vector3::zeros(), -Vector3::z()),
+            Anchor::facing("top", Vector3::new(0.0, 0.0, self.thickness), Vector3::z()),
+        ]
     }
 }
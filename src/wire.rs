@@ -1,4 +1,7 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
 
 /// Representation of an Electrical Wire
 #[derive(Debug, Clone)]
@@ -9,13 +12,31 @@ pub struct Wire {
 
 impl Wire {
     /// Generate the wire body
-    pub fn body(&self) -> CSG<()> {
-        CSG::cylinder_z(self.diameter / 2.0, self.length)
+    pub fn body(&self) -> CSG {
+        CSG::z_cylinder(self.diameter / 2.0, self.length)
+            .finished(Material::Plastic)
     }
 
     /// Assemble the complete wire
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         self.body()
     }
 }
 
+impl Part for Wire {
+    fn name(&self) -> &str {
+        "Wire"
+    }
+
+    fn category(&self) -> Category {
+        Category::Connector
+    }
+
+    fn assemble(&self) -> CSG {
+        Wire::assemble(self)
+    }
+
+    fn description(&self) -> String {
+        format!("Wire {}mm x {}mm", self.diameter, self.length)
+    }
+}
@@ -1,4 +1,8 @@
-use csgrs::CSG;
+use crate::CSG;
+use crate::material::{Finishing, Material};
+use crate::part::{Category, Part};
+use crate::primitives::Primitives;
+use nalgebra::Vector3;
 
 /// Representation of a Zip Tie module
 #[derive(Debug, Clone)]
@@ -14,18 +18,20 @@ pub struct ZipTie {
 
 impl ZipTie {
     /// Generate the strap of the zip tie
-    pub fn strap(&self) -> CSG<()> {
+    pub fn strap(&self) -> CSG {
         CSG::prism(self.length, self.width, self.thickness)
+            .finished(Material::Nylon)
     }
     
     /// Generate the head of the zip tie
-    pub fn head(&self) -> CSG<()> {
+    pub fn head(&self) -> CSG {
         CSG::prism(self.head_width, self.head_height, self.head_depth)
-            .translate(Vector3::new(self.length / 2.0 - self.head_depth / 2.0, 0.0, 0.0))
+            .translated(Vector3::new(self.length / 2.0 - self.head_depth / 2.0, 0.0, 0.0))
+            .finished(Material::Nylon)
     }
     
     /// Generate the full zip tie model
-    pub fn assemble(&self) -> CSG<()> {
+    pub fn assemble(&self) -> CSG {
         let mut ziptie = self.strap();
         let head = self.head();
         
@@ -35,3 +41,16 @@ impl ZipTie {
     }
 }
 
+impl Part for ZipTie {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn category(&self) -> Category {
+        Category::Fastener
+    }
+
+    fn assemble(&self) -> CSG {
+        ZipTie::assemble(self)
+    }
+}
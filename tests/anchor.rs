@@ -0,0 +1,81 @@
+use csgrs::enums::Axis;
+use nalgebra::{Point3, Vector3};
+use partsrs::anchor::{self, Anchor};
+use partsrs::assembly::{Assembly, Transform};
+use partsrs::error::PartError;
+use partsrs::extrusion::{Extrusion, E2020};
+use partsrs::part::Part;
+use partsrs::pcb::PCB;
+use partsrs::screws::Screw;
+use partsrs::stepper_motors;
+
+fn close(a: Point3<f64>, b: Point3<f64>) -> bool {
+    (a - b).norm() < 1e-9
+}
+
+fn board() -> PCB {
+    PCB { width: 50.0, height: 30.0, thickness: 1.6, hole_diameter: 3.2, hole_spacing: 40.0 }
+}
+
+fn m3() -> Screw {
+    Screw::new("M3x10", 3.0, 10.0, 5.5, 3.0, 0.5).unwrap()
+}
+
+#[test]
+fn screw_seats_on_a_board() {
+    let placement = anchor::attach(&board(), "top", &m3(), "head_underside").unwrap();
+    // The head sits on the board and the shank goes down through it.
+    assert!(close(placement.transform_point(&Point3::new(0.0, 0.0, 10.0)), Point3::new(0.0, 0.0, 1.6)));
+    assert!(close(placement.transform_point(&Point3::origin()), Point3::new(0.0, 0.0, -8.4)));
+
+    let hole = anchor::attach(&board(), "hole_0", &m3(), "head_underside").unwrap();
+    let centre = board().anchor("hole_0").unwrap().translation.vector;
+    let tip = hole.transform_point(&Point3::origin());
+    assert!((tip.x - centre.x).abs() < 1e-9 && (tip.y - centre.y).abs() < 1e-9);
+}
+
+#[test]
+fn mated_frames_face_each_other() {
+    let a = Anchor::facing("a", Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.0, 1.0, 0.0)).frame;
+    let b = Anchor::facing("b", Vector3::new(-4.0, 0.0, 5.0), Vector3::new(1.0, 1.0, 0.0)).frame;
+    let placed = anchor::mate(&a, &b).matrix * b.to_homogeneous();
+    let z = placed.fixed_view::<3, 1>(0, 2).into_owned();
+    let origin = placed.fixed_view::<3, 1>(0, 3).into_owned();
+    assert!((z + a * Vector3::z()).norm() < 1e-9, "Z axes oppose: {}", z);
+    assert!((origin - a.translation.vector).norm() < 1e-9);
+}
+
+#[test]
+fn unknown_anchors_are_errors() {
+    let motor = stepper_motors::nema17_40();
+    assert!(motor.anchor("shaft").is_some());
+    assert_eq!(
+        anchor::attach(&motor, "spindle", &m3(), "tip").unwrap_err(),
+        PartError::UnknownAnchor { part: motor.name().to_string(), anchor: "spindle".to_string() }
+    );
+}
+
+#[test]
+fn assembly_anchors_are_named_by_path() {
+    let mut assembly = Assembly::new("frame");
+    assembly.add_part("motor", stepper_motors::nema17_40(), Transform::translation(Vector3::new(10.0, 0.0, 0.0)));
+    assembly.add_part("mirrored", m3(), Transform::mirroring(Axis::X));
+    let shaft = assembly.anchor("motor/shaft").unwrap();
+    let length = stepper_motors::nema17_40().anchor("shaft").unwrap().translation.z;
+    assert_eq!(shaft.translation.vector, Vector3::new(10.0, 0.0, length));
+    assert!(assembly.anchor("mirrored/tip").is_none());
+}
+
+#[test]
+fn extrusion_slots_run_along_the_length() {
+    let extrusion = Extrusion { profile: E2020, length: 100.0, center: true, corner_holes: false };
+    let anchors = extrusion.anchors();
+    let names: Vec<&str> = anchors.iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(names, ["end_a", "end_b", "slot_+x_0", "slot_-x_0", "slot_+y_0", "slot_-y_0"]);
+
+    let end_b = extrusion.anchor("end_b").unwrap();
+    assert_eq!(end_b.translation.vector, Vector3::new(0.0, 0.0, 50.0));
+    let slot = extrusion.anchor("slot_+x_0").unwrap();
+    assert!((slot * Vector3::z() - Vector3::x()).norm() < 1e-9);
+    assert!((slot * Vector3::y() - Vector3::z()).norm() < 1e-9);
+}
@@ -0,0 +1,150 @@
+mod common;
+
+use csgrs::enums::Axis;
+use nalgebra::{Matrix4, Point3, Vector3};
+use partsrs::assembly::{Assembly, Node, Transform};
+use partsrs::colour::{BLUE, RED};
+use partsrs::export::{self, Format, Mesh};
+use partsrs::part::Part;
+
+use common::Block;
+
+fn close(a: Point3<f64>, b: Point3<f64>) -> bool {
+    (a - b).norm() < 1e-9
+}
+
+/// A frame holding two blocks, itself placed twice
+fn machine() -> Assembly {
+    let mut frame = Assembly::new("frame");
+    frame.add_part("left", Block::cube(), Transform::identity());
+    frame
+        .add_part("right", Block::cube(), Transform::translation(Vector3::new(20.0, 0.0, 0.0)))
+        .set_colour(RED);
+
+    let mut machine = Assembly::new("machine");
+    machine.add_assembly("lower", frame.clone(), Transform::identity());
+    let raised = Transform::identity().rotate(0.0, 0.0, 90.0).translate(Vector3::new(0.0, 0.0, 50.0));
+    machine.add_assembly("upper", frame, raised).set_colour(BLUE);
+    machine
+}
+
+#[test]
+fn leaves_accumulate_nested_placements() {
+    let leaves = machine().leaves();
+    let paths: Vec<&str> = leaves.iter().map(|leaf| leaf.path.as_str()).collect();
+    assert_eq!(paths, ["lower/left", "lower/right", "upper/left", "upper/right"]);
+
+    // The child's own placement applies first, then its parent's.
+    let origin = |i: usize| leaves[i].transform.transform_point(&Point3::origin());
+    assert!(close(origin(1), Point3::new(20.0, 0.0, 0.0)));
+    assert!(close(origin(2), Point3::new(0.0, 0.0, 50.0)));
+    assert!(close(origin(3), Point3::new(0.0, 20.0, 50.0)), "{}", origin(3));
+
+    // The innermost colour wins.
+    assert_eq!(leaves[0].colour, None);
+    assert_eq!(leaves[1].colour, Some(RED));
+    assert_eq!(leaves[2].colour, Some(BLUE));
+    assert_eq!(leaves[3].colour, Some(RED));
+}
+
+#[test]
+fn children_are_found_by_path() {
+    let machine = machine();
+    assert!(matches!(machine.find("upper").unwrap().node, Node::Assembly(_)));
+    let right = machine.find("upper/right").unwrap();
+    assert!(matches!(right.node, Node::Part(_)));
+    assert!(close(right.transform.transform_point(&Point3::origin()), Point3::new(20.0, 0.0, 0.0)));
+    assert!(machine.find("upper/middle").is_none());
+    assert!(machine.find("upper/right/screw").is_none());
+    assert!(machine.find("side").is_none());
+}
+
+#[test]
+fn flatten_unions_every_placed_part() {
+    let solid = machine().flatten();
+    assert!(Mesh::from_csg(&solid).is_closed());
+    let bounds = solid.bounding_box();
+    assert!(close(bounds.mins, Point3::new(-10.0, 0.0, 0.0)), "{:?}", bounds);
+    assert!(close(bounds.maxs, Point3::new(30.0, 30.0, 60.0)), "{:?}", bounds);
+
+    let mirrored = Transform::identity().mirror(Axis::X);
+    assert!(mirrored.is_mirrored());
+    assert!(Mesh::from_csg(&mirrored.apply(&Block::cube().assemble())).is_closed());
+}
+
+#[test]
+fn singular_transforms_place_nothing() {
+    let flat = Transform::from_matrix(Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, 1.0, 0.0)));
+    assert!(flat.apply(&Block::cube().assemble()).polygons.is_empty());
+
+    let mut assembly = Assembly::new("squashed");
+    assembly.add_part("block", Block::cube(), flat);
+    assembly.add_part("other", Block::cube(), Transform::translation(Vector3::new(0.0, 0.0, 20.0)));
+    assert_eq!(assembly.flatten().bounding_box().mins.z, 20.0);
+}
+
+/// A base with a lid fitted at step 1, exploded upwards, and a handle on the lid at step 2
+fn kit() -> Assembly {
+    let mut lid = Assembly::new("lid");
+    lid.add_part("plate", Block::cube(), Transform::identity());
+    lid.add_part("handle", Block::cube(), Transform::translation(Vector3::new(0.0, 0.0, 10.0)))
+        .set_step(2)
+        .set_explode(Vector3::new(0.0, 0.0, 30.0));
+
+    let mut kit = Assembly::new("kit");
+    kit.add_part("base", Block::cube(), Transform::identity());
+    kit.add_assembly("lid", lid, Transform::translation(Vector3::new(0.0, 0.0, 10.0)))
+        .set_step(1)
+        .set_explode(Vector3::new(0.0, 0.0, 40.0));
+    kit
+}
+
+fn heights(assembly: &Assembly) -> Vec<(String, f64)> {
+    assembly
+        .leaves()
+        .iter()
+        .map(|leaf| (leaf.path.clone(), leaf.transform.transform_point(&Point3::origin()).z))
+        .collect()
+}
+
+fn named(pairs: &[(&str, f64)]) -> Vec<(String, f64)> {
+    pairs.iter().map(|&(name, z)| (name.to_string(), z)).collect()
+}
+
+#[test]
+fn exploded_steps_move_along_their_vectors() {
+    let kit = kit();
+    assert_eq!(kit.steps(), [0, 1, 2]);
+    assert_eq!(heights(&kit.exploded(0, 1.0)), named(&[("base", 0.0)]));
+    // The lid is fitted at step 1, taking the parts on it along, but not those fitted later.
+    assert_eq!(heights(&kit.exploded(1, 0.5)), named(&[("base", 0.0), ("lid/plate", 30.0)]));
+    assert_eq!(
+        heights(&kit.exploded(2, 1.0)),
+        named(&[("base", 0.0), ("lid/plate", 10.0), ("lid/handle", 50.0)])
+    );
+    assert_eq!(heights(&kit.exploded(2, 0.0)), heights(&kit));
+    assert_eq!(
+        heights(&kit.explode_all(1.0)),
+        named(&[("base", 0.0), ("lid/plate", 50.0), ("lid/handle", 90.0)])
+    );
+}
+
+#[test]
+fn frames_animate_each_step_home() {
+    let frames = kit().frames(3);
+    let steps: Vec<(u32, f64)> = frames.iter().map(|frame| (frame.step, frame.factor)).collect();
+    assert_eq!(steps, [(0, 1.0), (0, 0.5), (0, 0.0), (1, 1.0), (1, 0.5), (1, 0.0), (2, 1.0), (2, 0.5), (2, 0.0)]);
+    assert_eq!(heights(&frames[4].assembly), named(&[("base", 0.0), ("lid/plate", 30.0)]));
+    assert_eq!(heights(&frames.last().unwrap().assembly), heights(&kit()));
+
+    let single: Vec<f64> = kit().frames(1).iter().map(|frame| frame.factor).collect();
+    assert_eq!(single, [1.0, 1.0, 1.0]);
+    assert!(kit().frames(0).is_empty());
+
+    let dir = std::env::temp_dir().join(format!("partsrs-frames-{}", std::process::id()));
+    let paths = export::write_frames(&kit(), &dir, Format::StlBinary, 2).unwrap();
+    let names: Vec<&str> = paths.iter().map(|p| p.file_name().unwrap().to_str().unwrap()).collect();
+    assert_eq!(names, ["kit_0000.stl", "kit_0001.stl", "kit_0002.stl", "kit_0003.stl", "kit_0004.stl", "kit_0005.stl"]);
+    assert!(paths.iter().all(|p| p.exists()));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
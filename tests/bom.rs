@@ -0,0 +1,75 @@
+use partsrs::bom::Bom;
+use partsrs::part::Category;
+
+fn sample() -> Bom {
+    let mut bom = Bom::new();
+    bom.add_costed("Screw M3 cap x 10mm", Category::Fastener, 4, 0.05);
+    bom.add("Bracket, \"left\"", Category::Printed, 1);
+    let mut other = Bom::new();
+    other.add_costed("Screw M3 cap x 10mm", Category::Fastener, 2, 0.05);
+    other.add("Bracket, \"left\"", Category::Printed, 1);
+    bom.merge(&other);
+    bom
+}
+
+#[test]
+fn identical_lines_are_rolled_up() {
+    let bom = sample();
+    assert_eq!(bom.items().len(), 2);
+    assert_eq!(bom.total_quantity(), 8);
+    assert_eq!(bom.vitamins()[0].quantity, 6);
+    assert_eq!(bom.printed()[0].quantity, 2);
+    assert!((bom.total_cost() - 0.3).abs() < 1e-12);
+    assert_eq!(bom.uncosted().len(), 1);
+}
+
+#[test]
+fn csv_quotes_commas_and_quotes() {
+    assert_eq!(
+        sample().to_csv(),
+        "quantity,description,category,unit_cost\n\
+         6,Screw M3 cap x 10mm,fastener,0.05\n\
+         2,\"Bracket, \"\"left\"\"\",printed,\n"
+    );
+}
+
+#[test]
+fn json_is_valid_even_for_non_finite_costs() {
+    let mut bom = sample();
+    bom.add_costed("Mystery part", Category::Misc, 1, f64::NAN);
+    let json: serde_json::Value = serde_json::from_str(&bom.to_json()).unwrap();
+    let lines = json.as_array().unwrap();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0]["description"], "Screw M3 cap x 10mm");
+    assert_eq!(lines[0]["quantity"], 6);
+    assert_eq!(lines[0]["category"], "fastener");
+    assert_eq!(lines[0]["unit_cost"], 0.05);
+    assert_eq!(lines[1]["description"], "Bracket, \"left\"");
+    assert!(lines[1]["unit_cost"].is_null());
+    assert_eq!(lines[2]["description"], "Mystery part");
+    assert!(lines[2]["unit_cost"].is_null());
+
+    assert_eq!(Bom::new().to_json(), "[]\n");
+}
+
+#[test]
+fn markdown_has_a_table_per_kind_with_totals() {
+    let markdown = sample().to_markdown();
+    let vitamins = markdown.find("### Vitamins").unwrap();
+    let printed = markdown.find("### Printed parts").unwrap();
+    assert!(vitamins < printed, "{}", markdown);
+    assert!(markdown.contains("| 6 | Screw M3 cap x 10mm | fastener | 0.05 | 0.30 |"), "{}", markdown);
+    assert!(markdown.contains("| 2 | Bracket, \"left\" | printed |  |  |"), "{}", markdown);
+    assert!(markdown.contains("| 6 | Total vitamins | | | 0.30 |"), "{}", markdown);
+
+    let mut plain = Bom::new();
+    plain.add("Pipe | tube", Category::Structural, 1);
+    assert_eq!(
+        plain.to_markdown(),
+        "### Vitamins\n\n\
+         | Qty | Description | Category |\n\
+         |----:|:------------|:---------|\n\
+         | 1 | Pipe \\| tube | structural |\n\
+         | 1 | Total vitamins | |\n"
+    );
+}
@@ -0,0 +1,189 @@
+use csgrs::float_types::parry3d::bounding_volume::Aabb;
+use partsrs::assembly::{Assembly, Transform};
+use partsrs::cache::{self, CacheStats, Cached, GeometryCache};
+use partsrs::mass::{MassProperties, Specified};
+use partsrs::nuts::Nut;
+use partsrs::part::{Category, Part};
+use partsrs::resolution::{self, Resolution};
+use partsrs::screws::Screw;
+use partsrs::veroboard::Veroboard;
+use partsrs::CSG;
+use nalgebra::{Point3, Vector3};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{fs, thread};
+
+fn m3() -> Screw {
+    Screw::new("M3x10", 3.0, 10.0, 5.5, 3.0, 0.5).unwrap()
+}
+
+fn scratch_directory(name: &str) -> std::path::PathBuf {
+    let directory = std::env::temp_dir().join(format!("partsrs-cache-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+    directory
+}
+
+// The only test using the shared cache's solids, so the counts aren't disturbed by the others.
+#[test]
+fn repeated_parts_are_built_once() {
+    let mut assembly = Assembly::new("plate");
+    for i in 0..10 {
+        let at = Transform::translation(Vector3::new(i as f64 * 10.0, 0.0, 0.0));
+        // Wrapped or not, the screws share one solid.
+        if i % 2 == 0 {
+            assembly.add_part(format!("screw_{}", i), Cached(m3()), at);
+        } else {
+            assembly.add_part(format!("screw_{}", i), m3(), at);
+        }
+    }
+    let board = Veroboard {
+        width: 10.0,
+        height: 8.0,
+        thickness: 1.6,
+        hole_diameter: 1.0,
+        hole_spacing: 2.54,
+        hole_count_x: 3,
+        hole_count_y: 2,
+    };
+    // A larger board drills the same holes.
+    assembly.add_part("large_board", Veroboard { hole_count_x: 4, ..board.clone() }, Transform::identity());
+    assembly.add_part("board", board, Transform::identity());
+
+    let before = cache::shared().stats();
+    let solids: Vec<CSG> = assembly.leaves().iter().map(|leaf| leaf.solid()).collect();
+    let after = cache::shared().stats();
+    // One screw, each board and the hole they share.
+    assert_eq!(after.misses - before.misses, 4);
+    assert_eq!(after.hits - before.hits, 9 + 1);
+    assert_eq!(solids[3].polygons.len(), m3().assemble().polygons.len());
+}
+
+#[test]
+fn parts_without_a_key_are_not_cached() {
+    let cache = GeometryCache::new();
+    let nut = Nut::new("M3", 6.4, 2.4, 3.0).unwrap();
+    let mut assembly = Assembly::new("stack");
+    assembly.add_part("nut", nut.clone(), Transform::identity());
+    assert_eq!(assembly.cache_key(), None);
+    cache.assemble(&assembly);
+    cache.assemble(&assembly);
+    assert!(cache.is_empty());
+    assert_eq!(cache.stats(), CacheStats::default());
+
+    // Overrides that change the mass are part of the key.
+    let light = Specified::new(nut.clone()).with_mass(1.0);
+    let heavy = Specified::new(nut).with_mass(2.0);
+    assert_ne!(light.cache_key(), heavy.cache_key());
+    assert_eq!(cache.mass_properties(&light).mass, 1.0);
+    assert_eq!(cache.mass_properties(&heavy).mass, 2.0);
+}
+
+#[test]
+fn keys_cover_dimensions_and_resolution() {
+    let cache = GeometryCache::new();
+    let nut = Nut::new("M3", 6.4, 2.4, 3.0).unwrap();
+    cache.assemble(&nut);
+    cache.assemble(&nut);
+    cache.assemble(&Nut { thickness: 3.0, ..nut.clone() });
+    resolution::with(Resolution::PREVIEW, || cache.assemble(&nut));
+    assert_eq!(cache.stats(), CacheStats { hits: 1, disk_hits: 0, misses: 3 });
+    assert_eq!(cache.len(), 3);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.stats(), CacheStats::default());
+}
+
+#[test]
+fn each_solid_is_built_by_one_thread() {
+    let cache = GeometryCache::new();
+    let builds = AtomicUsize::new(0);
+    thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                cache.get_or_build("slow cube", || {
+                    builds.fetch_add(1, Ordering::SeqCst);
+                    thread::sleep(std::time::Duration::from_millis(50));
+                    CSG::prism(1.0, 1.0, 1.0)
+                })
+            });
+        }
+    });
+    assert_eq!(builds.load(Ordering::SeqCst), 1);
+    assert_eq!(cache.stats().misses, 1);
+}
+
+#[test]
+fn meshes_are_read_back_from_the_directory() {
+    let directory = scratch_directory("disk");
+    let built = GeometryCache::new().with_directory(&directory).assemble(&m3());
+
+    let cache = GeometryCache::new().with_directory(&directory);
+    let read = cache.assemble(&m3());
+    assert_eq!(cache.stats(), CacheStats { hits: 0, disk_hits: 1, misses: 0 });
+    assert_eq!(read.polygons.len(), built.polygons.len());
+    for (a, b) in read.polygons.iter().zip(&built.polygons) {
+        assert_eq!(a.metadata, b.metadata);
+        assert_eq!(a.plane.normal, b.plane.normal);
+        assert!(a.vertices.iter().zip(&b.vertices).all(|(u, v)| u.pos == v.pos && u.normal == v.normal));
+    }
+
+    // A damaged file is ignored and replaced.
+    for entry in fs::read_dir(&directory).unwrap() {
+        let path = entry.unwrap().path();
+        let bytes = fs::read(&path).unwrap();
+        fs::write(&path, &bytes[..bytes.len() / 2]).unwrap();
+    }
+    let cache = GeometryCache::new().with_directory(&directory);
+    cache.assemble(&m3());
+    assert_eq!(cache.stats().misses, 1);
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+/// A part that knows its bounds and mass without building anything, counting how often it is asked
+#[derive(Debug)]
+struct Weighed;
+
+static ASKED: AtomicUsize = AtomicUsize::new(0);
+
+impl Part for Weighed {
+    fn name(&self) -> &str {
+        "Weighed"
+    }
+
+    fn category(&self) -> Category {
+        Category::Misc
+    }
+
+    fn assemble(&self) -> CSG {
+        CSG::prism(1.0, 1.0, 1.0)
+    }
+
+    fn cache_key(&self) -> Option<String> {
+        Some(cache::debug_key(self))
+    }
+
+    fn bounding_box(&self) -> Aabb {
+        ASKED.fetch_add(1, Ordering::SeqCst);
+        Aabb::new(Point3::origin(), Point3::new(2.0, 2.0, 2.0))
+    }
+
+    fn mass_properties(&self) -> MassProperties {
+        ASKED.fetch_add(1, Ordering::SeqCst);
+        MassProperties { mass: 42.0, ..Default::default() }
+    }
+}
+
+#[test]
+fn wrapped_parts_keep_their_own_bounds_and_mass() {
+    let part = Cached(Weighed);
+    for _ in 0..3 {
+        assert_eq!(part.bounding_box().maxs, Point3::new(2.0, 2.0, 2.0));
+        assert_eq!(part.mass_properties().mass, 42.0);
+    }
+    assert_eq!(ASKED.load(Ordering::SeqCst), 2, "each worked out once");
+
+    let motor = Cached(Specified::new(m3()).with_mass(5.0));
+    assert_eq!(motor.mass_properties().mass, 5.0);
+    assert_eq!(motor.catalogue_mass(), Some(5.0));
+    assert_eq!(motor.bounding_box(), m3().bounding_box());
+}
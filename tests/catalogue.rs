@@ -0,0 +1,90 @@
+use partsrs::catalogue::{Catalogue, CatalogueError, DataFormat};
+
+const FAN92: &str = r#"
+[[fans]]
+name = "fan92x25"
+base = "fan80x25"
+frame_width = 92
+frame_height = 92
+blade_diameter = 88
+mounting_hole_spacing = 82.5
+"#;
+
+fn parameter(catalogue: &Catalogue, part: &str, name: &str) -> f64 {
+    let entry = catalogue.find(part).unwrap();
+    entry.part.parameters().into_iter().find(|(key, _)| *key == name).unwrap().1
+}
+
+/// The error of the first entry, unwrapped from its `fans[0] "..."` context
+fn entry_error(result: Result<usize, CatalogueError>) -> CatalogueError {
+    match result.unwrap_err() {
+        CatalogueError::Entry { source, .. } => *source,
+        other => other,
+    }
+}
+
+#[test]
+fn toml_entries_override_their_base() {
+    let mut catalogue = Catalogue::builtin();
+    assert_eq!(catalogue.load_str(FAN92, DataFormat::Toml).unwrap(), 1);
+    let fan = catalogue.find("FAN92X25").unwrap();
+    assert_eq!(fan.family, "fans");
+    assert_eq!(parameter(&catalogue, "fan92x25", "mounting_hole_spacing"), 82.5);
+    // Parameters not given come from the base.
+    assert_eq!(parameter(&catalogue, "fan92x25", "frame_depth"), parameter(&catalogue, "fan80x25", "frame_depth"));
+
+    // A later entry with the same name replaces the part.
+    let deeper = FAN92.replace("blade_diameter = 88", "frame_depth = 38");
+    catalogue.load_str(&deeper, DataFormat::Toml).unwrap();
+    assert_eq!(parameter(&catalogue, "fan92x25", "frame_depth"), 38.0);
+    assert_eq!(catalogue.entries().len(), Catalogue::builtin().entries().len() + 1);
+}
+
+#[test]
+fn json_loads_into_an_empty_catalogue() {
+    let json = r#"{ "stepper_motors": [{
+        "name": "NEMA8", "side_length": 20.3, "body_length": 30, "shaft_diameter": 4,
+        "shaft_length": 15, "mount_hole_spacing": 16, "mount_hole_diameter": 2,
+        "boss_diameter": 12, "boss_height": 1.5
+    }] }"#;
+    let mut catalogue = Catalogue::empty();
+    assert_eq!(catalogue.load_str(json, DataFormat::Json).unwrap(), 1);
+    assert_eq!(catalogue.entries().len(), 1);
+    assert_eq!(parameter(&catalogue, "NEMA8", "body_length"), 30.0);
+
+    // Without a base every parameter is needed.
+    let partial = r#"{ "stepper_motors": [{ "name": "NEMA11", "side_length": 28 }] }"#;
+    let error = entry_error(Catalogue::empty().load_str(partial, DataFormat::Json));
+    assert!(matches!(&error, CatalogueError::MissingParameters { part, .. } if part == "NEMA11"), "{}", error);
+}
+
+#[test]
+fn malformed_entries_are_rejected() {
+    let mut catalogue = Catalogue::builtin();
+    let bad_syntax = catalogue.load_str("[[fans]\nname = ", DataFormat::Toml);
+    assert!(matches!(bad_syntax, Err(CatalogueError::File { .. })));
+
+    let unknown = entry_error(catalogue.load_str("[[blowers]]\nname = \"x\"", DataFormat::Toml));
+    assert_eq!(unknown, CatalogueError::UnknownFamily("blowers".to_string()));
+
+    let numeric_name = entry_error(catalogue.load_str("[[fans]]\nname = 5", DataFormat::Toml));
+    assert_eq!(numeric_name, CatalogueError::NotText { parameter: "name".to_string() });
+    assert_eq!(numeric_name.to_string(), "`name` must be text");
+
+    let text_value = FAN92.replace("frame_width = 92", "frame_width = \"92\"");
+    let error = entry_error(catalogue.load_str(&text_value, DataFormat::Toml));
+    assert_eq!(error, CatalogueError::NotANumber { parameter: "frame_width".to_string() });
+
+    let infinite = FAN92.replace("frame_width = 92", "frame_width = inf");
+    let error = entry_error(catalogue.load_str(&infinite, DataFormat::Toml));
+    assert!(matches!(error, CatalogueError::InvalidValue { .. }), "{}", error);
+
+    let wrong_base = FAN92.replace("fan80x25", "E2020");
+    let error = entry_error(catalogue.load_str(&wrong_base, DataFormat::Toml));
+    assert!(matches!(error, CatalogueError::WrongFamily { .. }), "{}", error);
+
+    // A bad entry leaves the catalogue as it was, even after good ones.
+    let mixed = format!("{}\n[[fans]]\nname = \"broken\"\nbase = \"fan80x25\"\nblade_count = 0\n", FAN92);
+    assert!(catalogue.load_str(&mixed, DataFormat::Toml).is_err());
+    assert!(catalogue.find("fan92x25").is_err());
+}
@@ -0,0 +1,93 @@
+use std::process::Command;
+
+/// Run the `partsrs` binary, returning whether it succeeded and its output or error
+fn partsrs(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_partsrs")).args(args).output().unwrap();
+    let text = if output.status.success() { output.stdout } else { output.stderr };
+    (output.status.success(), String::from_utf8(text).unwrap())
+}
+
+/// The value printed after `label:` by `partsrs info`
+fn field<'a>(info: &'a str, label: &str) -> &'a str {
+    let prefix = format!("{}:", label);
+    let line = info.lines().find(|line| line.starts_with(&prefix));
+    let line = line.unwrap_or_else(|| panic!("no {} in {}", label, info));
+    line[prefix.len()..].trim()
+}
+
+#[test]
+fn overrides_change_the_part() {
+    let (ok, info) = partsrs(&["info", "E2020", "length=250", "--bom", "csv"]);
+    assert!(ok, "{}", info);
+    assert_eq!(field(&info, "size"), "20 x 20 x 250");
+    assert!(info.ends_with("1,Extrusion E2020 x 250mm,structural,\n"), "{}", info);
+
+    let (ok, error) = partsrs(&["info", "E2020", "length=abc"]);
+    assert!(!ok);
+    assert_eq!(error, "error: `abc` is not a number\n");
+    let (ok, error) = partsrs(&["info", "E2020", "colour=1"]);
+    assert!(!ok);
+    assert!(error.contains("`colour`"), "{}", error);
+    let (ok, error) = partsrs(&["info", "E2020", "length=-5"]);
+    assert!(!ok, "{}", error);
+}
+
+#[test]
+fn resolution_flags_follow_openscad() {
+    let volume = |args: &[&str]| {
+        let (ok, info) = partsrs(&[&["info", "E2020"], args].concat());
+        assert!(ok, "{}", info);
+        field(&info, "volume").to_string()
+    };
+    let default = volume(&[]);
+    // `--fn 0` means no fixed count, leaving the resolution as it was.
+    assert_eq!(volume(&["--fn", "0"]), default);
+    assert_ne!(volume(&["--fn", "6"]), default);
+    let preview = volume(&["--fa", "12"]);
+    assert_ne!(preview, default);
+    assert_eq!(volume(&["--fs", "2"]), preview);
+    // A fixed count wins over `--fa` and `--fs`, in either order.
+    assert_eq!(volume(&["--fa", "12", "--fn", "32"]), default);
+
+    let (ok, error) = partsrs(&["info", "E2020", "--fn"]);
+    assert!(!ok);
+    assert_eq!(error, "error: `--fn` needs a value\n");
+    let (ok, error) = partsrs(&["info", "E2020", "--fs", "0"]);
+    assert!(!ok);
+    assert_eq!(error, "error: `--fs` must be greater than zero\n");
+    let (ok, error) = partsrs(&["info", "E2020", "--fa", "-1"]);
+    assert!(!ok);
+    assert_eq!(error, "error: `-1` is not a valid value for `--fa`\n");
+    // Segment counts are whole numbers, not rounded down.
+    for count in ["6.5", "0.5", "-1"] {
+        let (ok, error) = partsrs(&["info", "E2020", "--fn", count]);
+        assert!(!ok);
+        assert_eq!(error, format!("error: `{}` is not a valid value for `--fn`\n", count));
+    }
+}
+
+#[test]
+fn commands_and_arguments_are_checked() {
+    let (ok, list) = partsrs(&["list", "fans"]);
+    assert!(ok);
+    assert!(list.lines().count() > 1 && list.lines().all(|line| line.contains(" fans ")), "{}", list);
+
+    let (ok, params) = partsrs(&["params", "E2020"]);
+    assert!(ok);
+    assert!(params.lines().any(|line| line.split_whitespace().eq(["length", "100"])), "{}", params);
+
+    let (ok, error) = partsrs(&["frobnicate"]);
+    assert!(!ok);
+    assert!(error.starts_with("error: unknown command `frobnicate`\nusage:"), "{}", error);
+    let (ok, error) = partsrs(&["info", "E2020", "--bom", "xml"]);
+    assert!(!ok);
+    assert_eq!(error, "error: unknown BOM format `xml`\n");
+    let output = Command::new(env!("CARGO_BIN_EXE_partsrs")).args(["info", "E2020", "--bom", "xml"]).output().unwrap();
+    assert!(output.stdout.is_empty(), "no half report: {}", String::from_utf8_lossy(&output.stdout));
+    let (ok, error) = partsrs(&["export", "E2020", "out.stl", "--shiny"]);
+    assert!(!ok);
+    assert_eq!(error, "error: unexpected argument `--shiny`\n");
+    let (ok, error) = partsrs(&["--catalogue"]);
+    assert!(!ok);
+    assert_eq!(error, "error: `--catalogue` needs a file\n");
+}
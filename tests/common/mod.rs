@@ -0,0 +1,50 @@
+//! A plain block part shared by the tests that need something simpler than the catalogue parts.
+
+// Each test crate uses only some of this.
+#![allow(dead_code)]
+
+use nalgebra::Vector3;
+use partsrs::material::{Finishing, Material};
+use partsrs::part::{Category, Part};
+use partsrs::CSG;
+
+/// A block with a corner on the origin, optionally of one material
+#[derive(Debug, Clone)]
+pub struct Block {
+    pub size: Vector3<f64>,
+    pub material: Option<Material>,
+}
+
+impl Block {
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Block { size: Vector3::new(x, y, z), material: None }
+    }
+
+    /// A 10mm cube
+    pub fn cube() -> Self {
+        Block::new(10.0, 10.0, 10.0)
+    }
+
+    pub fn with_material(mut self, material: Material) -> Self {
+        self.material = Some(material);
+        self
+    }
+}
+
+impl Part for Block {
+    fn name(&self) -> &str {
+        "Block"
+    }
+
+    fn category(&self) -> Category {
+        Category::Misc
+    }
+
+    fn assemble(&self) -> CSG {
+        let block = CSG::prism(self.size.x, self.size.y, self.size.z);
+        match self.material {
+            Some(material) => block.finished(material),
+            None => block,
+        }
+    }
+}
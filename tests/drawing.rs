@@ -0,0 +1,59 @@
+use nalgebra::Vector3;
+use partsrs::drawing::{self, Layer};
+use partsrs::extrusion::E2020;
+use partsrs::pcb::PCB;
+use partsrs::primitives::Primitives;
+use partsrs::CSG;
+
+#[test]
+fn extrusion_profile_area_matches_its_volume() {
+    let layer = Layer::from_csg(&E2020.cross_section_2d(false));
+    let area: f64 = layer.regions.iter().map(|r| r.area()).sum();
+    let volume = partsrs::mass::MassProperties::of_solid(&E2020.extrude_3d(10.0, false, false), 1.0).volume;
+    assert!((area * 10.0 - volume).abs() < 1e-6 * volume, "{} vs {}", area * 10.0, volume);
+    assert_eq!(layer.regions.len(), 1);
+    assert_eq!(layer.regions[0].holes.len(), 1, "the centre bore");
+}
+
+#[test]
+fn pcb_footprint_has_its_mounting_holes() {
+    let pcb = PCB { width: 50.0, height: 40.0, thickness: 1.6, hole_diameter: 3.0, hole_spacing: 30.0 };
+    let layer = Layer::footprint(&pcb.assemble());
+    assert_eq!(layer.regions.len(), 1);
+    let region = &layer.regions[0];
+    assert_eq!(region.outer.len(), 4, "rectangle corners only: {:?}", region.outer);
+    assert_eq!(region.holes.len(), 4);
+    assert!(region.holes.iter().all(|h| h.len() >= 8));
+}
+
+#[test]
+fn touching_squares_stay_separate_regions() {
+    let a = CSG::prism(10.0, 10.0, 1.0);
+    let b = CSG::prism(10.0, 10.0, 1.0).translated(Vector3::new(10.0, 10.0, 0.0));
+    let layer = Layer::footprint(&a.union(&b));
+    assert_eq!(layer.regions.len(), 2);
+    assert!(layer.regions.iter().all(|r| r.outer.len() == 4 && (r.area() - 100.0).abs() < 1e-9));
+}
+
+#[test]
+fn writers_carry_layer_names() {
+    let layer = Layer::footprint(&CSG::prism(10.0, 5.0, 1.0).subtract(&CSG::prism(2.0, 1.0, 1.0).translated(Vector3::new(4.0, 2.0, 0.0))))
+        .named("cut");
+
+    let svg = drawing::svg(std::slice::from_ref(&layer));
+    assert!(svg.contains("inkscape:label=\"cut\""));
+    assert!(svg.contains("width=\"10mm\" height=\"5mm\""));
+    assert_eq!(svg.matches('M').count(), 2, "outline and hole in one path: {}", svg);
+
+    let dxf = drawing::dxf(&[layer, Layer::footprint(&CSG::prism(1.0, 1.0, 1.0))]);
+    assert!(dxf.contains("LAYER\n2\ncut\n"));
+    assert!(dxf.contains("LAYER\n2\n0\n"));
+    assert_eq!(dxf.matches("POLYLINE").count(), 3);
+    assert!(dxf.ends_with("0\nEOF\n"));
+    // Only what R12 has: no units variable, and the line type the layers use is defined first.
+    assert!(dxf.starts_with("0\nSECTION\n2\nHEADER\n9\n$ACADVER\n1\nAC1009\n0\nENDSEC\n"));
+    assert!(!dxf.contains("$INSUNITS"));
+    let linetype = dxf.find("0\nLTYPE\n2\nCONTINUOUS\n").unwrap();
+    assert!(linetype < dxf.find("0\nLAYER\n").unwrap());
+    assert_eq!(dxf.matches("\n6\nCONTINUOUS\n").count(), 2, "one reference per layer");
+}
@@ -0,0 +1,132 @@
+use partsrs::colour;
+use partsrs::export::{self, ExportObject, Mesh};
+use partsrs::CSG;
+
+fn cube() -> CSG {
+    CSG::prism(10.0, 10.0, 10.0)
+}
+
+fn objects() -> Vec<ExportObject> {
+    vec![
+        ExportObject { name: "left cube".to_string(), colour: Some(colour::RED), solid: cube() },
+        ExportObject { name: "<right> & co".to_string(), colour: None, solid: cube() },
+    ]
+}
+
+fn le16(bytes: &[u8], at: usize) -> usize {
+    u16::from_le_bytes([bytes[at], bytes[at + 1]]) as usize
+}
+
+fn le32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap())
+}
+
+/// The files of a stored ZIP archive, checking each local header's CRC and sizes against its data
+fn unzip(bytes: &[u8]) -> Vec<(String, Vec<u8>)> {
+    let end = bytes.len() - 22;
+    assert_eq!(le32(bytes, end), 0x0605_4b50, "end of central directory");
+    let count = le16(bytes, end + 10);
+    let mut files = Vec::new();
+    let mut at = 0;
+    for _ in 0..count {
+        assert_eq!(le32(bytes, at), 0x0403_4b50, "local file header at {}", at);
+        assert_eq!(le16(bytes, at + 8), 0, "stored");
+        let size = le32(bytes, at + 18) as usize;
+        assert_eq!(le32(bytes, at + 22) as usize, size);
+        let name_length = le16(bytes, at + 26);
+        let name = String::from_utf8(bytes[at + 30..at + 30 + name_length].to_vec()).unwrap();
+        let start = at + 30 + name_length + le16(bytes, at + 28);
+        let data = bytes[start..start + size].to_vec();
+        assert_eq!(le32(bytes, at + 14), export::crc32(&data), "CRC of {}", name);
+        files.push((name, data));
+        at = start + size;
+    }
+    assert_eq!(le32(bytes, at), 0x0201_4b50, "central directory follows the files");
+    assert_eq!(le32(bytes, end + 16) as usize, at);
+    files
+}
+
+#[test]
+fn crc32_matches_the_check_value() {
+    assert_eq!(export::crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(export::crc32(b""), 0);
+}
+
+#[test]
+fn binary_stl_reads_back() {
+    let bytes = export::stl_binary(&cube(), "cube");
+    assert!(bytes.starts_with(b"cube\0"));
+    let triangles = le32(&bytes, 80) as usize;
+    assert_eq!(triangles, Mesh::from_csg(&cube()).triangles.len());
+    assert_eq!(bytes.len(), 84 + 50 * triangles);
+    for t in 0..triangles {
+        let record = &bytes[84 + 50 * t..84 + 50 * (t + 1)];
+        let floats: Vec<f32> = record[..48].chunks(4).map(|c| f32::from_le_bytes(c.try_into().unwrap())).collect();
+        let normal = &floats[..3];
+        assert!((normal.iter().map(|n| n * n).sum::<f32>() - 1.0).abs() < 1e-6);
+        assert!(floats[3..].iter().all(|&c| c == 0.0 || c == 10.0), "{:?}", floats);
+    }
+}
+
+#[test]
+fn ascii_stl_reads_back() {
+    let text = export::stl_ascii(&cube(), "cube");
+    assert!(text.starts_with("solid cube\n"));
+    assert!(text.ends_with("endsolid cube\n"));
+    let facets = text.matches("facet normal").count();
+    assert_eq!(facets, Mesh::from_csg(&cube()).triangles.len());
+    let vertices: Vec<[f64; 3]> = text
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("vertex "))
+        .map(|v| {
+            let c: Vec<f64> = v.split(' ').map(|x| x.parse().unwrap()).collect();
+            [c[0], c[1], c[2]]
+        })
+        .collect();
+    assert_eq!(vertices.len(), 3 * facets);
+    assert!(vertices.iter().flatten().all(|&c| c == 0.0 || c == 10.0));
+}
+
+#[test]
+fn obj_numbers_vertices_across_objects() {
+    let (obj, mtl) = export::obj(&objects(), "cubes.mtl");
+    let lines: Vec<&str> = obj.lines().collect();
+    assert_eq!(lines[0], "mtllib cubes.mtl");
+    let groups: Vec<&&str> = lines.iter().filter(|l| l.starts_with("o ")).collect();
+    assert_eq!(groups, [&"o left_cube", &"o <right>_&_co"]);
+    assert_eq!(lines.iter().filter(|l| l.starts_with("usemtl ")).count(), 1);
+    assert!(mtl.starts_with("newmtl colour_"), "{}", mtl);
+    assert!(mtl.contains("Kd 1 0 0\n"), "{}", mtl);
+
+    // Every face refers to a vertex written before it, the second object's after the first's.
+    let vertex_count = lines.iter().filter(|l| l.starts_with("v ")).count();
+    assert_eq!(vertex_count, 16);
+    let faces: Vec<Vec<usize>> = lines
+        .iter()
+        .filter_map(|l| l.strip_prefix("f "))
+        .map(|f| f.split(' ').map(|i| i.parse().unwrap()).collect())
+        .collect();
+    let half = faces.len() / 2;
+    assert!(faces[..half].iter().flatten().all(|&i| (1..=8).contains(&i)));
+    assert!(faces[half..].iter().flatten().all(|&i| (9..=16).contains(&i)));
+
+    let (plain, mtl) = export::obj(&objects()[1..], "cubes.mtl");
+    assert!(!plain.contains("mtllib"));
+    assert!(mtl.is_empty());
+}
+
+#[test]
+fn three_mf_is_a_valid_package() {
+    let files = unzip(&export::three_mf(&objects()));
+    let names: Vec<&str> = files.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["[Content_Types].xml", "_rels/.rels", "3D/3dmodel.model"]);
+
+    let model = String::from_utf8(files[2].1.clone()).unwrap();
+    assert_eq!(model.matches("<object ").count(), 2);
+    assert_eq!(model.matches("<item objectid=").count(), 2);
+    assert!(model.contains("name=\"&lt;right&gt; &amp; co\""), "{}", model);
+    assert!(model.contains("<base name=\"colour_"), "{}", model);
+    assert!(model.contains("displaycolor=\"#FF0000FF\""), "{}", model);
+    let triangles = Mesh::from_csg(&cube()).triangles.len();
+    assert_eq!(model.matches("<triangle ").count(), 2 * triangles);
+}
@@ -0,0 +1,85 @@
+use nalgebra::Point3;
+use partsrs::bom::Bom;
+use partsrs::error::PartError;
+use partsrs::fastening::{Engagement, FastenerStack};
+use partsrs::insert::Insert;
+use partsrs::nuts::Nut;
+use partsrs::part::Part;
+use partsrs::screws::{screw_longer_than, Screw};
+use partsrs::washers::Washer;
+
+fn m3_cap() -> Screw {
+    Screw::new("M3 cap", 3.0, 10.0, 5.5, 3.0, 0.5).unwrap()
+}
+
+fn m3_nut() -> Nut {
+    Nut::new("M3 nut", 6.4, 2.4, 2.5).unwrap()
+}
+
+fn m3_washer() -> Washer {
+    Washer::new("M3 washer", 7.0, 3.2, 0.5).unwrap()
+}
+
+fn m3_insert() -> Insert {
+    Insert { name: "M3 insert".to_string(), outer_diameter: 4.6, inner_diameter: 3.0, length: 5.8, thread_pitch: 0.5 }
+}
+
+#[test]
+fn stock_lengths() {
+    assert_eq!(screw_longer_than(0.5), 3.0);
+    assert_eq!(screw_longer_than(6.0), 6.0);
+    assert_eq!(screw_longer_than(6.0 + 1e-12), 6.0);
+    assert_eq!(screw_longer_than(6.1), 8.0);
+    assert_eq!(screw_longer_than(16.5), 20.0);
+    assert_eq!(screw_longer_than(41.0), 45.0);
+}
+
+#[test]
+fn nut_is_fully_engaged() {
+    let joint = FastenerStack::new(m3_cap(), Engagement::Nut(m3_nut()))
+        .clamping(3.0)
+        .clamping(5.0)
+        .with_washers(m3_washer())
+        .solve()
+        .unwrap();
+    // 0.5 + 8 + 0.5 + 2.4 + one pitch = 11.9
+    assert_eq!(joint.screw.length, 12.0);
+    assert_eq!(joint.grip, 8.0);
+
+    let assembly = joint.assembly();
+    let tip = assembly.anchor("screw/tip").unwrap().translation.vector;
+    let nut = assembly.anchor("nut/top").unwrap().translation.vector;
+    assert!((tip.z - (-11.5)).abs() < 1e-9, "tip at {}", tip.z);
+    assert!((nut.z - (-10.9)).abs() < 1e-9, "nut under the washer: {}", nut.z);
+    let head = assembly.find("screw").unwrap().transform.transform_point(&Point3::new(0.0, 0.0, 12.0));
+    assert!((head.z - 0.5).abs() < 1e-9, "head on the washer: {}", head.z);
+
+    let mut bom = Bom::new();
+    bom.add_part(&joint);
+    let lines: Vec<(String, u32)> = bom.items().into_iter().map(|i| (i.description, i.quantity)).collect();
+    assert!(lines.contains(&("Screw M3 cap x 12mm".to_string(), 1)));
+    assert!(lines.contains(&("M3 washer".to_string(), 2)));
+    assert!(lines.contains(&("M3 nut".to_string(), 1)));
+}
+
+#[test]
+fn insert_depth_is_reached_without_bottoming_out() {
+    let engagement = Engagement::Insert { insert: m3_insert(), depth: 4.0 };
+    let joint = FastenerStack::new(m3_cap(), engagement.clone()).clamping(3.0).solve().unwrap();
+    assert_eq!(joint.screw.length, 8.0);
+    assert!(joint.nut.is_none() && joint.insert.is_some());
+
+    // 16.5mm needs a 20mm screw, which goes 1.7mm further than the insert.
+    let deep = FastenerStack::new(m3_cap(), engagement).clamping(12.5).solve();
+    assert!(matches!(deep, Err(PartError::Inconsistent { .. })), "{:?}", deep);
+}
+
+#[test]
+fn bad_stacks_are_rejected() {
+    let tight = Washer::new("M2.5 washer", 6.0, 2.7, 0.5).unwrap();
+    let stack = FastenerStack::new(m3_cap(), Engagement::Nut(m3_nut())).clamping(3.0).with_head_washer(tight);
+    assert!(matches!(stack.solve(), Err(PartError::Inconsistent { .. })));
+
+    let stack = FastenerStack::new(m3_cap(), Engagement::Nut(m3_nut())).clamping(0.0);
+    assert!(matches!(stack.solve(), Err(PartError::InvalidValue { .. })));
+}
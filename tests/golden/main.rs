@@ -0,0 +1,187 @@
+//! Golden-geometry regression tests.
+//!
+//! Every part in [`parts::all`] is built at the default resolution and its volume, bounding
+//! box, manifoldness and triangle count compared with the values recorded in `parts.golden`,
+//! so a slip in a translated module or a change in csgrs shows up as a failing test.
+//!
+//! After a deliberate change to some geometry, re-record the values with
+//!
+//! ```text
+//! PARTSRS_BLESS=1 cargo test --test golden
+//! ```
+//!
+//! and review the diff of `parts.golden` like any other change.
+
+mod parts;
+
+use partsrs::export::Mesh;
+use partsrs::mass::MassProperties;
+use partsrs::resolution::{self, Resolution};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+
+/// Stack for each measuring thread.
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+
+/// Largest difference allowed in volume, relative to the recorded volume.
+const VOLUME_TOLERANCE: f64 = 1e-6;
+
+/// Largest difference allowed in each bounding box coordinate, in mm.
+const BOUNDS_TOLERANCE: f64 = 1e-6;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Measurement {
+    volume: f64,
+    mins: [f64; 3],
+    maxs: [f64; 3],
+    manifold: bool,
+    triangles: usize,
+}
+
+impl Measurement {
+    fn of(part: &(dyn partsrs::part::Part + Send + Sync)) -> Self {
+        let solid = resolution::with(Resolution::DEFAULT, || part.assemble());
+        let (mins, maxs) = if solid.polygons.is_empty() {
+            ([0.0; 3], [0.0; 3])
+        } else {
+            let bb = solid.bounding_box();
+            (bb.mins.coords.into(), bb.maxs.coords.into())
+        };
+        let mesh = Mesh::from_csg(&solid);
+        Measurement {
+            volume: MassProperties::of_solid(&solid, 1.0).volume,
+            mins,
+            maxs,
+            manifold: mesh.is_closed(),
+            triangles: mesh.triangles.len(),
+        }
+    }
+
+    fn parse(fields: &[&str]) -> Option<Self> {
+        let numbers: Vec<f64> = fields.get(..7)?.iter().map(|f| f.parse().ok()).collect::<Option<_>>()?;
+        Some(Measurement {
+            volume: numbers[0],
+            mins: [numbers[1], numbers[2], numbers[3]],
+            maxs: [numbers[4], numbers[5], numbers[6]],
+            manifold: fields.get(7)?.parse().ok()?,
+            triangles: fields.get(8)?.parse().ok()?,
+        })
+    }
+
+    /// Descriptions of every way `self` differs from the recorded `golden` values
+    fn differences(&self, golden: &Measurement) -> Vec<String> {
+        let mut out = Vec::new();
+        if (self.volume - golden.volume).abs() > VOLUME_TOLERANCE * golden.volume.abs().max(1.0) {
+            out.push(format!("volume {} != {}", self.volume, golden.volume));
+        }
+        let bounds = self.mins.iter().chain(&self.maxs).zip(golden.mins.iter().chain(&golden.maxs));
+        if bounds.clone().any(|(a, b)| (a - b).abs() > BOUNDS_TOLERANCE) {
+            out.push(format!("bounds {:?}..{:?} != {:?}..{:?}", self.mins, self.maxs, golden.mins, golden.maxs));
+        }
+        if self.manifold != golden.manifold {
+            out.push(format!("manifold {} != {}", self.manifold, golden.manifold));
+        }
+        if self.triangles != golden.triangles {
+            out.push(format!("triangles {} != {}", self.triangles, golden.triangles));
+        }
+        out
+    }
+}
+
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.6}", self.volume)?;
+        for v in self.mins.iter().chain(&self.maxs) {
+            // Avoid recording `-0.000000`, which reads as a change when it flips sign.
+            write!(f, " {:.6}", if v.abs() < 5e-7 { 0.0 } else { *v })?;
+        }
+        write!(f, " {} {}", self.manifold, self.triangles)
+    }
+}
+
+fn golden_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden/parts.golden")
+}
+
+fn read_golden() -> BTreeMap<String, Measurement> {
+    let text = std::fs::read_to_string(golden_path()).unwrap_or_default();
+    text.lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let measurement = Measurement::parse(&fields[1..])
+                .unwrap_or_else(|| panic!("malformed line in parts.golden: {}", line));
+            (fields[0].to_string(), measurement)
+        })
+        .collect()
+}
+
+fn write_golden(measured: &[(String, Measurement)]) {
+    let mut text = String::from("# key volume min_x min_y min_z max_x max_y max_z manifold triangles\n");
+    for (key, m) in measured {
+        text.push_str(&format!("{} {}\n", key, m));
+    }
+    std::fs::write(golden_path(), text).expect("writing parts.golden");
+}
+
+/// Build every part, spread over the available cores
+fn measure_all() -> Vec<(String, Measurement)> {
+    let fixtures = parts::all();
+    let threads = std::thread::available_parallelism().map_or(4, |n| n.get());
+    let chunk = fixtures.len().div_ceil(threads).max(1);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = fixtures
+            .chunks(chunk)
+            .map(|chunk| {
+                // The BSP booleans recurse deeply, far past the default thread stack.
+                std::thread::Builder::new()
+                    .stack_size(STACK_SIZE)
+                    .spawn_scoped(scope, move || {
+                        chunk
+                            .iter()
+                            .map(|(key, part)| (key.clone(), Measurement::of(part.as_ref())))
+                            .collect::<Vec<_>>()
+                    })
+                    .expect("spawning a measuring thread")
+            })
+            .collect();
+        handles.into_iter().flat_map(|h| h.join().expect("building a part panicked")).collect()
+    })
+}
+
+#[test]
+fn fixture_keys_are_unique() {
+    let fixtures = parts::all();
+    let mut keys: Vec<&str> = fixtures.iter().map(|(key, _)| key.as_str()).collect();
+    keys.sort_unstable();
+    let before = keys.len();
+    keys.dedup();
+    assert_eq!(before, keys.len(), "two fixtures share a key");
+}
+
+#[test]
+fn every_part_matches_its_golden_geometry() {
+    let measured = measure_all();
+    if std::env::var_os("PARTSRS_BLESS").is_some() {
+        write_golden(&measured);
+        return;
+    }
+
+    let mut golden = read_golden();
+    let mut failures = Vec::new();
+    for (key, m) in &measured {
+        match golden.remove(key) {
+            Some(expected) => failures.extend(m.differences(&expected).into_iter().map(|d| format!("{}: {}", key, d))),
+            None => failures.push(format!("{}: no golden values recorded", key)),
+        }
+    }
+    failures.extend(golden.keys().map(|key| format!("{}: recorded but no longer built", key)));
+
+    assert!(
+        failures.is_empty(),
+        "{} golden mismatches (re-record with PARTSRS_BLESS=1 if intended):\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,996 @@
+//! One instance of every part in the library, in NopSCADLib's sizes where it has them.
+
+use partsrs::catalogue;
+use partsrs::part::Part;
+
+use partsrs::antennas::Antenna;
+use partsrs::audio_jack::AudioJack;
+use partsrs::axial_capacitor::AxialCapacitor;
+use partsrs::ball_bearing::BallBearing;
+use partsrs::batteries::Battery;
+use partsrs::bearing_clock::BearingBlock;
+use partsrs::bearings::Bearing;
+use partsrs::belts::Belt;
+use partsrs::bldc_motors::BLDCMotor;
+use partsrs::blowers::Blower;
+use partsrs::box_assembly::BoxAssembly;
+use partsrs::box_section::BoxSection;
+use partsrs::bulldogs::BulldogClamp;
+use partsrs::butt_box::ButtBox;
+use partsrs::button::Button as PushButton;
+use partsrs::buttons::Button;
+use partsrs::buzzers::Buzzer;
+use partsrs::cable_clip::CableClip;
+use partsrs::cable_grommets::CableGrommet;
+use partsrs::cable_strip::CableStrip;
+use partsrs::camera_housing::CameraHousing;
+use partsrs::cameras::Camera;
+use partsrs::capacitors::Capacitor;
+use partsrs::carriers::CarrierBracket;
+use partsrs::circlip::Circlip;
+use partsrs::connectors::Connector;
+use partsrs::corner_block::CornerBlock;
+use partsrs::crystals::Crystal;
+use partsrs::d_connectors;
+use partsrs::diodes::Diode;
+use partsrs::dip::DIP;
+use partsrs::displays::Display;
+use partsrs::door_hinge::DoorHinge;
+use partsrs::door_latch::DoorLatch;
+use partsrs::dot_matrix_displays::DotMatrixDisplay;
+use partsrs::drag_chain::DragChainLink;
+use partsrs::e3d_hot_end::E3DHotEnd;
+use partsrs::eink_displays::EInkDisplay;
+use partsrs::electronic_components::Component;
+use partsrs::extrusion_brackets::ExtrusionBracket;
+use partsrs::fan_guard::FanGuard;
+use partsrs::faston::FastonTerminal;
+use partsrs::fixing_block::FixingBlock;
+use partsrs::flat_hinge::FlatHinge;
+use partsrs::foot::FootPad;
+use partsrs::fuse_holder::FuseHolder;
+use partsrs::fuses::Fuse;
+use partsrs::gear_motor::GearMotor;
+use partsrs::geared_stepper::GearedStepperMotor;
+use partsrs::generic_box::BoxModel;
+use partsrs::green_terminal::GreenTerminal;
+use partsrs::handle::Handle;
+use partsrs::heat_pipes::HeatPipe;
+use partsrs::hot_end::HotEnd;
+use partsrs::hygrometer::Hygrometer;
+use partsrs::iec_power_connectors::IECConnector;
+use partsrs::inductors::Inductor;
+use partsrs::insert::Insert;
+use partsrs::integrated_circuits::IC;
+use partsrs::j_head::JHead;
+use partsrs::joysticks::Joystick;
+use partsrs::keypads::Keypad;
+use partsrs::knob::Knob;
+use partsrs::lcd_displays::LCDDisplay;
+use partsrs::ldrs::LDR;
+use partsrs::leadnuts::Leadnut;
+use partsrs::led_bezel::LEDBezel;
+use partsrs::led_meter::LEDMeter;
+use partsrs::leds::LED;
+use partsrs::light_strips::LightStrip;
+use partsrs::linear_bearing::LinearBearing;
+use partsrs::magnet::Magnet;
+use partsrs::mains_sockets::MainsSocket;
+use partsrs::microphones::Microphone;
+use partsrs::microswitches::Microswitch;
+use partsrs::microview_oled_display::MicroView;
+use partsrs::module::ModuleEnclosure;
+use partsrs::motors::Motor;
+use partsrs::nuts::Nut;
+use partsrs::o_ring::ORing;
+use partsrs::oled_displays::OLEDDisplay;
+use partsrs::opengrab::OpenGrabMagnet;
+use partsrs::panel_meters::PanelMeter;
+use partsrs::pcb::PCB;
+use partsrs::pcb_mount::PCBMount;
+use partsrs::photo_interrupter::PhotoInterrupter;
+use partsrs::pillars::Pillar;
+use partsrs::pillow_blocks::PillowBlock;
+use partsrs::pin_headers::PinHeader;
+use partsrs::pocket_handle::PocketHandle;
+use partsrs::potentiometers::Potentiometer;
+use partsrs::power_supplies::PowerSupply;
+use partsrs::press_fit::PressFitPeg;
+use partsrs::printed_box::PrintedBox;
+use partsrs::printed_pulleys::PrintedPulley;
+use partsrs::psu_shroud::PSUShroud;
+use partsrs::pulleys::Pulley;
+use partsrs::rails::Rail;
+use partsrs::relays::Relay;
+use partsrs::resistors::Resistor;
+use partsrs::ribbon_clamp::RibbonClamp;
+use partsrs::ring_terminal::RingTerminal;
+use partsrs::rocker_switch::RockerSwitch;
+use partsrs::rod::Rod;
+use partsrs::rod_ends::RodEnd;
+use partsrs::rotary_encoders::RotaryEncoder;
+use partsrs::sbr_rails;
+use partsrs::screw_knob::ScrewKnob;
+use partsrs::screws::Screw;
+use partsrs::sealing_strip::SealingStrip;
+use partsrs::sensors::Sensor;
+use partsrs::servo_motor::ServoMotor;
+use partsrs::seven_segment_displays::SevenSegmentDisplay;
+use partsrs::shaft_coupling::ShaftCoupling;
+use partsrs::sheets::{self, Sheet};
+use partsrs::sk_brackets::SKBracket;
+use partsrs::smd::SMD;
+use partsrs::socket_box::SocketBox;
+use partsrs::spade::SpadeTerminal;
+use partsrs::speakers::Speaker;
+use partsrs::speakers_large::LargeSpeaker;
+use partsrs::spools::Spool;
+use partsrs::spring::Spring;
+use partsrs::ssr_shroud::SSRShroud;
+use partsrs::ssrs::SSR;
+use partsrs::strap_handle::StrapHandle;
+use partsrs::swiss_clip::SwissClip;
+use partsrs::switches::Switch;
+use partsrs::t_tracks::TTrack;
+use partsrs::toggle_switches::ToggleSwitch;
+use partsrs::tolerance::Fit;
+use partsrs::touchscreens::Touchscreen;
+use partsrs::transformers::Transformer;
+use partsrs::transistors::Transistor;
+use partsrs::tubing::Tubing;
+use partsrs::variacs::Variac;
+use partsrs::veroboard::Veroboard;
+use partsrs::vfd_displays::VFDDisplay;
+use partsrs::vibration_motors::VibrationMotor;
+use partsrs::washers::Washer;
+use partsrs::wire::Wire;
+use partsrs::ziptie::ZipTie;
+
+pub type Fixture = (String, Box<dyn Part + Send + Sync>);
+
+fn fixture(key: &str, part: impl Part + Send + Sync + 'static) -> Fixture {
+    (key.to_string(), Box::new(part))
+}
+
+/// Every catalogue entry, keyed by family and name, then every other part keyed by module
+pub fn all() -> Vec<Fixture> {
+    let mut parts: Vec<Fixture> = catalogue::entries()
+        .into_iter()
+        .map(|e| (format!("{}/{}", e.family, e.name), e.part as Box<dyn Part + Send + Sync>))
+        .collect();
+
+    parts.extend([
+        fixture("antennas", Antenna {
+            name: "WiFi".into(),
+            base_diameter: 12.0,
+            base_height: 10.0,
+            rod_diameter: 8.0,
+            rod_length: 100.0,
+            mounting_hole_diameter: 6.5,
+            mounting_hole_spacing: 0.0,
+        }),
+        fixture("audio_jack", AudioJack { body_diameter: 6.0, body_length: 12.0, pin_diameter: 3.5, pin_length: 14.0 }),
+        fixture("axial_capacitor", AxialCapacitor { body_diameter: 6.0, body_length: 12.0, lead_diameter: 0.6, lead_length: 10.0 }),
+        fixture("ball_bearing", BallBearing { outer_diameter: 22.0, inner_diameter: 8.0, bore_fit: Fit::Nominal, width: 7.0 }),
+        fixture("batteries", Battery {
+            name: "18650".into(),
+            body_width: 18.0,
+            body_height: 65.0,
+            body_depth: 18.0,
+            terminal_diameter: 8.0,
+            terminal_height: 1.0,
+            terminal_spacing: 0.0,
+        }),
+        fixture("bearing_clock", BearingBlock {
+            width: 40.0,
+            height: 20.0,
+            depth: 15.0,
+            bearing_diameter: 22.0,
+            bearing_depth: 7.0,
+            mount_hole_spacing: 30.0,
+            mount_hole_diameter: 3.4,
+        }),
+        fixture("bearings", Bearing {
+            name: "LM8UU".into(),
+            outer_diameter: 15.0,
+            inner_diameter: 8.0,
+            bore_fit: Fit::Nominal,
+            length: 24.0,
+            groove_diameter: Some(14.3),
+            groove_spacing: Some(17.5),
+        }),
+        fixture("belts", Belt { name: "GT2x6".into(), length: 40.0, width: 6.0, thickness: 1.38, tooth_pitch: 2.0, tooth_height: 0.75 }),
+        fixture("bldc_motors", BLDCMotor {
+            name: "BT2212".into(),
+            stator_diameter: 22.0,
+            rotor_diameter: 27.5,
+            height: 26.0,
+            shaft_diameter: 3.17,
+            shaft_length: 12.0,
+            mounting_hole_diameter: 3.0,
+            mounting_hole_spacing: 16.0,
+        }),
+        fixture("blowers", Blower {
+            name: "RB5015".into(),
+            body_diameter: 50.0,
+            body_height: 15.0,
+            outlet_width: 20.0,
+            outlet_height: 15.0,
+            fan_diameter: 40.0,
+            fan_blades: 9,
+        }),
+        fixture("box_assembly", BoxAssembly { width: 100.0, depth: 80.0, height: 60.0, sheet_thickness: 3.0 }),
+        fixture("box_section", BoxSection {
+            name: "AL20x20".into(),
+            outer_width: 20.0,
+            outer_height: 20.0,
+            outer_length: 100.0,
+            wall_thickness: 1.5,
+        }),
+        fixture("bulldogs", BulldogClamp {
+            name: "small".into(),
+            body_width: 19.0,
+            body_height: 12.0,
+            body_depth: 9.0,
+            jaw_width: 19.0,
+            jaw_depth: 8.0,
+            spring_diameter: 5.0,
+            spring_length: 15.0,
+        }),
+        fixture("butt_box", ButtBox { width: 100.0, height: 60.0, depth: 80.0, wall_thickness: 3.0 }),
+        fixture("button", PushButton {
+            body_diameter: 12.0,
+            body_height: 8.0,
+            cap_diameter: 10.0,
+            cap_height: 4.0,
+            pin_diameter: 0.8,
+            pin_length: 3.5,
+        }),
+        fixture("buttons", Button {
+            name: "6mm".into(),
+            body_diameter: 6.0,
+            body_height: 3.5,
+            button_diameter: 3.5,
+            button_height: 1.5,
+            pin_diameter: 0.7,
+            pin_length: 3.5,
+            pin_spacing: 4.5,
+        }),
+        fixture("buzzers", Buzzer {
+            name: "12mm".into(),
+            body_diameter: 12.0,
+            body_height: 9.5,
+            sound_hole_diameter: 2.0,
+            pin_diameter: 0.6,
+            pin_length: 6.0,
+            pin_spacing: 6.5,
+        }),
+        fixture("cable_clip", CableClip { width: 10.0, height: 8.0, depth: 12.0, cable_diameter: 5.0, hole_diameter: 3.4 }),
+        fixture("cable_grommets", CableGrommet { outer_diameter: 12.0, height: 6.0, inner_diameter: 6.0 }),
+        fixture("cable_strip", CableStrip {
+            width: 40.0,
+            height: 5.0,
+            depth: 10.0,
+            slot_width: 2.0,
+            slot_depth: 3.0,
+            slot_spacing: 6.0,
+            slot_count: 5,
+        }),
+        fixture("camera_housing", CameraHousing { width: 30.0, height: 30.0, depth: 20.0, lens_diameter: 10.0, lens_depth: 5.0 }),
+        fixture("cameras", Camera {
+            name: "rpi_camera".into(),
+            body_width: 25.0,
+            body_height: 24.0,
+            body_depth: 1.0,
+            lens_diameter: 8.5,
+            lens_depth: 5.0,
+            mounting_hole_diameter: 2.2,
+            mounting_hole_spacing: 21.0,
+        }),
+        fixture("capacitors", Capacitor {
+            name: "10x20".into(),
+            body_diameter: 10.0,
+            body_height: 20.0,
+            lead_diameter: 0.6,
+            lead_length: 5.0,
+            lead_spacing: 5.0,
+        }),
+        fixture("carriers", CarrierBracket {
+            width: 40.0,
+            height: 20.0,
+            thickness: 3.0,
+            hole_diameter: 3.4,
+            hole_spacing: 10.0,
+            hole_count: 3,
+        }),
+        fixture("circlip", Circlip::new(8.0, 8.4, 8.7, 0.8, 2.2, 1.1, 1.0, None).unwrap()),
+        fixture("connectors", Connector {
+            name: "KF301".into(),
+            body_width: 15.0,
+            body_height: 10.0,
+            body_depth: 7.5,
+            pin_diameter: 1.0,
+            pin_length: 3.5,
+            pin_count: 3,
+            pin_spacing: 5.0,
+        }),
+        fixture("corner_block", CornerBlock { size: 20.0, hole_diameter: 3.4 }),
+        fixture("crystals", Crystal {
+            name: "HC49".into(),
+            body_width: 11.0,
+            body_height: 13.5,
+            body_depth: 4.6,
+            lead_diameter: 0.43,
+            lead_length: 5.0,
+            lead_spacing: 4.88,
+        }),
+        fixture("d_connectors/DCONN9", d_connectors::dconn9()),
+        fixture("d_connectors/DCONN15", d_connectors::dconn15()),
+        fixture("d_connectors/DCONN25", d_connectors::dconn25()),
+        fixture("d_connectors/DCONN37", d_connectors::dconn37()),
+        fixture("diodes", Diode {
+            name: "1N4007".into(),
+            body_length: 5.2,
+            body_diameter: 2.7,
+            lead_diameter: 0.8,
+            lead_length: 10.0,
+            cathode_band_width: 0.8,
+        }),
+        fixture("dip", DIP {
+            name: "DIP8".into(),
+            pin_count: 8,
+            pin_diameter: 0.5,
+            pin_spacing: 2.54,
+            body_length: 9.5,
+            body_width: 6.35,
+            body_height: 3.3,
+        }),
+        fixture("displays", Display {
+            name: "LCD1602".into(),
+            screen_width: 64.5,
+            screen_height: 14.5,
+            screen_depth: 1.0,
+            bezel_width: 71.2,
+            bezel_height: 24.2,
+            bezel_depth: 7.0,
+            mounting_hole_diameter: 3.0,
+            mounting_hole_spacing: 75.0,
+        }),
+        fixture("door_hinge", DoorHinge {
+            leaf_width: 20.0,
+            leaf_height: 40.0,
+            thickness: 3.0,
+            pin_diameter: 3.0,
+            hole_diameter: 3.4,
+            hole_spacing: 15.0,
+            hole_count: 2,
+        }),
+        fixture("door_latch", DoorLatch {
+            width: 20.0,
+            height: 40.0,
+            thickness: 4.0,
+            latch_depth: 10.0,
+            hole_diameter: 3.4,
+            hole_spacing: 20.0,
+            hole_count: 2,
+        }),
+        fixture("dot_matrix_displays", DotMatrixDisplay {
+            name: "MAX7219".into(),
+            pixel_diameter: 3.0,
+            pixel_spacing: 4.0,
+            rows: 4,
+            columns: 4,
+            panel_width: 16.0,
+            panel_height: 16.0,
+            panel_depth: 7.0,
+        }),
+        fixture("drag_chain", DragChainLink { length: 20.0, width: 15.0, height: 10.0, hole_diameter: 3.0 }),
+        fixture("e3d_hot_end", E3DHotEnd {
+            heat_sink_diameter: 22.3,
+            heat_sink_height: 26.0,
+            heat_break_diameter: 6.0,
+            heat_break_length: 22.0,
+            nozzle_diameter: 6.0,
+            nozzle_length: 5.0,
+        }),
+        fixture("eink_displays", EInkDisplay {
+            name: "EPD2.9".into(),
+            screen_width: 66.9,
+            screen_height: 29.1,
+            screen_depth: 1.0,
+            bezel_width: 79.0,
+            bezel_height: 36.7,
+            bezel_depth: 1.2,
+            mounting_hole_diameter: 3.0,
+            mounting_hole_spacing: 72.0,
+        }),
+        fixture("electronic_components", Component {
+            width: 10.0,
+            height: 5.0,
+            depth: 4.0,
+            lead_spacing: 5.0,
+            lead_diameter: 0.6,
+            lead_length: 5.0,
+        }),
+        fixture("extrusion_brackets", ExtrusionBracket { width: 20.0, height: 20.0, thickness: 3.0, hole_diameter: 5.5, hole_spacing: 10.0 }),
+        fixture("fan_guard", FanGuard { outer_diameter: 40.0, thickness: 1.5, vent_diameter: 5.0, vent_count: 6 }),
+        fixture("faston", FastonTerminal { width: 6.3, thickness: 0.8, length: 20.0, barrel_diameter: 4.0, barrel_length: 8.0 }),
+        fixture("fixing_block", FixingBlock::for_screw(3.0)),
+        fixture("flat_hinge", FlatHinge {
+            width: 40.0,
+            height: 20.0,
+            thickness: 3.0,
+            pin_diameter: 3.0,
+            hole_diameter: 3.4,
+            hole_spacing: 15.0,
+            hole_count: 2,
+        }),
+        fixture("foot", FootPad { diameter: 20.0, height: 8.0, hole_diameter: 3.4 }),
+        fixture("fuse_holder", FuseHolder {
+            body_diameter: 12.0,
+            body_length: 30.0,
+            cap_diameter: 14.0,
+            cap_length: 10.0,
+            fuse_diameter: 5.0,
+            fuse_length: 20.0,
+        }),
+        fixture("fuses", Fuse { name: "5x20".into(), body_length: 20.0, body_diameter: 5.0, cap_diameter: 5.2, cap_height: 5.0 }),
+        fixture("gear_motor", GearMotor {
+            name: "GM37".into(),
+            body_diameter: 37.0,
+            body_length: 33.0,
+            shaft_diameter: 6.0,
+            shaft_length: 14.0,
+            gear_diameter: 37.0,
+            gear_width: 22.0,
+            mounting_hole_diameter: 3.0,
+            mounting_hole_spacing: 31.0,
+        }),
+        fixture("geared_stepper", GearedStepperMotor {
+            body_diameter: 28.0,
+            body_length: 19.0,
+            gear_diameter: 9.0,
+            gear_length: 1.5,
+            shaft_diameter: 5.0,
+            shaft_length: 10.0,
+        }),
+        fixture("generic_box", BoxModel { width: 100.0, height: 60.0, depth: 80.0, wall_thickness: 3.0 }),
+        fixture("green_terminal", GreenTerminal {
+            width: 10.0,
+            height: 10.0,
+            depth: 8.0,
+            hole_diameter: 3.0,
+            hole_spacing: 5.0,
+            hole_count: 2,
+        }),
+        fixture("handle", Handle { length: 100.0, diameter: 10.0, height: 30.0, screw_diameter: 4.0 }),
+        fixture("heat_pipes", HeatPipe {
+            name: "6mm".into(),
+            outer_diameter: 6.0,
+            inner_diameter: 5.0,
+            length: 60.0,
+            fin_diameter: 20.0,
+            fin_thickness: 0.5,
+            fin_count: 5,
+        }),
+        fixture("hot_end", HotEnd {
+            name: "V6".into(),
+            heater_block_width: 16.0,
+            heater_block_height: 11.5,
+            heater_block_depth: 20.0,
+            nozzle_diameter: 6.0,
+            nozzle_length: 5.0,
+            heat_break_diameter: 6.0,
+            heat_break_length: 22.0,
+        }),
+        fixture("hygrometer", Hygrometer { width: 50.0, height: 30.0, depth: 15.0, sensor_diameter: 10.0 }),
+        fixture("iec_power_connectors", IECConnector {
+            name: "IEC_320_C14".into(),
+            width: 27.0,
+            height: 19.0,
+            depth: 23.0,
+            mount_hole_spacing: 40.0,
+            mount_hole_diameter: 3.5,
+        }),
+        fixture("inductors", Inductor {
+            name: "toroid".into(),
+            core_diameter: 10.0,
+            core_height: 5.0,
+            winding_diameter: 10.0,
+            winding_spacing: 1.0,
+            winding_count: 1,
+        }),
+        fixture("insert", Insert { name: "M3".into(), outer_diameter: 4.6, inner_diameter: 3.0, length: 5.7, thread_pitch: 0.5 }),
+        fixture("integrated_circuits", IC {
+            name: "SOIC8".into(),
+            body_width: 4.9,
+            body_height: 1.75,
+            body_depth: 3.9,
+            pin_diameter: 0.4,
+            pin_length: 1.0,
+            pin_spacing: 1.27,
+            pin_count: 8,
+        }),
+        fixture("j_head", JHead {
+            heat_sink_diameter: 16.0,
+            heat_sink_height: 40.0,
+            heat_break_diameter: 6.0,
+            heat_break_length: 10.0,
+            nozzle_diameter: 6.0,
+            nozzle_length: 5.0,
+        }),
+        fixture("joysticks", Joystick {
+            name: "thumb".into(),
+            base_diameter: 26.0,
+            base_height: 10.0,
+            stick_diameter: 4.0,
+            stick_length: 12.0,
+            top_diameter: 20.0,
+            top_height: 6.0,
+        }),
+        fixture("keypads", Keypad {
+            name: "4x4".into(),
+            base_width: 60.0,
+            base_height: 60.0,
+            base_depth: 3.0,
+            key_width: 10.0,
+            key_height: 10.0,
+            key_depth: 3.0,
+            rows: 4,
+            columns: 4,
+            key_spacing: 14.0,
+        }),
+        fixture("knob", Knob { outer_diameter: 20.0, height: 15.0, shaft_diameter: 6.0, shaft_depth: 10.0, shaft_fit: Fit::Nominal }),
+        fixture("lcd_displays", LCDDisplay {
+            name: "LCD2004".into(),
+            screen_width: 76.0,
+            screen_height: 25.2,
+            screen_depth: 1.0,
+            bezel_width: 97.5,
+            bezel_height: 40.0,
+            bezel_depth: 9.0,
+            mounting_hole_diameter: 3.5,
+            mounting_hole_spacing: 93.0,
+        }),
+        fixture("ldrs", LDR {
+            name: "5mm".into(),
+            body_diameter: 5.0,
+            body_height: 2.0,
+            lead_diameter: 0.5,
+            lead_length: 20.0,
+            lead_spacing: 3.4,
+        }),
+        fixture("leadnuts", Leadnut {
+            outer_diameter: 10.2,
+            inner_diameter: 8.0,
+            length: 15.0,
+            flange_diameter: 22.0,
+            flange_thickness: 3.5,
+            mounting_hole_diameter: 3.5,
+            mounting_hole_spacing: 16.0,
+        }),
+        fixture("led_bezel", LEDBezel { outer_diameter: 8.0, inner_diameter: 5.0, height: 6.0 }),
+        fixture("led_meter", LEDMeter { width: 48.0, height: 29.0, depth: 21.0, led_diameter: 3.0, led_spacing: 6.0, led_count: 4 }),
+        fixture("leds", LED {
+            name: "5mm".into(),
+            body_diameter: 5.0,
+            body_height: 8.6,
+            pin_diameter: 0.5,
+            pin_length: 25.0,
+            pin_spacing: 2.54,
+        }),
+        fixture("light_strips", LightStrip {
+            name: "RIBBON".into(),
+            strip_length: 50.0,
+            strip_width: 10.0,
+            strip_thickness: 0.5,
+            led_diameter: 5.0,
+            led_spacing: 16.6,
+            led_count: 3,
+        }),
+        fixture("linear_bearing", LinearBearing {
+            name: "LM8UU".into(),
+            outer_diameter: 15.0,
+            inner_diameter: 8.0,
+            length: 24.0,
+            ball_diameter: 2.0,
+            ball_spacing: 5.0,
+            ball_rows: 2,
+            ball_columns: 3,
+        }),
+        fixture("magnet", Magnet { diameter: 10.0, thickness: 3.0, hole_diameter: 3.0 }),
+        fixture("mains_sockets", MainsSocket { width: 86.0, height: 86.0, depth: 25.0, hole_diameter: 4.0, hole_spacing: 60.3 }),
+        fixture("microphones", Microphone {
+            name: "BOB-12758".into(),
+            body_diameter: 9.7,
+            body_height: 5.0,
+            grille_diameter: 6.0,
+            grille_height: 1.0,
+            mounting_hole_diameter: 3.3,
+            mounting_hole_spacing: 12.7,
+        }),
+        fixture("microswitches", Microswitch {
+            name: "SS-01".into(),
+            body_width: 19.8,
+            body_height: 10.2,
+            body_depth: 6.4,
+            lever_length: 16.0,
+            lever_width: 4.0,
+            lever_thickness: 0.5,
+            mounting_hole_diameter: 2.3,
+            mounting_hole_spacing: 9.5,
+        }),
+        fixture("microview_oled_display", MicroView { width: 26.0, height: 20.0, depth: 14.0, screen_width: 18.0, screen_height: 12.0 }),
+        fixture("module", ModuleEnclosure {
+            width: 60.0,
+            height: 40.0,
+            depth: 20.0,
+            vent_hole_diameter: 3.0,
+            vent_hole_spacing: 6.0,
+            vent_hole_count: 5,
+        }),
+        fixture("motors", Motor {
+            name: "RF300".into(),
+            body_diameter: 24.0,
+            body_length: 12.5,
+            shaft_diameter: 2.0,
+            shaft_length: 9.0,
+            mounting_hole_diameter: 2.0,
+            mounting_hole_spacing: 13.0,
+        }),
+        fixture("nuts", Nut { name: "M3".into(), diameter: 6.4, thickness: 2.4, hole_diameter: 3.0 }),
+        fixture("o_ring", ORing { outer_diameter: 20.0, thickness: 2.0 }),
+        fixture("oled_displays", OLEDDisplay {
+            name: "SSD1306".into(),
+            screen_width: 21.74,
+            screen_height: 10.86,
+            screen_depth: 1.0,
+            bezel_width: 27.3,
+            bezel_height: 27.8,
+            bezel_depth: 3.7,
+            mounting_hole_diameter: 2.0,
+            mounting_hole_spacing: 23.5,
+        }),
+        fixture("opengrab", OpenGrabMagnet::new(37.0, 37.0, 5.0, 4.0, 18.0, 4).unwrap()),
+        fixture("panel_meters", PanelMeter {
+            name: "PZEM021".into(),
+            body_width: 75.0,
+            body_height: 45.0,
+            body_depth: 20.0,
+            display_width: 50.0,
+            display_height: 25.0,
+            display_depth: 2.0,
+            mounting_hole_diameter: 3.0,
+            mounting_hole_spacing: 65.0,
+        }),
+        fixture("pcb", PCB { width: 50.0, height: 40.0, thickness: 1.6, hole_diameter: 3.0, hole_spacing: 40.0 }),
+        fixture("pcb_mount", PCBMount { width: 60.0, height: 40.0, thickness: 3.0, hole_diameter: 3.0, hole_spacing: 10.0, hole_count: 4 }),
+        fixture("photo_interrupter", PhotoInterrupter {
+            body_width: 24.0,
+            body_height: 11.0,
+            body_depth: 6.0,
+            slot_width: 5.0,
+            slot_depth: 8.0,
+            slot_height: 6.0,
+            pin_diameter: 0.5,
+            pin_length: 4.0,
+        }),
+        fixture("pillars", Pillar { diameter: 6.0, height: 20.0, hole_diameter: 3.0 }),
+        fixture("pillow_blocks", PillowBlock {
+            name: "KP08".into(),
+            base_width: 13.0,
+            base_length: 55.0,
+            base_height: 5.0,
+            bore_diameter: 8.0,
+            bore_fit: Fit::Nominal,
+            housing_diameter: 27.0,
+            housing_height: 15.0,
+            mounting_hole_diameter: 5.0,
+            mounting_hole_spacing: 42.0,
+        }),
+        fixture("pin_headers", PinHeader {
+            pin_count: 4,
+            pin_diameter: 0.64,
+            pin_length: 11.5,
+            row_spacing: 2.54,
+            base_width: 2.5,
+            base_length: 10.16,
+            base_thickness: 2.5,
+        }),
+        fixture("pocket_handle", PocketHandle { width: 80.0, height: 40.0, depth: 15.0, hole_diameter: 4.0, hole_spacing: 60.0, hole_count: 2 }),
+        fixture("potentiometers", Potentiometer {
+            name: "RV24".into(),
+            body_diameter: 24.0,
+            body_height: 9.0,
+            shaft_diameter: 6.0,
+            shaft_length: 20.0,
+            pin_diameter: 1.0,
+            pin_length: 5.0,
+            pin_spacing: 5.0,
+        }),
+        fixture("power_supplies", PowerSupply {
+            name: "S-50-12".into(),
+            body_width: 99.0,
+            body_height: 30.0,
+            body_depth: 82.0,
+            vent_hole_diameter: 4.0,
+            vent_hole_spacing: 10.0,
+            mounting_hole_diameter: 3.5,
+            mounting_hole_spacing: 80.0,
+        }),
+        fixture("press_fit", PressFitPeg { diameter: 4.0, length: 8.0, interference: 0.2, chamfer: 0.5 }),
+        fixture("printed_box", PrintedBox { width: 100.0, height: 60.0, depth: 80.0, wall_thickness: 2.0 }),
+        fixture("printed_pulleys", PrintedPulley { outer_diameter: 20.0, width: 8.0, bore_diameter: 5.0, bore_fit: Fit::Nominal, tooth_count: 20, tooth_depth: 0.75 }),
+        fixture("psu_shroud", PSUShroud {
+            width: 115.0,
+            height: 50.0,
+            depth: 40.0,
+            vent_hole_diameter: 4.0,
+            vent_hole_spacing: 8.0,
+            vent_hole_count: 5,
+        }),
+        fixture("pulleys", Pulley {
+            name: "GT2x20".into(),
+            outer_diameter: 12.2,
+            inner_diameter: 5.0,
+            width: 16.0,
+            groove_depth: 0.75,
+            groove_count: 2,
+        }),
+        fixture("rails", Rail { name: "MGN12".into(), length: 100.0, width: 12.0, height: 8.0, hole_diameter: 3.5, hole_spacing: 25.0 }),
+        fixture("relays", Relay {
+            name: "HF3F".into(),
+            body_width: 19.0,
+            body_height: 15.5,
+            body_depth: 15.5,
+            coil_diameter: 8.0,
+            coil_height: 10.0,
+            contact_diameter: 1.0,
+            contact_spacing: 5.0,
+        }),
+        fixture("resistors", Resistor { name: "RES1_4".into(), body_length: 6.3, body_diameter: 2.5, lead_diameter: 0.6, lead_length: 10.0 }),
+        fixture("ribbon_clamp", RibbonClamp { width: 30.0, height: 8.0, depth: 10.0, slot_width: 20.0, slot_height: 2.0 }),
+        fixture("ring_terminal", RingTerminal {
+            name: "M3".into(),
+            outer_diameter: 6.0,
+            inner_diameter: 3.2,
+            terminal_length: 12.0,
+            terminal_width: 3.0,
+            terminal_thickness: 0.8,
+        }),
+        fixture("rocker_switch", RockerSwitch { width: 21.0, height: 15.0, depth: 20.0, rocker_width: 13.0, rocker_height: 9.0 }),
+        fixture("rod", Rod { diameter: 8.0, length: 100.0, fit: Fit::Nominal }),
+        fixture("rod_ends", RodEnd {
+            name: "SI5".into(),
+            ball_diameter: 8.0,
+            housing_diameter: 18.0,
+            housing_thickness: 8.0,
+            shank_diameter: 5.0,
+            shank_length: 20.0,
+        }),
+        fixture("rotary_encoders", RotaryEncoder {
+            name: "KY-040".into(),
+            body_diameter: 12.0,
+            body_height: 6.5,
+            shaft_diameter: 6.0,
+            shaft_length: 15.0,
+            pin_diameter: 0.8,
+            pin_length: 4.0,
+            pin_spacing: 2.5,
+        }),
+        fixture("sbr_rails/SBR12", sbr_rails::sbr12(200.0)),
+        fixture("sbr_rails/SBR16", sbr_rails::sbr16(200.0)),
+        fixture("sbr_rails/SBR20", sbr_rails::sbr20(200.0)),
+        fixture("screw_knob", ScrewKnob { outer_diameter: 20.0, height: 10.0, screw_diameter: 3.0, screw_length: 16.0 }),
+        fixture("screws", Screw { name: "M3x10".into(), diameter: 3.0, length: 10.0, head_diameter: 5.5, head_height: 3.0, thread_pitch: 0.5 }),
+        fixture("sealing_strip", SealingStrip { length: 100.0, width: 10.0, height: 5.0, groove_width: 3.0, groove_depth: 2.0 }),
+        fixture("sensors", Sensor {
+            name: "HC-SR04".into(),
+            body_width: 45.0,
+            body_height: 20.0,
+            body_depth: 1.6,
+            lens_diameter: 16.0,
+            lens_depth: 12.0,
+            mounting_hole_diameter: 1.8,
+            mounting_hole_spacing: 41.0,
+        }),
+        fixture("servo_motor", ServoMotor {
+            body_width: 23.0,
+            body_height: 12.2,
+            body_depth: 29.0,
+            horn_diameter: 20.0,
+            shaft_diameter: 4.8,
+            shaft_length: 4.0,
+        }),
+        fixture("seven_segment_displays", SevenSegmentDisplay {
+            name: "2digit".into(),
+            digit_width: 12.5,
+            digit_height: 19.0,
+            digit_depth: 8.0,
+            segment_width: 1.5,
+            segment_height: 6.0,
+            segment_depth: 0.5,
+            digit_spacing: 12.7,
+            num_digits: 2,
+        }),
+        fixture("shaft_coupling", ShaftCoupling { outer_diameter: 19.0, length: 25.0, bore_diameter: 5.0, bore_fit: Fit::Nominal }),
+        fixture("sheets/MDF6", Sheet { kind: sheets::MDF6, width: 100.0, depth: 60.0, corner_radius: 0.0 }),
+        fixture("sheets/AL3", Sheet { kind: sheets::AL3, width: 100.0, depth: 60.0, corner_radius: 5.0 }),
+        fixture("sk_brackets", SKBracket {
+            name: "SK8".into(),
+            hole_diameter: 8.0,
+            base_width: 42.0,
+            base_length: 14.0,
+            height: 33.5,
+            mount_hole_spacing: 32.0,
+        }),
+        fixture("smd", SMD {
+            name: "0805".into(),
+            body_length: 2.0,
+            body_width: 1.25,
+            body_height: 0.5,
+            pad_length: 0.4,
+            pad_width: 1.25,
+            pad_height: 0.5,
+            pad_spacing: 1.6,
+        }),
+        fixture("socket_box", SocketBox {
+            width: 86.0,
+            height: 86.0,
+            depth: 35.0,
+            wall_thickness: 2.0,
+            hole_diameter: 4.0,
+            hole_spacing: 60.3,
+            hole_count: 2,
+        }),
+        fixture("spade", SpadeTerminal {
+            name: "spade6p3".into(),
+            blade_width: 6.3,
+            blade_thickness: 0.8,
+            blade_length: 8.0,
+            barrel_diameter: 4.0,
+            barrel_length: 6.0,
+        }),
+        fixture("speakers", Speaker {
+            name: "CDM4".into(),
+            body_diameter: 40.0,
+            body_height: 15.0,
+            cone_diameter: 30.0,
+            cone_depth: 5.0,
+            mounting_hole_diameter: 3.0,
+            mounting_hole_spacing: 34.0,
+        }),
+        fixture("speakers_large", LargeSpeaker {
+            name: "BT2".into(),
+            body_diameter: 100.0,
+            body_height: 50.0,
+            cone_diameter: 80.0,
+            cone_depth: 20.0,
+            mounting_hole_diameter: 4.5,
+            mounting_hole_spacing: 90.0,
+        }),
+        fixture("spools", Spool {
+            name: "reel".into(),
+            outer_diameter: 200.0,
+            inner_diameter: 52.0,
+            width: 70.0,
+            hub_diameter: 80.0,
+            hub_width: 60.0,
+        }),
+        fixture("spring", Spring { coil_diameter: 6.0, wire_diameter: 0.8, turns: 5, height: 15.0 }),
+        fixture("ssr_shroud", SSRShroud {
+            width: 62.0,
+            height: 45.0,
+            depth: 30.0,
+            vent_hole_diameter: 3.0,
+            vent_hole_spacing: 8.0,
+            vent_hole_count: 4,
+        }),
+        fixture("ssrs", SSR {
+            name: "SSR25DA".into(),
+            model: "FOTEK SSR-25 DA".into(),
+            length: 62.5,
+            width: 45.0,
+            height: 23.0,
+            hole_diameter: 4.5,
+            hole_spacing: 47.6,
+            slot_width: 4.5,
+        }),
+        fixture("strap_handle", StrapHandle { width: 20.0, thickness: 3.0, length: 100.0, hole_diameter: 4.0 }),
+        fixture("swiss_clip", SwissClip { width: 20.0, height: 30.0, thickness: 2.0, hole_diameter: 3.4, hole_spacing: 15.0 }),
+        fixture("switches", Switch {
+            name: "tact".into(),
+            body_width: 12.0,
+            body_height: 12.0,
+            body_depth: 4.3,
+            button_diameter: 6.5,
+            button_height: 3.0,
+            terminal_diameter: 1.0,
+            terminal_spacing: 5.0,
+        }),
+        fixture("t_tracks", TTrack { length: 100.0, base_width: 19.0, base_height: 9.5, slot_width: 8.0, slot_depth: 5.0 }),
+        fixture("toggle_switches", ToggleSwitch {
+            name: "MTS-101".into(),
+            body_width: 13.0,
+            body_height: 8.0,
+            body_depth: 10.0,
+            lever_diameter: 3.0,
+            lever_length: 10.0,
+            pin_diameter: 1.0,
+            pin_length: 5.0,
+            pin_spacing: 4.7,
+        }),
+        fixture("touchscreens", Touchscreen {
+            name: "RPI7".into(),
+            screen_width: 155.0,
+            screen_height: 86.0,
+            screen_depth: 1.0,
+            bezel_width: 194.0,
+            bezel_height: 110.0,
+            bezel_depth: 6.0,
+            mounting_hole_diameter: 3.0,
+            mounting_hole_spacing: 126.2,
+        }),
+        fixture("transformers", Transformer {
+            name: "CCM300".into(),
+            core_width: 57.0,
+            core_height: 48.0,
+            core_depth: 30.0,
+            winding_diameter: 40.0,
+            winding_height: 25.0,
+            mounting_hole_diameter: 4.5,
+            mounting_hole_spacing: 48.0,
+        }),
+        fixture("transistors", Transistor {
+            name: "TO92".into(),
+            body_diameter: 4.8,
+            body_height: 4.5,
+            lead_diameter: 0.45,
+            lead_length: 12.0,
+            lead_spacing: 1.27,
+        }),
+        fixture("tubing", Tubing { name: "PTFE4".into(), outer_diameter: 4.0, inner_diameter: 2.0, length: 50.0 }),
+        fixture("variacs", Variac { base_diameter: 100.0, height: 60.0, knob_diameter: 40.0, knob_height: 20.0 }),
+        fixture("veroboard", Veroboard {
+            width: 20.0,
+            height: 15.0,
+            thickness: 1.6,
+            hole_diameter: 1.0,
+            hole_spacing: 2.54,
+            hole_count_x: 4,
+            hole_count_y: 3,
+        }),
+        fixture("vfd_displays", VFDDisplay {
+            name: "IV-18".into(),
+            screen_width: 80.0,
+            screen_height: 15.0,
+            screen_depth: 1.0,
+            bezel_width: 100.0,
+            bezel_height: 25.0,
+            bezel_depth: 10.0,
+            mounting_hole_diameter: 3.0,
+            mounting_hole_spacing: 90.0,
+        }),
+        fixture("vibration_motors", VibrationMotor {
+            name: "1027".into(),
+            body_diameter: 10.0,
+            body_height: 2.7,
+            eccentric_mass_diameter: 6.0,
+            eccentric_mass_thickness: 1.5,
+            shaft_diameter: 0.8,
+            shaft_length: 2.0,
+        }),
+        fixture("washers", Washer { name: "M3".into(), outer_diameter: 7.0, inner_diameter: 3.2, thickness: 0.5 }),
+        fixture("wire", Wire { diameter: 1.6, length: 50.0 }),
+        fixture("ziptie", ZipTie {
+            name: "100mm".into(),
+            length: 100.0,
+            width: 2.5,
+            thickness: 1.0,
+            head_width: 4.5,
+            head_height: 5.0,
+            head_depth: 4.0,
+        }),
+    ]);
+    parts
+}
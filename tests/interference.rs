@@ -0,0 +1,69 @@
+mod common;
+
+use nalgebra::Vector3;
+use partsrs::assembly::{Assembly, Transform};
+use partsrs::interference::{self, InterferenceCheck, NearMiss, Overlap};
+use partsrs::part::Part;
+
+use common::Block;
+
+/// Cubes placed at each x offset, named `cube_0`, `cube_1` ... inside a `row` sub-assembly
+fn row(offsets: &[f64]) -> Assembly {
+    let mut row = Assembly::new("row");
+    for (i, &x) in offsets.iter().enumerate() {
+        row.add_part(format!("cube_{}", i), Block::cube(), Transform::translation(Vector3::new(x, 0.0, 0.0)));
+    }
+    let mut assembly = Assembly::new("test");
+    assembly.add_assembly("row", row, Transform::identity());
+    assembly
+}
+
+#[test]
+fn overlapping_cubes_report_their_common_volume() {
+    assert!((interference::overlap_volume(&Block::cube().assemble(), &Block::cube().assemble()) - 1000.0).abs() < 1e-6);
+
+    let report = InterferenceCheck::new().check(&row(&[0.0, 7.5, 30.0]));
+    assert_eq!(report.overlaps.len(), 1, "{}", report);
+    let Overlap { a, b, volume } = &report.overlaps[0];
+    assert_eq!((a.as_str(), b.as_str()), ("row/cube_0", "row/cube_1"));
+    // They share a 2.5 x 10 x 10mm slab.
+    assert!((volume - 250.0).abs() < 1e-6, "{}", volume);
+    assert!(report.near_misses.is_empty());
+    assert!(!report.is_clear());
+    assert!(report.to_string().starts_with("row/cube_0 overlaps row/cube_1 by 250.000 mm³"), "{}", report);
+}
+
+#[test]
+fn separated_cubes_report_their_clearance() {
+    let assembly = row(&[0.0, 10.3, 30.0]);
+    assert!(InterferenceCheck::new().check(&assembly).is_clear(), "only overlaps without a clearance");
+
+    let report = InterferenceCheck::new().with_clearance(0.5).check(&assembly);
+    assert!(report.overlaps.is_empty());
+    assert_eq!(report.near_misses.len(), 1, "{}", report);
+    let NearMiss { a, b, distance } = &report.near_misses[0];
+    assert_eq!((a.as_str(), b.as_str()), ("row/cube_0", "row/cube_1"));
+    assert!((distance - 0.3).abs() < 1e-9, "{}", distance);
+
+    // Cubes sharing a face touch without overlapping.
+    let touching = InterferenceCheck::new().with_clearance(0.5).check(&row(&[0.0, 10.0]));
+    assert!(touching.overlaps.is_empty());
+    assert_eq!(touching.near_misses[0].distance, 0.0);
+    assert_eq!(InterferenceCheck::new().with_clearance(0.2).check(&assembly).to_string(), "no interference\n");
+}
+
+#[test]
+fn allowed_pairs_are_skipped() {
+    let assembly = row(&[0.0, 5.0, 8.0]);
+    assert_eq!(InterferenceCheck::new().check(&assembly).overlaps.len(), 3);
+
+    let check = InterferenceCheck::new().allow("row/cube_0", "row/cube_*");
+    assert!(check.is_allowed("row/cube_2", "row/cube_0"));
+    assert!(!check.is_allowed("row/cube_1", "row/cube_2"));
+    let report = check.check(&assembly);
+    assert_eq!(report.overlaps.len(), 1);
+    assert_eq!((report.overlaps[0].a.as_str(), report.overlaps[0].b.as_str()), ("row/cube_1", "row/cube_2"));
+
+    // A pattern covers everything below it.
+    assert!(InterferenceCheck::new().allow("row", "row").check(&assembly).is_clear());
+}
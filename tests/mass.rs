@@ -0,0 +1,109 @@
+mod common;
+
+use nalgebra::{Matrix3, Point3, Vector3};
+use partsrs::assembly::{Assembly, Transform};
+use partsrs::mass::{self, MassProperties, Specified, DEFAULT_DENSITY};
+use partsrs::material::{Finishing, Material};
+use partsrs::part::Part;
+use partsrs::primitives::Primitives;
+use partsrs::CSG;
+
+use common::Block;
+
+/// A 10 x 20 x 30mm block with a corner on the origin
+fn block() -> Block {
+    Block::new(10.0, 20.0, 30.0)
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9 * b.abs().max(1.0)
+}
+
+fn close_matrix(a: &Matrix3<f64>, b: &Matrix3<f64>) -> bool {
+    (a - b).norm() < 1e-9 * b.norm().max(1.0)
+}
+
+/// Inertia of a `x` by `y` by `z` cuboid of `mass` about its centre
+fn cuboid_inertia(mass: f64, x: f64, y: f64, z: f64) -> Matrix3<f64> {
+    Matrix3::from_diagonal(&Vector3::new(y * y + z * z, x * x + z * z, x * x + y * y)) * mass / 12.0
+}
+
+#[test]
+fn cuboid_matches_closed_form() {
+    let properties = MassProperties::of_solid(&CSG::prism(10.0, 20.0, 30.0), 2.0);
+    assert!(close(properties.volume, 6000.0));
+    // 2 g/cm³ is 0.002 g/mm³.
+    assert!(close(properties.mass, 12.0));
+    assert!(close(properties.density(), 2.0));
+    assert!((properties.centre_of_mass - Point3::new(5.0, 10.0, 15.0)).norm() < 1e-9);
+    assert!(close_matrix(&properties.inertia, &cuboid_inertia(12.0, 10.0, 20.0, 30.0)), "{}", properties.inertia);
+    let moments = properties.principal_moments();
+    assert!(close(moments.x, 500.0) && close(moments.y, 1000.0) && close(moments.z, 1300.0), "{}", moments);
+
+    assert_eq!(MassProperties::of_solid(&CSG::new(), 1.0), MassProperties::default());
+}
+
+#[test]
+fn moving_and_combining_follow_the_parallel_axis_theorem() {
+    let cube = MassProperties::of_solid(&CSG::prism(10.0, 10.0, 10.0), 1.0);
+    let turned = MassProperties::of_solid(&CSG::prism(10.0, 20.0, 30.0), 1.0)
+        .transformed(&Transform::identity().rotate(0.0, 0.0, 90.0));
+    // Turning about Z swaps the X and Y moments and moves the centre.
+    let expected = cuboid_inertia(6.0, 20.0, 10.0, 30.0);
+    assert!(close_matrix(&turned.inertia, &expected), "{}", turned.inertia);
+    assert!((turned.centre_of_mass - Point3::new(-10.0, 5.0, 15.0)).norm() < 1e-9);
+
+    let apart = Transform::translation(Vector3::new(20.0, 0.0, 0.0));
+    let pair = cube.combine(&cube.transformed(&apart));
+    assert!(close(pair.mass, 2.0 * cube.mass) && close(pair.volume, 2000.0));
+    assert!((pair.centre_of_mass - Point3::new(15.0, 5.0, 5.0)).norm() < 1e-9);
+    // Each cube is 10mm from the common centre along X.
+    let offset = Matrix3::from_diagonal(&Vector3::new(0.0, 100.0, 100.0)) * cube.mass;
+    assert!(close_matrix(&pair.inertia, &(2.0 * (cube.inertia + offset))), "{}", pair.inertia);
+}
+
+#[test]
+fn parts_weigh_what_they_are_made_of() {
+    let plastic = block().mass_properties();
+    assert!(close(plastic.density(), DEFAULT_DENSITY));
+    let steel = block().with_material(Material::Steel);
+    assert!(close(mass::surface_density(&steel.assemble()), Material::Steel.density()));
+    assert!(close(steel.mass_properties().mass, 6.0 * Material::Steel.density()));
+
+    // A catalogue mass rescales the inertia with it, and a density is overridden by it.
+    let weighed = Specified::new(block()).with_density(4.0).with_mass(12.0);
+    let properties = weighed.mass_properties();
+    assert!(close(properties.mass, 12.0));
+    assert!(close_matrix(&properties.inertia, &cuboid_inertia(12.0, 10.0, 20.0, 30.0)));
+    assert_eq!(weighed.catalogue_mass(), Some(12.0));
+    assert!(close(Specified::new(block()).with_density(4.0).mass_properties().mass, 24.0));
+}
+
+#[test]
+fn buried_materials_do_not_count() {
+    // A steel core inside a plastic shell leaves only plastic on the surface of the union.
+    let core = CSG::prism(10.0, 10.0, 10.0).translated(Vector3::new(5.0, 5.0, 5.0)).finished(Material::Steel);
+    let part = CSG::prism(20.0, 20.0, 20.0).finished(Material::Plastic).union(&core);
+    assert!(close(mass::surface_density(&part), Material::Plastic.density()));
+    // Once steel covers most of the surface, the whole part weighs as steel.
+    let base = CSG::prism(40.0, 40.0, 5.0).finished(Material::Steel);
+    let proud = CSG::prism(20.0, 20.0, 20.0).finished(Material::Plastic).union(&base);
+    assert!(close(mass::surface_density(&proud), Material::Steel.density()));
+}
+
+#[test]
+fn assemblies_add_up_their_parts() {
+    let mut assembly = Assembly::new("pair");
+    assembly.add_part("plastic", block(), Transform::identity());
+    assembly.add_part(
+        "steel",
+        block().with_material(Material::Steel),
+        Transform::translation(Vector3::new(0.0, 0.0, 30.0)),
+    );
+    let total = assembly.mass_properties();
+    let (light, heavy) = (6.0 * DEFAULT_DENSITY, 6.0 * Material::Steel.density());
+    assert!(close(total.mass, light + heavy));
+    assert!(close(total.volume, 12000.0));
+    let z = (15.0 * light + 45.0 * heavy) / (light + heavy);
+    assert!((total.centre_of_mass - Point3::new(5.0, 10.0, z)).norm() < 1e-9, "{}", total.centre_of_mass);
+}
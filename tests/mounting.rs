@@ -0,0 +1,94 @@
+use nalgebra::{Point3, Vector3};
+use partsrs::fans;
+use partsrs::iec_power_connectors::IECConnector;
+use partsrs::mounting::{Mountable, CUTOUT_DEPTH};
+use partsrs::rocker_switch::RockerSwitch;
+use partsrs::stepper_motors;
+
+fn iec() -> IECConnector {
+    IECConnector {
+        name: "IEC_320_C14".into(),
+        width: 27.0,
+        height: 19.0,
+        depth: 23.0,
+        mount_hole_spacing: 40.0,
+        mount_hole_diameter: 3.5,
+    }
+}
+
+fn rocker() -> RockerSwitch {
+    RockerSwitch { width: 21.0, height: 15.0, depth: 20.0, rocker_width: 13.0, rocker_height: 9.0 }
+}
+
+/// Hole centres and the direction each points out of the part, sorted for comparison
+fn holes(part: &dyn Mountable) -> Vec<(Point3<f64>, Vector3<f64>)> {
+    let mut holes: Vec<_> =
+        part.hole_positions().iter().map(|frame| (frame * Point3::origin(), frame * Vector3::z())).collect();
+    holes.sort_by(|a, b| a.0.x.total_cmp(&b.0.x).then(a.0.y.total_cmp(&b.0.y)));
+    holes
+}
+
+fn close(a: &Point3<f64>, b: &Point3<f64>) -> bool {
+    (a - b).norm() < 1e-9
+}
+
+#[test]
+fn fan_holes_are_a_square_of_its_pitch() {
+    let fan = fans::fan40x11();
+    assert_eq!(fan.hole_diameter(), 3.4);
+    let holes = holes(&fan);
+    let corners = [(-16.0, -16.0), (-16.0, 16.0), (16.0, -16.0), (16.0, 16.0)];
+    assert_eq!(holes.len(), 4);
+    for ((centre, out), (x, y)) in holes.iter().zip(corners) {
+        assert!(close(centre, &Point3::new(x, y, 0.0)), "{}", centre);
+        // Fans are screwed on from below.
+        assert!((out + Vector3::z()).norm() < 1e-9, "{}", out);
+    }
+}
+
+#[test]
+fn stepper_holes_follow_the_nema_pitch() {
+    let motor = stepper_motors::nema17_40();
+    assert_eq!(motor.hole_diameter(), 3.0);
+    let holes = holes(&motor);
+    assert_eq!(holes.len(), 4);
+    for (centre, out) in &holes {
+        // NEMA 17 screws are 31mm apart, in the front face.
+        assert!((centre.x.abs() - 15.5).abs() < 1e-9 && (centre.y.abs() - 15.5).abs() < 1e-9, "{}", centre);
+        assert_eq!(centre.z, motor.body_length);
+        assert!((out - Vector3::z()).norm() < 1e-9, "{}", out);
+    }
+}
+
+#[test]
+fn cutouts_grow_by_the_clearance() {
+    let parts: [&dyn Mountable; 4] = [&fans::fan40x11(), &stepper_motors::nema17_40(), &iec(), &rocker()];
+    for part in parts {
+        let tight = part.panel_cutout(0.0).bounding_box();
+        let loose = part.panel_cutout(0.25).bounding_box();
+        let growth = (loose.maxs - loose.mins) - (tight.maxs - tight.mins);
+        assert!((growth - Vector3::new(0.5, 0.5, 0.0)).norm() < 1e-9, "{}: {}", part.name(), growth);
+        assert!(close(&loose.center(), &tight.center()), "{}", part.name());
+    }
+}
+
+#[test]
+fn sockets_and_switches_have_openings_for_their_bodies() {
+    // The IEC socket has a screw either side of a rectangular opening the body fits through.
+    let iec = iec();
+    let holes = holes(&iec);
+    assert_eq!(holes.len(), 2);
+    assert!(close(&holes[0].0, &Point3::new(-20.0, 0.0, 23.0)) && close(&holes[1].0, &Point3::new(20.0, 0.0, 23.0)));
+    let cutout = iec.panel_cutout(0.5).bounding_box();
+    assert!((cutout.maxs.y - 10.0).abs() < 1e-9 && (cutout.mins.y + 10.0).abs() < 1e-9, "{:?}", cutout);
+    assert!((cutout.maxs.x - 22.25).abs() < 1e-9, "past the screw holes: {:?}", cutout);
+    assert!((cutout.maxs.z - cutout.mins.z - CUTOUT_DEPTH).abs() < 1e-9);
+    assert!(iec.assemble().bounding_box().maxs.z > cutout.mins.z, "reaches the front face");
+
+    // Rocker switches snap in, so there is just a hole the size of the casing.
+    let rocker = rocker();
+    assert!(rocker.hole_positions().is_empty());
+    let cutout = rocker.panel_cutout(0.5).bounding_box();
+    assert!(close(&cutout.mins, &Point3::new(-0.5, -0.5, 20.0 - CUTOUT_DEPTH / 2.0)), "{:?}", cutout);
+    assert!(close(&cutout.maxs, &Point3::new(21.5, 15.5, 20.0 + CUTOUT_DEPTH / 2.0)), "{:?}", cutout);
+}
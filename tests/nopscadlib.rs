@@ -0,0 +1,109 @@
+use partsrs::catalogue::{Catalogue, CatalogueError};
+use partsrs::nopscadlib::{parse, ScadValue};
+use std::fs;
+
+const SCREWS: &str = r#"
+include <../utils/core/core.scad>
+// name, description, head type, size, ...
+M3_cap_screw = ["M3_cap", "M3 cap", hs_cap, 3, 5.5, 3, 0.5];
+M4_cap_screw = ["M4_cap", "M4 cap", hs_cap, 4, 7.0, 4, 0.7];
+"#;
+
+const FANS: &str = r#"
+//               width, depth, bore, hole pitch, screw, hub
+fan80x25 =      [80,    25,    75,   71.5 / 2,   M4_cap_screw, 40];
+fan92x25 =      [92,    25,    88,   82.5 / 2,   M4_cap_screw, 42, /* thickness */ 4.5];
+fans = [fan80x25, fan92x25];
+use <fan.scad>
+"#;
+
+fn number(value: &ScadValue) -> f64 {
+    value.as_number().unwrap_or_else(|| panic!("not a number: {:?}", value))
+}
+
+#[test]
+fn values_are_evaluated() {
+    let text = r#"
+        pitch = 31;  /* NEMA 17 */
+        function half(x) = x / 2;
+        module stepper(type) { cube(type[1]); }
+        stepper(["x"; 1]);
+        sizes = [-4.2, pitch / 2 + 1, 2 * (3 - 1), 7 % 4, inch(1), [1, 2] * 3, -[1, 2]];
+        item = sizes[1];
+        label = "M3 \"cap\"";
+        missing = [half(4), nowhere, false, undef,];
+    "#;
+    let assignments = parse(text).unwrap();
+    let names: Vec<&str> = assignments.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["pitch", "sizes", "item", "label", "missing"]);
+
+    let sizes = &assignments[1].1;
+    let numbers: Vec<f64> = (0..5).map(|i| number(sizes.get(i))).collect();
+    assert_eq!(numbers, [-4.2, 16.5, 4.0, 3.0, 25.4]);
+    assert_eq!(*sizes.get(5), ScadValue::Vector(vec![ScadValue::Number(3.0), ScadValue::Number(6.0)]));
+    assert_eq!(*sizes.get(6), ScadValue::Vector(vec![ScadValue::Number(-1.0), ScadValue::Number(-2.0)]));
+    assert_eq!(number(&assignments[2].1), 16.5);
+    assert_eq!(assignments[3].1.as_text(), Some("M3 \"cap\""));
+    assert_eq!(
+        assignments[4].1,
+        ScadValue::Vector(vec![ScadValue::Undef, ScadValue::Undef, ScadValue::Bool(false), ScadValue::Undef])
+    );
+
+    let error = parse("a = 1;\nb = [1, 2;\n").unwrap_err();
+    assert_eq!(error.line, 2, "{}", error);
+}
+
+#[test]
+fn builtin_extrusions_match_the_data_file() {
+    let mut catalogue = Catalogue::builtin();
+    let added = catalogue.load_scad(include_str!("../data/nopscadlib/extrusions.scad"), "extrusion").unwrap();
+    assert_eq!(added, 3);
+    assert_eq!(catalogue.entries().len(), Catalogue::builtin().entries().len());
+    for name in ["E1515", "E2020", "E2020t"] {
+        let builtin = Catalogue::builtin().find(name).unwrap().part.parameters();
+        assert_eq!(catalogue.find(name).unwrap().part.parameters(), builtin, "{}", name);
+    }
+}
+
+#[test]
+fn fans_use_the_screws_loaded_before_them() {
+    let dir = std::env::temp_dir().join(format!("partsrs-nopscadlib-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("screws.scad"), SCREWS).unwrap();
+    fs::write(dir.join("fans.scad"), FANS).unwrap();
+
+    let mut catalogue = Catalogue::builtin();
+    assert_eq!(catalogue.load_file(&dir.join("screws.scad")).unwrap(), 0);
+    assert_eq!(catalogue.load_file(&dir.join("fans.scad")).unwrap(), 2);
+    let fan = catalogue.find("fan92x25").unwrap();
+    assert_eq!(fan.family, "fans");
+    let parameters = fan.part.parameters();
+    for (name, value) in [("frame_height", 92.0), ("mounting_hole_spacing", 82.5), ("mounting_hole_diameter", 4.5)] {
+        assert!(parameters.contains(&(name, value)), "{} in {:?}", name, parameters);
+    }
+
+    // Without the screws there is nothing to size the holes from.
+    let error = Catalogue::builtin().load_file(&dir.join("fans.scad")).unwrap_err();
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(error.to_string().contains("mounting_hole_diameter"), "{}", error);
+}
+
+#[test]
+fn stepper_motors_are_read_from_their_type_vectors() {
+    let text = r#"
+        //                        side  length radius  body_r boss_r boss_h shaft len      pitch caps    thread
+        NEMA17_40 = ["NEMA17_40", 42.3, 40,    53.6/2, 25,    11,    2,     5,    [24, 2], 31,   [8, 8], 3, false];
+        NEMA17_60 = ["NEMA17_60", 42.3, 60,    53.6/2, 25,    11,    2,     5,    24,      31,   [8, 8], 3, false];
+    "#;
+    let mut catalogue = Catalogue::builtin();
+    assert_eq!(catalogue.load_scad(text, "stepper_motors").unwrap(), 2);
+    let loaded = catalogue.find("NEMA17_40").unwrap().part.parameters();
+    let builtin = Catalogue::builtin().find("NEMA17_40").unwrap().part.parameters();
+    assert_eq!(loaded, builtin);
+    assert!(catalogue.find("NEMA17_60").unwrap().part.parameters().contains(&("body_length", 60.0)));
+
+    let bad = text.replace("[24, 2]", "-24");
+    let error = Catalogue::builtin().load_scad(&bad, "stepper_motors").unwrap_err();
+    assert!(matches!(&error, CatalogueError::Entry { entry, .. } if entry.contains("NEMA17_40")), "{}", error);
+    assert!(matches!(Catalogue::builtin().load_scad(text, "steppers"), Err(CatalogueError::UnknownFamily(_))));
+}
@@ -0,0 +1,75 @@
+use nalgebra::{Point3, Vector3};
+use partsrs::export::Mesh;
+use partsrs::mass::MassProperties;
+use partsrs::pattern;
+use partsrs::primitives::Primitives;
+use partsrs::CSG;
+
+fn volume(solid: &CSG) -> f64 {
+    MassProperties::of_solid(solid, 1.0).volume
+}
+
+fn cube() -> CSG {
+    CSG::prism(1.0, 1.0, 1.0)
+}
+
+#[test]
+fn placements_are_centred() {
+    let row = pattern::linear(3, Vector3::new(2.0, 0.0, 0.0));
+    let xs: Vec<f64> = row.iter().map(|p| p.translation.x).collect();
+    assert_eq!(xs, [-2.0, 0.0, 2.0]);
+
+    let grid = pattern::grid(4, 2, Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 3.0));
+    assert_eq!(grid.len(), 8);
+    assert_eq!(grid[0].translation.vector, Vector3::new(-1.5, 0.0, -1.5));
+    assert_eq!(grid[7].translation.vector, Vector3::new(1.5, 0.0, 1.5));
+
+    let ring = pattern::polar(4, 10.0);
+    let p = ring[1] * Point3::new(1.0, 0.0, 0.0);
+    assert!((p - Point3::new(0.0, 11.0, 0.0)).norm() < 1e-9, "copies face outwards: {}", p);
+
+    // None of anything is no placements, and one is at the origin or on the circle.
+    assert!(pattern::linear(0, Vector3::new(2.0, 0.0, 0.0)).is_empty());
+    assert!(pattern::polar(0, 10.0).is_empty());
+    assert!(pattern::grid(0, 3, Vector3::x(), Vector3::y()).is_empty());
+    assert_eq!(pattern::linear(1, Vector3::new(2.0, 0.0, 0.0))[0].translation.vector, Vector3::zeros());
+    assert_eq!(pattern::polar(1, 10.0)[0].translation.vector, Vector3::new(10.0, 0.0, 0.0));
+    assert!(pattern::place(&cube(), &[]).polygons.is_empty());
+}
+
+#[test]
+fn apart_copies_skip_the_boolean() {
+    let copies = pattern::copies(&cube(), &pattern::linear(5, Vector3::new(2.0, 0.0, 0.0)));
+    let together = pattern::union_all(copies);
+    assert_eq!(together.polygons.len(), 5 * cube().polygons.len());
+    assert!((volume(&together) - 5.0).abs() < 1e-9);
+}
+
+#[test]
+fn meeting_copies_are_merged() {
+    let overlapping = pattern::place(&cube(), &pattern::linear(4, Vector3::new(0.5, 0.0, 0.0)));
+    assert!((volume(&overlapping) - 2.5).abs() < 1e-9);
+    assert!(Mesh::from_csg(&overlapping).is_closed());
+
+    let touching = pattern::place(&cube(), &pattern::grid(3, 3, Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)));
+    assert!((volume(&touching) - 9.0).abs() < 1e-9);
+    assert!(Mesh::from_csg(&touching).is_closed());
+}
+
+#[test]
+fn subtracting_many_tools_matches_one_boolean() {
+    let plate = CSG::prism(30.0, 20.0, 2.0);
+    // Holes right across the plate, some on its edges and some clear of it.
+    let hole = CSG::z_cylinder(0.6, 2.0);
+    let holes = pattern::copies(&hole, &pattern::grid(14, 10, Vector3::new(2.5, 0.0, 0.0), Vector3::new(0.0, 2.5, 0.0)))
+        .into_iter()
+        .map(|h| h.translated(Vector3::new(15.0, 10.0, 0.0)))
+        .chain([hole.translated(Vector3::new(50.0, 0.0, 0.0))])
+        .collect::<Vec<_>>();
+
+    let expected = plate.subtract(&pattern::union_all(holes.clone()));
+    let perforated = pattern::subtract_all(&plate, holes);
+    assert!((volume(&perforated) - volume(&expected)).abs() < 1e-9 * volume(&expected));
+    assert!(Mesh::from_csg(&perforated).is_closed());
+    assert_eq!(perforated.bounding_box(), expected.bounding_box());
+}
@@ -0,0 +1,53 @@
+use nalgebra::{UnitQuaternion, Vector3};
+use partsrs::primitives::Primitives;
+use partsrs::printability::PrintCheck;
+use partsrs::CSG;
+
+fn cube(x: f64, y: f64, z: f64) -> CSG {
+    CSG::prism(x, y, z)
+}
+
+#[test]
+fn a_cube_prints_flat() {
+    let report = PrintCheck::new().check(&cube(20.0, 20.0, 20.0), &UnitQuaternion::identity());
+    assert!(report.is_printable(), "{}", report);
+    assert!((report.first_layer_area - 400.0).abs() < 1e-9);
+    assert_eq!(report.faces(&report.first_layer).polygons.len(), report.first_layer.len());
+}
+
+#[test]
+fn a_tilted_cube_overhangs_and_balances_on_an_edge() {
+    let tilt = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), 60f64.to_radians());
+    let report = PrintCheck::new().check(&cube(20.0, 20.0, 20.0), &tilt);
+    assert_eq!(report.first_layer_area, 0.0);
+    assert_eq!(report.overhangs.len(), 2, "the lower face steeper than 45°");
+    assert!(!report.is_printable());
+    assert!(report.overhangs.iter().all(|&t| report.mesh.normal(t).z < -0.8));
+}
+
+#[test]
+fn long_bridges_are_reported() {
+    let legs = cube(5.0, 5.0, 10.0).union(&cube(5.0, 5.0, 10.0).translated(Vector3::new(35.0, 0.0, 0.0)));
+    let table = legs.union(&cube(40.0, 5.0, 2.0).translated(Vector3::new(0.0, 0.0, 10.0)));
+
+    let report = PrintCheck::new().check(&table, &UnitQuaternion::identity());
+    assert!(report.overhangs.is_empty());
+    assert_eq!(report.bridges.len(), 1, "{}", report);
+    assert!((report.bridges[0].span - 30.0).abs() < 1.0, "between the legs: {}", report.bridges[0].span);
+
+    let report = PrintCheck::new().with_max_bridge(35.0).check(&table, &UnitQuaternion::identity());
+    assert!(report.bridges.is_empty());
+}
+
+#[test]
+fn thin_walls_are_found() {
+    let shell = |wall: f64| {
+        cube(20.0, 20.0, 10.0).subtract(&cube(20.0 - 2.0 * wall, 20.0 - 2.0 * wall, 10.0).translated(Vector3::new(wall, wall, wall)))
+    };
+    let report = PrintCheck::new().check(&shell(0.5), &UnitQuaternion::identity());
+    assert!(!report.thin_walls.is_empty());
+    assert!(report.thin_walls.iter().all(|w| (w.thickness - 0.5).abs() < 1e-6), "{:?}", report.thin_walls);
+
+    let report = PrintCheck::new().check(&shell(2.0), &UnitQuaternion::identity());
+    assert!(report.thin_walls.is_empty(), "{}", report);
+}
@@ -0,0 +1,46 @@
+use partsrs::resolution::{self, Resolution};
+use std::panic;
+use std::thread;
+
+#[test]
+fn fragments_match_openscad() {
+    // `get_fragments_from_r()` with `$fa = 12, $fs = 2`: the angle limit for large circles,
+    // the size limit for middling ones and never fewer than five.
+    let preview = Resolution::PREVIEW;
+    assert_eq!(preview.fragments(10.0), 30);
+    assert_eq!(preview.fragments(5.0), 16);
+    assert_eq!(preview.fragments(1.0), 5);
+    let fine = Resolution::FINE;
+    assert_eq!(fine.fragments(10.0), 60);
+    assert_eq!(fine.fragments(2.0), 26);
+
+    // `$fn` wins whatever the size, but is at least three, as is a circle too small to see.
+    assert_eq!(Resolution::fixed(7).fragments(100.0), 7);
+    assert_eq!(Resolution::fixed(1).fragments(10.0), 3);
+    assert_eq!(Resolution::DEFAULT.fragments(0.0), 3);
+    assert_eq!(Resolution::default(), Resolution::fixed(32));
+}
+
+#[test]
+fn overrides_are_scoped_to_the_thread() {
+    // The only test to touch the global, so the others running alongside can't see it change.
+    resolution::set_global(Resolution::FINE);
+    assert_eq!(resolution::current(), Resolution::FINE);
+
+    let inner = resolution::with(Resolution::PREVIEW, || {
+        let nested = resolution::with(Resolution::fixed(6), resolution::current);
+        assert_eq!(nested, Resolution::fixed(6));
+        // Other threads keep the global.
+        assert_eq!(thread::spawn(resolution::current).join().unwrap(), Resolution::FINE);
+        resolution::current()
+    });
+    assert_eq!(inner, Resolution::PREVIEW);
+    assert_eq!(resolution::current(), Resolution::FINE);
+
+    let panicked = panic::catch_unwind(|| resolution::with(Resolution::PREVIEW, || panic!("while overridden")));
+    assert!(panicked.is_err());
+    assert_eq!(resolution::current(), Resolution::FINE);
+
+    resolution::set_global(Resolution::DEFAULT);
+    assert_eq!(resolution::current(), Resolution::DEFAULT);
+}
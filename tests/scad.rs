@@ -0,0 +1,81 @@
+use nalgebra::Vector3;
+use partsrs::assembly::{Assembly, Transform};
+use partsrs::colour;
+use partsrs::export::{self, ExportObject, Format};
+use partsrs::material::{Finishing, Material};
+use partsrs::nuts::Nut;
+use partsrs::pcb::PCB;
+use partsrs::CSG;
+use std::fs;
+
+fn numbers(text: &str) -> Vec<f64> {
+    text.split(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == 'e'))
+        .filter_map(|n| n.parse().ok())
+        .collect()
+}
+
+#[test]
+fn faces_wind_clockwise_from_outside() {
+    let cube = ExportObject { name: "cube".to_string(), colour: None, solid: CSG::prism(1.0, 1.0, 1.0) };
+    let source = export::scad(&[cube], "cube");
+    assert!(source.contains("\ncube();\n"));
+    assert!(source.contains("module cube_2() {"), "the object doesn't clash with the top module");
+
+    let line = |start: &str| source.lines().find(|l| l.trim_start().starts_with(start)).unwrap().to_string();
+    let points: Vec<Vector3<f64>> =
+        numbers(&line("points")).chunks(3).map(|p| Vector3::new(p[0], p[1], p[2])).collect();
+    let faces = line("polyhedron");
+    let faces: Vec<usize> = numbers(&faces[faces.find(", [").unwrap()..]).iter().map(|&i| i as usize).collect();
+    assert_eq!(faces.len(), 12 * 3);
+    for face in faces.chunks(3) {
+        let [a, b, c] = [points[face[0]], points[face[1]], points[face[2]]];
+        let inwards = (b - a).cross(&(c - a));
+        let centre = (a + b + c) / 3.0 - Vector3::repeat(0.5);
+        assert!(inwards.dot(&centre) < 0.0, "face {:?} winds anticlockwise", face);
+    }
+}
+
+#[test]
+fn keywords_and_builtins_are_not_module_names() {
+    let object = |name: &str| ExportObject { name: name.to_string(), colour: None, solid: CSG::prism(1.0, 1.0, 1.0) };
+    let source = export::scad(&[object("polyhedron"), object("if")], "module");
+    assert!(source.contains("\nmodule_2();\n"), "{}", source);
+    assert!(source.contains("module module_2() {\n    polyhedron_2();\n    if_2();\n}"), "{}", source);
+    assert!(source.contains("// polyhedron\nmodule polyhedron_2() {"));
+    assert!(!source.contains("module polyhedron()") && !source.contains("module if()"));
+    // The object still draws with the builtin rather than calling itself.
+    assert_eq!(source.matches("    polyhedron(points, [").count(), 2);
+}
+
+#[test]
+fn each_leaf_is_a_module() {
+    let nut = Nut::new("M3 nut", 6.4, 2.4, 2.5).unwrap();
+    let mut assembly = Assembly::new("bracket");
+    let board = PCB { width: 20.0, height: 10.0, thickness: 1.6, hole_diameter: 3.0, hole_spacing: 14.0 };
+    assembly.add_part("board", board, Transform::identity());
+    assembly.add_part("nut 1", nut.clone(), Transform::translation(Vector3::new(5.0, 5.0, 2.0))).set_colour(colour::GOLD);
+    assembly.add_part("nut 2", nut, Transform::translation(Vector3::new(15.0, 5.0, 2.0)));
+
+    let path = std::env::temp_dir().join(format!("partsrs-scad-{}.scad", std::process::id()));
+    assert_eq!(Format::from_path(&path), Some(Format::Scad));
+    export::write_assembly(&assembly, &path).unwrap();
+    let source = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let stem = path.file_stem().unwrap().to_str().unwrap().replace('-', "_");
+    assert!(source.contains(&format!("module {}() {{\n    board();\n    nut_1();\n    nut_2();\n}}", stem)));
+    assert!(source.contains("// nut 1\nmodule nut_1() {"));
+    assert_eq!(source.matches("color([").count(), 3, "one colour for each leaf's only finish");
+    assert!(!source.contains("$preview"));
+}
+
+#[test]
+fn several_finishes_render_as_one_solid() {
+    let plate = CSG::prism(10.0, 10.0, 1.0).finished(Material::Aluminium);
+    let pin = CSG::prism(2.0, 2.0, 4.0).finished(Material::Brass);
+    let object = ExportObject { name: "pinned".to_string(), colour: None, solid: plate.union(&pin) };
+    let source = export::scad(&[object], "pinned_plate");
+    assert!(source.contains("    if ($preview) {\n"));
+    assert_eq!(source.matches("color([").count(), 2);
+    assert_eq!(source.matches("polyhedron(").count(), 3);
+}
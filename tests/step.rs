@@ -0,0 +1,133 @@
+use csgrs::enums::Axis;
+use nalgebra::{Point3, Vector3};
+use partsrs::assembly::{Assembly, Transform};
+use partsrs::colour;
+use partsrs::export::{self, ExportObject, Format};
+use partsrs::nuts::Nut;
+use partsrs::pcb::PCB;
+use partsrs::CSG;
+use std::collections::HashMap;
+use std::fs;
+
+/// The entities of a STEP file by number, checking each is defined once and every reference
+/// is to one that exists
+fn entities(text: &str) -> HashMap<usize, String> {
+    assert!(text.starts_with("ISO-10303-21;\nHEADER;"));
+    assert!(text.contains("FILE_SCHEMA(('AUTOMOTIVE_DESIGN { 1 0 10303 214 1 1 1 1 }'));"));
+    assert!(text.ends_with("ENDSEC;\nEND-ISO-10303-21;\n"));
+    let mut entities = HashMap::new();
+    for line in text.lines().filter(|l| l.starts_with('#')) {
+        let (id, entity) = line.split_once('=').unwrap();
+        assert!(entity.ends_with(';'), "{}", line);
+        let previous = entities.insert(id[1..].parse::<usize>().unwrap(), entity.to_string());
+        assert!(previous.is_none(), "{} defined twice", id);
+    }
+    for entity in entities.values() {
+        for reference in entity.split('#').skip(1) {
+            let id: String = reference.chars().take_while(char::is_ascii_digit).collect();
+            assert!(entities.contains_key(&id.parse().unwrap()), "#{} is not defined: {}", id, entity);
+        }
+    }
+    entities
+}
+
+fn count(entities: &HashMap<usize, String>, kind: &str) -> usize {
+    entities.values().filter(|e| e.starts_with(kind)).count()
+}
+
+fn nut() -> Nut {
+    Nut::new("M3 nut", 6.4, 2.4, 2.5).unwrap()
+}
+
+#[test]
+fn a_part_is_one_faceted_brep() {
+    let cube = ExportObject { name: "cube".to_string(), colour: Some(colour::GOLD), solid: CSG::prism(1.0, 1.0, 1.0) };
+    let text = export::step_objects(&[cube], "cube").unwrap();
+    let entities = entities(&text);
+    assert_eq!(count(&entities, "FACETED_BREP("), 1);
+    assert_eq!(count(&entities, "FACE_SURFACE("), 12);
+    assert_eq!(count(&entities, "CARTESIAN_POINT("), 8 + 12 + 1, "vertices, face planes and origin");
+    assert_eq!(count(&entities, "NEXT_ASSEMBLY_USAGE_OCCURRENCE("), 0);
+    assert_eq!(count(&entities, "STYLED_ITEM("), 1, "one colour for the whole solid");
+    assert!(text.contains("COLOUR_RGB('',1.,0.84,0.)"));
+    assert!(entities.values().any(|e| e.starts_with("PRODUCT('cube','cube'")));
+}
+
+#[test]
+fn assemblies_keep_their_tree() {
+    let mut fixings = Assembly::new("fixings");
+    fixings.add_part("nut 1", nut(), Transform::translation(Vector3::new(5.0, 5.0, 2.0)));
+    fixings.add_part("nut 2", nut(), Transform::translation(Vector3::new(15.0, 5.0, 2.0)));
+    fixings.add_part("nut 3", nut(), Transform::translation(Vector3::new(25.0, 5.0, 2.0))).set_colour(colour::GOLD);
+
+    let mut machine = Assembly::new("machine");
+    let board = PCB { width: 30.0, height: 10.0, thickness: 1.6, hole_diameter: 3.0, hole_spacing: 20.0 };
+    machine.add_part("board", board, Transform::identity());
+    machine.add_assembly("fixings", fixings, Transform::translation(Vector3::new(0.0, 0.0, 1.6)));
+    // Off centre, as a nut is symmetric and would be the same shape mirrored.
+    let mirrored = Transform::translation(Vector3::new(3.0, 0.0, 0.0)).mirror(Axis::X);
+    machine.add_part("mirrored", nut(), mirrored);
+
+    let path = std::env::temp_dir().join(format!("partsrs-step-{}.stp", std::process::id()));
+    assert_eq!(Format::from_path(&path), Some(Format::Step));
+    export::write_assembly(&machine, &path).unwrap();
+    let text = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    let entities = entities(&text);
+
+    // The nuts share a product, as gold doesn't override their own finish, apart from the
+    // mirrored one.
+    assert_eq!(count(&entities, "FACETED_BREP("), 3);
+    assert_eq!(count(&entities, "NEXT_ASSEMBLY_USAGE_OCCURRENCE("), 6);
+    assert_eq!(count(&entities, "PRODUCT("), 5, "machine, fixings, board and two nuts");
+    for name in ["'nut 1'", "'nut 3'", "'fixings'", "'mirrored'"] {
+        assert!(
+            entities.values().any(|e| e.starts_with("NEXT_ASSEMBLY_USAGE_OCCURRENCE(") && e.contains(name)),
+            "no occurrence {}",
+            name
+        );
+    }
+    assert!(entities.values().any(|e| e.starts_with("PRODUCT('fixings'")));
+    assert!(entities.values().any(|e| e == "CARTESIAN_POINT('',(15.,5.,2.));"), "nut 2 placed in the fixings");
+    assert!(entities.values().any(|e| e == "CARTESIAN_POINT('',(0.,0.,1.6));"), "fixings placed on the board");
+    assert!(count(&entities, "COLOUR_RGB(") > 0);
+    assert!(!text.contains("COLOUR_RGB('',1.,0.84,0.)"), "gold is unused");
+    assert_eq!(count(&entities, "MECHANICAL_DESIGN_GEOMETRIC_PRESENTATION_REPRESENTATION("), 1);
+}
+
+#[test]
+fn names_are_escaped() {
+    let object = ExportObject { name: "Bob's µ-rail".to_string(), colour: None, solid: CSG::prism(1.0, 2.0, 3.0) };
+    let text = export::step_objects(&[object.clone(), object], "rails").unwrap();
+    let entities = entities(&text);
+    assert!(text.contains("PRODUCT('Bob''s \\X2\\00B5\\X0\\-rail'"), "{}", text);
+    assert_eq!(count(&entities, "FACETED_BREP("), 1, "identical objects are instanced");
+    assert_eq!(count(&entities, "NEXT_ASSEMBLY_USAGE_OCCURRENCE("), 2);
+}
+
+#[test]
+fn non_finite_geometry_is_rejected() {
+    let mut solid = CSG::prism(1.0, 1.0, 1.0);
+    solid.polygons[0].vertices[0].pos.x = f64::NAN;
+    let object = ExportObject { name: "broken".to_string(), colour: None, solid };
+    let error = export::step_objects(&[object], "broken").unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(error.to_string().contains("broken"), "{}", error);
+
+    // A NaN vertex would weld onto the corner at the origin and be lost from the mesh.
+    let mut solid = CSG::prism(1.0, 1.0, 1.0);
+    let last = solid.polygons.last_mut().unwrap();
+    let vertex = last.vertices.iter_mut().find(|v| v.pos.coords.norm() > 0.0).unwrap();
+    vertex.pos = Point3::new(f64::NAN, 0.0, 0.0);
+    assert!(solid.polygons.iter().flat_map(|p| &p.vertices).any(|v| v.pos == Point3::origin()));
+    let object = ExportObject { name: "welded".to_string(), colour: None, solid };
+    assert_eq!(export::step_objects(&[object], "welded").unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+
+    let mut assembly = Assembly::new("stretched");
+    let infinite = Transform::identity().translate(Vector3::new(f64::INFINITY, 0.0, 0.0));
+    assembly.add_part("nut", nut(), infinite);
+    assert!(export::step(&assembly, "stretched").is_err());
+    let path = std::env::temp_dir().join(format!("partsrs-non-finite-{}.step", std::process::id()));
+    assert!(export::write_assembly(&assembly, &path).is_err());
+    assert!(!path.exists());
+}
@@ -0,0 +1,91 @@
+use nalgebra::Vector3;
+use partsrs::error::PartError;
+use partsrs::export::Mesh;
+use partsrs::mass::{MassProperties, Specified};
+use partsrs::part::Part;
+use partsrs::printed_box::PrintedBox;
+use partsrs::text::{HAlign, Labelled, Text, VAlign};
+use partsrs::CSG;
+
+fn volume(solid: &CSG) -> f64 {
+    MassProperties::of_solid(solid, 1.0).volume
+}
+
+fn extent(text: &Text) -> (f64, f64, f64, f64) {
+    text.strokes().iter().flatten().fold(
+        (f64::MAX, f64::MIN, f64::MAX, f64::MIN),
+        |(x0, x1, y0, y1), p| (x0.min(p.x), x1.max(p.x), y0.min(p.y), y1.max(p.y)),
+    )
+}
+
+fn enclosure() -> PrintedBox {
+    PrintedBox { width: 100.0, height: 60.0, depth: 80.0, wall_thickness: 2.0 }
+}
+
+#[test]
+fn layout_follows_alignment() {
+    let (x0, x1, y0, y1) = extent(&Text::new("HE", 6.0));
+    assert_eq!((x0, x1, y0, y1), (0.0, 10.0, 0.0, 6.0));
+
+    let centred = Text::new("HE", 6.0).with_halign(HAlign::Center).with_valign(VAlign::Center);
+    assert_eq!(extent(&centred), (-5.0, 5.0, -3.0, 3.0));
+    let top_right = Text::new("HE\nHE", 3.0).with_halign(HAlign::Right).with_valign(VAlign::Top);
+    assert_eq!(extent(&top_right), (-5.0, 0.0, -8.0, 0.0));
+
+    // Lower case is drawn as capitals and missing characters as `?`.
+    assert_eq!(Text::new("mains", 5.0).strokes(), Text::new("MAINS", 5.0).strokes());
+    assert_eq!(Text::new("\u{2603}", 5.0).strokes(), Text::new("?", 5.0).strokes());
+}
+
+#[test]
+fn text_is_a_closed_solid() {
+    let text = Text::new("MAINS: 230V", 5.0).with_stroke(0.8).extrude(0.6);
+    assert!(Mesh::from_csg(&text).is_closed());
+    let bounds = text.bounding_box();
+    assert!((bounds.maxs.z - 0.6).abs() < 1e-9 && bounds.mins.z.abs() < 1e-9);
+    assert!((bounds.maxs.y - 5.4).abs() < 1e-9, "caps plus half a stroke: {}", bounds.maxs.y);
+}
+
+#[test]
+fn labels_are_added_and_cut() {
+    let plain = enclosure().assemble();
+    let text = Text::new("PSU", 8.0).with_halign(HAlign::Center).with_valign(VAlign::Center);
+
+    let embossed = Labelled::new(enclosure()).emboss("top", text.clone(), 0.5).unwrap().assemble();
+    assert!(Mesh::from_csg(&embossed).is_closed());
+    assert!(volume(&embossed) > volume(&plain));
+    assert!((embossed.bounding_box().maxs.z - 80.5).abs() < 1e-9);
+    let proud = Labelled::new(enclosure()).emboss("top", text.clone(), 0.5).unwrap().bounding_box();
+    assert!((proud.mins - embossed.bounding_box().mins).norm() < 1e-9, "{:?}", proud);
+    assert!((proud.maxs - embossed.bounding_box().maxs).norm() < 1e-9, "{:?}", proud);
+
+    let labelled = Labelled::new(enclosure()).deboss("front", text, 0.5).unwrap();
+    let debossed = labelled.assemble();
+    assert!(Mesh::from_csg(&debossed).is_closed());
+    assert!(volume(&debossed) < volume(&plain));
+    assert_eq!(debossed.bounding_box(), plain.bounding_box());
+    assert_eq!(labelled.bounding_box(), plain.bounding_box());
+    // Upright on the front, so the text runs along X and up Z.
+    let cut = labelled.labels[0].solid().bounding_box();
+    assert!((cut.center().coords - Vector3::new(50.0, 0.25, 40.0)).norm() < 0.1, "{:?}", cut);
+    assert!(cut.extents().x > cut.extents().z);
+}
+
+#[test]
+fn bad_labels_are_rejected() {
+    let unknown = Labelled::new(enclosure()).emboss("lid", Text::new("A", 5.0), 0.5);
+    assert!(matches!(unknown, Err(PartError::UnknownAnchor { .. })));
+    let flat = Labelled::new(enclosure()).deboss("top", Text::new("A", 5.0), 0.0);
+    assert!(matches!(flat, Err(PartError::InvalidValue { .. })));
+    let tiny = Labelled::new(enclosure()).deboss("top", Text::new("A", -1.0), 0.5);
+    assert!(matches!(tiny, Err(PartError::InvalidValue { .. })));
+}
+
+#[test]
+fn labels_keep_the_mass_of_their_part() {
+    let weighed = Specified::new(enclosure()).with_mass(250.0);
+    let labelled = Labelled::new(weighed);
+    assert_eq!(labelled.catalogue_mass(), Some(250.0));
+    assert_eq!(labelled.mass_properties().mass, 250.0);
+    assert_eq!(labelled.mass_properties(), labelled.part.mass_properties());
+}
@@ -0,0 +1,64 @@
+use partsrs::rod::Rod;
+use partsrs::shaft_coupling::ShaftCoupling;
+use partsrs::tolerance::{Fit, ToleranceClass, ToleranceError};
+
+fn limits(class: &str, nominal: f64) -> (f64, f64) {
+    let l = class.parse::<ToleranceClass>().unwrap().limits(nominal).unwrap();
+    (((l.min - nominal) * 1000.0).round(), ((l.max - nominal) * 1000.0).round())
+}
+
+#[test]
+fn limits_match_the_iso_286_tables() {
+    assert_eq!(limits("H7", 25.0), (0.0, 21.0));
+    assert_eq!(limits("g6", 25.0), (-20.0, -7.0));
+    assert_eq!(limits("g6", 10.0), (-14.0, -5.0));
+    assert_eq!(limits("p6", 8.0), (15.0, 24.0));
+    assert_eq!(limits("k6", 50.0), (2.0, 18.0));
+    assert_eq!(limits("h6", 3.0), (-6.0, 0.0));
+    assert_eq!(limits("F8", 12.0), (16.0, 43.0));
+    assert_eq!(limits("js7", 40.0), (-13.0, 13.0));
+}
+
+#[test]
+fn fits_give_clearance_or_interference() {
+    let sliding = Fit::SLIDING.clearance(10.0).unwrap();
+    assert!((sliding.min - 0.005).abs() < 1e-9 && (sliding.max - 0.029).abs() < 1e-9);
+
+    let press = Fit::PRESS.clearance(10.0).unwrap();
+    assert!(press.max < 1e-9 && press.min < -0.02, "never a clearance: {:?}", press);
+
+    assert_eq!("H7/g6".parse::<Fit>().unwrap(), Fit::SLIDING);
+    assert_eq!(Fit::SLIDING.to_string(), "H7/g6");
+}
+
+#[test]
+fn bad_classes_are_rejected() {
+    assert_eq!("H7g6".parse::<Fit>(), Err(ToleranceError::Syntax("H7g6".into())));
+    assert_eq!("g6/H7".parse::<Fit>(), Err(ToleranceError::Syntax("g6/H7".into())));
+    assert_eq!("P7/h6".parse::<Fit>(), Err(ToleranceError::UnknownDeviation("P".into())));
+    assert_eq!("H3/h6".parse::<Fit>(), Err(ToleranceError::UnknownGrade(3)));
+    // Classes are only made by parsing, which wants one or two letters of the same case.
+    assert_eq!("Js7".parse::<ToleranceClass>(), Err(ToleranceError::Syntax("Js7".into())));
+    assert_eq!("7".parse::<ToleranceClass>(), Err(ToleranceError::Syntax("7".into())));
+    assert_eq!("JS7".parse::<ToleranceClass>().unwrap().letters(), "JS");
+    assert_eq!(Fit::SLIP.hole_limits(600.0), Err(ToleranceError::SizeOutOfRange(600.0)));
+}
+
+#[test]
+fn printed_holes_are_opened_up() {
+    let fit = Fit::printed(0.2, 0.15);
+    assert!((fit.hole_diameter(5.0) - 5.35).abs() < 1e-9);
+    assert_eq!(fit.shaft_diameter(5.0), 5.0);
+    let gap = fit.clearance(5.0).unwrap();
+    assert!((gap.min - 0.2).abs() < 1e-9 && (gap.max - 0.2).abs() < 1e-9);
+}
+
+#[test]
+fn matching_parts_are_sized_by_their_fit() {
+    let coupling = ShaftCoupling { outer_diameter: 19.0, length: 25.0, bore_diameter: 5.0, bore_fit: Fit::SLIDING };
+    let rod = Rod { diameter: 5.0, length: 50.0, fit: Fit::SLIDING };
+    let bore = coupling.bore().bounding_box();
+    let shaft = rod.body().bounding_box();
+    assert!(bore.maxs.x > 2.5 && shaft.maxs.x < 2.5);
+    assert!(bore.maxs.x - shaft.maxs.x > 0.002);
+}
@@ -0,0 +1,130 @@
+use partsrs::ball_bearing::BallBearing;
+use partsrs::catalogue::CatalogueError;
+use partsrs::circlip::{Circlip, CirclipState};
+use partsrs::error::PartError;
+use partsrs::export::Mesh;
+use partsrs::fans::{all_fans, Fan};
+use partsrs::mass::MassProperties;
+use partsrs::nuts::Nut;
+use partsrs::opengrab::OpenGrabMagnet;
+use partsrs::stepper_motors::all_stepper_motors;
+use partsrs::tolerance::{Fit, ToleranceError};
+use partsrs::washers::Washer;
+
+fn inconsistent(part: &str, reason: &str) -> PartError {
+    PartError::Inconsistent { part: part.into(), reason: reason.into() }
+}
+
+#[test]
+fn nuts_and_washers_need_room_for_their_hole() {
+    assert!(Nut::new("M3", 6.4, 2.4, 3.0).is_ok());
+    assert_eq!(Nut::new("M3", 6.4, 2.4, 6.0).unwrap_err(), inconsistent("M3", "the hole breaks through the flats"));
+    assert_eq!(
+        Nut::new("M3", 6.4, -2.4, 3.0).unwrap_err(),
+        PartError::InvalidValue { parameter: "thickness".into(), value: -2.4 }
+    );
+    assert_eq!(Washer::new("M3", 7.0, 7.0, 0.5).unwrap_err(), inconsistent("M3", "the hole is wider than the washer"));
+}
+
+#[test]
+fn fans_need_blades() {
+    let builder = Fan::builder("fan40x11").with_frame(40.0, 40.0, 11.0).with_hub(25.0).with_mounting_holes(3.4, 32.0);
+    assert!(builder.clone().with_blades(37.5, 7).build().is_ok());
+    assert_eq!(
+        builder.clone().with_blades(37.5, 0).build().unwrap_err(),
+        PartError::InvalidValue { parameter: "blade_count".into(), value: 0.0 }
+    );
+    assert_eq!(
+        builder.with_blades(41.0, 7).build().unwrap_err(),
+        inconsistent("fan40x11", "the blades are bigger than the frame")
+    );
+    assert!(Fan::builder("fan").build().is_err(), "nothing set");
+
+    let mut bladeless = all_fans().remove(0);
+    bladeless.blade_count = 0;
+    assert!(bladeless.blades().polygons.is_empty());
+}
+
+#[test]
+fn catalogue_parts_are_valid() {
+    for fan in all_fans() {
+        fan.validate().unwrap();
+    }
+    for motor in all_stepper_motors() {
+        motor.validate().unwrap();
+    }
+}
+
+#[test]
+fn circlip_states_pick_the_ring_diameter() {
+    let circlip = Circlip::new(8.0, 8.4, 8.7, 0.8, 2.2, 1.1, 1.0, None).unwrap();
+    assert_eq!(circlip.diameter(CirclipState::Nominal), 8.0);
+    assert_eq!(circlip.diameter(CirclipState::Installed), 8.4);
+    assert_eq!(circlip.diameter(CirclipState::Relaxed), 8.7);
+    assert_eq!(CirclipState::default(), CirclipState::Installed);
+
+    // Every state is a whole ring the width of the taper, not just the lugs.
+    for state in [CirclipState::Nominal, CirclipState::Installed, CirclipState::Relaxed] {
+        let ring = circlip.ring(state);
+        assert!(Mesh::from_csg(&ring).is_closed(), "{:?}", state);
+        let volume = MassProperties::of_solid(&ring, 1.0).volume;
+        let (outer, inner) = (circlip.diameter(state) / 2.0, circlip.diameter(state) / 2.0 - 1.1);
+        let annulus = std::f64::consts::PI * (outer * outer - inner * inner) * 0.8;
+        assert!(volume > 0.95 * annulus && volume <= annulus, "{:?}: {} of {}", state, volume, annulus);
+    }
+
+    let error = Circlip::new(8.0, 8.4, 8.7, 0.8, 2.2, 1.1, 1.5, None).unwrap_err();
+    assert_eq!(error, inconsistent("Circlip", "the plier holes are bigger than the lugs"));
+    let error = Circlip::new(8.0, 7.6, 8.7, 0.8, 2.2, 1.1, 1.0, None).unwrap_err();
+    assert_eq!(error, inconsistent("Circlip", "the groove is no wider than the tube"));
+    let error = Circlip::new(8.0, 8.4, 8.2, 0.8, 2.2, 1.1, 1.0, None).unwrap_err();
+    assert_eq!(error, inconsistent("Circlip", "the relaxed ring is smaller than its groove"));
+    let error = Circlip::new(8.0, 8.4, 8.7, 0.8, 2.2, 4.0, 1.0, None).unwrap_err();
+    assert_eq!(error, inconsistent("Circlip", "the ring is wider than its radius"));
+}
+
+#[test]
+fn bores_are_checked_with_their_fit() {
+    assert!(BallBearing::new(22.0, 8.0, 7.0, Fit::SLIDING).is_ok());
+    assert_eq!(
+        BallBearing::new(22.0, 21.95, 7.0, Fit::printed(0.2, 0.0)).unwrap_err(),
+        inconsistent("BallBearing", "the bore is wider than the bearing")
+    );
+
+    let error = BallBearing::new(700.0, 600.0, 50.0, Fit::SLIP).unwrap_err();
+    assert_eq!(error, PartError::Fit { part: "BallBearing".into(), source: ToleranceError::SizeOutOfRange(600.0) });
+    assert!(std::error::Error::source(&error).is_some());
+    assert_eq!(
+        CatalogueError::from(error),
+        CatalogueError::Inconsistent {
+            part: "BallBearing".into(),
+            reason: "600mm is outside the 0 to 500mm of ISO 286".into()
+        }
+    );
+}
+
+#[test]
+fn magnet_poles_fit_across_the_base() {
+    assert!(OpenGrabMagnet::new(37.0, 37.0, 5.0, 4.0, 18.0, 4).is_ok());
+    assert_eq!(
+        OpenGrabMagnet::new(37.0, 37.0, 5.0, 4.0, 18.0, 0).unwrap_err(),
+        PartError::InvalidValue { parameter: "poles".into(), value: 0.0 }
+    );
+    assert_eq!(
+        OpenGrabMagnet::new(37.0, 37.0, 5.0, 10.0, 18.0, 4).unwrap_err(),
+        inconsistent("OpenGrabMagnet", "the poles are wider than the base")
+    );
+
+    // A single pole has no gap to divide the spare width by.
+    let single = OpenGrabMagnet::new(37.0, 37.0, 5.0, 4.0, 18.0, 1).unwrap();
+    let bounds = single.poles().bounding_box();
+    assert!(bounds.mins.iter().chain(bounds.maxs.iter()).all(|c| c.is_finite()), "{:?}", bounds);
+}
+
+#[test]
+fn unknown_anchors_keep_their_meaning_in_the_catalogue() {
+    let error = PartError::UnknownAnchor { part: "Nut".into(), anchor: "shaft".into() };
+    let error = CatalogueError::from(error);
+    assert_eq!(error, CatalogueError::UnknownAnchor { part: "Nut".into(), anchor: "shaft".into() });
+    assert_eq!(error.to_string(), "`Nut` has no anchor `shaft`");
+}